@@ -153,10 +153,12 @@ impl BinanceMarketStream {
     }
 
     async fn subscribe_kline(&mut self, symbol: String, interval: Interval) -> BrokerResult<()> {
-        let params =
-            KlineCandlestickStreamsParams::builder(symbol.to_lowercase(), interval_label(interval))
-                .build()
-                .map_err(|err| BrokerError::InvalidRequest(err.to_string()))?;
+        let params = KlineCandlestickStreamsParams::builder(
+            symbol.to_lowercase(),
+            interval_label(interval)?,
+        )
+        .build()
+        .map_err(|err| BrokerError::InvalidRequest(err.to_string()))?;
         let stream = self
             .ws
             .kline_candlestick_streams(params)
@@ -391,7 +393,7 @@ impl DiffDepthTask {
             .ok_or_else(|| BrokerError::Serialization("invalid snapshot asks".into()))?;
         self.book.load_snapshot(&bids, &asks);
         self.last_update_id = snapshot.last_update_id;
-        self.emit_snapshot(Utc::now()).await;
+        self.emit_book(Utc::now(), None).await;
         Ok(())
     }
 
@@ -439,8 +441,12 @@ impl DiffDepthTask {
             .map_err(BrokerError::Serialization)?;
         self.apply_levels(&event.asks, Side::Sell)
             .map_err(BrokerError::Serialization)?;
-        self.last_update_id = event.final_update_id;
-        self.emit_book(event.event_time).await;
+        let prev_update_id = std::mem::replace(&mut self.last_update_id, event.final_update_id);
+        self.emit_book(
+            timestamp_from_ms(Some(event.event_time)),
+            Some(prev_update_id),
+        )
+        .await;
         Ok(())
     }
 
@@ -459,20 +465,18 @@ impl DiffDepthTask {
         Ok(())
     }
 
-    async fn emit_snapshot(&self, ts: DateTime<Utc>) {
-        if let Some(book) = self.build_book(ts) {
+    /// Publish the local book; `prev_update_id` is `None` right after a snapshot sync.
+    async fn emit_book(&self, timestamp: DateTime<Utc>, prev_update_id: Option<u64>) {
+        if let Some(book) = self.build_book(timestamp, prev_update_id) {
             let _ = self.tx.send(book).await;
         }
     }
 
-    async fn emit_book(&self, event_time: i64) {
-        let timestamp = timestamp_from_ms(Some(event_time));
-        if let Some(book) = self.build_book(timestamp) {
-            let _ = self.tx.send(book).await;
-        }
-    }
-
-    fn build_book(&self, timestamp: DateTime<Utc>) -> Option<OrderBook> {
+    fn build_book(
+        &self,
+        timestamp: DateTime<Utc>,
+        prev_update_id: Option<u64>,
+    ) -> Option<OrderBook> {
         if self.book.is_empty() {
             return None;
         }
@@ -495,6 +499,8 @@ impl DiffDepthTask {
             timestamp,
             exchange_checksum: None,
             local_checksum: Some(self.book.checksum(self.depth)),
+            sequence: Some(self.last_update_id),
+            prev_sequence: prev_update_id,
         })
     }
 }
@@ -588,8 +594,10 @@ pub fn extract_order_update(event: &UserDataStreamEventsResponse) -> Option<&Ord
     }
 }
 
-fn interval_label(interval: Interval) -> String {
-    interval.to_binance().to_string()
+fn interval_label(interval: Interval) -> BrokerResult<String> {
+    interval.to_binance().map(str::to_string).ok_or_else(|| {
+        BrokerError::InvalidRequest(format!("Binance does not stream {interval} klines"))
+    })
 }
 
 #[cfg(test)]
@@ -28,8 +28,8 @@ type HmacSha256 = Hmac<Sha256>;
 
 use tesser_broker::{BrokerError, BrokerErrorKind, BrokerInfo, BrokerResult, MarketStream};
 use tesser_core::{
-    AssetId, Candle, ExchangeId, Fill, Interval, LocalOrderBook, Order, OrderBook, OrderBookLevel,
-    OrderRequest, OrderType, Side, Symbol, Tick,
+    AssetId, Candle, ExchangeId, Fill, Interval, IntervalUnit, LocalOrderBook, Order, OrderBook,
+    OrderBookLevel, OrderRequest, OrderType, Side, Symbol, Tick,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -78,16 +78,19 @@ pub enum BybitSubscription {
 }
 
 impl BybitSubscription {
-    fn topic(&self) -> String {
-        match self {
+    fn topic(&self) -> BrokerResult<String> {
+        Ok(match self {
             Self::Kline { symbol, interval } => {
-                format!("kline.{}.{}", interval.to_bybit(), symbol)
+                let code = interval.to_bybit().ok_or_else(|| {
+                    BrokerError::InvalidRequest(format!("Bybit does not stream {interval} klines"))
+                })?;
+                format!("kline.{code}.{symbol}")
             }
             Self::Trades { symbol } => format!("publicTrade.{symbol}"),
             Self::OrderBook { symbol, depth } => {
                 format!("orderbook.{depth}.{symbol}")
             }
-        }
+        })
     }
 }
 
@@ -248,7 +251,7 @@ impl MarketStream for BybitMarketStream {
     }
 
     async fn subscribe(&mut self, subscription: Self::Subscription) -> BrokerResult<()> {
-        let topic = subscription.topic();
+        let topic = subscription.topic()?;
         self.command_tx
             .send(WsCommand::Subscribe(topic.clone()))
             .map_err(|err| BrokerError::from_display(err, BrokerErrorKind::Transport))?;
@@ -603,13 +606,10 @@ fn build_candle(exchange: ExchangeId, topic: &str, entry: &KlineEntry) -> Option
 
 fn parse_interval(value: &str) -> Option<Interval> {
     match value {
-        "1" => Some(Interval::OneMinute),
-        "5" => Some(Interval::FiveMinutes),
-        "15" => Some(Interval::FifteenMinutes),
-        "60" => Some(Interval::OneHour),
-        "240" => Some(Interval::FourHours),
         "D" | "d" => Some(Interval::OneDay),
-        _ => None,
+        "W" | "w" => Interval::new(1, IntervalUnit::Week).ok(),
+        "M" => Interval::new(1, IntervalUnit::Month).ok(),
+        minutes => Interval::new(minutes.parse().ok()?, IntervalUnit::Minute).ok(),
     }
 }
 
@@ -803,7 +803,7 @@ impl SymbolBook {
         self.last_seq = Some(data.sequence());
         self.synced = true;
         let mut updates = Vec::new();
-        if let Some(book) = self.snapshot(ts) {
+        if let Some(book) = self.snapshot(ts, None) {
             updates.push(book);
         }
         let pending = std::mem::take(&mut self.pending);
@@ -857,16 +857,16 @@ impl SymbolBook {
             self.book
                 .apply_delta(Side::Sell, level.price, level.quantity);
         }
-        self.last_seq = Some(delta.seq);
+        let prev_seq = self.last_seq.replace(delta.seq);
 
-        if let Some(book) = self.snapshot(delta.ts) {
+        if let Some(book) = self.snapshot(delta.ts, prev_seq) {
             ApplyOutcome::Updates(vec![book])
         } else {
             ApplyOutcome::Updates(Vec::new())
         }
     }
 
-    fn snapshot(&self, ts: i64) -> Option<OrderBook> {
+    fn snapshot(&self, ts: i64, prev_seq: Option<i64>) -> Option<OrderBook> {
         if self.book.is_empty() {
             return None;
         }
@@ -890,6 +890,8 @@ impl SymbolBook {
             timestamp,
             exchange_checksum: self.last_checksum,
             local_checksum: Some(self.book.checksum(self.depth)),
+            sequence: self.last_seq.and_then(|seq| u64::try_from(seq).ok()),
+            prev_sequence: prev_seq.and_then(|seq| u64::try_from(seq).ok()),
         })
     }
 
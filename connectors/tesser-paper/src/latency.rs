@@ -0,0 +1,267 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::Duration as ChronoDuration;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand_distr::{Distribution, LogNormal};
+
+/// Distribution sampled for every simulated network hop.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LatencyDistribution {
+    /// Always the same delay.
+    Constant(ChronoDuration),
+    /// Uniformly distributed between `min` and `max` (inclusive).
+    Uniform {
+        min: ChronoDuration,
+        max: ChronoDuration,
+    },
+    /// Log-normal delay with the provided median and shape (`sigma` of the underlying normal).
+    LogNormal { median: ChronoDuration, sigma: f64 },
+    /// Resample observed delays, e.g. `received_at - exchange_timestamp` from the flight recorder.
+    Empirical(Arc<[ChronoDuration]>),
+}
+
+impl Default for LatencyDistribution {
+    fn default() -> Self {
+        Self::Constant(ChronoDuration::zero())
+    }
+}
+
+impl LatencyDistribution {
+    /// Build an empirical distribution from observed samples, discarding negative delays.
+    pub fn empirical(samples: impl IntoIterator<Item = ChronoDuration>) -> Option<Self> {
+        let samples: Vec<ChronoDuration> = samples
+            .into_iter()
+            .filter(|sample| *sample >= ChronoDuration::zero())
+            .collect();
+        (!samples.is_empty()).then(|| Self::Empirical(samples.into()))
+    }
+
+    /// Draw a single non-negative delay.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ChronoDuration {
+        let sampled = match self {
+            Self::Constant(value) => *value,
+            Self::Uniform { min, max } => {
+                let lo = nanos(*min).min(nanos(*max));
+                let hi = nanos(*min).max(nanos(*max));
+                ChronoDuration::nanoseconds(rng.gen_range(lo..=hi))
+            }
+            Self::LogNormal { median, sigma } => {
+                let median_ns = nanos(*median).max(1) as f64;
+                match LogNormal::new(median_ns.ln(), sigma.abs()) {
+                    Ok(dist) => {
+                        let value: f64 = dist.sample(rng);
+                        ChronoDuration::nanoseconds(value.min(i64::MAX as f64) as i64)
+                    }
+                    Err(_) => *median,
+                }
+            }
+            Self::Empirical(samples) => samples[rng.gen_range(0..samples.len())],
+        };
+        sampled.max(ChronoDuration::zero())
+    }
+}
+
+impl FromStr for LatencyDistribution {
+    type Err = String;
+
+    /// Parses `5`, `constant:5`, `uniform:2:8` or `lognormal:5:0.4` (values in milliseconds).
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let mut parts = value.split(':');
+        let head = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let args: Vec<&str> = parts.collect();
+        let expect_args = |count: usize| {
+            if args.len() == count {
+                Ok(())
+            } else {
+                Err(format!(
+                    "latency spec '{value}' expects {count} argument(s) after '{head}'"
+                ))
+            }
+        };
+        match head.as_str() {
+            "constant" | "const" | "fixed" => {
+                expect_args(1)?;
+                Ok(Self::Constant(parse_millis(args[0])?))
+            }
+            "uniform" => {
+                expect_args(2)?;
+                Ok(Self::Uniform {
+                    min: parse_millis(args[0])?,
+                    max: parse_millis(args[1])?,
+                })
+            }
+            "lognormal" | "log-normal" => {
+                expect_args(2)?;
+                let sigma = args[1]
+                    .parse::<f64>()
+                    .map_err(|err| format!("invalid lognormal sigma '{}': {err}", args[1]))?;
+                if !sigma.is_finite() || sigma < 0.0 {
+                    return Err(format!("lognormal sigma must be >= 0 (got {sigma})"));
+                }
+                Ok(Self::LogNormal {
+                    median: parse_millis(args[0])?,
+                    sigma,
+                })
+            }
+            "empirical" => Err("empirical latency must be built from recorded samples".into()),
+            _ if args.is_empty() => Ok(Self::Constant(parse_millis(&head)?)),
+            other => Err(format!("unknown latency distribution '{other}'")),
+        }
+    }
+}
+
+/// Latency assumptions applied to each leg of the simulated round trip.
+#[derive(Clone, Debug, Default)]
+pub struct LatencyModel {
+    /// Delay between the exchange timestamp of an event and the strategy observing it.
+    pub market_data: LatencyDistribution,
+    /// Delay before a new order reaches the book.
+    pub place: LatencyDistribution,
+    /// Delay before a cancel request takes effect.
+    pub cancel: LatencyDistribution,
+    /// Delay before an amend request takes effect.
+    pub amend: LatencyDistribution,
+    /// Seed for the sampler so runs are reproducible.
+    pub seed: u64,
+}
+
+impl LatencyModel {
+    /// Fixed order-entry latency with instantaneous market data.
+    pub fn constant(latency: ChronoDuration) -> Self {
+        let latency = latency.max(ChronoDuration::zero());
+        Self {
+            market_data: LatencyDistribution::default(),
+            place: LatencyDistribution::Constant(latency),
+            cancel: LatencyDistribution::Constant(latency),
+            amend: LatencyDistribution::Constant(latency),
+            seed: 0,
+        }
+    }
+}
+
+impl From<ChronoDuration> for LatencyModel {
+    fn from(latency: ChronoDuration) -> Self {
+        Self::constant(latency)
+    }
+}
+
+/// Leg of the round trip being sampled.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum LatencyKind {
+    MarketData,
+    Place,
+    Cancel,
+    Amend,
+}
+
+/// Seeded sampler shared by the matching engine.
+pub(crate) struct LatencySampler {
+    model: LatencyModel,
+    rng: StdRng,
+}
+
+impl LatencySampler {
+    pub(crate) fn new(model: LatencyModel) -> Self {
+        let rng = StdRng::seed_from_u64(model.seed);
+        Self { model, rng }
+    }
+
+    pub(crate) fn sample(&mut self, kind: LatencyKind) -> ChronoDuration {
+        let distribution = match kind {
+            LatencyKind::MarketData => &self.model.market_data,
+            LatencyKind::Place => &self.model.place,
+            LatencyKind::Cancel => &self.model.cancel,
+            LatencyKind::Amend => &self.model.amend,
+        };
+        distribution.sample(&mut self.rng)
+    }
+}
+
+fn nanos(value: ChronoDuration) -> i64 {
+    value.num_nanoseconds().unwrap_or(i64::MAX).max(0)
+}
+
+fn parse_millis(value: &str) -> Result<ChronoDuration, String> {
+    let millis = value
+        .trim()
+        .parse::<f64>()
+        .map_err(|err| format!("invalid latency '{value}': {err}"))?;
+    if !millis.is_finite() || millis < 0.0 {
+        return Err(format!(
+            "latency must be a non-negative number (got {value})"
+        ));
+    }
+    Ok(ChronoDuration::nanoseconds((millis * 1_000_000.0) as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_latency_specs() {
+        assert_eq!(
+            "5".parse::<LatencyDistribution>().unwrap(),
+            LatencyDistribution::Constant(ChronoDuration::milliseconds(5))
+        );
+        assert_eq!(
+            "uniform:2:8".parse::<LatencyDistribution>().unwrap(),
+            LatencyDistribution::Uniform {
+                min: ChronoDuration::milliseconds(2),
+                max: ChronoDuration::milliseconds(8),
+            }
+        );
+        assert_eq!(
+            "lognormal:0.5:0.25".parse::<LatencyDistribution>().unwrap(),
+            LatencyDistribution::LogNormal {
+                median: ChronoDuration::microseconds(500),
+                sigma: 0.25,
+            }
+        );
+        assert!("uniform:2".parse::<LatencyDistribution>().is_err());
+        assert!("empirical:ticks".parse::<LatencyDistribution>().is_err());
+    }
+
+    #[test]
+    fn seeded_sampler_is_reproducible() {
+        let model = LatencyModel {
+            market_data: "lognormal:3:0.5".parse().unwrap(),
+            place: "uniform:1:10".parse().unwrap(),
+            cancel: LatencyDistribution::empirical([
+                ChronoDuration::milliseconds(4),
+                ChronoDuration::milliseconds(-1),
+                ChronoDuration::milliseconds(9),
+            ])
+            .unwrap(),
+            amend: LatencyDistribution::default(),
+            seed: 42,
+        };
+        let draw = |model: &LatencyModel| {
+            let mut sampler = LatencySampler::new(model.clone());
+            (0..32)
+                .flat_map(|_| {
+                    [
+                        sampler.sample(LatencyKind::MarketData),
+                        sampler.sample(LatencyKind::Place),
+                        sampler.sample(LatencyKind::Cancel),
+                    ]
+                })
+                .collect::<Vec<_>>()
+        };
+        let first = draw(&model);
+        assert_eq!(first, draw(&model));
+        for chunk in first.chunks(3) {
+            assert!(chunk[0] >= ChronoDuration::zero());
+            assert!(
+                chunk[1] >= ChronoDuration::milliseconds(1)
+                    && chunk[1] <= ChronoDuration::milliseconds(10)
+            );
+            assert!(
+                chunk[2] == ChronoDuration::milliseconds(4)
+                    || chunk[2] == ChronoDuration::milliseconds(9)
+            );
+        }
+    }
+}
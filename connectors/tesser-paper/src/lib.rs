@@ -2,6 +2,7 @@
 
 mod conditional;
 mod fees;
+mod latency;
 
 use std::{
     any::Any,
@@ -31,8 +32,8 @@ use tesser_broker::{
 };
 use tesser_core::{
     AccountBalance, AssetId, Candle, DepthUpdate, Fill, Instrument, Interval, LocalOrderBook,
-    Order, OrderBook, OrderId, OrderRequest, OrderStatus, OrderType, OrderUpdateRequest, Position,
-    Price, Quantity, Side, Symbol, Tick, TimeInForce,
+    MarketByOrderAction, MarketByOrderUpdate, Order, OrderBook, OrderId, OrderRequest, OrderStatus,
+    OrderType, OrderUpdateRequest, Position, Price, Quantity, Side, Symbol, Tick, TimeInForce,
 };
 use tokio::task::JoinHandle;
 use tokio::{
@@ -44,6 +45,9 @@ use tracing::{error, info, warn};
 use uuid::Uuid;
 
 pub use fees::{FeeContext, FeeModel, FeeScheduleConfig, LiquidityRole, MarketFeeConfig};
+pub use latency::{LatencyDistribution, LatencyModel};
+
+use latency::{LatencyKind, LatencySampler};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(tag = "mode", rename_all = "snake_case")]
@@ -699,12 +703,12 @@ impl ExecutionClient for MatchingEngine {
                     Ok(order)
                 } else {
                     let now = self.simulated_now();
-                    let activation_time = self.activation_deadline(now);
-                    if self.latency <= ChronoDuration::zero() {
+                    let activation_time = self.action_deadline(LatencyKind::Place, now);
+                    if activation_time <= now {
                         order.status = OrderStatus::Accepted;
                         order.updated_at = now;
                     }
-                    self.record_resting_order(order.clone(), limit_price, activation_time)
+                    self.record_resting_order(order.clone(), limit_price, now, activation_time)
                         .await;
                     Ok(order)
                 }
@@ -727,7 +731,9 @@ impl ExecutionClient for MatchingEngine {
     async fn cancel_order(&self, order_id: OrderId, _symbol: Symbol) -> BrokerResult<()> {
         let mut open = self.open_orders.lock().await;
         if let Some(resting) = open.get_mut(&order_id) {
-            if self.latency <= ChronoDuration::zero() {
+            let now = self.simulated_now();
+            let deadline = self.action_deadline(LatencyKind::Cancel, now);
+            if deadline <= now {
                 let resting = open.remove(&order_id).unwrap();
                 drop(open);
                 if resting.active && resting.remaining > Decimal::ZERO {
@@ -736,8 +742,7 @@ impl ExecutionClient for MatchingEngine {
                 }
                 return Ok(());
             }
-            let now = self.simulated_now();
-            resting.cancel_after = Some(self.activation_deadline(now));
+            resting.cancel_after = Some(deadline);
             return Ok(());
         }
         Err(BrokerError::InvalidRequest(format!(
@@ -761,41 +766,23 @@ impl ExecutionClient for MatchingEngine {
                 "side mismatch for amend".into(),
             ));
         }
-        if resting.active && resting.remaining > Decimal::ZERO {
-            let mut book = self.resting_depth.lock().unwrap();
-            book.remove_order(resting.order.request.side, resting.price, resting.remaining);
-        }
-        if let Some(price) = request.new_price {
-            resting.price = price;
-            resting.order.request.price = Some(price);
-        }
-        if let Some(quantity) = request.new_quantity {
-            if quantity < resting.order.filled_quantity {
-                return Err(BrokerError::InvalidRequest(
-                    "new quantity below filled amount".into(),
-                ));
-            }
-            resting.order.request.quantity = quantity;
-            resting.remaining = (quantity - resting.order.filled_quantity).max(Decimal::ZERO);
-            resting.original_quantity = quantity;
-        } else {
-            resting.remaining =
-                (resting.order.request.quantity - resting.order.filled_quantity).max(Decimal::ZERO);
+        if request
+            .new_quantity
+            .is_some_and(|quantity| quantity < resting.order.filled_quantity)
+        {
+            return Err(BrokerError::InvalidRequest(
+                "new quantity below filled amount".into(),
+            ));
         }
-        if resting.remaining <= Decimal::ZERO {
-            resting.order.status = OrderStatus::Filled;
-        } else if resting.order.filled_quantity > Decimal::ZERO {
-            resting.order.status = OrderStatus::PartiallyFilled;
+        let now = self.simulated_now();
+        let effective_at = self.action_deadline(LatencyKind::Amend, now);
+        if effective_at <= now {
+            self.apply_amend(resting, &request, now)?;
         } else {
-            resting.order.status = OrderStatus::Accepted;
-        }
-        resting.order.updated_at = Utc::now();
-        if resting.active && resting.remaining > Decimal::ZERO {
-            resting.initial_queue_position =
-                self.queue_ahead_for(resting.order.request.side, resting.price, Decimal::ZERO);
-            resting.processed_volume = Decimal::ZERO;
-            let mut book = self.resting_depth.lock().unwrap();
-            book.add_order(resting.order.request.side, resting.price, resting.remaining);
+            resting.pending_amends.push(PendingAmend {
+                effective_at,
+                request,
+            });
         }
         Ok(resting.order.clone())
     }
@@ -835,20 +822,97 @@ struct RestingOrder {
     cancel_after: Option<DateTime<Utc>>,
     active: bool,
     original_quantity: Quantity,
+    /// L3 priority counter observed when the order joined the queue.
+    queue_priority: u64,
+    /// Amends sent to the venue that have not taken effect yet.
+    pending_amends: Vec<PendingAmend>,
+}
+
+/// Amend request still in flight to the simulated venue.
+#[derive(Clone)]
+struct PendingAmend {
+    effective_at: DateTime<Utc>,
+    request: OrderUpdateRequest,
 }
 
 /// Controls how aggressively the matching engine assumes we improve queue position.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Default)]
 pub enum QueueModel {
+    /// Join behind all visible depth and only advance on trade prints.
     #[default]
     Conservative,
+    /// Assume we are at the front of every level.
     Optimistic,
+    /// Join behind visible depth, then treat depth decreases not explained by trade prints
+    /// as cancellations spread proportionally across the queue.
+    DepthInferred,
+    /// Track the exact set of orders ahead using market-by-order (L3) events.
+    MarketByOrder,
+}
+
+impl QueueModel {
+    fn tracks_departures(self) -> bool {
+        matches!(self, Self::DepthInferred | Self::MarketByOrder)
+    }
+}
+
+/// Order resting in the replayed L3 book.
+#[derive(Clone, Copy)]
+struct MarketOrderEntry {
+    side: Side,
+    price: Price,
+    size: Quantity,
+    priority: u64,
+}
+
+/// Per-order view of the market reconstructed from market-by-order events.
+#[derive(Default)]
+struct MarketOrderBook {
+    orders: HashMap<String, MarketOrderEntry>,
+    next_priority: u64,
+}
+
+impl MarketOrderBook {
+    fn allocate_priority(&mut self) -> u64 {
+        let priority = self.next_priority;
+        self.next_priority += 1;
+        priority
+    }
+}
+
+/// How long a trade print may wait for the depth update that reflects it.
+const TRADE_RECONCILE_WINDOW_MS: i64 = 1_000;
+
+/// Trade volume printed at a level that no depth decrease has accounted for yet.
+#[derive(Clone, Copy)]
+struct UnreconciledTrades {
+    quantity: Quantity,
+    last_trade: DateTime<Utc>,
+}
+
+/// Queue-ahead reduction derived from book events, applied on the next clock advance.
+#[derive(Clone, Copy)]
+enum QueueAdjustment {
+    /// Volume cancelled somewhere in a level of `level_before`; each order is credited its share.
+    Proportional {
+        side: Side,
+        price: Price,
+        cancelled: Quantity,
+        level_before: Quantity,
+    },
+    /// A specific L3 order with `priority` left (or lost its place in) the queue.
+    Departure {
+        side: Side,
+        price: Price,
+        priority: u64,
+        quantity: Quantity,
+    },
 }
 
 /// Configuration block wiring latency and queue modeling assumptions.
 #[derive(Clone)]
 pub struct MatchingEngineConfig {
-    pub latency: ChronoDuration,
+    pub latency: LatencyModel,
     pub queue_model: QueueModel,
     pub fee_model: Arc<dyn FeeModel>,
     pub cash_asset: Option<AssetId>,
@@ -857,7 +921,7 @@ pub struct MatchingEngineConfig {
 impl Default for MatchingEngineConfig {
     fn default() -> Self {
         Self {
-            latency: ChronoDuration::zero(),
+            latency: LatencyModel::default(),
             queue_model: QueueModel::default(),
             fee_model: FeeScheduleConfig::default().build_model(),
             cash_asset: None,
@@ -883,10 +947,14 @@ pub struct MatchingEngine {
     open_orders: Arc<AsyncMutex<HashMap<OrderId, RestingOrder>>>,
     fills: Arc<AsyncMutex<Vec<Fill>>>,
     conditional_orders: Arc<AsyncMutex<ConditionalOrderManager>>,
-    latency: ChronoDuration,
+    latency: Arc<Mutex<LatencySampler>>,
+    market_data_delay: Arc<Mutex<ChronoDuration>>,
     queue_model: QueueModel,
     clock: Arc<Mutex<Option<DateTime<Utc>>>>,
     queue_reset: Arc<AtomicBool>,
+    market_orders: Arc<Mutex<MarketOrderBook>>,
+    unreconciled_trades: Arc<Mutex<HashMap<(Side, Price), UnreconciledTrades>>>,
+    queue_adjustments: Arc<Mutex<Vec<QueueAdjustment>>>,
     fee_model: Arc<dyn FeeModel>,
     cash_asset: Arc<Mutex<AssetId>>,
 }
@@ -905,11 +973,6 @@ impl MatchingEngine {
         config: MatchingEngineConfig,
     ) -> Self {
         let now = Utc::now();
-        let latency = if config.latency < ChronoDuration::zero() {
-            ChronoDuration::zero()
-        } else {
-            config.latency
-        };
         let cash_asset = config.cash_asset.unwrap_or_else(|| AssetId::from("USDT"));
         let broker_markets: Vec<String> = markets
             .iter()
@@ -934,10 +997,14 @@ impl MatchingEngine {
             open_orders: Arc::new(AsyncMutex::new(HashMap::new())),
             fills: Arc::new(AsyncMutex::new(Vec::new())),
             conditional_orders: Arc::new(AsyncMutex::new(ConditionalOrderManager::new())),
-            latency,
+            latency: Arc::new(Mutex::new(LatencySampler::new(config.latency))),
+            market_data_delay: Arc::new(Mutex::new(ChronoDuration::zero())),
             queue_model: config.queue_model,
             clock: Arc::new(Mutex::new(None)),
             queue_reset: Arc::new(AtomicBool::new(false)),
+            market_orders: Arc::new(Mutex::new(MarketOrderBook::default())),
+            unreconciled_trades: Arc::new(Mutex::new(HashMap::new())),
+            queue_adjustments: Arc::new(Mutex::new(Vec::new())),
             fee_model: config.fee_model.clone(),
             cash_asset: Arc::new(Mutex::new(cash_asset)),
         }
@@ -957,15 +1024,163 @@ impl MatchingEngine {
             .map(|level| (level.price, level.size))
             .collect();
         depth.load_snapshot(&bids, &asks);
+        drop(depth);
+        self.unreconciled_trades.lock().unwrap().clear();
         self.queue_reset.store(true, Ordering::Relaxed);
     }
 
     /// Apply an incremental depth update.
     pub fn upsert_market_level(&self, side: Side, price: Price, quantity: Quantity) {
-        let mut depth = self.market_depth.lock().unwrap();
-        depth.clear_level(side, price);
-        if quantity > Decimal::ZERO {
-            depth.add_order(side, price, quantity);
+        let before = {
+            let mut depth = self.market_depth.lock().unwrap();
+            let before = depth.volume_at_level(side, price);
+            depth.clear_level(side, price);
+            if quantity > Decimal::ZERO {
+                depth.add_order(side, price, quantity);
+            }
+            before
+        };
+        if self.queue_model == QueueModel::DepthInferred && quantity < before {
+            self.infer_cancellations(side, price, before, before - quantity);
+        }
+    }
+
+    /// Split a level decrease into traded and cancelled volume and queue the cancelled share.
+    fn infer_cancellations(&self, side: Side, price: Price, before: Quantity, decrease: Quantity) {
+        let horizon =
+            self.simulated_now() - ChronoDuration::milliseconds(TRADE_RECONCILE_WINDOW_MS);
+        let explained = {
+            let mut trades = self.unreconciled_trades.lock().unwrap();
+            // Prints older than the window are never credited against a later decrease.
+            match trades
+                .remove(&(side, price))
+                .filter(|pending| pending.last_trade >= horizon)
+            {
+                Some(pending) => {
+                    let explained = decrease.min(pending.quantity);
+                    if pending.quantity > explained {
+                        trades.insert(
+                            (side, price),
+                            UnreconciledTrades {
+                                quantity: pending.quantity - explained,
+                                last_trade: pending.last_trade,
+                            },
+                        );
+                    }
+                    explained
+                }
+                None => Decimal::ZERO,
+            }
+        };
+        let cancelled = decrease - explained;
+        if cancelled > Decimal::ZERO {
+            self.push_queue_adjustment(QueueAdjustment::Proportional {
+                side,
+                price,
+                cancelled,
+                level_before: before - explained,
+            });
+        }
+    }
+
+    fn push_queue_adjustment(&self, adjustment: QueueAdjustment) {
+        self.queue_adjustments.lock().unwrap().push(adjustment);
+    }
+
+    /// Apply a market-by-order (L3) event to the replayed book.
+    ///
+    /// The aggregated depth is kept in sync with the per-order view. When running with
+    /// [`QueueModel::MarketByOrder`], cancels, size reductions and priority resets of orders
+    /// queued ahead of ours shrink our queue position. Fills do not: trade prints fed through
+    /// [`process_trade`](Self::process_trade) already advance the queue.
+    pub fn apply_market_by_order(&self, update: &MarketByOrderUpdate) {
+        let departure = {
+            let mut orders = self.market_orders.lock().unwrap();
+            let mut depth = self.market_depth.lock().unwrap();
+            let existing = orders.orders.get(&update.order_id).copied();
+            match (update.action, existing) {
+                (MarketByOrderAction::Add, None) | (MarketByOrderAction::Modify, None) => {
+                    if update.size > Decimal::ZERO {
+                        let priority = orders.allocate_priority();
+                        orders.orders.insert(
+                            update.order_id.clone(),
+                            MarketOrderEntry {
+                                side: update.side,
+                                price: update.price,
+                                size: update.size,
+                                priority,
+                            },
+                        );
+                        depth.add_order(update.side, update.price, update.size);
+                    }
+                    None
+                }
+                (MarketByOrderAction::Add, Some(entry))
+                | (MarketByOrderAction::Modify, Some(entry)) => {
+                    depth.remove_order(entry.side, entry.price, entry.size);
+                    let loses_priority = entry.side != update.side
+                        || entry.price != update.price
+                        || update.size > entry.size;
+                    let departed = if loses_priority {
+                        entry.size
+                    } else {
+                        entry.size - update.size
+                    };
+                    if update.size > Decimal::ZERO {
+                        let priority = if loses_priority {
+                            orders.allocate_priority()
+                        } else {
+                            entry.priority
+                        };
+                        orders.orders.insert(
+                            update.order_id.clone(),
+                            MarketOrderEntry {
+                                side: update.side,
+                                price: update.price,
+                                size: update.size,
+                                priority,
+                            },
+                        );
+                        depth.add_order(update.side, update.price, update.size);
+                    } else {
+                        orders.orders.remove(&update.order_id);
+                    }
+                    (departed > Decimal::ZERO).then_some(QueueAdjustment::Departure {
+                        side: entry.side,
+                        price: entry.price,
+                        priority: entry.priority,
+                        quantity: departed,
+                    })
+                }
+                (MarketByOrderAction::Cancel, Some(entry)) => {
+                    depth.remove_order(entry.side, entry.price, entry.size);
+                    orders.orders.remove(&update.order_id);
+                    Some(QueueAdjustment::Departure {
+                        side: entry.side,
+                        price: entry.price,
+                        priority: entry.priority,
+                        quantity: entry.size,
+                    })
+                }
+                (MarketByOrderAction::Fill, Some(entry)) => {
+                    let remaining = update.size.max(Decimal::ZERO).min(entry.size);
+                    depth.remove_order(entry.side, entry.price, entry.size - remaining);
+                    if remaining > Decimal::ZERO {
+                        if let Some(stored) = orders.orders.get_mut(&update.order_id) {
+                            stored.size = remaining;
+                        }
+                    } else {
+                        orders.orders.remove(&update.order_id);
+                    }
+                    None
+                }
+                (MarketByOrderAction::Cancel, None) | (MarketByOrderAction::Fill, None) => None,
+            }
+        };
+        if self.queue_model == QueueModel::MarketByOrder {
+            if let Some(adjustment) = departure {
+                self.push_queue_adjustment(adjustment);
+            }
         }
     }
 
@@ -1006,8 +1221,14 @@ impl MatchingEngine {
         guard.unwrap_or_else(Utc::now)
     }
 
-    fn activation_deadline(&self, now: DateTime<Utc>) -> DateTime<Utc> {
-        now + self.latency
+    /// Time at which an action decided at `now` reaches the venue.
+    ///
+    /// The strategy only saw the latest event after the market-data delay, so that delay is
+    /// added on top of the order-entry leg.
+    fn action_deadline(&self, kind: LatencyKind, now: DateTime<Utc>) -> DateTime<Utc> {
+        let observed = *self.market_data_delay.lock().unwrap();
+        let leg = self.latency.lock().unwrap().sample(kind);
+        now + observed + leg
     }
 
     fn queue_ahead_for(&self, side: Side, price: Price, exclude_self: Quantity) -> Quantity {
@@ -1027,16 +1248,24 @@ impl MatchingEngine {
     }
 
     pub async fn advance_time(&self, now: DateTime<Utc>) {
-        {
+        let advanced = {
             let mut guard = self.clock.lock().unwrap();
-            if guard.is_none_or(|prev| now > prev) {
+            let advanced = guard.is_none_or(|prev| now > prev);
+            if advanced {
                 *guard = Some(now);
             }
+            advanced
+        };
+        if advanced {
+            let delay = self.latency.lock().unwrap().sample(LatencyKind::MarketData);
+            *self.market_data_delay.lock().unwrap() = delay;
         }
+        self.apply_queue_adjustments().await;
         if self.queue_reset.swap(false, Ordering::Relaxed) {
             self.reseed_queue_positions().await;
         }
         self.activate_due(now).await;
+        self.apply_due_amends(now).await;
         self.finalize_cancels(now).await;
     }
 
@@ -1051,9 +1280,7 @@ impl MatchingEngine {
                 resting.active = true;
                 resting.order.status = OrderStatus::Accepted;
                 resting.order.updated_at = now;
-                resting.initial_queue_position =
-                    self.queue_ahead_for(resting.order.request.side, resting.price, Decimal::ZERO);
-                resting.processed_volume = Decimal::ZERO;
+                self.seed_queue_position(resting);
                 if resting.remaining > Decimal::ZERO {
                     additions.push((resting.order.request.side, resting.price, resting.remaining));
                 }
@@ -1107,13 +1334,147 @@ impl MatchingEngine {
             if !resting.active || resting.remaining <= Decimal::ZERO {
                 continue;
             }
-            let ahead =
+            let mut ahead =
                 self.queue_ahead_for(resting.order.request.side, resting.price, resting.remaining);
+            if self.queue_model.tracks_departures() {
+                // Snapshots cannot move us backwards in the queue; only clamp to what is visible.
+                ahead = ahead.min(Self::queue_remaining_ahead(resting));
+            }
             resting.initial_queue_position = ahead;
             resting.processed_volume = Decimal::ZERO;
         }
     }
 
+    /// Reset queue tracking for an order that just joined the back of its level.
+    fn seed_queue_position(&self, resting: &mut RestingOrder) {
+        resting.initial_queue_position =
+            self.queue_ahead_for(resting.order.request.side, resting.price, Decimal::ZERO);
+        resting.processed_volume = Decimal::ZERO;
+        resting.queue_priority = self.market_orders.lock().unwrap().next_priority;
+    }
+
+    fn queue_remaining_ahead(resting: &RestingOrder) -> Quantity {
+        (resting.initial_queue_position - resting.processed_volume).max(Decimal::ZERO)
+    }
+
+    fn shrink_queue_ahead(resting: &mut RestingOrder, amount: Quantity) {
+        let cut = amount.min(Self::queue_remaining_ahead(resting));
+        resting.initial_queue_position -= cut;
+    }
+
+    async fn apply_queue_adjustments(&self) {
+        let adjustments = std::mem::take(&mut *self.queue_adjustments.lock().unwrap());
+        if adjustments.is_empty() {
+            return;
+        }
+        let mut open = self.open_orders.lock().await;
+        for adjustment in adjustments {
+            for resting in open.values_mut() {
+                if !resting.active || resting.remaining <= Decimal::ZERO {
+                    continue;
+                }
+                match adjustment {
+                    QueueAdjustment::Proportional {
+                        side,
+                        price,
+                        cancelled,
+                        level_before,
+                    } => {
+                        if resting.order.request.side != side
+                            || resting.price != price
+                            || level_before <= Decimal::ZERO
+                        {
+                            continue;
+                        }
+                        let ahead = Self::queue_remaining_ahead(resting).min(level_before);
+                        let share = cancelled * ahead / level_before;
+                        Self::shrink_queue_ahead(resting, share);
+                    }
+                    QueueAdjustment::Departure {
+                        side,
+                        price,
+                        priority,
+                        quantity,
+                    } => {
+                        if resting.order.request.side != side
+                            || resting.price != price
+                            || priority >= resting.queue_priority
+                        {
+                            continue;
+                        }
+                        Self::shrink_queue_ahead(resting, quantity);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Apply an amend to a resting order, re-queuing it at the back of its (new) level.
+    fn apply_amend(
+        &self,
+        resting: &mut RestingOrder,
+        request: &OrderUpdateRequest,
+        now: DateTime<Utc>,
+    ) -> BrokerResult<()> {
+        if request
+            .new_quantity
+            .is_some_and(|quantity| quantity < resting.order.filled_quantity)
+        {
+            return Err(BrokerError::InvalidRequest(
+                "new quantity below filled amount".into(),
+            ));
+        }
+        if resting.active && resting.remaining > Decimal::ZERO {
+            let mut book = self.resting_depth.lock().unwrap();
+            book.remove_order(resting.order.request.side, resting.price, resting.remaining);
+        }
+        if let Some(price) = request.new_price {
+            resting.price = price;
+            resting.order.request.price = Some(price);
+        }
+        if let Some(quantity) = request.new_quantity {
+            resting.order.request.quantity = quantity;
+            resting.remaining = (quantity - resting.order.filled_quantity).max(Decimal::ZERO);
+            resting.original_quantity = quantity;
+        } else {
+            resting.remaining =
+                (resting.order.request.quantity - resting.order.filled_quantity).max(Decimal::ZERO);
+        }
+        if resting.remaining <= Decimal::ZERO {
+            resting.order.status = OrderStatus::Filled;
+        } else if resting.order.filled_quantity > Decimal::ZERO {
+            resting.order.status = OrderStatus::PartiallyFilled;
+        } else {
+            resting.order.status = OrderStatus::Accepted;
+        }
+        resting.order.updated_at = now;
+        if resting.active && resting.remaining > Decimal::ZERO {
+            self.seed_queue_position(resting);
+            let mut book = self.resting_depth.lock().unwrap();
+            book.add_order(resting.order.request.side, resting.price, resting.remaining);
+        }
+        Ok(())
+    }
+
+    async fn apply_due_amends(&self, now: DateTime<Utc>) {
+        let mut open = self.open_orders.lock().await;
+        for resting in open.values_mut() {
+            if resting.pending_amends.is_empty() {
+                continue;
+            }
+            let pending = std::mem::take(&mut resting.pending_amends);
+            for amend in pending {
+                if amend.effective_at > now {
+                    resting.pending_amends.push(amend);
+                    continue;
+                }
+                if let Err(err) = self.apply_amend(resting, &amend.request, amend.effective_at) {
+                    warn!(order_id = %resting.order.id, error = %err, "deferred amend rejected");
+                }
+            }
+        }
+    }
+
     fn passive_fillable_volume(
         &self,
         resting: &mut RestingOrder,
@@ -1289,6 +1650,21 @@ impl MatchingEngine {
             return generated;
         }
 
+        if self.queue_model == QueueModel::DepthInferred {
+            let horizon = timestamp - ChronoDuration::milliseconds(TRADE_RECONCILE_WINDOW_MS);
+            let mut trades = self.unreconciled_trades.lock().unwrap();
+            trades.retain(|_, pending| pending.last_trade >= horizon);
+            let pending =
+                trades
+                    .entry((aggressor_side.inverse(), price))
+                    .or_insert(UnreconciledTrades {
+                        quantity: Decimal::ZERO,
+                        last_trade: timestamp,
+                    });
+            pending.quantity += quantity;
+            pending.last_trade = timestamp;
+        }
+
         let trade_size = quantity;
         let mut open = self.open_orders.lock().await;
         let mut finished = Vec::new();
@@ -1426,6 +1802,7 @@ impl MatchingEngine {
         &self,
         order: Order,
         price: Price,
+        now: DateTime<Utc>,
         activation_time: DateTime<Utc>,
     ) {
         let original_quantity = order.request.quantity;
@@ -1437,12 +1814,13 @@ impl MatchingEngine {
             processed_volume: Decimal::ZERO,
             activation_time,
             cancel_after: None,
-            active: self.latency <= ChronoDuration::zero(),
+            active: activation_time <= now,
             original_quantity,
+            queue_priority: 0,
+            pending_amends: Vec::new(),
         };
         if entry.active && entry.remaining > Decimal::ZERO {
-            entry.initial_queue_position =
-                self.queue_ahead_for(entry.order.request.side, entry.price, Decimal::ZERO);
+            self.seed_queue_position(&mut entry);
             let mut book = self.resting_depth.lock().unwrap();
             book.add_order(entry.order.request.side, entry.price, entry.remaining);
         }
@@ -2361,6 +2739,9 @@ mod tests {
             display_quantity: None,
         };
 
+        // Backtests drive the engine clock; immediate amends must use it, not wall-clock time.
+        let simulated = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        engine.advance_time(simulated).await;
         let order = engine.place_order(request).await.unwrap();
         assert_eq!(order.status, OrderStatus::Accepted);
         let update = OrderUpdateRequest {
@@ -2374,6 +2755,7 @@ mod tests {
         let amended = engine.amend_order(update).await.unwrap();
         assert_eq!(amended.request.price, Some(Decimal::from(25_500)));
         assert_eq!(amended.request.quantity, Decimal::from(3));
+        assert_eq!(amended.updated_at, simulated);
 
         let book = engine.resting_depth.lock().unwrap();
         let (price, qty) = book.best_bid().expect("resting bid exists");
@@ -2382,13 +2764,247 @@ mod tests {
     }
 
     #[tokio::test(flavor = "current_thread")]
+    async fn depth_inferred_queue_credits_unexplained_decreases() {
+        let engine = MatchingEngine::with_config(
+            "paper",
+            vec![Symbol::from("BTCUSDT")],
+            Decimal::from(10_000),
+            MatchingEngineConfig {
+                latency: LatencyModel::default(),
+                queue_model: QueueModel::DepthInferred,
+                fee_model: FeeScheduleConfig::default().build_model(),
+                cash_asset: None,
+            },
+        );
+        let ts = Utc::now();
+        engine.load_market_snapshot(&OrderBook {
+            symbol: "BTCUSDT".into(),
+            bids: vec![OrderBookLevel {
+                price: Decimal::from(9_950),
+                size: Decimal::from(2),
+            }],
+            asks: vec![OrderBookLevel {
+                price: Decimal::from(10_000),
+                size: Decimal::from(1),
+            }],
+            timestamp: ts,
+            exchange_checksum: None,
+            local_checksum: None,
+            sequence: None,
+            prev_sequence: None,
+        });
+        engine.advance_time(ts).await;
+        engine
+            .place_order(OrderRequest {
+                symbol: "BTCUSDT".into(),
+                side: Side::Buy,
+                order_type: OrderType::Limit,
+                quantity: Decimal::ONE,
+                price: Some(Decimal::from(9_950)),
+                trigger_price: None,
+                time_in_force: Some(TimeInForce::GoodTilCanceled),
+                client_order_id: None,
+                take_profit: None,
+                stop_loss: None,
+                display_quantity: None,
+            })
+            .await
+            .unwrap();
+
+        // One lot trades, leaving one lot ahead of us.
+        engine
+            .process_trade(Side::Sell, Decimal::from(9_950), Decimal::ONE, ts)
+            .await;
+        assert!(engine.drain_fills().await.is_empty());
+
+        // The level drops by 1.5: one lot is the print above, the rest was cancelled ahead.
+        engine.apply_depth_update(&DepthUpdate {
+            symbol: "BTCUSDT".into(),
+            bids: vec![OrderBookLevel {
+                price: Decimal::from(9_950),
+                size: Decimal::new(5, 1),
+            }],
+            asks: Vec::new(),
+            timestamp: ts + ChronoDuration::milliseconds(1),
+        });
+
+        engine
+            .process_trade(
+                Side::Sell,
+                Decimal::from(9_950),
+                Decimal::ONE,
+                ts + ChronoDuration::milliseconds(2),
+            )
+            .await;
+        let fills = engine.drain_fills().await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].fill_quantity, Decimal::new(5, 1));
+    }
+
+    #[tokio::test]
+    async fn depth_inferred_queue_ignores_stale_trade_prints() {
+        let engine = MatchingEngine::with_config(
+            "paper",
+            vec![Symbol::from("BTCUSDT")],
+            Decimal::from(10_000),
+            MatchingEngineConfig {
+                latency: LatencyModel::default(),
+                queue_model: QueueModel::DepthInferred,
+                fee_model: FeeScheduleConfig::default().build_model(),
+                cash_asset: None,
+            },
+        );
+        let ts = Utc::now();
+        engine.load_market_snapshot(&OrderBook {
+            symbol: "BTCUSDT".into(),
+            bids: vec![OrderBookLevel {
+                price: Decimal::from(9_950),
+                size: Decimal::from(2),
+            }],
+            asks: vec![OrderBookLevel {
+                price: Decimal::from(10_000),
+                size: Decimal::from(1),
+            }],
+            timestamp: ts,
+            exchange_checksum: None,
+            local_checksum: None,
+            sequence: None,
+            prev_sequence: None,
+        });
+        engine.advance_time(ts).await;
+        engine
+            .place_order(OrderRequest {
+                symbol: "BTCUSDT".into(),
+                side: Side::Buy,
+                order_type: OrderType::Limit,
+                quantity: Decimal::ONE,
+                price: Some(Decimal::from(9_950)),
+                trigger_price: None,
+                time_in_force: Some(TimeInForce::GoodTilCanceled),
+                client_order_id: None,
+                take_profit: None,
+                stop_loss: None,
+                display_quantity: None,
+            })
+            .await
+            .unwrap();
+
+        // One lot trades but the level only ever grows afterwards.
+        engine
+            .process_trade(Side::Sell, Decimal::from(9_950), Decimal::ONE, ts)
+            .await;
+        engine.apply_depth_update(&DepthUpdate {
+            symbol: "BTCUSDT".into(),
+            bids: vec![OrderBookLevel {
+                price: Decimal::from(9_950),
+                size: Decimal::from(4),
+            }],
+            asks: Vec::new(),
+            timestamp: ts + ChronoDuration::milliseconds(1),
+        });
+
+        // Much later the level halves; the old print must not explain any of it.
+        let later = ts + ChronoDuration::seconds(5);
+        engine.advance_time(later).await;
+        engine.apply_depth_update(&DepthUpdate {
+            symbol: "BTCUSDT".into(),
+            bids: vec![OrderBookLevel {
+                price: Decimal::from(9_950),
+                size: Decimal::from(2),
+            }],
+            asks: Vec::new(),
+            timestamp: later,
+        });
+
+        engine
+            .process_trade(
+                Side::Sell,
+                Decimal::from(9_950),
+                Decimal::ONE,
+                later + ChronoDuration::milliseconds(1),
+            )
+            .await;
+        let fills = engine.drain_fills().await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].fill_quantity, Decimal::new(5, 1));
+    }
+
+    #[tokio::test]
+    async fn market_by_order_queue_tracks_orders_ahead() {
+        let engine = MatchingEngine::with_config(
+            "paper",
+            vec![Symbol::from("BTCUSDT")],
+            Decimal::from(10_000),
+            MatchingEngineConfig {
+                latency: LatencyModel::default(),
+                queue_model: QueueModel::MarketByOrder,
+                fee_model: FeeScheduleConfig::default().build_model(),
+                cash_asset: None,
+            },
+        );
+        let ts = Utc::now();
+        let mbo = |order_id: &str, size: i64, action: MarketByOrderAction| MarketByOrderUpdate {
+            symbol: "BTCUSDT".into(),
+            order_id: order_id.into(),
+            side: Side::Buy,
+            price: Decimal::from(9_950),
+            size: Decimal::from(size),
+            action,
+            timestamp: ts,
+        };
+        engine.apply_market_by_order(&MarketByOrderUpdate {
+            side: Side::Sell,
+            price: Decimal::from(10_000),
+            ..mbo("ask", 1, MarketByOrderAction::Add)
+        });
+        engine.apply_market_by_order(&mbo("a", 1, MarketByOrderAction::Add));
+        engine.apply_market_by_order(&mbo("b", 1, MarketByOrderAction::Add));
+        engine.advance_time(ts).await;
+        engine
+            .place_order(OrderRequest {
+                symbol: "BTCUSDT".into(),
+                side: Side::Buy,
+                order_type: OrderType::Limit,
+                quantity: Decimal::ONE,
+                price: Some(Decimal::from(9_950)),
+                trigger_price: None,
+                time_in_force: Some(TimeInForce::GoodTilCanceled),
+                client_order_id: None,
+                take_profit: None,
+                stop_loss: None,
+                display_quantity: None,
+            })
+            .await
+            .unwrap();
+
+        // Orders joining and leaving behind us do not move our position.
+        engine.apply_market_by_order(&mbo("c", 3, MarketByOrderAction::Add));
+        engine.apply_market_by_order(&mbo("c", 0, MarketByOrderAction::Cancel));
+        // "a" cancels and "b" loses priority by growing, clearing the queue ahead.
+        engine.apply_market_by_order(&mbo("a", 0, MarketByOrderAction::Cancel));
+        engine.apply_market_by_order(&mbo("b", 2, MarketByOrderAction::Modify));
+
+        engine
+            .process_trade(
+                Side::Sell,
+                Decimal::from(9_950),
+                Decimal::ONE,
+                ts + ChronoDuration::milliseconds(1),
+            )
+            .await;
+        let fills = engine.drain_fills().await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].fill_quantity, Decimal::ONE);
+    }
+
+    #[tokio::test]
     async fn queue_model_waits_for_depth_before_fill() {
         let engine = MatchingEngine::with_config(
             "paper",
             vec![Symbol::from("BTCUSDT")],
             Decimal::from(10_000),
             MatchingEngineConfig {
-                latency: ChronoDuration::zero(),
+                latency: LatencyModel::default(),
                 queue_model: QueueModel::Conservative,
                 fee_model: FeeScheduleConfig::default().build_model(),
                 cash_asset: None,
@@ -2408,6 +3024,8 @@ mod tests {
             timestamp: book_time,
             exchange_checksum: None,
             local_checksum: None,
+            sequence: None,
+            prev_sequence: None,
         };
         engine.load_market_snapshot(&snapshot);
         engine.advance_time(book_time).await;
@@ -2466,7 +3084,7 @@ mod tests {
             vec![Symbol::from("BTCUSDT")],
             Decimal::from(10_000),
             MatchingEngineConfig {
-                latency: ChronoDuration::milliseconds(50),
+                latency: ChronoDuration::milliseconds(50).into(),
                 queue_model: QueueModel::Optimistic,
                 fee_model: FeeScheduleConfig::default().build_model(),
                 cash_asset: None,
@@ -2486,6 +3104,8 @@ mod tests {
             timestamp: book_time,
             exchange_checksum: None,
             local_checksum: None,
+            sequence: None,
+            prev_sequence: None,
         };
         engine.load_market_snapshot(&snapshot);
         engine.advance_time(book_time).await;
@@ -2541,6 +3161,111 @@ mod tests {
         assert!(open.is_empty());
     }
 
+    #[tokio::test]
+    async fn cancel_and_amend_latency_are_sampled_separately() {
+        let engine = MatchingEngine::with_config(
+            "paper",
+            vec![Symbol::from("BTCUSDT")],
+            Decimal::from(10_000),
+            MatchingEngineConfig {
+                latency: LatencyModel {
+                    cancel: LatencyDistribution::Constant(ChronoDuration::milliseconds(50)),
+                    amend: LatencyDistribution::Constant(ChronoDuration::milliseconds(20)),
+                    ..LatencyModel::default()
+                },
+                queue_model: QueueModel::Optimistic,
+                fee_model: FeeScheduleConfig::default().build_model(),
+                cash_asset: None,
+            },
+        );
+        let ts = Utc::now();
+        engine.load_market_snapshot(&OrderBook {
+            symbol: "BTCUSDT".into(),
+            bids: vec![OrderBookLevel {
+                price: Decimal::from(9_900),
+                size: Decimal::from(1),
+            }],
+            asks: vec![OrderBookLevel {
+                price: Decimal::from(10_000),
+                size: Decimal::from(1),
+            }],
+            timestamp: ts,
+            exchange_checksum: None,
+            local_checksum: None,
+            sequence: None,
+            prev_sequence: None,
+        });
+        engine.advance_time(ts).await;
+
+        let order = engine
+            .place_order(OrderRequest {
+                symbol: "BTCUSDT".into(),
+                side: Side::Buy,
+                order_type: OrderType::Limit,
+                quantity: Decimal::from(2),
+                price: Some(Decimal::from(9_900)),
+                trigger_price: None,
+                time_in_force: Some(TimeInForce::GoodTilCanceled),
+                client_order_id: None,
+                take_profit: None,
+                stop_loss: None,
+                display_quantity: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(order.status, OrderStatus::Accepted);
+
+        let amended = engine
+            .amend_order(OrderUpdateRequest {
+                order_id: order.id.clone(),
+                symbol: "BTCUSDT".into(),
+                side: Side::Buy,
+                new_price: Some(Decimal::from(9_800)),
+                new_quantity: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(amended.request.price, Some(Decimal::from(9_900)));
+
+        // The amend is still in flight, so the original price trades.
+        engine
+            .process_trade(
+                Side::Sell,
+                Decimal::from(9_900),
+                Decimal::ONE,
+                ts + ChronoDuration::milliseconds(10),
+            )
+            .await;
+        assert_eq!(engine.drain_fills().await.len(), 1);
+        engine
+            .cancel_order(order.id.clone(), "BTCUSDT".into())
+            .await
+            .unwrap();
+
+        engine
+            .process_trade(
+                Side::Sell,
+                Decimal::from(9_900),
+                Decimal::ONE,
+                ts + ChronoDuration::milliseconds(30),
+            )
+            .await;
+        assert!(engine.drain_fills().await.is_empty());
+
+        // The cancel has not reached the venue yet, so the amended price still fills.
+        engine
+            .process_trade(
+                Side::Sell,
+                Decimal::from(9_800),
+                Decimal::ONE,
+                ts + ChronoDuration::milliseconds(40),
+            )
+            .await;
+        let fills = engine.drain_fills().await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].fill_price, Decimal::from(9_800));
+    }
+
     #[tokio::test(flavor = "current_thread")]
     async fn optimistic_queue_fills_even_with_depth_ahead() {
         let engine = MatchingEngine::with_config(
@@ -2548,7 +3273,7 @@ mod tests {
             vec![Symbol::from("BTCUSDT")],
             Decimal::from(10_000),
             MatchingEngineConfig {
-                latency: ChronoDuration::zero(),
+                latency: LatencyModel::default(),
                 queue_model: QueueModel::Optimistic,
                 fee_model: FeeScheduleConfig::default().build_model(),
                 cash_asset: None,
@@ -2568,6 +3293,8 @@ mod tests {
             timestamp: book_time,
             exchange_checksum: None,
             local_checksum: None,
+            sequence: None,
+            prev_sequence: None,
         };
         engine.load_market_snapshot(&snapshot);
         engine.advance_time(book_time).await;
@@ -2605,7 +3332,7 @@ mod tests {
             vec![Symbol::from("BTCUSDT")],
             Decimal::from(10_000),
             MatchingEngineConfig {
-                latency: ChronoDuration::zero(),
+                latency: LatencyModel::default(),
                 queue_model: QueueModel::Optimistic,
                 fee_model: fee_cfg.build_model(),
                 cash_asset: None,
@@ -2625,6 +3352,8 @@ mod tests {
             timestamp: book_time,
             exchange_checksum: None,
             local_checksum: None,
+            sequence: None,
+            prev_sequence: None,
         };
         engine.load_market_snapshot(&snapshot);
         engine.advance_time(book_time).await;
@@ -5,10 +5,10 @@ from __future__ import annotations
 
 import asyncio
 import datetime as dt
+import os
 
-import grpc
-
-from tesser.protos import tesser_pb2, tesser_pb2_grpc
+from tesser import ControlClient
+from tesser.protos import tesser_pb2
 
 CONTROL_ADDR = "127.0.0.1:50052"
 
@@ -24,11 +24,16 @@ def fmt_timestamp(ts: tesser_pb2.Timestamp | None) -> str:
 
 
 async def main() -> None:
-    async with grpc.aio.insecure_channel(CONTROL_ADDR) as channel:
-        stub = tesser_pb2_grpc.ControlServiceStub(channel)
+    # Set TESSER_CONTROL_TOKEN / TESSER_CONTROL_CA when the control plane requires them.
+    client = ControlClient(
+        CONTROL_ADDR,
+        token=os.environ.get("TESSER_CONTROL_TOKEN"),
+        root_certificates=os.environ.get("TESSER_CONTROL_CA"),
+    )
+    async with client:
         while True:
-            portfolio = await stub.GetPortfolio(tesser_pb2.GetPortfolioRequest())
-            status = await stub.GetStatus(tesser_pb2.GetStatusRequest())
+            portfolio = await client.get_portfolio()
+            status = await client.get_status()
             equity = fmt_decimal(portfolio.portfolio.equity if portfolio.portfolio else None)
             realized = fmt_decimal(
                 portfolio.portfolio.realized_pnl if portfolio.portfolio else None
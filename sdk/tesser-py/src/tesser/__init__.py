@@ -1,6 +1,7 @@
 """Python SDK for remote Tesser strategies and offline analytics."""
 
 from .analysis import calculate_slippage, summarize_fills
+from .client import ControlClient
 from .data import DataStore, load_candles, load_fills, load_orders, load_ticks
 from .models import (
     Candle,
@@ -18,6 +19,7 @@ from .strategy import Strategy, StrategyInitResult
 
 __all__ = [
     "Candle",
+    "ControlClient",
     "OrderBook",
     "OrderBookLevel",
     "Position",
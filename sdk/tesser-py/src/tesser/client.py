@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+from pathlib import Path
+
 import grpc
 
 from .protos import tesser_pb2 as proto
@@ -30,3 +32,134 @@ class StrategyServiceClient:
         if self._stub is None:
             raise RuntimeError("Client not connected")
         return await self._stub.Heartbeat(proto.HeartbeatRequest())
+
+
+class _BearerTokenInterceptor(
+    grpc.aio.UnaryUnaryClientInterceptor, grpc.aio.UnaryStreamClientInterceptor
+):
+    """Attaches ``authorization: Bearer <token>`` to every control-plane call."""
+
+    def __init__(self, token: str):
+        self._metadata = ("authorization", f"Bearer {token}")
+
+    def _with_token(
+        self, details: grpc.aio.ClientCallDetails
+    ) -> grpc.aio.ClientCallDetails:
+        metadata = list(details.metadata or [])
+        metadata.append(self._metadata)
+        return grpc.aio.ClientCallDetails(
+            details.method,
+            details.timeout,
+            metadata,
+            details.credentials,
+            details.wait_for_ready,
+        )
+
+    async def intercept_unary_unary(self, continuation, client_call_details, request):
+        return await continuation(self._with_token(client_call_details), request)
+
+    async def intercept_unary_stream(self, continuation, client_call_details, request):
+        return await continuation(self._with_token(client_call_details), request)
+
+
+def _read_pem(value: bytes | str | Path | None) -> bytes | None:
+    if value is None or isinstance(value, bytes):
+        return value
+    return Path(value).read_bytes()
+
+
+class ControlClient:
+    """Async client for the live runtime's control plane.
+
+    ``token`` is sent as a bearer token. Passing ``root_certificates`` (for example
+    the self-signed ``reports/control-tls/cert.pem``) or a client certificate switches
+    to TLS; certificate arguments accept PEM bytes or a file path.
+    """
+
+    def __init__(
+        self,
+        address: str,
+        *,
+        token: str | None = None,
+        root_certificates: bytes | str | Path | None = None,
+        private_key: bytes | str | Path | None = None,
+        certificate_chain: bytes | str | Path | None = None,
+    ):
+        if (private_key is None) != (certificate_chain is None):
+            raise ValueError(
+                "private_key and certificate_chain must be provided together"
+            )
+        self.address = address
+        self.token = token
+        self._root_certificates = _read_pem(root_certificates)
+        self._private_key = _read_pem(private_key)
+        self._certificate_chain = _read_pem(certificate_chain)
+        self._channel: grpc.aio.Channel | None = None
+        self._stub: tesser_pb2_grpc.ControlServiceStub | None = None
+
+    @property
+    def tls_enabled(self) -> bool:
+        return (
+            self._root_certificates is not None
+            or self._certificate_chain is not None
+        )
+
+    def _interceptors(self) -> list[grpc.aio.ClientInterceptor]:
+        return [_BearerTokenInterceptor(self.token)] if self.token else []
+
+    async def __aenter__(self):
+        if self._channel is None:
+            interceptors = self._interceptors()
+            if self.tls_enabled:
+                credentials = grpc.ssl_channel_credentials(
+                    root_certificates=self._root_certificates,
+                    private_key=self._private_key,
+                    certificate_chain=self._certificate_chain,
+                )
+                self._channel = grpc.aio.secure_channel(
+                    self.address, credentials, interceptors=interceptors
+                )
+            else:
+                self._channel = grpc.aio.insecure_channel(
+                    self.address, interceptors=interceptors
+                )
+            self._stub = tesser_pb2_grpc.ControlServiceStub(self._channel)
+        return self
+
+    async def __aexit__(self, exc_type, exc, tb):
+        if self._channel:
+            await self._channel.close()
+            self._channel = None
+            self._stub = None
+
+    @property
+    def stub(self) -> tesser_pb2_grpc.ControlServiceStub:
+        if self._stub is None:
+            raise RuntimeError("Client not connected")
+        return self._stub
+
+    async def get_status(self) -> proto.GetStatusResponse:
+        return await self.stub.GetStatus(proto.GetStatusRequest())
+
+    async def get_portfolio(self) -> proto.GetPortfolioResponse:
+        return await self.stub.GetPortfolio(proto.GetPortfolioRequest())
+
+    async def reload_strategy(
+        self, config_toml: str = ""
+    ) -> proto.ReloadStrategyResponse:
+        """Re-apply the session's strategy config file, or ``config_toml`` if given."""
+        return await self.stub.ReloadStrategy(
+            proto.ReloadStrategyRequest(config_toml=config_toml)
+        )
+
+    async def swap_strategy(
+        self, config_toml: str = "", *, dry_run: bool = False
+    ) -> proto.SwapStrategyResponse:
+        """Replace the running strategy; with ``dry_run`` only compare signals."""
+        return await self.stub.SwapStrategy(
+            proto.SwapStrategyRequest(config_toml=config_toml, dry_run=dry_run)
+        )
+
+    def monitor(self):
+        """Stream runtime events; iterate the returned call with ``async for``."""
+        return self.stub.Monitor(proto.MonitorRequest())
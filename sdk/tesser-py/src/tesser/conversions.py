@@ -48,7 +48,7 @@ def tick_from_proto(message: proto.Tick) -> Tick:
 def candle_from_proto(message: proto.Candle) -> Candle:
     return Candle(
         symbol=message.symbol,
-        interval=proto.Interval.Name(message.interval),
+        interval=message.interval_spec or proto.Interval.Name(message.interval),
         open=to_decimal(message.open.value),
         high=to_decimal(message.high.value),
         low=to_decimal(message.low.value),
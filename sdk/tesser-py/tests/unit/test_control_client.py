@@ -0,0 +1,33 @@
+import asyncio
+
+import grpc
+import pytest
+
+from tesser.client import ControlClient, _BearerTokenInterceptor
+
+
+def test_token_is_attached_as_bearer_metadata():
+    interceptor = _BearerTokenInterceptor("secret")
+    seen = {}
+
+    async def continuation(details, request):
+        seen["metadata"] = list(details.metadata)
+        return request
+
+    details = grpc.aio.ClientCallDetails(
+        "/tesser.rpc.v1.ControlService/GetStatus", None, [("x-trace", "1")], None, None
+    )
+    asyncio.run(interceptor.intercept_unary_unary(continuation, details, "req"))
+    assert seen["metadata"] == [("x-trace", "1"), ("authorization", "Bearer secret")]
+
+
+def test_certificates_enable_tls(tmp_path):
+    ca = tmp_path / "cert.pem"
+    ca.write_bytes(b"-----BEGIN CERTIFICATE-----\n")
+    assert ControlClient("127.0.0.1:50052", root_certificates=ca).tls_enabled
+    assert not ControlClient("127.0.0.1:50052", token="secret").tls_enabled
+
+
+def test_client_key_requires_certificate_chain():
+    with pytest.raises(ValueError):
+        ControlClient("127.0.0.1:50052", private_key=b"key")
@@ -11,12 +11,13 @@ use std::{
 use anyhow::{anyhow, Context};
 use chrono::{DateTime, Utc};
 use futures::{stream, Stream, StreamExt};
+use reporting::benchmark::{Benchmark, DEFAULT_ROLLING_WINDOW};
 use reporting::{PerformanceReport, Reporter};
 use rust_decimal::Decimal;
 use tesser_broker::MarketStream;
 use tesser_core::{
-    AssetId, Candle, DepthUpdate, Fill, InstrumentKind, Order, OrderBook, Price, Quantity, Side,
-    Symbol, Tick,
+    AssetId, Candle, DepthUpdate, Fill, InstrumentKind, MarketByOrderUpdate, Order, OrderBook,
+    Price, Quantity, Side, Symbol, Tick,
 };
 use tesser_data::merger::{UnifiedEvent, UnifiedEventKind};
 use tesser_execution::{ExecutionEngine, RiskContext};
@@ -36,6 +37,10 @@ pub struct BacktestConfig {
     pub reporting_currency: AssetId,
     pub execution: ExecutionModel,
     pub mode: BacktestMode,
+    /// Optional benchmark used for relative metrics in the report.
+    pub benchmark: Option<Benchmark>,
+    /// Daily observations per window for the rolling benchmark statistics.
+    pub benchmark_window: usize,
 }
 
 impl BacktestConfig {
@@ -49,6 +54,8 @@ impl BacktestConfig {
             reporting_currency: AssetId::from("USDT"),
             execution: ExecutionModel::default(),
             mode: BacktestMode::Candle,
+            benchmark: None,
+            benchmark_window: DEFAULT_ROLLING_WINDOW,
         }
     }
 }
@@ -99,6 +106,7 @@ pub enum MarketEventKind {
     OrderBook(OrderBook),
     Depth(DepthUpdate),
     Trade(Tick),
+    MarketByOrder(MarketByOrderUpdate),
 }
 
 impl From<UnifiedEvent> for MarketEvent {
@@ -107,6 +115,7 @@ impl From<UnifiedEvent> for MarketEvent {
             UnifiedEventKind::OrderBook(book) => MarketEventKind::OrderBook(book),
             UnifiedEventKind::Depth(update) => MarketEventKind::Depth(update),
             UnifiedEventKind::Trade(trade) => MarketEventKind::Trade(trade),
+            UnifiedEventKind::MarketByOrder(update) => MarketEventKind::MarketByOrder(update),
         };
         Self {
             timestamp: event.timestamp,
@@ -147,6 +156,7 @@ pub struct Backtester {
     lob_stream: Option<MarketEventStream>,
     candle_index: usize,
     market_registry: Arc<MarketRegistry>,
+    benchmark_prices: Vec<(DateTime<Utc>, Price)>,
 }
 
 struct PendingFill {
@@ -184,6 +194,7 @@ impl Backtester {
             lob_stream,
             candle_index: 0,
             market_registry,
+            benchmark_prices: Vec::new(),
         }
     }
 
@@ -245,8 +256,29 @@ impl Backtester {
             );
         }
 
-        let reporter = Reporter::new(self.portfolio.initial_equity(), equity_curve, all_fills);
-        reporter.calculate()
+        self.build_reporter(equity_curve, all_fills).calculate()
+    }
+
+    fn build_reporter(
+        &mut self,
+        equity_curve: Vec<(DateTime<Utc>, Decimal)>,
+        fills: Vec<Fill>,
+    ) -> Reporter {
+        let reporter = Reporter::new(self.portfolio.initial_equity(), equity_curve, fills);
+        let prices = match self.config.benchmark.take() {
+            Some(Benchmark::BuyAndHold) => std::mem::take(&mut self.benchmark_prices),
+            Some(Benchmark::Series(prices)) => prices,
+            None => return reporter,
+        };
+        reporter.with_benchmark(prices, self.config.benchmark_window)
+    }
+
+    fn record_benchmark_price(&mut self, symbol: Symbol, timestamp: DateTime<Utc>, price: Price) {
+        if symbol == self.config.symbol
+            && matches!(self.config.benchmark, Some(Benchmark::BuyAndHold))
+        {
+            self.benchmark_prices.push((timestamp, price));
+        }
     }
 
     async fn handle_tick_event(&mut self, tick: Tick) -> anyhow::Result<()> {
@@ -356,6 +388,7 @@ impl Backtester {
             );
         }
 
+        self.record_benchmark_price(candle.symbol, candle.timestamp, candle.close);
         let equity = self.portfolio.equity();
         equity_curve.push((candle.timestamp, equity));
         Ok(())
@@ -469,11 +502,15 @@ impl Backtester {
                 MarketEventKind::Depth(update) => {
                     matching.apply_depth_update(update);
                 }
+                MarketEventKind::MarketByOrder(update) => {
+                    matching.apply_market_by_order(update);
+                }
                 MarketEventKind::Trade(tick) => {
                     matching
                         .process_trade(tick.side, tick.price, tick.size, tick.exchange_timestamp)
                         .await;
                     last_trade_price = Some(tick.price);
+                    self.record_benchmark_price(tick.symbol, event.timestamp, tick.price);
                     if let Err(err) = self.portfolio.update_market_data(tick.symbol, tick.price) {
                         warn!(symbol = %tick.symbol, error = %err, "failed to refresh market data");
                     }
@@ -493,8 +530,7 @@ impl Backtester {
             equity_curve.push((event.timestamp, equity));
         }
 
-        let reporter = Reporter::new(self.portfolio.initial_equity(), equity_curve, all_fills);
-        reporter.calculate()
+        self.build_reporter(equity_curve, all_fills).calculate()
     }
 
     async fn process_signals_tick(&mut self, fallback_price: Option<Price>) -> anyhow::Result<()> {
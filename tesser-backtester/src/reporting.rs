@@ -1,3 +1,7 @@
+pub mod benchmark;
+pub mod html;
+pub mod robustness;
+
 use std::fmt;
 
 use anyhow::{anyhow, Result};
@@ -6,6 +10,8 @@ use itertools::Itertools;
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 use tesser_core::{Fill, Side};
 
+use benchmark::BenchmarkReport;
+
 const TRADING_DAYS_PER_YEAR: f64 = 252.0;
 const RISK_FREE_RATE: f64 = 0.0;
 
@@ -13,6 +19,21 @@ const RISK_FREE_RATE: f64 = 0.0;
 struct Trade {
     pnl: Decimal,
     is_win: bool,
+    record: TradeRecord,
+}
+
+/// Round trip reconstructed from consecutive opposite-side fills.
+#[derive(Clone, Debug)]
+pub struct TradeRecord {
+    pub entry_time: DateTime<Utc>,
+    pub exit_time: DateTime<Utc>,
+    /// Side of the opening fill.
+    pub side: Side,
+    pub quantity: f64,
+    /// Price return of the round trip relative to the entry price.
+    pub return_ratio: f64,
+    /// Realized PnL in the quote currency, net of fees.
+    pub pnl: f64,
 }
 
 #[derive(Debug, Default)]
@@ -29,6 +50,14 @@ pub struct PerformanceReport {
     pub avg_loss_pct: f64,
     pub profit_loss_ratio: f64,
     pub ending_equity: f64,
+    /// Equity at the start of the run.
+    pub initial_equity: f64,
+    /// Mark-to-market equity sampled at every processed event.
+    pub equity_curve: Vec<(DateTime<Utc>, f64)>,
+    /// Round trips used for the trade statistics above.
+    pub trades: Vec<TradeRecord>,
+    /// Benchmark-relative statistics, when a benchmark was supplied.
+    pub benchmark: Option<BenchmarkReport>,
 }
 
 impl fmt::Display for PerformanceReport {
@@ -54,7 +83,12 @@ impl fmt::Display for PerformanceReport {
             "Profit/Loss Ratio", self.profit_loss_ratio
         )?;
         writeln!(f, "{:<25} ${:.2}", "Ending Equity", self.ending_equity)?;
-        writeln!(f, "------------------------------------")
+        writeln!(f, "------------------------------------")?;
+        if let Some(benchmark) = &self.benchmark {
+            write!(f, "{benchmark}")?;
+            writeln!(f, "------------------------------------")?;
+        }
+        Ok(())
     }
 }
 
@@ -62,6 +96,8 @@ pub struct Reporter {
     initial_equity: Decimal,
     equity_curve: Vec<(DateTime<Utc>, Decimal)>,
     fills: Vec<Fill>,
+    benchmark: Option<Vec<(DateTime<Utc>, Decimal)>>,
+    benchmark_window: usize,
 }
 
 impl Reporter {
@@ -74,9 +110,23 @@ impl Reporter {
             initial_equity,
             equity_curve,
             fills,
+            benchmark: None,
+            benchmark_window: benchmark::DEFAULT_ROLLING_WINDOW,
         }
     }
 
+    /// Compare the run against benchmark prices, using `rolling_window` daily observations for
+    /// the rolling statistics.
+    pub fn with_benchmark(
+        mut self,
+        prices: Vec<(DateTime<Utc>, Decimal)>,
+        rolling_window: usize,
+    ) -> Self {
+        self.benchmark = Some(prices);
+        self.benchmark_window = rolling_window;
+        self
+    }
+
     pub fn calculate(&self) -> Result<PerformanceReport> {
         if self.equity_curve.len() < 2 {
             return Err(anyhow!("not enough data points to generate a report"));
@@ -103,6 +153,16 @@ impl Reporter {
             0.0
         };
 
+        let equity_curve = to_f64_series(&self.equity_curve, "equity")?;
+        let benchmark = match &self.benchmark {
+            Some(prices) => BenchmarkReport::calculate(
+                &equity_curve,
+                &to_f64_series(prices, "benchmark price")?,
+                self.benchmark_window,
+            ),
+            None => None,
+        };
+
         let trades = self.process_fills_into_trades();
         let total_trades = trades.len();
         let wins = trades.iter().filter(|t| t.is_win).count();
@@ -148,6 +208,10 @@ impl Reporter {
             avg_loss_pct: avg_loss_pct_f64,
             profit_loss_ratio,
             ending_equity: decimal_to_f64(ending_equity, "ending equity")?,
+            initial_equity: decimal_to_f64(self.initial_equity, "initial equity")?,
+            trades: trades.into_iter().map(|trade| trade.record).collect(),
+            benchmark,
+            equity_curve,
         })
     }
 
@@ -249,9 +313,20 @@ impl Reporter {
                         return None;
                     }
                     let pnl_ratio = pnl / open.fill_price;
+                    let quantity = open.fill_quantity.min(close.fill_quantity);
+                    let fees = open.fee.unwrap_or_default() + close.fee.unwrap_or_default();
+                    let record = TradeRecord {
+                        entry_time: open.timestamp,
+                        exit_time: close.timestamp,
+                        side: open.side,
+                        quantity: quantity.to_f64().unwrap_or_default(),
+                        return_ratio: pnl_ratio.to_f64().unwrap_or_default(),
+                        pnl: (pnl * quantity - fees).to_f64().unwrap_or_default(),
+                    };
                     Some(Trade {
                         pnl: pnl_ratio,
                         is_win: pnl > Decimal::ZERO,
+                        record,
                     })
                 } else {
                     None
@@ -261,6 +336,16 @@ impl Reporter {
     }
 }
 
+fn to_f64_series(
+    series: &[(DateTime<Utc>, Decimal)],
+    label: &str,
+) -> Result<Vec<(DateTime<Utc>, f64)>> {
+    series
+        .iter()
+        .map(|(ts, value)| Ok((*ts, decimal_to_f64(*value, label)?)))
+        .collect()
+}
+
 fn decimal_to_f64(value: Decimal, label: &str) -> Result<f64> {
     value
         .to_f64()
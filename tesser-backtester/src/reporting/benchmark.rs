@@ -0,0 +1,316 @@
+//! Metrics describing a strategy relative to a benchmark price series.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+
+use super::TRADING_DAYS_PER_YEAR;
+
+/// Default rolling window, in daily observations.
+pub const DEFAULT_ROLLING_WINDOW: usize = 30;
+
+/// Benchmark the backtest is compared against.
+#[derive(Clone, Debug)]
+pub enum Benchmark {
+    /// Buy-and-hold of the backtest's primary symbol, sampled from the replayed data.
+    BuyAndHold,
+    /// Externally supplied price series (e.g. BTC closes loaded from parquet).
+    Series(Vec<(DateTime<Utc>, Decimal)>),
+}
+
+/// Benchmark-relative statistics computed on aligned daily returns.
+#[derive(Clone, Debug, Default)]
+pub struct BenchmarkReport {
+    pub benchmark_return_pct: f64,
+    /// Annualized Jensen's alpha (risk-free rate assumed zero).
+    pub alpha: f64,
+    pub beta: f64,
+    pub correlation: f64,
+    /// Annualized standard deviation of active returns.
+    pub tracking_error: f64,
+    pub information_ratio: f64,
+    /// Strategy return on benchmark up days as a percentage of the benchmark's.
+    pub up_capture: f64,
+    /// Strategy return on benchmark down days as a percentage of the benchmark's.
+    pub down_capture: f64,
+    /// Portion of the total return explained by benchmark exposure (`beta * benchmark return`).
+    pub beta_return_pct: f64,
+    /// Total return left after removing the benchmark exposure.
+    pub residual_return_pct: f64,
+    /// Number of daily observations the rolling series uses.
+    pub rolling_window: usize,
+    pub rolling: Vec<RollingBenchmarkPoint>,
+}
+
+/// Benchmark statistics over the trailing window ending at `timestamp`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RollingBenchmarkPoint {
+    pub timestamp: DateTime<Utc>,
+    pub alpha: f64,
+    pub beta: f64,
+    pub tracking_error: f64,
+    pub information_ratio: f64,
+}
+
+impl BenchmarkReport {
+    /// Compare an equity curve with benchmark prices. Returns `None` when fewer than two
+    /// overlapping daily returns exist.
+    pub fn calculate(
+        equity_curve: &[(DateTime<Utc>, f64)],
+        benchmark: &[(DateTime<Utc>, f64)],
+        rolling_window: usize,
+    ) -> Option<Self> {
+        let strategy = daily_closes(equity_curve);
+        let benchmark = daily_closes(benchmark);
+        let common: Vec<(DateTime<Utc>, f64, f64)> = strategy
+            .iter()
+            .filter_map(|(date, (ts, equity))| {
+                benchmark.get(date).map(|(_, price)| (*ts, *equity, *price))
+            })
+            .collect();
+        if common.len() < 3 {
+            return None;
+        }
+        let mut timestamps = Vec::with_capacity(common.len() - 1);
+        let mut strategy_returns = Vec::with_capacity(common.len() - 1);
+        let mut benchmark_returns = Vec::with_capacity(common.len() - 1);
+        for window in common.windows(2) {
+            let (_, prev_equity, prev_price) = window[0];
+            let (ts, equity, price) = window[1];
+            if prev_equity.abs() < f64::EPSILON || prev_price.abs() < f64::EPSILON {
+                continue;
+            }
+            timestamps.push(ts);
+            strategy_returns.push(equity / prev_equity - 1.0);
+            benchmark_returns.push(price / prev_price - 1.0);
+        }
+        let stats = WindowStats::compute(&strategy_returns, &benchmark_returns)?;
+
+        let first = common.first()?;
+        let last = common.last()?;
+        let strategy_total = last.1 / first.1 - 1.0;
+        let benchmark_total = last.2 / first.2 - 1.0;
+        let beta_return = stats.beta * benchmark_total;
+
+        let rolling_window = rolling_window.max(2);
+        let rolling = if strategy_returns.len() >= rolling_window {
+            (rolling_window..=strategy_returns.len())
+                .filter_map(|end| {
+                    let start = end - rolling_window;
+                    let window = WindowStats::compute(
+                        &strategy_returns[start..end],
+                        &benchmark_returns[start..end],
+                    )?;
+                    Some(RollingBenchmarkPoint {
+                        timestamp: timestamps[end - 1],
+                        alpha: window.alpha,
+                        beta: window.beta,
+                        tracking_error: window.tracking_error,
+                        information_ratio: window.information_ratio,
+                    })
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Some(Self {
+            benchmark_return_pct: benchmark_total * 100.0,
+            alpha: stats.alpha,
+            beta: stats.beta,
+            correlation: stats.correlation,
+            tracking_error: stats.tracking_error,
+            information_ratio: stats.information_ratio,
+            up_capture: capture(&strategy_returns, &benchmark_returns, |r| r > 0.0),
+            down_capture: capture(&strategy_returns, &benchmark_returns, |r| r < 0.0),
+            beta_return_pct: beta_return * 100.0,
+            residual_return_pct: (strategy_total - beta_return) * 100.0,
+            rolling_window,
+            rolling,
+        })
+    }
+
+    /// Smallest and largest rolling beta, if the rolling series is populated.
+    pub fn rolling_beta_range(&self) -> Option<(f64, f64)> {
+        let mut betas = self.rolling.iter().map(|point| point.beta);
+        let first = betas.next()?;
+        Some(betas.fold((first, first), |(lo, hi), beta| {
+            (lo.min(beta), hi.max(beta))
+        }))
+    }
+}
+
+impl fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<25} {:.2}%",
+            "Benchmark Return", self.benchmark_return_pct
+        )?;
+        writeln!(f, "{:<25} {:.4}", "Alpha (ann.)", self.alpha)?;
+        writeln!(f, "{:<25} {:.4}", "Beta", self.beta)?;
+        writeln!(f, "{:<25} {:.4}", "Correlation", self.correlation)?;
+        writeln!(f, "{:<25} {:.4}", "Tracking Error", self.tracking_error)?;
+        writeln!(
+            f,
+            "{:<25} {:.2}",
+            "Information Ratio", self.information_ratio
+        )?;
+        writeln!(f, "{:<25} {:.2}%", "Up Capture", self.up_capture)?;
+        writeln!(f, "{:<25} {:.2}%", "Down Capture", self.down_capture)?;
+        writeln!(f, "{:<25} {:.2}%", "Beta Return", self.beta_return_pct)?;
+        writeln!(
+            f,
+            "{:<25} {:.2}%",
+            "Residual Return", self.residual_return_pct
+        )?;
+        if let Some((lo, hi)) = self.rolling_beta_range() {
+            writeln!(
+                f,
+                "{:<25} {:.4} .. {:.4}",
+                format!("Rolling Beta ({}d)", self.rolling_window),
+                lo,
+                hi
+            )?;
+        }
+        Ok(())
+    }
+}
+
+struct WindowStats {
+    alpha: f64,
+    beta: f64,
+    correlation: f64,
+    tracking_error: f64,
+    information_ratio: f64,
+}
+
+impl WindowStats {
+    fn compute(strategy: &[f64], benchmark: &[f64]) -> Option<Self> {
+        let n = strategy.len().min(benchmark.len());
+        if n < 2 {
+            return None;
+        }
+        let count = n as f64;
+        let mean_s = strategy[..n].iter().sum::<f64>() / count;
+        let mean_b = benchmark[..n].iter().sum::<f64>() / count;
+        let mut cov = 0.0;
+        let mut var_s = 0.0;
+        let mut var_b = 0.0;
+        for (s, b) in strategy[..n].iter().zip(&benchmark[..n]) {
+            cov += (s - mean_s) * (b - mean_b);
+            var_s += (s - mean_s).powi(2);
+            var_b += (b - mean_b).powi(2);
+        }
+        cov /= count - 1.0;
+        var_s /= count - 1.0;
+        var_b /= count - 1.0;
+        let beta = if var_b > 1e-18 { cov / var_b } else { 0.0 };
+        let correlation = if var_s > 1e-18 && var_b > 1e-18 {
+            cov / (var_s.sqrt() * var_b.sqrt())
+        } else {
+            0.0
+        };
+        let active: Vec<f64> = strategy[..n]
+            .iter()
+            .zip(&benchmark[..n])
+            .map(|(s, b)| s - b)
+            .collect();
+        let mean_active = active.iter().sum::<f64>() / count;
+        let active_std = (active
+            .iter()
+            .map(|r| (r - mean_active).powi(2))
+            .sum::<f64>()
+            / (count - 1.0))
+            .sqrt();
+        let information_ratio = if active_std > 1e-9 {
+            mean_active * TRADING_DAYS_PER_YEAR.sqrt() / active_std
+        } else {
+            0.0
+        };
+        Some(Self {
+            alpha: (mean_s - beta * mean_b) * TRADING_DAYS_PER_YEAR,
+            beta,
+            correlation,
+            tracking_error: active_std * TRADING_DAYS_PER_YEAR.sqrt(),
+            information_ratio,
+        })
+    }
+}
+
+fn capture(strategy: &[f64], benchmark: &[f64], filter: impl Fn(f64) -> bool) -> f64 {
+    let (sum_s, sum_b, count) = strategy
+        .iter()
+        .zip(benchmark)
+        .filter(|(_, b)| filter(**b))
+        .fold((0.0, 0.0, 0usize), |(s_acc, b_acc, n), (s, b)| {
+            (s_acc + s, b_acc + b, n + 1)
+        });
+    if count == 0 || sum_b.abs() < f64::EPSILON {
+        return 0.0;
+    }
+    (sum_s / sum_b) * 100.0
+}
+
+fn daily_closes(series: &[(DateTime<Utc>, f64)]) -> BTreeMap<NaiveDate, (DateTime<Utc>, f64)> {
+    let mut closes = BTreeMap::new();
+    for (ts, value) in series {
+        let entry = closes.entry(ts.date_naive()).or_insert((*ts, *value));
+        if *ts >= entry.0 {
+            *entry = (*ts, *value);
+        }
+    }
+    closes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn series(returns: &[f64], start_value: f64) -> Vec<(DateTime<Utc>, f64)> {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut value = start_value;
+        let mut out = vec![(start, value)];
+        for (idx, r) in returns.iter().enumerate() {
+            value *= 1.0 + r;
+            out.push((start + Duration::days(idx as i64 + 1), value));
+        }
+        out
+    }
+
+    #[test]
+    fn leveraged_benchmark_has_expected_beta_and_capture() {
+        let bench_returns: Vec<f64> = (0..60)
+            .map(|i| if i % 3 == 0 { -0.02 } else { 0.015 })
+            .collect();
+        let strategy_returns: Vec<f64> = bench_returns.iter().map(|r| 2.0 * r).collect();
+        let report = BenchmarkReport::calculate(
+            &series(&strategy_returns, 10_000.0),
+            &series(&bench_returns, 40_000.0),
+            20,
+        )
+        .unwrap();
+        assert!((report.beta - 2.0).abs() < 1e-9);
+        assert!(report.alpha.abs() < 1e-9);
+        assert!((report.correlation - 1.0).abs() < 1e-9);
+        assert!((report.up_capture - 200.0).abs() < 1e-6);
+        assert!((report.down_capture - 200.0).abs() < 1e-6);
+        assert!(report.tracking_error > 0.0);
+        assert_eq!(report.rolling.len(), 60 - 20 + 1);
+        let (lo, hi) = report.rolling_beta_range().unwrap();
+        assert!((lo - 2.0).abs() < 1e-9 && (hi - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn disjoint_series_yield_no_report() {
+        let strategy = series(&[0.01, 0.02, -0.01], 100.0);
+        let benchmark: Vec<_> = strategy
+            .iter()
+            .map(|(ts, v)| (*ts + Duration::days(30), *v))
+            .collect();
+        assert!(BenchmarkReport::calculate(&strategy, &benchmark, 5).is_none());
+    }
+}
@@ -0,0 +1,434 @@
+//! Self-contained HTML tear sheets rendered from [`PerformanceReport`]s.
+//!
+//! Charts are emitted as inline SVG so the output can be opened offline or attached to an email
+//! without any scripts, fonts or external assets.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use chrono::{DateTime, Datelike, Utc};
+
+use super::PerformanceReport;
+
+const CHART_WIDTH: f64 = 900.0;
+const CHART_HEIGHT: f64 = 240.0;
+const CHART_PADDING: f64 = 40.0;
+const MAX_CHART_POINTS: usize = 2_000;
+const HISTOGRAM_BINS: usize = 20;
+
+const STYLE: &str = "body{font-family:-apple-system,Segoe UI,Helvetica,Arial,sans-serif;margin:24px;color:#1f2933;background:#fff}\
+h1{font-size:22px}h2{font-size:18px;margin-top:32px;border-bottom:1px solid #d9e2ec;padding-bottom:4px}\
+h3{font-size:15px;margin:20px 0 8px}table{border-collapse:collapse;font-size:13px;margin:8px 0}\
+th,td{padding:4px 10px;border:1px solid #d9e2ec;text-align:right}th:first-child,td:first-child{text-align:left}\
+th{background:#f0f4f8}svg{display:block;margin:8px 0}.axis{font-size:11px;fill:#52606d}\
+.muted{color:#7b8794;font-size:12px}";
+
+/// Render a single report as a standalone HTML document.
+pub fn render_tear_sheet(title: &str, report: &PerformanceReport) -> String {
+    render_document(title, &[(title, report)])
+}
+
+/// Render several reports (e.g. one per `backtest batch` config) into one document, preceded
+/// by a comparison table.
+pub fn render_document(title: &str, reports: &[(&str, &PerformanceReport)]) -> String {
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"utf-8\"><title>{}</title><style>{STYLE}</style></head><body>",
+        escape(title)
+    );
+    let _ = write!(html, "<h1>{}</h1>", escape(title));
+    let _ = write!(
+        html,
+        "<p class=\"muted\">Generated {}</p>",
+        Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    if reports.len() > 1 {
+        html.push_str("<h2>Summary</h2>");
+        html.push_str(&summary_table(reports));
+    }
+    for (name, report) in reports {
+        if reports.len() > 1 {
+            let _ = write!(html, "<h2>{}</h2>", escape(name));
+        }
+        html.push_str(&report_section(report));
+    }
+    html.push_str("</body></html>");
+    html
+}
+
+fn report_section(report: &PerformanceReport) -> String {
+    let mut html = String::new();
+    html.push_str("<h3>Performance</h3>");
+    html.push_str(&metrics_table(report));
+
+    html.push_str("<h3>Equity Curve</h3>");
+    html.push_str(&line_chart(&report.equity_curve, "#2f80ed", false));
+
+    html.push_str("<h3>Underwater</h3>");
+    html.push_str(&line_chart(
+        &drawdown_series(report.initial_equity, &report.equity_curve),
+        "#d64545",
+        true,
+    ));
+
+    html.push_str("<h3>Monthly Returns (%)</h3>");
+    html.push_str(&monthly_heatmap(
+        report.initial_equity,
+        &report.equity_curve,
+    ));
+
+    html.push_str("<h3>Trade Return Distribution (%)</h3>");
+    let returns: Vec<f64> = report
+        .trades
+        .iter()
+        .map(|trade| trade.return_ratio * 100.0)
+        .collect();
+    html.push_str(&histogram(&returns));
+    html
+}
+
+fn metrics_table(report: &PerformanceReport) -> String {
+    let mut rows = vec![
+        ("Total Return", format!("{:.2}%", report.total_return_pct)),
+        (
+            "Annualized Return",
+            format!("{:.2}%", report.annualized_return_pct),
+        ),
+        ("Sharpe Ratio", format!("{:.2}", report.sharpe_ratio)),
+        ("Sortino Ratio", format!("{:.2}", report.sortino_ratio)),
+        ("Max Drawdown", format!("{:.2}%", report.max_drawdown_pct)),
+        ("Calmar Ratio", format!("{:.2}", report.calmar_ratio)),
+        ("Total Trades", report.total_trades.to_string()),
+        ("Win Rate", format!("{:.2}%", report.win_rate_pct)),
+        ("Avg Win", format!("{:.2}%", report.avg_win_pct)),
+        ("Avg Loss", format!("{:.2}%", report.avg_loss_pct)),
+        (
+            "Profit/Loss Ratio",
+            format!("{:.4}", report.profit_loss_ratio),
+        ),
+        ("Ending Equity", format!("${:.2}", report.ending_equity)),
+    ];
+    if let Some(benchmark) = &report.benchmark {
+        rows.extend([
+            (
+                "Benchmark Return",
+                format!("{:.2}%", benchmark.benchmark_return_pct),
+            ),
+            ("Alpha (ann.)", format!("{:.4}", benchmark.alpha)),
+            ("Beta", format!("{:.4}", benchmark.beta)),
+            ("Tracking Error", format!("{:.4}", benchmark.tracking_error)),
+            (
+                "Information Ratio",
+                format!("{:.2}", benchmark.information_ratio),
+            ),
+            ("Up Capture", format!("{:.2}%", benchmark.up_capture)),
+            ("Down Capture", format!("{:.2}%", benchmark.down_capture)),
+        ]);
+    }
+    let mut html = String::from("<table><tbody>");
+    for (label, value) in rows {
+        let _ = write!(html, "<tr><td>{label}</td><td>{}</td></tr>", escape(&value));
+    }
+    html.push_str("</tbody></table>");
+    html
+}
+
+fn summary_table(reports: &[(&str, &PerformanceReport)]) -> String {
+    let mut html = String::from(
+        "<table><thead><tr><th>Config</th><th>Total Return</th><th>Sharpe</th><th>Sortino</th>\
+         <th>Max Drawdown</th><th>Trades</th><th>Win Rate</th><th>Ending Equity</th></tr></thead><tbody>",
+    );
+    for (name, report) in reports {
+        let _ = write!(
+            html,
+            "<tr><td>{}</td><td>{:.2}%</td><td>{:.2}</td><td>{:.2}</td><td>{:.2}%</td><td>{}</td><td>{:.2}%</td><td>${:.2}</td></tr>",
+            escape(name),
+            report.total_return_pct,
+            report.sharpe_ratio,
+            report.sortino_ratio,
+            report.max_drawdown_pct,
+            report.total_trades,
+            report.win_rate_pct,
+            report.ending_equity
+        );
+    }
+    html.push_str("</tbody></table>");
+    html
+}
+
+fn drawdown_series(
+    initial_equity: f64,
+    equity_curve: &[(DateTime<Utc>, f64)],
+) -> Vec<(DateTime<Utc>, f64)> {
+    let mut peak = initial_equity;
+    equity_curve
+        .iter()
+        .map(|(ts, equity)| {
+            peak = peak.max(*equity);
+            let drawdown = if peak > 0.0 {
+                (equity - peak) / peak * 100.0
+            } else {
+                0.0
+            };
+            (*ts, drawdown)
+        })
+        .collect()
+}
+
+fn line_chart(series: &[(DateTime<Utc>, f64)], color: &str, fill_to_zero: bool) -> String {
+    if series.len() < 2 {
+        return "<p class=\"muted\">Not enough data.</p>".to_string();
+    }
+    let step = series.len().div_ceil(MAX_CHART_POINTS).max(1);
+    let mut points: Vec<(DateTime<Utc>, f64)> = series.iter().step_by(step).copied().collect();
+    if let Some(last) = series.last() {
+        if points.last().map(|p| p.0) != Some(last.0) {
+            points.push(*last);
+        }
+    }
+    let start = points[0].0.timestamp() as f64;
+    let end = points[points.len() - 1].0.timestamp() as f64;
+    let span = (end - start).max(1.0);
+    let mut min = points.iter().map(|p| p.1).fold(f64::INFINITY, f64::min);
+    let mut max = points.iter().map(|p| p.1).fold(f64::NEG_INFINITY, f64::max);
+    if fill_to_zero {
+        max = max.max(0.0);
+        min = min.min(0.0);
+    }
+    if (max - min).abs() < f64::EPSILON {
+        max += 1.0;
+        min -= 1.0;
+    }
+    let plot_w = CHART_WIDTH - 2.0 * CHART_PADDING;
+    let plot_h = CHART_HEIGHT - 2.0 * CHART_PADDING;
+    let x = |ts: DateTime<Utc>| CHART_PADDING + (ts.timestamp() as f64 - start) / span * plot_w;
+    let y = |value: f64| CHART_PADDING + (max - value) / (max - min) * plot_h;
+
+    let coords = points
+        .iter()
+        .map(|(ts, value)| format!("{:.1},{:.1}", x(*ts), y(*value)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut svg = format!(
+        "<svg width=\"{CHART_WIDTH}\" height=\"{CHART_HEIGHT}\" viewBox=\"0 0 {CHART_WIDTH} {CHART_HEIGHT}\" xmlns=\"http://www.w3.org/2000/svg\">"
+    );
+    let _ = write!(
+        svg,
+        "<rect x=\"{CHART_PADDING}\" y=\"{CHART_PADDING}\" width=\"{plot_w}\" height=\"{plot_h}\" fill=\"#f8fafc\" stroke=\"#d9e2ec\"/>"
+    );
+    if fill_to_zero {
+        let zero = y(0.0);
+        let _ = write!(
+            svg,
+            "<polygon points=\"{:.1},{zero:.1} {coords} {:.1},{zero:.1}\" fill=\"{color}\" fill-opacity=\"0.3\" stroke=\"none\"/>",
+            x(points[0].0),
+            x(points[points.len() - 1].0)
+        );
+    }
+    let _ = write!(
+        svg,
+        "<polyline points=\"{coords}\" fill=\"none\" stroke=\"{color}\" stroke-width=\"1.5\"/>"
+    );
+    let _ = write!(
+        svg,
+        "<text class=\"axis\" x=\"4\" y=\"{:.1}\">{}</text><text class=\"axis\" x=\"4\" y=\"{:.1}\">{}</text>",
+        CHART_PADDING + 4.0,
+        format_axis(max),
+        CHART_PADDING + plot_h,
+        format_axis(min)
+    );
+    let _ = write!(
+        svg,
+        "<text class=\"axis\" x=\"{CHART_PADDING}\" y=\"{:.1}\">{}</text><text class=\"axis\" x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+        CHART_HEIGHT - 12.0,
+        points[0].0.format("%Y-%m-%d"),
+        CHART_WIDTH - CHART_PADDING,
+        CHART_HEIGHT - 12.0,
+        points[points.len() - 1].0.format("%Y-%m-%d")
+    );
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Month-over-month returns in percent keyed by `(year, month)`.
+fn monthly_returns(
+    initial_equity: f64,
+    equity_curve: &[(DateTime<Utc>, f64)],
+) -> BTreeMap<(i32, u32), f64> {
+    let mut closes: BTreeMap<(i32, u32), f64> = BTreeMap::new();
+    for (ts, equity) in equity_curve {
+        closes.insert((ts.year(), ts.month()), *equity);
+    }
+    let mut previous = initial_equity;
+    let mut returns = BTreeMap::new();
+    for (key, close) in closes {
+        if previous.abs() > f64::EPSILON {
+            returns.insert(key, (close / previous - 1.0) * 100.0);
+        }
+        previous = close;
+    }
+    returns
+}
+
+fn monthly_heatmap(initial_equity: f64, equity_curve: &[(DateTime<Utc>, f64)]) -> String {
+    let returns = monthly_returns(initial_equity, equity_curve);
+    if returns.is_empty() {
+        return "<p class=\"muted\">Not enough data.</p>".to_string();
+    }
+    let scale = returns
+        .values()
+        .fold(0.0_f64, |acc, value| acc.max(value.abs()))
+        .max(f64::EPSILON);
+    let mut html = String::from("<table><thead><tr><th>Year</th>");
+    for month in [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ] {
+        let _ = write!(html, "<th>{month}</th>");
+    }
+    html.push_str("<th>Year</th></tr></thead><tbody>");
+    let years: Vec<i32> = returns.keys().map(|(year, _)| *year).collect();
+    let (first_year, last_year) = (years[0], years[years.len() - 1]);
+    for year in first_year..=last_year {
+        let _ = write!(html, "<tr><td>{year}</td>");
+        let mut compounded = 1.0;
+        let mut any = false;
+        for month in 1..=12 {
+            match returns.get(&(year, month)) {
+                Some(value) => {
+                    compounded *= 1.0 + value / 100.0;
+                    any = true;
+                    let _ = write!(
+                        html,
+                        "<td style=\"background:{}\">{value:.2}</td>",
+                        heat_color(*value / scale)
+                    );
+                }
+                None => html.push_str("<td></td>"),
+            }
+        }
+        if any {
+            let _ = write!(html, "<td>{:.2}</td></tr>", (compounded - 1.0) * 100.0);
+        } else {
+            html.push_str("<td></td></tr>");
+        }
+    }
+    html.push_str("</tbody></table>");
+    html
+}
+
+fn heat_color(intensity: f64) -> String {
+    let alpha = intensity.abs().clamp(0.0, 1.0) * 0.75 + 0.05;
+    if intensity >= 0.0 {
+        format!("rgba(39,174,96,{alpha:.2})")
+    } else {
+        format!("rgba(214,69,69,{alpha:.2})")
+    }
+}
+
+fn histogram(values: &[f64]) -> String {
+    if values.is_empty() {
+        return "<p class=\"muted\">No closed trades.</p>".to_string();
+    }
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let width = ((max - min) / HISTOGRAM_BINS as f64).max(f64::EPSILON);
+    let mut counts = [0usize; HISTOGRAM_BINS];
+    for value in values {
+        let idx = (((value - min) / width) as usize).min(HISTOGRAM_BINS - 1);
+        counts[idx] += 1;
+    }
+    let tallest = counts.iter().copied().max().unwrap_or(1).max(1) as f64;
+    let plot_w = CHART_WIDTH - 2.0 * CHART_PADDING;
+    let plot_h = CHART_HEIGHT - 2.0 * CHART_PADDING;
+    let bar_w = plot_w / HISTOGRAM_BINS as f64;
+    let mut svg = format!(
+        "<svg width=\"{CHART_WIDTH}\" height=\"{CHART_HEIGHT}\" viewBox=\"0 0 {CHART_WIDTH} {CHART_HEIGHT}\" xmlns=\"http://www.w3.org/2000/svg\">"
+    );
+    for (idx, count) in counts.iter().enumerate() {
+        let height = *count as f64 / tallest * plot_h;
+        let lower = min + width * idx as f64;
+        let color = if lower + width / 2.0 >= 0.0 {
+            "#27ae60"
+        } else {
+            "#d64545"
+        };
+        let _ = write!(
+            svg,
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{height:.1}\" fill=\"{color}\"><title>{:.2}% .. {:.2}%: {count}</title></rect>",
+            CHART_PADDING + bar_w * idx as f64 + 1.0,
+            CHART_PADDING + plot_h - height,
+            (bar_w - 2.0).max(1.0),
+            lower,
+            lower + width
+        );
+    }
+    let _ = write!(
+        svg,
+        "<text class=\"axis\" x=\"{CHART_PADDING}\" y=\"{:.1}\">{min:.2}%</text><text class=\"axis\" x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"end\">{max:.2}%</text><text class=\"axis\" x=\"4\" y=\"{:.1}\">{}</text>",
+        CHART_HEIGHT - 12.0,
+        CHART_WIDTH - CHART_PADDING,
+        CHART_HEIGHT - 12.0,
+        CHART_PADDING + 4.0,
+        tallest as usize
+    );
+    svg.push_str("</svg>");
+    svg
+}
+
+fn format_axis(value: f64) -> String {
+    if value.abs() >= 1_000.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn renders_self_contained_document() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let equity_curve: Vec<_> = (0..90)
+            .map(|day| {
+                let equity = 10_000.0 + (day as f64 * 0.3).sin() * 250.0 + day as f64 * 5.0;
+                (start + Duration::days(day), equity)
+            })
+            .collect();
+        let report = PerformanceReport {
+            initial_equity: 10_000.0,
+            ending_equity: equity_curve.last().unwrap().1,
+            equity_curve,
+            ..PerformanceReport::default()
+        };
+        let html = render_document("sma <cross>", &[("a", &report), ("b", &report)]);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("sma &lt;cross&gt;"));
+        assert!(html.contains("<h2>Summary</h2>"));
+        assert_eq!(html.matches("<polyline").count(), 4);
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("src=\"http") && !html.contains("href=\"http"));
+
+        let monthly = monthly_returns(report.initial_equity, &report.equity_curve);
+        assert_eq!(monthly.len(), 4);
+        assert!(monthly.contains_key(&(2024, 1)));
+    }
+}
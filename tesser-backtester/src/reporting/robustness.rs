@@ -0,0 +1,515 @@
+//! Resampling diagnostics that estimate how much of a backtest result is noise.
+
+use std::fmt;
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use itertools::Itertools;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use super::{PerformanceReport, TRADING_DAYS_PER_YEAR};
+
+const EULER_MASCHERONI: f64 = 0.577_215_664_901_532_9;
+
+/// Strategy used to generate synthetic trade sequences.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ResamplingMethod {
+    /// Draw trades independently with replacement.
+    #[default]
+    Bootstrap,
+    /// Draw contiguous blocks of trades (with wrap-around) to preserve serial dependence.
+    BlockBootstrap { block_size: usize },
+    /// Permute the original trades; ending equity is unchanged but the path is not.
+    Shuffle,
+}
+
+impl fmt::Display for ResamplingMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bootstrap => write!(f, "bootstrap"),
+            Self::BlockBootstrap { block_size } => write!(f, "block bootstrap ({block_size})"),
+            Self::Shuffle => write!(f, "shuffle"),
+        }
+    }
+}
+
+/// Parameters controlling a robustness run.
+#[derive(Clone, Debug)]
+pub struct RobustnessConfig {
+    pub method: ResamplingMethod,
+    pub iterations: usize,
+    /// Two-sided confidence level of the reported intervals (e.g. 0.95).
+    pub confidence: f64,
+    pub seed: u64,
+    /// Number of configurations tried before picking this one (e.g. rows of `backtest batch`).
+    pub trials: usize,
+    /// Variance of the annualized Sharpe ratios observed across those trials, when known.
+    pub trial_sharpe_variance: Option<f64>,
+}
+
+impl Default for RobustnessConfig {
+    fn default() -> Self {
+        Self {
+            method: ResamplingMethod::default(),
+            iterations: 1_000,
+            confidence: 0.95,
+            seed: 0,
+            trials: 1,
+            trial_sharpe_variance: None,
+        }
+    }
+}
+
+/// Lower bound, median and upper bound of a resampled statistic.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ConfidenceInterval {
+    pub lower: f64,
+    pub median: f64,
+    pub upper: f64,
+}
+
+/// Metrics computed on a single (observed or resampled) trade path.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PathMetrics {
+    pub sharpe_ratio: f64,
+    pub max_drawdown_pct: f64,
+    pub ending_equity: f64,
+}
+
+/// Output of [`analyze`].
+#[derive(Clone, Debug)]
+pub struct RobustnessReport {
+    pub method: ResamplingMethod,
+    pub iterations: usize,
+    pub confidence: f64,
+    pub trades: usize,
+    pub observed: PathMetrics,
+    pub sharpe_ratio: ConfidenceInterval,
+    pub max_drawdown_pct: ConfidenceInterval,
+    pub ending_equity: ConfidenceInterval,
+    /// Share of resampled paths that finished below the initial equity.
+    pub probability_of_loss: f64,
+    /// Probability that the true Sharpe ratio is positive after correcting for the number of
+    /// trials, skewness and kurtosis (Bailey & López de Prado). `None` when there are too few
+    /// daily returns to estimate it.
+    pub deflated_sharpe: Option<f64>,
+}
+
+impl fmt::Display for RobustnessReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = self.confidence * 100.0;
+        writeln!(f, "Backtest Robustness Report")?;
+        writeln!(f, "------------------------------------")?;
+        writeln!(f, "{:<25} {}", "Method", self.method)?;
+        writeln!(f, "{:<25} {}", "Iterations", self.iterations)?;
+        writeln!(f, "{:<25} {}", "Trades", self.trades)?;
+        writeln!(f, "------------------------------------")?;
+        writeln!(
+            f,
+            "{:<25} {:>12} {:>12} {:>12} {:>12}",
+            "Metric",
+            "Observed",
+            format!("{:.1}% low", level),
+            "Median",
+            format!("{:.1}% high", level)
+        )?;
+        writeln!(
+            f,
+            "{:<25} {:>12.2} {:>12.2} {:>12.2} {:>12.2}",
+            "Sharpe Ratio",
+            self.observed.sharpe_ratio,
+            self.sharpe_ratio.lower,
+            self.sharpe_ratio.median,
+            self.sharpe_ratio.upper
+        )?;
+        writeln!(
+            f,
+            "{:<25} {:>11.2}% {:>11.2}% {:>11.2}% {:>11.2}%",
+            "Max Drawdown",
+            self.observed.max_drawdown_pct,
+            self.max_drawdown_pct.lower,
+            self.max_drawdown_pct.median,
+            self.max_drawdown_pct.upper
+        )?;
+        writeln!(
+            f,
+            "{:<25} {:>12.2} {:>12.2} {:>12.2} {:>12.2}",
+            "Ending Equity",
+            self.observed.ending_equity,
+            self.ending_equity.lower,
+            self.ending_equity.median,
+            self.ending_equity.upper
+        )?;
+        writeln!(f, "------------------------------------")?;
+        writeln!(
+            f,
+            "{:<25} {:.2}%",
+            "Probability of Loss",
+            self.probability_of_loss * 100.0
+        )?;
+        match self.deflated_sharpe {
+            Some(value) => writeln!(f, "{:<25} {:.4}", "Deflated Sharpe (p)", value)?,
+            None => writeln!(f, "{:<25} n/a", "Deflated Sharpe (p)")?,
+        }
+        writeln!(f, "------------------------------------")
+    }
+}
+
+/// Resample the report's trades and summarize the spread of the resulting metrics.
+pub fn analyze(report: &PerformanceReport, config: &RobustnessConfig) -> Result<RobustnessReport> {
+    if report.trades.is_empty() {
+        bail!("backtest produced no trades to resample");
+    }
+    if config.iterations == 0 {
+        bail!("robustness analysis requires at least one iteration");
+    }
+    if !(0.0..1.0).contains(&config.confidence) || config.confidence <= 0.0 {
+        bail!(
+            "confidence must be between 0 and 1 (got {})",
+            config.confidence
+        );
+    }
+    if let ResamplingMethod::BlockBootstrap { block_size: 0 } = config.method {
+        bail!("block size must be positive");
+    }
+
+    let pnls: Vec<f64> = report.trades.iter().map(|trade| trade.pnl).collect();
+    let initial = report.initial_equity;
+    let annualization = trade_annualization(&report.equity_curve, pnls.len());
+    let observed = path_metrics(initial, pnls.iter().copied(), annualization);
+
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let mut indices: Vec<usize> = (0..pnls.len()).collect();
+    let mut sharpes = Vec::with_capacity(config.iterations);
+    let mut drawdowns = Vec::with_capacity(config.iterations);
+    let mut endings = Vec::with_capacity(config.iterations);
+    for _ in 0..config.iterations {
+        resample_indices(config.method, pnls.len(), &mut rng, &mut indices);
+        let metrics = path_metrics(initial, indices.iter().map(|&i| pnls[i]), annualization);
+        sharpes.push(metrics.sharpe_ratio);
+        drawdowns.push(metrics.max_drawdown_pct);
+        endings.push(metrics.ending_equity);
+    }
+    let losses = endings.iter().filter(|&&equity| equity < initial).count();
+
+    let daily = daily_returns(&report.equity_curve);
+    let deflated_sharpe = deflated_sharpe_ratio(
+        &daily,
+        config.trials,
+        config
+            .trial_sharpe_variance
+            .map(|variance| variance / TRADING_DAYS_PER_YEAR),
+    );
+
+    Ok(RobustnessReport {
+        method: config.method,
+        iterations: config.iterations,
+        confidence: config.confidence,
+        trades: pnls.len(),
+        observed,
+        sharpe_ratio: interval(&mut sharpes, config.confidence),
+        max_drawdown_pct: interval(&mut drawdowns, config.confidence),
+        ending_equity: interval(&mut endings, config.confidence),
+        probability_of_loss: losses as f64 / config.iterations as f64,
+        deflated_sharpe,
+    })
+}
+
+/// Deflated Sharpe ratio of per-period `returns` given the number of `trials` attempted.
+///
+/// `sharpe_variance` is the variance of the per-period (non-annualized) Sharpe ratios across
+/// trials; when unknown, the sampling variance of this strategy's estimate is used instead.
+/// Returns the probability that the true Sharpe ratio exceeds the maximum expected from
+/// `trials` unskilled configurations.
+pub fn deflated_sharpe_ratio(
+    returns: &[f64],
+    trials: usize,
+    sharpe_variance: Option<f64>,
+) -> Option<f64> {
+    let n = returns.len();
+    if n < 3 {
+        return None;
+    }
+    let count = n as f64;
+    let mean = returns.iter().sum::<f64>() / count;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / count;
+    let std_dev = variance.sqrt();
+    if std_dev < 1e-12 {
+        return None;
+    }
+    let skew = returns
+        .iter()
+        .map(|r| ((r - mean) / std_dev).powi(3))
+        .sum::<f64>()
+        / count;
+    let kurtosis = returns
+        .iter()
+        .map(|r| ((r - mean) / std_dev).powi(4))
+        .sum::<f64>()
+        / count;
+    let sharpe = mean / std_dev;
+    let dispersion = 1.0 - skew * sharpe + (kurtosis - 1.0) / 4.0 * sharpe.powi(2);
+    if dispersion <= 0.0 {
+        return None;
+    }
+    let benchmark = if trials <= 1 {
+        0.0
+    } else {
+        let trials = trials as f64;
+        let variance = sharpe_variance
+            .unwrap_or(dispersion / (count - 1.0))
+            .max(0.0);
+        variance.sqrt()
+            * ((1.0 - EULER_MASCHERONI) * normal_inverse_cdf(1.0 - 1.0 / trials)
+                + EULER_MASCHERONI * normal_inverse_cdf(1.0 - 1.0 / (trials * std::f64::consts::E)))
+    };
+    Some(normal_cdf(
+        (sharpe - benchmark) * (count - 1.0).sqrt() / dispersion.sqrt(),
+    ))
+}
+
+fn resample_indices(method: ResamplingMethod, len: usize, rng: &mut StdRng, out: &mut Vec<usize>) {
+    out.clear();
+    match method {
+        ResamplingMethod::Bootstrap => out.extend((0..len).map(|_| rng.gen_range(0..len))),
+        ResamplingMethod::BlockBootstrap { block_size } => {
+            while out.len() < len {
+                let start = rng.gen_range(0..len);
+                let take = block_size.min(len - out.len());
+                out.extend((0..take).map(|offset| (start + offset) % len));
+            }
+        }
+        ResamplingMethod::Shuffle => {
+            out.extend(0..len);
+            out.shuffle(rng);
+        }
+    }
+}
+
+fn path_metrics(initial: f64, pnls: impl Iterator<Item = f64>, annualization: f64) -> PathMetrics {
+    let mut equity = initial;
+    let mut peak = initial;
+    let mut max_drawdown = 0.0_f64;
+    let mut returns = Vec::new();
+    for pnl in pnls {
+        if equity.abs() > f64::EPSILON {
+            returns.push(pnl / equity);
+        }
+        equity += pnl;
+        peak = peak.max(equity);
+        if peak > 0.0 {
+            max_drawdown = max_drawdown.max((peak - equity) / peak);
+        }
+    }
+    PathMetrics {
+        sharpe_ratio: sharpe(&returns) * annualization,
+        max_drawdown_pct: max_drawdown * 100.0,
+        ending_equity: equity,
+    }
+}
+
+fn sharpe(returns: &[f64]) -> f64 {
+    let n = returns.len() as f64;
+    if n < 2.0 {
+        return 0.0;
+    }
+    let mean = returns.iter().sum::<f64>() / n;
+    let std_dev = (returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+    if std_dev > 1e-9 {
+        mean / std_dev
+    } else {
+        0.0
+    }
+}
+
+/// Square root of the number of trades per year, so per-trade Sharpe ratios are comparable
+/// with the annualized figure in [`PerformanceReport`].
+fn trade_annualization(equity_curve: &[(DateTime<Utc>, f64)], trades: usize) -> f64 {
+    let (Some(first), Some(last)) = (equity_curve.first(), equity_curve.last()) else {
+        return 1.0;
+    };
+    let years = (last.0 - first.0).num_seconds() as f64 / (365.25 * 86_400.0);
+    if years <= 0.0 || trades == 0 {
+        return 1.0;
+    }
+    (trades as f64 / years).sqrt()
+}
+
+fn daily_returns(equity_curve: &[(DateTime<Utc>, f64)]) -> Vec<f64> {
+    let closes: Vec<f64> = equity_curve
+        .iter()
+        .chunk_by(|(ts, _)| ts.date_naive())
+        .into_iter()
+        .filter_map(|(_, group)| group.last().map(|(_, equity)| *equity))
+        .collect();
+    closes
+        .windows(2)
+        .filter(|window| window[0].abs() > f64::EPSILON)
+        .map(|window| window[1] / window[0] - 1.0)
+        .collect()
+}
+
+fn interval(values: &mut [f64], confidence: f64) -> ConfidenceInterval {
+    values.sort_by(|a, b| a.total_cmp(b));
+    let tail = (1.0 - confidence) / 2.0;
+    ConfidenceInterval {
+        lower: percentile(values, tail),
+        median: percentile(values, 0.5),
+        upper: percentile(values, 1.0 - tail),
+    }
+}
+
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = q.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let lo = rank.floor() as usize;
+    let hi = rank.ceil() as usize;
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+}
+
+fn normal_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2))
+}
+
+/// Abramowitz & Stegun 7.1.26 (absolute error below 1.5e-7).
+fn erf(x: f64) -> f64 {
+    let sign = x.signum();
+    let x = x.abs();
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    sign * (1.0 - poly * (-x * x).exp())
+}
+
+/// Acklam's rational approximation of the standard normal quantile function.
+fn normal_inverse_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const LOW: f64 = 0.024_25;
+
+    let p = p.clamp(f64::MIN_POSITIVE, 1.0 - f64::EPSILON);
+    if p < LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -normal_inverse_cdf(1.0 - p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporting::TradeRecord;
+    use chrono::{Duration, TimeZone};
+    use tesser_core::Side;
+
+    fn sample_report() -> PerformanceReport {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let pnls = [
+            120.0, -40.0, 75.0, -60.0, 30.0, 90.0, -25.0, 45.0, -80.0, 60.0,
+        ];
+        let mut equity = 10_000.0;
+        let mut curve = vec![(start, equity)];
+        let mut trades = Vec::new();
+        for (idx, pnl) in pnls.iter().enumerate() {
+            let entry_time = start + Duration::days(idx as i64);
+            let exit_time = entry_time + Duration::hours(12);
+            equity += pnl;
+            curve.push((exit_time, equity));
+            trades.push(TradeRecord {
+                entry_time,
+                exit_time,
+                side: Side::Buy,
+                quantity: 1.0,
+                return_ratio: pnl / 10_000.0,
+                pnl: *pnl,
+            });
+        }
+        PerformanceReport {
+            initial_equity: 10_000.0,
+            ending_equity: equity,
+            equity_curve: curve,
+            trades,
+            ..PerformanceReport::default()
+        }
+    }
+
+    #[test]
+    fn resampling_is_seeded_and_brackets_observed_path() {
+        let report = sample_report();
+        let config = RobustnessConfig {
+            iterations: 500,
+            seed: 7,
+            ..RobustnessConfig::default()
+        };
+        let first = analyze(&report, &config).unwrap();
+        let second = analyze(&report, &config).unwrap();
+        assert_eq!(first.sharpe_ratio, second.sharpe_ratio);
+        assert!((first.observed.ending_equity - report.ending_equity).abs() < 1e-9);
+        assert!(first.ending_equity.lower <= first.observed.ending_equity);
+        assert!(first.ending_equity.upper >= first.observed.ending_equity);
+        assert!(first.max_drawdown_pct.lower <= first.max_drawdown_pct.upper);
+
+        let shuffled = analyze(
+            &report,
+            &RobustnessConfig {
+                method: ResamplingMethod::Shuffle,
+                ..config
+            },
+        )
+        .unwrap();
+        assert!((shuffled.ending_equity.lower - report.ending_equity).abs() < 1e-6);
+        assert!((shuffled.ending_equity.upper - report.ending_equity).abs() < 1e-6);
+        assert_eq!(shuffled.probability_of_loss, 0.0);
+    }
+
+    #[test]
+    fn deflated_sharpe_penalizes_many_trials() {
+        let returns: Vec<f64> = (0..250)
+            .map(|i| 0.001 + if i % 2 == 0 { 0.01 } else { -0.009 })
+            .collect();
+        let single = deflated_sharpe_ratio(&returns, 1, None).unwrap();
+        let many = deflated_sharpe_ratio(&returns, 200, None).unwrap();
+        assert!(single > 0.5);
+        assert!(many < single);
+        assert!((normal_inverse_cdf(0.975) - 1.959_964).abs() < 1e-4);
+        assert!((normal_cdf(1.959_964) - 0.975).abs() < 1e-6);
+    }
+}
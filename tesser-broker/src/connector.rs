@@ -6,7 +6,7 @@ use std::{
 use async_trait::async_trait;
 use once_cell::sync::Lazy;
 use serde_json::Value;
-use tesser_core::{Candle, Interval, OrderBook, Tick};
+use tesser_core::{Candle, Interval, MarketByOrderUpdate, OrderBook, Tick};
 
 use crate::{BrokerError, BrokerResult, ExecutionClient};
 
@@ -35,6 +35,12 @@ pub trait ConnectorStream: Send {
 
     /// Fetch the next order book snapshot generated by the subscribed markets.
     async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>>;
+
+    /// Fetch the next order-level (L3) update; venues without a market-by-order feed never
+    /// produce one.
+    async fn next_market_by_order(&mut self) -> BrokerResult<Option<MarketByOrderUpdate>> {
+        Ok(None)
+    }
 }
 
 /// Factory trait implemented by each connector to expose standardized construction hooks.
@@ -0,0 +1,563 @@
+//! Runtime alerting: health watchdogs feeding a routed, rate-limited dispatcher.
+
+mod sinks;
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use reqwest::Client;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use tesser_config::{
+    AlertChannelConfig, AlertRouteConfig, AlertSeverity, AlertSinkConfig, AlertingConfig,
+};
+use tokio::sync::Mutex;
+use tokio::time::interval;
+use tracing::{error, info, warn};
+
+pub use sinks::AlertSink;
+
+/// A single notification raised by the live runtime.
+#[derive(Clone, Debug)]
+pub struct Alert {
+    pub severity: AlertSeverity,
+    pub title: String,
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+struct AlertChannel {
+    name: String,
+    min_severity: AlertSeverity,
+    sink: Box<dyn AlertSink>,
+}
+
+/// Suppression bookkeeping shared by every clone of a dispatcher.
+#[derive(Default)]
+struct DispatchState {
+    /// Last delivery per `(title, message)`.
+    recent: HashMap<(String, String), Instant>,
+    /// Delivery times per title inside the current rate-limit window.
+    windows: HashMap<String, Vec<Instant>>,
+    /// Alerts dropped per title since the last delivery.
+    suppressed: HashMap<String, u32>,
+}
+
+#[derive(Clone)]
+pub struct AlertDispatcher {
+    channels: Arc<Vec<AlertChannel>>,
+    routes: Arc<Vec<AlertRouteConfig>>,
+    dedup_window: Duration,
+    rate_limit: u32,
+    rate_window: Duration,
+    state: Arc<std::sync::Mutex<DispatchState>>,
+}
+
+impl AlertDispatcher {
+    /// Builds the channels and routes declared under `[live.alerting]`.
+    pub fn from_config(config: &AlertingConfig) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(sinks::DELIVERY_TIMEOUT)
+            .build()
+            .context("failed to build the alert HTTP client")?;
+        let mut declared = config.channels.clone();
+        if let Some(url) = config.webhook_url.clone() {
+            if !declared.iter().any(|channel| channel.name == "webhook") {
+                declared.push(AlertChannelConfig {
+                    name: "webhook".into(),
+                    min_severity: AlertSeverity::Info,
+                    sink: AlertSinkConfig::Webhook { url },
+                });
+            }
+        }
+        let mut names = HashSet::new();
+        let mut channels = Vec::with_capacity(declared.len());
+        for channel in declared {
+            if !names.insert(channel.name.clone()) {
+                bail!("duplicate alert channel '{}'", channel.name);
+            }
+            channels.push(AlertChannel {
+                sink: sinks::build_sink(&channel.sink, &client)
+                    .with_context(|| format!("invalid alert channel '{}'", channel.name))?,
+                name: channel.name,
+                min_severity: channel.min_severity,
+            });
+        }
+        for route in &config.routes {
+            if let Some(unknown) = route.channels.iter().find(|name| !names.contains(*name)) {
+                bail!("alert route references unknown channel '{unknown}'");
+            }
+        }
+        Ok(Self {
+            channels: Arc::new(channels),
+            routes: Arc::new(config.routes.clone()),
+            dedup_window: Duration::from_secs(config.dedup_window_secs),
+            rate_limit: config.rate_limit,
+            rate_window: Duration::from_secs(config.rate_limit_window_secs),
+            state: Arc::new(std::sync::Mutex::new(DispatchState::default())),
+        })
+    }
+
+    pub async fn notify(&self, severity: AlertSeverity, title: &str, message: &str) {
+        match severity {
+            AlertSeverity::Info => info!(%title, %message, "alert raised"),
+            _ => warn!(%title, severity = severity.as_str(), %message, "alert raised"),
+        }
+        let Some(message) = self.admit(title, message) else {
+            return;
+        };
+        let alert = Alert {
+            severity,
+            title: title.to_string(),
+            message,
+            timestamp: Utc::now(),
+        };
+        let alert = &alert;
+        let deliveries = self.route(alert).into_iter().map(|channel| async move {
+            if let Err(err) = channel.sink.send(alert).await {
+                error!(channel = %channel.name, error = %err, "failed to deliver alert");
+            }
+        });
+        join_all(deliveries).await;
+    }
+
+    /// Applies dedup and rate limiting, returning the message to deliver (annotated with the
+    /// number of alerts suppressed since the last delivery) or `None` when suppressed.
+    fn admit(&self, title: &str, message: &str) -> Option<String> {
+        let now = Instant::now();
+        let mut guard = self.state.lock().unwrap();
+        let state = &mut *guard;
+        let dedup_key = (title.to_string(), message.to_string());
+        if !self.dedup_window.is_zero() {
+            let window = self.dedup_window;
+            state
+                .recent
+                .retain(|_, sent| now.duration_since(*sent) < window);
+            if state.recent.contains_key(&dedup_key) {
+                *state.suppressed.entry(title.to_string()).or_default() += 1;
+                return None;
+            }
+        }
+        if self.rate_limit > 0 {
+            let window = self.rate_window;
+            let sent = state.windows.entry(title.to_string()).or_default();
+            sent.retain(|at| now.duration_since(*at) < window);
+            if sent.len() >= self.rate_limit as usize {
+                *state.suppressed.entry(title.to_string()).or_default() += 1;
+                return None;
+            }
+            sent.push(now);
+        }
+        if !self.dedup_window.is_zero() {
+            state.recent.insert(dedup_key, now);
+        }
+        match state.suppressed.remove(title) {
+            Some(count) if count > 0 => {
+                Some(format!("{message} ({count} similar alerts suppressed)"))
+            }
+            _ => Some(message.to_string()),
+        }
+    }
+
+    fn route(&self, alert: &Alert) -> Vec<&AlertChannel> {
+        let title = alert.title.to_ascii_lowercase();
+        let matching: Vec<&AlertRouteConfig> = self
+            .routes
+            .iter()
+            .filter(|route| alert.severity >= route.min_severity)
+            .filter(|route| {
+                route.titles.is_empty()
+                    || route
+                        .titles
+                        .iter()
+                        .any(|prefix| title.starts_with(&prefix.to_ascii_lowercase()))
+            })
+            .collect();
+        self.channels
+            .iter()
+            .filter(|channel| alert.severity >= channel.min_severity)
+            .filter(|channel| {
+                matching.is_empty()
+                    || matching
+                        .iter()
+                        .any(|route| route.channels.contains(&channel.name))
+            })
+            .collect()
+    }
+}
+
+struct AlertState {
+    last_data: Instant,
+    consecutive_failures: u32,
+    peak_equity: Decimal,
+    drawdown_triggered: bool,
+    data_gap_triggered: bool,
+    last_public_connection: Instant,
+    last_private_connection: Instant,
+    public_alerted: bool,
+    private_alerted: bool,
+    checksum_alerts: HashMap<String, Instant>,
+}
+
+pub struct AlertManager {
+    config: AlertingConfig,
+    dispatcher: AlertDispatcher,
+    state: Arc<Mutex<AlertState>>,
+    public_connection: Option<Arc<std::sync::atomic::AtomicBool>>,
+    private_connection: Option<Arc<std::sync::atomic::AtomicBool>>,
+}
+
+impl AlertManager {
+    pub fn new(
+        config: AlertingConfig,
+        dispatcher: AlertDispatcher,
+        public_connection: Option<Arc<std::sync::atomic::AtomicBool>>,
+        private_connection: Option<Arc<std::sync::atomic::AtomicBool>>,
+    ) -> Self {
+        let state = AlertState {
+            last_data: Instant::now(),
+            consecutive_failures: 0,
+            peak_equity: Decimal::ZERO,
+            drawdown_triggered: false,
+            data_gap_triggered: false,
+            last_public_connection: Instant::now(),
+            last_private_connection: Instant::now(),
+            public_alerted: false,
+            private_alerted: false,
+            checksum_alerts: HashMap::new(),
+        };
+        Self {
+            config,
+            dispatcher,
+            state: Arc::new(Mutex::new(state)),
+            public_connection,
+            private_connection,
+        }
+    }
+
+    pub async fn heartbeat(&self) {
+        let mut state = self.state.lock().await;
+        state.last_data = Instant::now();
+        state.data_gap_triggered = false;
+    }
+
+    pub async fn order_failure(&self, reason: &str) {
+        let mut state = self.state.lock().await;
+        state.consecutive_failures += 1;
+        let max_failures = self.config.max_order_failures.max(1);
+        if state.consecutive_failures >= max_failures {
+            drop(state);
+            self.dispatcher
+                .notify(
+                    AlertSeverity::Critical,
+                    "Execution failures",
+                    &format!("{} consecutive order failures ({reason})", max_failures),
+                )
+                .await;
+            let mut state = self.state.lock().await;
+            state.consecutive_failures = 0;
+        }
+    }
+
+    pub async fn reset_order_failures(&self) {
+        let mut state = self.state.lock().await;
+        state.consecutive_failures = 0;
+    }
+
+    pub async fn order_book_checksum_mismatch(
+        &self,
+        driver: &str,
+        symbol: &str,
+        expected: u32,
+        actual: u32,
+    ) {
+        let mut state = self.state.lock().await;
+        let key = format!("{driver}:{symbol}");
+        let now = Instant::now();
+        if let Some(last) = state.checksum_alerts.get(&key) {
+            if now.duration_since(*last) < Duration::from_secs(30) {
+                return;
+            }
+        }
+        state.checksum_alerts.insert(key, now);
+        drop(state);
+        self.dispatcher
+            .notify(
+                AlertSeverity::Warning,
+                "Order book checksum mismatch",
+                &format!(
+                    "Driver {driver} symbol {symbol} checksum mismatch (expected {expected}, local {actual})"
+                ),
+            )
+            .await;
+    }
+
+    pub async fn notify(&self, severity: AlertSeverity, title: &str, message: &str) {
+        self.dispatcher.notify(severity, title, message).await;
+    }
+
+    pub async fn update_equity(&self, equity: Decimal) {
+        if equity <= Decimal::ZERO {
+            return;
+        }
+        let mut state = self.state.lock().await;
+        if equity > state.peak_equity {
+            state.peak_equity = equity;
+            state.drawdown_triggered = false;
+            return;
+        }
+        if state.peak_equity <= Decimal::ZERO {
+            state.peak_equity = equity;
+            return;
+        }
+        let drawdown = (state.peak_equity - equity) / state.peak_equity;
+        if drawdown >= self.config.max_drawdown && !state.drawdown_triggered {
+            state.drawdown_triggered = true;
+            let peak = state.peak_equity;
+            drop(state);
+            let equity_val = equity.to_f64().unwrap_or(0.0);
+            let peak_val = peak.to_f64().unwrap_or(0.0);
+            let drawdown_pct = drawdown.to_f64().unwrap_or(0.0) * 100.0;
+            self.dispatcher
+                .notify(
+                    AlertSeverity::Critical,
+                    "Drawdown limit breached",
+                    &format!(
+                        "Current equity {:.2} vs peak {:.2} (drawdown {:.2}%)",
+                        equity_val, peak_val, drawdown_pct
+                    ),
+                )
+                .await;
+        }
+    }
+
+    pub fn spawn_watchdog(&self) -> Option<tokio::task::JoinHandle<()>> {
+        let threshold = self.config.max_data_gap_secs;
+        if threshold == 0 {
+            return None;
+        }
+        let dispatcher = self.dispatcher.clone();
+        let state = self.state.clone();
+        let public_connection = self.public_connection.clone();
+        let private_connection = self.private_connection.clone();
+        let period = Duration::from_secs(threshold);
+        Some(tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_secs(30));
+            loop {
+                ticker.tick().await;
+                let mut guard = state.lock().await;
+                let now = Instant::now();
+                if let Some(flag) = &public_connection {
+                    if flag.load(std::sync::atomic::Ordering::SeqCst) {
+                        guard.last_public_connection = now;
+                        guard.public_alerted = false;
+                    } else if !guard.public_alerted
+                        && now.duration_since(guard.last_public_connection)
+                            >= Duration::from_secs(60)
+                    {
+                        guard.public_alerted = true;
+                        drop(guard);
+                        dispatcher
+                            .notify(
+                                AlertSeverity::Critical,
+                                "Exchange connection lost (public)",
+                                "Public stream disconnected for over 60s",
+                            )
+                            .await;
+                        guard = state.lock().await;
+                    }
+                }
+                if let Some(flag) = &private_connection {
+                    if flag.load(std::sync::atomic::Ordering::SeqCst) {
+                        guard.last_private_connection = now;
+                        guard.private_alerted = false;
+                    } else if !guard.private_alerted
+                        && now.duration_since(guard.last_private_connection)
+                            >= Duration::from_secs(60)
+                    {
+                        guard.private_alerted = true;
+                        drop(guard);
+                        dispatcher
+                            .notify(
+                                AlertSeverity::Critical,
+                                "Exchange connection lost (private)",
+                                "Private stream disconnected for over 60s",
+                            )
+                            .await;
+                        guard = state.lock().await;
+                    }
+                }
+                if guard.last_data.elapsed() >= period && !guard.data_gap_triggered {
+                    guard.data_gap_triggered = true;
+                    drop(guard);
+                    dispatcher
+                        .notify(
+                            AlertSeverity::Warning,
+                            "Market data stalled",
+                            "No heartbeat in configured window",
+                        )
+                        .await;
+                }
+            }
+        }))
+    }
+}
+
+pub fn sanitize_webhook(input: Option<String>) -> Option<String> {
+    input.and_then(|value| {
+        let trimmed = value.trim().to_string();
+        if trimmed.is_empty() {
+            None
+        } else {
+            Some(trimmed)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::service::{make_service_fn, service_fn};
+    use hyper::{body::to_bytes, Body, Request, Response};
+    use serde_json::Value;
+    use tokio::sync::mpsc;
+
+    /// Local HTTP stand-in recording every JSON body posted to it.
+    async fn stand_in() -> (String, mpsc::UnboundedReceiver<Value>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let make_svc = make_service_fn(move |_| {
+            let tx = tx.clone();
+            async move {
+                Ok::<_, hyper::Error>(service_fn(move |req: Request<Body>| {
+                    let tx = tx.clone();
+                    async move {
+                        let bytes = to_bytes(req.into_body()).await?;
+                        if let Ok(json) = serde_json::from_slice::<Value>(&bytes) {
+                            let _ = tx.send(json);
+                        }
+                        Ok::<_, hyper::Error>(Response::new(Body::from("ok")))
+                    }
+                }))
+            }
+        });
+        let server = hyper::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(make_svc);
+        let url = format!("http://{}", server.local_addr());
+        tokio::spawn(server);
+        (url, rx)
+    }
+
+    fn drain(rx: &mut mpsc::UnboundedReceiver<Value>) -> Vec<Value> {
+        let mut out = Vec::new();
+        while let Ok(value) = rx.try_recv() {
+            out.push(value);
+        }
+        out
+    }
+
+    #[tokio::test]
+    async fn routes_by_title_and_severity() {
+        let (slack_url, mut slack_rx) = stand_in().await;
+        let (pager_url, mut pager_rx) = stand_in().await;
+        let config = AlertingConfig {
+            channels: vec![
+                AlertChannelConfig {
+                    name: "chat".into(),
+                    min_severity: AlertSeverity::Info,
+                    sink: AlertSinkConfig::Slack {
+                        webhook_url: slack_url,
+                    },
+                },
+                AlertChannelConfig {
+                    name: "pager".into(),
+                    min_severity: AlertSeverity::Critical,
+                    sink: AlertSinkConfig::PagerDuty {
+                        routing_key: "key".into(),
+                        events_url: pager_url,
+                    },
+                },
+            ],
+            routes: vec![AlertRouteConfig {
+                titles: vec!["exchange connection".into()],
+                min_severity: AlertSeverity::Info,
+                channels: vec!["pager".into()],
+            }],
+            ..AlertingConfig::default()
+        };
+        let dispatcher = AlertDispatcher::from_config(&config).unwrap();
+        dispatcher
+            .notify(
+                AlertSeverity::Critical,
+                "Exchange connection lost (public)",
+                "down",
+            )
+            .await;
+        // Unrouted alerts fall back to every channel admitting their severity.
+        dispatcher
+            .notify(AlertSeverity::Warning, "Order rejected", "rejected")
+            .await;
+
+        let pages = drain(&mut pager_rx);
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0]["payload"]["severity"], "critical");
+        assert_eq!(pages[0]["event_action"], "trigger");
+        let chats = drain(&mut slack_rx);
+        assert_eq!(chats.len(), 1);
+        assert!(chats[0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("[WARNING] Order rejected"));
+    }
+
+    #[tokio::test]
+    async fn dedups_and_rate_limits_storms() {
+        let (url, mut rx) = stand_in().await;
+        let config = AlertingConfig {
+            webhook_url: Some(url),
+            rate_limit: 2,
+            ..AlertingConfig::default()
+        };
+        let dispatcher = AlertDispatcher::from_config(&config).unwrap();
+        for _ in 0..3 {
+            dispatcher
+                .notify(AlertSeverity::Critical, "Disconnect", "stream dropped")
+                .await;
+        }
+        for idx in 0..3 {
+            dispatcher
+                .notify(
+                    AlertSeverity::Critical,
+                    "Disconnect",
+                    &format!("retry {idx}"),
+                )
+                .await;
+        }
+        let delivered = drain(&mut rx);
+        let messages: Vec<_> = delivered
+            .iter()
+            .map(|value| value["message"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "stream dropped".to_string(),
+                "retry 0 (2 similar alerts suppressed)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_routes_to_unknown_channels() {
+        let config = AlertingConfig {
+            routes: vec![AlertRouteConfig {
+                titles: Vec::new(),
+                min_severity: AlertSeverity::Info,
+                channels: vec!["missing".into()],
+            }],
+            ..AlertingConfig::default()
+        };
+        assert!(AlertDispatcher::from_config(&config).is_err());
+    }
+}
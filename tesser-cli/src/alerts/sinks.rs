@@ -0,0 +1,524 @@
+//! Delivery transports for alert channels.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use reqwest::Client;
+use serde_json::json;
+use tesser_config::{AlertSinkConfig, SmtpSecurity};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+use tokio_rustls::TlsConnector;
+
+use super::Alert;
+
+/// Upper bound on a single delivery, so an endpoint that stops responding cannot stall the
+/// caller (alerts are raised inline from the live loop).
+pub(super) const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Transport capable of delivering a single alert.
+#[async_trait]
+pub trait AlertSink: Send + Sync {
+    async fn send(&self, alert: &Alert) -> Result<()>;
+}
+
+/// Builds the sink described by `config`, sharing `client` across HTTP transports.
+pub fn build_sink(config: &AlertSinkConfig, client: &Client) -> Result<Box<dyn AlertSink>> {
+    Ok(match config.clone() {
+        AlertSinkConfig::Webhook { url } => Box::new(WebhookSink {
+            client: client.clone(),
+            url,
+        }),
+        AlertSinkConfig::Slack { webhook_url } => Box::new(SlackSink {
+            client: client.clone(),
+            url: webhook_url,
+        }),
+        AlertSinkConfig::Telegram {
+            bot_token,
+            chat_id,
+            api_url,
+        } => Box::new(TelegramSink {
+            client: client.clone(),
+            url: format!(
+                "{}/bot{bot_token}/sendMessage",
+                api_url.trim_end_matches('/')
+            ),
+            chat_id,
+        }),
+        AlertSinkConfig::Smtp {
+            host,
+            port,
+            security,
+            username,
+            password,
+            from,
+            to,
+        } => {
+            if security == SmtpSecurity::None && username.is_some() {
+                bail!("smtp channel for {host}:{port} refuses to send credentials without TLS");
+            }
+            let tls = match security {
+                SmtpSecurity::None => None,
+                SmtpSecurity::StartTls | SmtpSecurity::Tls => Some(tls_connector()?),
+            };
+            Box::new(SmtpSink {
+                host,
+                port,
+                security,
+                tls,
+                credentials: username.map(|user| (user, password.unwrap_or_default())),
+                from,
+                to,
+                timeout: DELIVERY_TIMEOUT,
+            })
+        }
+        AlertSinkConfig::PagerDuty {
+            routing_key,
+            events_url,
+        } => Box::new(PagerDutySink {
+            client: client.clone(),
+            url: events_url,
+            routing_key,
+        }),
+        AlertSinkConfig::File { path } => Box::new(FileSink { path }),
+    })
+}
+
+/// TLS client verifying relays against the platform trust store.
+fn tls_connector() -> Result<TlsConnector> {
+    let mut roots = RootCertStore::empty();
+    let certs = rustls_native_certs::load_native_certs()
+        .context("failed to load the platform certificate store")?;
+    roots.add_parsable_certificates(certs);
+    let config = ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    Ok(TlsConnector::from(Arc::new(config)))
+}
+
+async fn post_json(client: &Client, url: &str, payload: &serde_json::Value) -> Result<()> {
+    client
+        .post(url)
+        .json(payload)
+        .send()
+        .await
+        .with_context(|| format!("failed to POST alert to {url}"))?
+        .error_for_status()
+        .with_context(|| format!("alert endpoint {url} rejected the request"))?;
+    Ok(())
+}
+
+fn headline(alert: &Alert) -> String {
+    format!(
+        "[{}] {}",
+        alert.severity.as_str().to_uppercase(),
+        alert.title
+    )
+}
+
+struct WebhookSink {
+    client: Client,
+    url: String,
+}
+
+#[async_trait]
+impl AlertSink for WebhookSink {
+    async fn send(&self, alert: &Alert) -> Result<()> {
+        let payload = json!({
+            "title": alert.title,
+            "message": alert.message,
+            "severity": alert.severity.as_str(),
+            "timestamp": alert.timestamp.to_rfc3339(),
+        });
+        post_json(&self.client, &self.url, &payload).await
+    }
+}
+
+struct SlackSink {
+    client: Client,
+    url: String,
+}
+
+#[async_trait]
+impl AlertSink for SlackSink {
+    async fn send(&self, alert: &Alert) -> Result<()> {
+        let payload = json!({ "text": format!("*{}*\n{}", headline(alert), alert.message) });
+        post_json(&self.client, &self.url, &payload).await
+    }
+}
+
+struct TelegramSink {
+    client: Client,
+    url: String,
+    chat_id: String,
+}
+
+#[async_trait]
+impl AlertSink for TelegramSink {
+    async fn send(&self, alert: &Alert) -> Result<()> {
+        let payload = json!({
+            "chat_id": self.chat_id,
+            "text": format!("{}\n{}", headline(alert), alert.message),
+        });
+        post_json(&self.client, &self.url, &payload).await
+    }
+}
+
+struct PagerDutySink {
+    client: Client,
+    url: String,
+    routing_key: String,
+}
+
+#[async_trait]
+impl AlertSink for PagerDutySink {
+    async fn send(&self, alert: &Alert) -> Result<()> {
+        // Keying on the title lets PagerDuty fold repeats into the open incident.
+        let payload = json!({
+            "routing_key": self.routing_key,
+            "event_action": "trigger",
+            "dedup_key": format!("tesser:{}", alert.title),
+            "payload": {
+                "summary": format!("{}: {}", alert.title, alert.message),
+                "source": "tesser",
+                "severity": alert.severity.as_str(),
+                "timestamp": alert.timestamp.to_rfc3339(),
+            },
+        });
+        post_json(&self.client, &self.url, &payload).await
+    }
+}
+
+struct FileSink {
+    path: PathBuf,
+}
+
+#[async_trait]
+impl AlertSink for FileSink {
+    async fn send(&self, alert: &Alert) -> Result<()> {
+        let mut line = serde_json::to_string(&json!({
+            "timestamp": alert.timestamp.to_rfc3339(),
+            "severity": alert.severity.as_str(),
+            "title": alert.title,
+            "message": alert.message,
+        }))?;
+        line.push('\n');
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .with_context(|| format!("failed to open alert log {}", self.path.display()))?;
+        file.write_all(line.as_bytes()).await?;
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+/// Minimal SMTP submission client speaking STARTTLS, implicit TLS or (for trusted local
+/// relays) cleartext.
+struct SmtpSink {
+    host: String,
+    port: u16,
+    security: SmtpSecurity,
+    tls: Option<TlsConnector>,
+    credentials: Option<(String, String)>,
+    from: String,
+    to: Vec<String>,
+    timeout: Duration,
+}
+
+impl SmtpSink {
+    fn message(&self, alert: &Alert) -> String {
+        let mut body = String::new();
+        for line in alert.message.lines() {
+            // Dot-stuffing keeps a lone "." from terminating DATA early.
+            if line.starts_with('.') {
+                body.push('.');
+            }
+            body.push_str(line);
+            body.push_str("\r\n");
+        }
+        format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{body}",
+            self.from,
+            self.to.join(", "),
+            headline(alert),
+            alert.timestamp.to_rfc2822(),
+        )
+    }
+
+    async fn deliver(&self, alert: &Alert) -> Result<()> {
+        let stream = TcpStream::connect((self.host.as_str(), self.port))
+            .await
+            .with_context(|| format!("failed to connect to smtp://{}:{}", self.host, self.port))?;
+        match self.security {
+            SmtpSecurity::None => {
+                let mut session = SmtpSession::new(stream);
+                session.expect(220).await?;
+                session.command("EHLO tesser", 250).await?;
+                self.submit(session, alert).await
+            }
+            SmtpSecurity::StartTls => {
+                let mut session = SmtpSession::new(stream);
+                session.expect(220).await?;
+                session.command("EHLO tesser", 250).await?;
+                session.command("STARTTLS", 220).await?;
+                let mut session = SmtpSession::new(self.handshake(session.into_inner()).await?);
+                session.command("EHLO tesser", 250).await?;
+                self.submit(session, alert).await
+            }
+            SmtpSecurity::Tls => {
+                let mut session = SmtpSession::new(self.handshake(stream).await?);
+                session.expect(220).await?;
+                session.command("EHLO tesser", 250).await?;
+                self.submit(session, alert).await
+            }
+        }
+    }
+
+    async fn handshake(&self, stream: TcpStream) -> Result<TlsStream<TcpStream>> {
+        let connector = self
+            .tls
+            .as_ref()
+            .ok_or_else(|| anyhow!("smtp channel has no TLS configuration"))?;
+        let name = ServerName::try_from(self.host.clone())
+            .with_context(|| format!("invalid smtp host name '{}'", self.host))?;
+        connector
+            .connect(name, stream)
+            .await
+            .with_context(|| format!("TLS handshake with {}:{} failed", self.host, self.port))
+    }
+
+    async fn submit<S>(&self, mut session: SmtpSession<S>, alert: &Alert) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        if let Some((user, password)) = &self.credentials {
+            let token = BASE64.encode(format!("\0{user}\0{password}"));
+            session.command(&format!("AUTH PLAIN {token}"), 235).await?;
+        }
+        session
+            .command(&format!("MAIL FROM:<{}>", self.from), 250)
+            .await?;
+        for recipient in &self.to {
+            session
+                .command(&format!("RCPT TO:<{recipient}>"), 250)
+                .await?;
+        }
+        session.command("DATA", 354).await?;
+        let message = self.message(alert);
+        session.command(&format!("{message}."), 250).await?;
+        session.command("QUIT", 221).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AlertSink for SmtpSink {
+    async fn send(&self, alert: &Alert) -> Result<()> {
+        if self.to.is_empty() {
+            bail!("smtp channel has no recipients");
+        }
+        tokio::time::timeout(self.timeout, self.deliver(alert))
+            .await
+            .map_err(|_| {
+                anyhow!(
+                    "smtp://{}:{} did not complete within {:?}",
+                    self.host,
+                    self.port,
+                    self.timeout
+                )
+            })?
+    }
+}
+
+struct SmtpSession<S> {
+    stream: BufReader<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> SmtpSession<S> {
+    fn new(stream: S) -> Self {
+        Self {
+            stream: BufReader::new(stream),
+        }
+    }
+
+    fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+
+    async fn command(&mut self, line: &str, expected: u16) -> Result<()> {
+        let stream = self.stream.get_mut();
+        stream.write_all(line.as_bytes()).await?;
+        stream.write_all(b"\r\n").await?;
+        stream.flush().await?;
+        self.expect(expected).await
+    }
+
+    /// Reads a (possibly multi-line) reply and checks its status code.
+    async fn expect(&mut self, expected: u16) -> Result<()> {
+        loop {
+            let mut line = String::new();
+            if self.stream.read_line(&mut line).await? == 0 {
+                bail!("smtp server closed the connection");
+            }
+            let code = line
+                .get(..3)
+                .and_then(|code| code.parse::<u16>().ok())
+                .ok_or_else(|| anyhow!("malformed smtp reply: {}", line.trim_end()))?;
+            if code != expected {
+                bail!("unexpected smtp reply: {}", line.trim_end());
+            }
+            // "250-" continues a multi-line reply; "250 " ends it.
+            if line.as_bytes().get(3) != Some(&b'-') {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use tesser_config::AlertSeverity;
+    use tokio::net::TcpListener;
+
+    fn alert() -> Alert {
+        Alert {
+            severity: AlertSeverity::Critical,
+            title: "Exchange connection lost (public)".into(),
+            message: "Public stream disconnected\n.for over 60s".into(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn smtp_sink_speaks_submission_dialogue() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let (read, mut write) = socket.into_split();
+            let mut reader = BufReader::new(read);
+            let mut transcript = Vec::new();
+            write.write_all(b"220 stand-in ready\r\n").await.unwrap();
+            let mut in_data = false;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).await.unwrap() == 0 {
+                    break;
+                }
+                let line = line.trim_end().to_string();
+                if in_data {
+                    if line == "." {
+                        in_data = false;
+                        write.write_all(b"250 queued\r\n").await.unwrap();
+                    }
+                    transcript.push(line);
+                    continue;
+                }
+                let reply: &[u8] = match line.split(' ').next().unwrap() {
+                    "EHLO" => b"250-stand-in\r\n250 8BITMIME\r\n",
+                    "DATA" => {
+                        in_data = true;
+                        b"354 go ahead\r\n"
+                    }
+                    "QUIT" => b"221 bye\r\n",
+                    _ => b"250 ok\r\n",
+                };
+                transcript.push(line);
+                write.write_all(reply).await.unwrap();
+            }
+            transcript
+        });
+
+        let sink = build_sink(
+            &AlertSinkConfig::Smtp {
+                host: "127.0.0.1".into(),
+                port,
+                security: SmtpSecurity::None,
+                username: None,
+                password: None,
+                from: "tesser@example.com".into(),
+                to: vec!["oncall@example.com".into()],
+            },
+            &Client::new(),
+        )
+        .unwrap();
+        sink.send(&alert()).await.unwrap();
+        let transcript = server.await.unwrap();
+        assert!(!transcript.iter().any(|line| line.starts_with("AUTH")));
+        assert!(transcript.contains(&"RCPT TO:<oncall@example.com>".to_string()));
+        assert!(transcript
+            .iter()
+            .any(|line| line == "Subject: [CRITICAL] Exchange connection lost (public)"));
+        assert!(transcript.contains(&"..for over 60s".to_string()));
+        assert_eq!(transcript.last().map(String::as_str), Some("QUIT"));
+    }
+
+    #[test]
+    fn smtp_sink_refuses_credentials_without_tls() {
+        let config = AlertSinkConfig::Smtp {
+            host: "relay.example.com".into(),
+            port: 25,
+            security: SmtpSecurity::None,
+            username: Some("user".into()),
+            password: Some("pass".into()),
+            from: "tesser@example.com".into(),
+            to: vec!["oncall@example.com".into()],
+        };
+        let err = build_sink(&config, &Client::new()).err().unwrap();
+        assert!(err.to_string().contains("without TLS"));
+    }
+
+    #[tokio::test]
+    async fn smtp_sink_gives_up_on_silent_relay() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        // Accepts the connection but never sends the greeting.
+        let server = tokio::spawn(async move { listener.accept().await.unwrap() });
+        let sink = SmtpSink {
+            host: "127.0.0.1".into(),
+            port,
+            security: SmtpSecurity::None,
+            tls: None,
+            credentials: None,
+            from: "tesser@example.com".into(),
+            to: vec!["oncall@example.com".into()],
+            timeout: Duration::from_millis(100),
+        };
+        let err = sink.send(&alert()).await.unwrap_err();
+        assert!(err.to_string().contains("did not complete"));
+        drop(server);
+    }
+
+    #[tokio::test]
+    async fn file_sink_appends_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("alerts.jsonl");
+        let sink = build_sink(
+            &AlertSinkConfig::File { path: path.clone() },
+            &Client::new(),
+        )
+        .unwrap();
+        sink.send(&alert()).await.unwrap();
+        sink.send(&alert()).await.unwrap();
+        let contents = std::fs::read_to_string(path).unwrap();
+        let lines: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["severity"], "critical");
+    }
+}
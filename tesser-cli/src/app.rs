@@ -1,13 +1,19 @@
 use crate::alerts::sanitize_webhook;
 use crate::analyze;
-use crate::data_validation::{validate_dataset, ValidationConfig, ValidationOutcome};
+use crate::control::client::ControlClientOptions;
+use crate::data_validation::{
+    validate_dataset, RepairAction, SpikeFilter, ValidationConfig, ValidationOutcome,
+};
 use crate::live::{
     run_live, ExecutionBackend, LiveSessionSettings, NamedExchange, PersistenceBackend,
     PersistenceSettings,
 };
+use crate::record::{run_record, RecordChannels, RecordPlan};
+use crate::reload::{StrategyDefinition, StrategyReloadSettings};
 use crate::state;
 use crate::telemetry::init_tracing;
 use crate::tui;
+use crate::warmup::{WarmupSettings, WarmupSource};
 use crate::PublicChannel;
 use arrow::util::pretty::print_batches;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
@@ -30,6 +36,9 @@ use rust_decimal::{
     Decimal,
 };
 use serde::{Deserialize, Serialize};
+use tesser_backtester::reporting::benchmark::{Benchmark, BenchmarkReport, DEFAULT_ROLLING_WINDOW};
+use tesser_backtester::reporting::html;
+use tesser_backtester::reporting::robustness::{self, ResamplingMethod, RobustnessConfig};
 use tesser_backtester::reporting::PerformanceReport;
 use tesser_backtester::{
     stream_from_events, BacktestConfig, BacktestMode, BacktestStream, Backtester, MarketEvent,
@@ -38,19 +47,29 @@ use tesser_backtester::{
 use tesser_broker::{ExecutionClient, RouterExecutionClient};
 use tesser_config::{load_config, AppConfig, PersistenceEngine, RiskManagementConfig};
 use tesser_core::{
-    AssetId, Candle, DepthUpdate, ExchangeId, Interval, OrderBook, OrderBookLevel, Side, Symbol,
-    Tick,
+    AssetId, Candle, DepthUpdate, ExchangeId, Interval, MarketByOrderAction, MarketByOrderUpdate,
+    OrderBook, OrderBookLevel, Side, Symbol, Tick,
 };
-use tesser_data::analytics::ExecutionAnalysisRequest;
+use tesser_data::analytics::{collect_parquet_files, ExecutionAnalysisRequest};
+use tesser_data::capture::CaptureConfig;
+use tesser_data::catalog::{CatalogQuery, DataKind, DatasetCatalog, IndexOptions, TimeWindow};
+use tesser_data::compaction::{
+    compact_flight_recorder, compactable_kinds, CompactionConfig, RetentionPolicy,
+};
+use tesser_data::consolidated::{measure_clock_offset, ConsolidatedFeed, VenueSource};
 use tesser_data::download::{
-    BinanceDownloader, BybitDownloader, KlineRequest, NormalizedTrade, TradeRequest, TradeSource,
+    BinanceDownloader, BybitDownloader, KlinePrice, KlineRequest, MarketDataDownloader,
+    MetricRequest, NormalizedTrade, TradeRequest, TradeSource,
 };
 use tesser_data::etl::{
     MappingConfig as EtlMappingConfig, Partitioning as EtlPartitioning, Pipeline as EtlPipeline,
 };
 use tesser_data::io::{self, DatasetFormat as IoDatasetFormat, TicksWriter};
-use tesser_data::merger::UnifiedEventStream;
-use tesser_data::parquet::ParquetMarketStream;
+use tesser_data::merger::{UnifiedEvent, UnifiedEventStream};
+use tesser_data::parquet::{bars_from_ticks, tick_latency_samples, ParquetMarketStream};
+use tesser_data::reconstruct::{reconstruct_flight_recorder, ReconstructionConfig, SampleCadence};
+use tesser_data::recorder::RecorderConfig;
+use tesser_data::transform::bars::BarSpec;
 use tesser_data::transform::Resampler;
 use tesser_execution::{
     ExecutionEngine, FixedOrderSizer, NoopRiskChecker, OrderSizer, PanicCloseConfig,
@@ -58,8 +77,8 @@ use tesser_execution::{
 };
 use tesser_markets::MarketRegistry;
 use tesser_paper::{
-    FeeModel, FeeScheduleConfig, MatchingEngine, MatchingEngineConfig, PaperExecutionClient,
-    PaperMarketStream, QueueModel,
+    FeeModel, FeeScheduleConfig, LatencyDistribution, LatencyModel, MatchingEngine,
+    MatchingEngineConfig, PaperExecutionClient, PaperMarketStream, QueueModel,
 };
 use tesser_strategy::{builtin_strategy_names, load_strategy};
 use tracing::{info, warn};
@@ -117,14 +136,39 @@ pub enum DataCommand {
     Download(DataDownloadArgs),
     /// Download historical trade ticks
     DownloadTrades(DataDownloadTradesArgs),
+    /// Download perpetual funding rates, open interest or long/short ratios
+    DownloadMetrics(DataDownloadMetricsArgs),
     /// Validate and optionally repair a local data set
     Validate(DataValidateArgs),
     /// Resample existing data (placeholder)
     Resample(DataResampleArgs),
+    /// Build tick, volume, dollar or imbalance bars from recorded trades
+    Bars(DataBarsArgs),
     /// Inspect a parquet file emitted by the flight recorder
     InspectParquet(DataInspectParquetArgs),
     /// Normalize raw data into the canonical schema
     Normalize(DataNormalizeArgs),
+    /// Capture live trades, depth and candles to flight-recorder parquet without a strategy
+    Record(DataRecordArgs),
+    /// Merge small flight-recorder files per kind/day and apply retention
+    Compact(DataCompactArgs),
+    /// Rebuild sampled full order books from recorded snapshots and depth deltas
+    RebuildBooks(DataRebuildBooksArgs),
+    /// Index canonical parquet data for time-range queries
+    Catalog {
+        #[command(subcommand)]
+        action: DataCatalogCommand,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DataCatalogCommand {
+    /// Summarize the indexed datasets
+    List(DataCatalogListArgs),
+    /// Index parquet files or directories (re-indexes files already present)
+    Add(DataCatalogAddArgs),
+    /// Check that indexed files still exist and have not changed
+    Verify(DataCatalogVerifyArgs),
 }
 
 #[derive(Subcommand)]
@@ -133,6 +177,8 @@ pub enum BacktestCommand {
     Run(BacktestRunArgs),
     /// Run multiple strategy configs and aggregate the results
     Batch(BacktestBatchArgs),
+    /// Resample the trades of a backtest to estimate how much of the result is noise
+    Robustness(BacktestRobustnessArgs),
 }
 
 #[derive(Subcommand)]
@@ -163,6 +209,9 @@ pub struct DataDownloadArgs {
     category: String,
     #[arg(long, default_value = "1m")]
     interval: String,
+    /// Price series to download (mark and index candles carry zero volume)
+    #[arg(long, value_enum, default_value_t = KlinePriceArg::Trade)]
+    price: KlinePriceArg,
     #[arg(long)]
     start: String,
     #[arg(long)]
@@ -218,12 +267,35 @@ pub struct DataDownloadTradesArgs {
     binance_market: BinanceMarketArg,
 }
 
+#[derive(Args)]
+pub struct DataDownloadMetricsArgs {
+    #[arg(long, default_value = "bybit")]
+    exchange: String,
+    #[arg(long)]
+    symbol: String,
+    /// Bybit market category (linear or inverse)
+    #[arg(long, default_value = "linear")]
+    category: String,
+    #[arg(long, value_enum)]
+    metric: MetricArg,
+    /// Sampling period for open interest and long/short ratios (ignored for funding)
+    #[arg(long, default_value = "1h")]
+    interval: String,
+    #[arg(long)]
+    start: String,
+    #[arg(long)]
+    end: Option<String>,
+    /// Parquet output path (defaults to <data_path>/<exchange>/<symbol>/<metric>_...parquet)
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
 #[derive(Args)]
 pub struct DataNormalizeArgs {
-    /// Glob pointing at the raw input files (e.g. ./raw/binance/*.csv)
+    /// Glob pointing at the raw input files (e.g. ./raw/binance/*.csv, *.ndjson.gz, *.csv.zst)
     #[arg(long)]
     pub source: String,
-    /// Output directory for canonical parquet partitions
+    /// Output directory for canonical candle partitions or flight-recorder trade/depth parquet
     #[arg(long)]
     pub output: PathBuf,
     /// Path to the mapping configuration TOML file
@@ -235,7 +307,7 @@ pub struct DataNormalizeArgs {
     /// Partitioning strategy (daily or monthly)
     #[arg(long, value_enum, default_value = "daily")]
     pub partition: DataPartitionArg,
-    /// Override the canonical interval label defined in the mapping config
+    /// Override the canonical interval label defined in the mapping config (candles only)
     #[arg(long)]
     pub interval: Option<String>,
 }
@@ -255,6 +327,42 @@ pub enum TradeSourceArg {
     BinancePublic,
 }
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum KlinePriceArg {
+    Trade,
+    Mark,
+    Index,
+}
+
+impl From<KlinePriceArg> for KlinePrice {
+    fn from(value: KlinePriceArg) -> Self {
+        match value {
+            KlinePriceArg::Trade => KlinePrice::Trade,
+            KlinePriceArg::Mark => KlinePrice::Mark,
+            KlinePriceArg::Index => KlinePrice::Index,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum MetricArg {
+    Funding,
+    #[value(name = "open-interest")]
+    OpenInterest,
+    #[value(name = "long-short")]
+    LongShort,
+}
+
+impl MetricArg {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Funding => "funding",
+            Self::OpenInterest => "open_interest",
+            Self::LongShort => "long_short",
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
 pub enum BinanceMarketArg {
     #[value(name = "spot")]
@@ -309,6 +417,22 @@ pub struct MonitorArgs {
     /// UI refresh rate in milliseconds
     #[arg(long, default_value_t = 250)]
     tick_rate: u64,
+    /// Bearer token presented to the control plane (defaults to $TESSER_CONTROL_TOKEN)
+    #[arg(long)]
+    token: Option<String>,
+    /// PEM CA bundle used to verify the control plane; enables TLS. Defaults to the
+    /// self-signed certificate when config.live.control_plane.tls.self_signed is set
+    #[arg(long, value_name = "PATH")]
+    tls_ca: Option<PathBuf>,
+    /// PEM client certificate for mTLS
+    #[arg(long, value_name = "PATH", requires = "tls_key")]
+    tls_cert: Option<PathBuf>,
+    /// PEM private key matching --tls-cert
+    #[arg(long, value_name = "PATH", requires = "tls_cert")]
+    tls_key: Option<PathBuf>,
+    /// Server name to verify when it differs from the address host
+    #[arg(long)]
+    tls_domain: Option<String>,
 }
 
 impl StateInspectArgs {
@@ -330,9 +454,30 @@ impl MonitorArgs {
             .clone()
             .unwrap_or_else(|| config.live.control_addr.clone());
         let refresh = self.tick_rate.max(50);
-        let monitor_config = tui::MonitorConfig::new(addr, StdDuration::from_millis(refresh));
+        let monitor_config = tui::MonitorConfig::new(addr, StdDuration::from_millis(refresh))
+            .with_client_options(self.client_options(config));
         tui::run_monitor(monitor_config).await
     }
+
+    fn client_options(&self, config: &AppConfig) -> ControlClientOptions {
+        let self_signed_ca = config
+            .live
+            .control_plane
+            .tls
+            .as_ref()
+            .filter(|tls| tls.self_signed && tls.cert_path.is_none())
+            .map(|tls| tls.self_signed_dir.join("cert.pem"));
+        ControlClientOptions {
+            token: self
+                .token
+                .clone()
+                .or_else(|| std::env::var("TESSER_CONTROL_TOKEN").ok()),
+            ca_cert: self.tls_ca.clone().or(self_signed_ca),
+            client_cert: self.tls_cert.clone(),
+            client_key: self.tls_key.clone(),
+            domain: self.tls_domain.clone(),
+        }
+    }
 }
 
 impl AnalyzeExecutionArgs {
@@ -370,13 +515,14 @@ impl DataDownloadArgs {
         }
 
         info!(
-            "Downloading {} candles for {} ({})",
-            self.interval, self.symbol, self.exchange
+            "Downloading {} {:?}-price candles for {} ({})",
+            self.interval, self.price, self.symbol, self.exchange
         );
         let mut candles = match exchange_cfg.driver.as_str() {
             "bybit" | "" => {
                 let downloader = BybitDownloader::new(&exchange_cfg.rest_url);
-                let request = KlineRequest::new(&self.category, &self.symbol, interval, start, end);
+                let request = KlineRequest::new(&self.category, &self.symbol, interval, start, end)
+                    .with_price(self.price.into());
                 downloader
                     .download_klines(&request)
                     .await
@@ -384,7 +530,8 @@ impl DataDownloadArgs {
             }
             "binance" => {
                 let downloader = BinanceDownloader::new(&exchange_cfg.rest_url);
-                let request = KlineRequest::new("", &self.symbol, interval, start, end);
+                let request = KlineRequest::new("", &self.symbol, interval, start, end)
+                    .with_price(self.price.into());
                 downloader
                     .download_klines(&request)
                     .await
@@ -400,9 +547,11 @@ impl DataDownloadArgs {
 
         if !self.skip_validation {
             let config = ValidationConfig {
-                price_jump_threshold: self.validation_jump_threshold.max(f64::EPSILON),
-                reference_tolerance: self.validation_reference_tolerance.max(f64::EPSILON),
                 repair_missing: self.repair_missing,
+                ..ValidationConfig::new(
+                    self.validation_jump_threshold.max(f64::EPSILON),
+                    self.validation_reference_tolerance.max(f64::EPSILON),
+                )
             };
             let outcome =
                 validate_dataset(candles.clone(), None, config).context("validation failed")?;
@@ -429,6 +578,102 @@ impl DataDownloadArgs {
     }
 }
 
+impl DataDownloadMetricsArgs {
+    async fn run(&self, config: &AppConfig) -> Result<()> {
+        let exchange_cfg = config
+            .exchange
+            .get(&self.exchange)
+            .ok_or_else(|| anyhow!("exchange profile '{}' not found in config", self.exchange))?;
+        let interval: Interval = self.interval.parse().map_err(|err: String| anyhow!(err))?;
+        let start = parse_datetime(&self.start)?;
+        let end = match &self.end {
+            Some(value) => parse_datetime(value)?,
+            None => Utc::now(),
+        };
+        if start >= end {
+            return Err(anyhow!("start time must be earlier than end time"));
+        }
+
+        info!(
+            "Downloading {} for {} ({})",
+            self.metric.label(),
+            self.symbol,
+            self.exchange
+        );
+        let output_path = self.output.clone().unwrap_or_else(|| {
+            config
+                .data_path
+                .join(&self.exchange)
+                .join(&self.symbol)
+                .join(format!(
+                    "{}_{}_{}-{}.parquet",
+                    self.metric.label(),
+                    interval.label(),
+                    start.format("%Y%m%d"),
+                    end.format("%Y%m%d")
+                ))
+        });
+        let request = MetricRequest::new(&self.category, &self.symbol, interval, start, end);
+        let rows = match exchange_cfg.driver.as_str() {
+            "bybit" | "" => {
+                let downloader = BybitDownloader::new(&exchange_cfg.rest_url);
+                self.download(&downloader, &request, &output_path)
+                    .await
+                    .with_context(|| "failed to download metrics from Bybit")?
+            }
+            "binance" => {
+                let downloader = BinanceDownloader::new(&exchange_cfg.rest_url);
+                self.download(&downloader, &request, &output_path)
+                    .await
+                    .with_context(|| "failed to download metrics from Binance")?
+            }
+            other => bail!("unknown exchange driver '{other}' for {}", self.exchange),
+        };
+        if rows == 0 {
+            info!("No {} returned for {}", self.metric.label(), self.symbol);
+        } else {
+            info!(
+                "Saved {} {} rows to {}",
+                rows,
+                self.metric.label(),
+                output_path.display()
+            );
+        }
+        Ok(())
+    }
+
+    async fn download<D: MarketDataDownloader>(
+        &self,
+        downloader: &D,
+        request: &MetricRequest<'_>,
+        output: &Path,
+    ) -> Result<usize> {
+        match self.metric {
+            MetricArg::Funding => {
+                let rows = downloader.download_funding_rates(request).await?;
+                if !rows.is_empty() {
+                    io::write_funding_rates(output, &rows)?;
+                }
+                Ok(rows.len())
+            }
+            MetricArg::OpenInterest => {
+                let rows = downloader.download_open_interest(request).await?;
+                if !rows.is_empty() {
+                    io::write_open_interest(output, &rows)?;
+                }
+                Ok(rows.len())
+            }
+            MetricArg::LongShort => {
+                let rows = downloader.download_long_short_ratios(request).await?;
+                if !rows.is_empty() {
+                    io::write_long_short_ratios(output, &rows)?;
+                }
+                Ok(rows.len())
+            }
+        }
+    }
+}
+
 impl DataDownloadTradesArgs {
     async fn run(&self, config: &AppConfig) -> Result<()> {
         let exchange_cfg = config
@@ -707,9 +952,30 @@ pub struct DataValidateArgs {
     /// Attempt to fill gaps by synthesizing candles
     #[arg(long)]
     repair_missing: bool,
+    /// Enable every repair mode and write the corrected dataset next to the input
+    #[arg(long)]
+    repair: bool,
+    /// Clip price spikes against a rolling median (Hampel filter)
+    #[arg(long)]
+    clip_spikes: bool,
+    /// Bars on each side of a candle used for the rolling median
+    #[arg(long, default_value_t = 10)]
+    spike_window: usize,
+    /// Robust standard deviations (scaled MAD) a price may sit from the median
+    #[arg(long, default_value_t = 5.0)]
+    spike_threshold: f64,
+    /// Replace bars that disagree with --reference by the reference bar
+    #[arg(long)]
+    repair_from_reference: bool,
+    /// Widen high/low to cover open/close and clamp negative volume to zero
+    #[arg(long)]
+    fix_ohlc: bool,
     /// Location to write the repaired dataset
     #[arg(long)]
     output: Option<PathBuf>,
+    /// Location of the JSON repair log (defaults to <output>.repairs.json)
+    #[arg(long)]
+    repair_log: Option<PathBuf>,
 }
 
 impl DataValidateArgs {
@@ -742,27 +1008,65 @@ impl DataValidateArgs {
             self.reference_tolerance
         };
 
-        let config = ValidationConfig {
-            price_jump_threshold,
-            reference_tolerance,
+        let spike_filter = SpikeFilter {
+            window: self.spike_window.max(1),
+            threshold: self.spike_threshold.max(f64::EPSILON),
+        };
+        let mut config = ValidationConfig {
             repair_missing: self.repair_missing,
+            spike_filter: self.clip_spikes.then_some(spike_filter),
+            replace_from_reference: self.repair_from_reference,
+            fix_ohlc: self.fix_ohlc,
+            ..ValidationConfig::new(price_jump_threshold, reference_tolerance)
         };
+        if self.repair {
+            config.spike_filter = Some(spike_filter);
+            config = config.with_all_repairs();
+        }
+        if config.replace_from_reference && reference.is_none() {
+            warn!("--repair-from-reference has no effect without --reference");
+        }
 
         let outcome = validate_dataset(candles, reference, config)?;
         print_validation_summary(&outcome);
 
-        if let Some(output) = &self.output {
+        let output = self
+            .output
+            .clone()
+            .or_else(|| self.repair.then(|| repaired_dataset_path(&self.paths[0])));
+        if let Some(output) = &output {
             write_candles_csv(output, &outcome.repaired)?;
             info!(
-                "Wrote {} candles ({} new) to {}",
+                "Wrote {} candles ({} repaired) to {}",
                 outcome.repaired.len(),
-                outcome.summary.repaired_candles,
+                outcome.summary.total_repairs(),
                 output.display()
             );
-        } else if self.repair_missing && outcome.summary.repaired_candles > 0 {
+        } else if outcome.summary.total_repairs() > 0 {
             warn!(
-                "Detected {} gap(s) filled with synthetic candles but --output was not provided",
-                outcome.summary.repaired_candles
+                "Applied {} repair(s) but --output was not provided",
+                outcome.summary.total_repairs()
+            );
+        }
+
+        let repair_log = self.repair_log.clone().or_else(|| {
+            output
+                .as_deref()
+                .map(|path| path.with_extension("repairs.json"))
+        });
+        if let Some(log_path) = repair_log {
+            let contents = serde_json::to_string_pretty(&outcome.repairs)
+                .context("failed to serialize repair log")?;
+            if let Some(parent) = log_path.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("failed to create directory {}", parent.display()))?;
+            }
+            fs::write(&log_path, contents)
+                .with_context(|| format!("failed to write {}", log_path.display()))?;
+            info!(
+                "Wrote repair log with {} entr(ies) to {}",
+                outcome.repairs.len(),
+                log_path.display()
             );
         }
 
@@ -770,6 +1074,49 @@ impl DataValidateArgs {
     }
 }
 
+/// `data/BTCUSDT_1m.csv` -> `data/BTCUSDT_1m.repaired.csv`.
+fn repaired_dataset_path(input: &Path) -> PathBuf {
+    let stem = input
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "dataset".to_string());
+    input.with_file_name(format!("{stem}.repaired.csv"))
+}
+
+impl DataBarsArgs {
+    async fn run(&self) -> Result<()> {
+        let spec: BarSpec = self.spec.parse().map_err(|err: String| anyhow!(err))?;
+        let symbol = self.symbol.as_deref().map(Symbol::from);
+        let files = resolve_tick_files(&self.input)?;
+        let bars = bars_from_ticks(files, spec, symbol, self.include_partial)
+            .await
+            .with_context(|| format!("failed to read ticks from {}", self.input.display()))?;
+        if bars.is_empty() {
+            bail!(
+                "no {spec} bars produced from {}; try a smaller threshold",
+                self.input.display()
+            );
+        }
+        if let Some(parent) = self.output.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create directory {}", parent.display()))?;
+        }
+        let mut writer = Writer::from_path(&self.output)
+            .with_context(|| format!("failed to create {}", self.output.display()))?;
+        for bar in &bars {
+            writer.serialize(bar)?;
+        }
+        writer.flush()?;
+        info!(
+            "Built {} {} bars into {}",
+            bars.len(),
+            spec,
+            self.output.display()
+        );
+        Ok(())
+    }
+}
+
 impl DataResampleArgs {
     fn run(&self) -> Result<()> {
         if !self.input.exists() {
@@ -827,6 +1174,25 @@ pub struct DataResampleArgs {
     format: Option<DatasetFormatArg>,
 }
 
+#[derive(Args)]
+pub struct DataBarsArgs {
+    /// Tick parquet file, directory, or flight-recorder root (uses its `ticks/` folder)
+    #[arg(long)]
+    input: PathBuf,
+    /// Output CSV path
+    #[arg(long)]
+    output: PathBuf,
+    /// Bar rule (e.g. "tick:1000", "volume:50", "dollar:1000000", "tick-imbalance:200[:span]", "volume-imbalance:200[:span]")
+    #[arg(long = "bar", value_name = "SPEC")]
+    spec: String,
+    /// Only aggregate trades for this symbol
+    #[arg(long)]
+    symbol: Option<String>,
+    /// Emit the trailing partially built bar of each symbol
+    #[arg(long)]
+    include_partial: bool,
+}
+
 #[derive(Copy, Clone, Debug, ValueEnum)]
 pub enum DatasetFormatArg {
     Csv,
@@ -898,6 +1264,540 @@ impl DataInspectParquetArgs {
     }
 }
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum RecordChannelArg {
+    Trades,
+    Depth,
+    Candles,
+}
+
+#[derive(Args)]
+pub struct DataRecordArgs {
+    /// Exchange profiles to capture from (comma separated or repeated)
+    #[arg(long = "exchange", value_name = "NAME", required = true, num_args = 1.., value_delimiter = ',')]
+    exchanges: Vec<String>,
+    /// Symbols subscribed on every exchange (comma separated or repeated)
+    #[arg(long = "symbols", value_name = "SYMBOL", required = true, num_args = 1.., value_delimiter = ',')]
+    symbols: Vec<String>,
+    #[arg(long, default_value = "linear")]
+    category: String,
+    /// Candle interval subscribed alongside trades and depth
+    #[arg(long, default_value = "1m")]
+    interval: String,
+    /// Order book depth requested from the exchange
+    #[arg(long = "orderbook-depth", default_value_t = 50)]
+    orderbook_depth: usize,
+    /// Event families written to disk
+    #[arg(long, value_enum, num_args = 1.., value_delimiter = ',', default_values_t = [RecordChannelArg::Trades, RecordChannelArg::Depth, RecordChannelArg::Candles])]
+    channels: Vec<RecordChannelArg>,
+    /// Output directory (defaults to `<data_path>/flight_recorder`)
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+    /// Rows per parquet file before rotating to a new one
+    #[arg(long = "max-rows-per-file", default_value_t = 250_000)]
+    max_rows_per_file: usize,
+    /// Seconds between buffer flushes when traffic is low
+    #[arg(long = "flush-interval-secs", default_value_t = 5)]
+    flush_interval_secs: u64,
+    /// Seconds between full order book snapshots; depth deltas are written in between
+    #[arg(long = "snapshot-interval-secs", default_value_t = 300)]
+    snapshot_interval_secs: u64,
+    /// Warn when a symbol produced no book updates for this many seconds; re-subscribe when the
+    /// whole stream stays silent that long
+    #[arg(long = "stall-timeout-secs", default_value_t = 60)]
+    stall_timeout_secs: u64,
+    /// Stop after this many seconds (runs until Ctrl-C when omitted)
+    #[arg(long = "duration-secs")]
+    duration_secs: Option<u64>,
+}
+
+impl DataRecordArgs {
+    async fn run(&self, config: &AppConfig) -> Result<()> {
+        let category =
+            PublicChannel::from_str(&self.category).map_err(|err| anyhow!(err.to_string()))?;
+        let interval: Interval = self.interval.parse().map_err(|err: String| anyhow!(err))?;
+        let profiles = config.exchange_profiles();
+        let mut exchanges = Vec::new();
+        for name in &self.exchanges {
+            let config_entry = profiles
+                .get(name)
+                .cloned()
+                .ok_or_else(|| anyhow!("exchange profile {} not found", name))?;
+            exchanges.push(NamedExchange {
+                name: name.clone(),
+                config: config_entry,
+            });
+        }
+        let root = self
+            .output
+            .clone()
+            .unwrap_or_else(|| config.data_path.join("flight_recorder"));
+        let plan = RecordPlan {
+            exchanges,
+            symbols: self.symbols.clone(),
+            category: category.as_path().to_string(),
+            interval,
+            orderbook_depth: self.orderbook_depth.max(1),
+            channels: RecordChannels {
+                trades: self.channels.contains(&RecordChannelArg::Trades),
+                candles: self.channels.contains(&RecordChannelArg::Candles),
+                depth: self.channels.contains(&RecordChannelArg::Depth),
+            },
+            recorder: RecorderConfig {
+                root: root.clone(),
+                max_rows_per_file: self.max_rows_per_file.max(1),
+                flush_interval: StdDuration::from_secs(self.flush_interval_secs.max(1)),
+                ..RecorderConfig::default()
+            },
+            capture: CaptureConfig {
+                snapshot_interval: Duration::seconds(self.snapshot_interval_secs.max(1) as i64),
+                stall_timeout: Duration::seconds(self.stall_timeout_secs.max(1) as i64),
+            },
+            duration: self.duration_secs.map(StdDuration::from_secs),
+        };
+        let reports = run_record(plan).await?;
+        for report in reports {
+            println!(
+                "{}: {} trades, {} candles, {} order events, {} book snapshots, {} depth deltas, {} gaps, {} stalls, {} reconnects",
+                report.exchange,
+                report.ticks,
+                report.candles,
+                report.market_by_order,
+                report.book.snapshots,
+                report.book.deltas,
+                report.book.gaps,
+                report.book.stalls,
+                report.reconnects
+            );
+        }
+        println!("recording stored under {}", root.display());
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct DataCompactArgs {
+    /// Flight recorder root (defaults to `<data_path>/flight_recorder`)
+    #[arg(long, value_name = "PATH")]
+    root: Option<PathBuf>,
+    /// Only compact these kinds (ticks, candles, fills, orders, order_books, depth, mbo, signals)
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    kinds: Vec<String>,
+    /// Leave files modified within this many seconds alone; the recorder may still own them
+    #[arg(long = "min-age-secs", default_value_t = 300)]
+    min_age_secs: u64,
+    /// Maximum rows per compacted file
+    #[arg(long = "rows-per-file", default_value_t = 5_000_000)]
+    rows_per_file: usize,
+    /// Rows per row group in compacted files (smaller groups prune time ranges more finely)
+    #[arg(long = "row-group-rows", default_value_t = 131_072)]
+    row_group_rows: usize,
+    /// ZSTD compression level of compacted files
+    #[arg(long = "zstd-level", default_value_t = 9)]
+    zstd_level: i32,
+    /// Delete date partitions older than this many days
+    #[arg(long = "retain-days")]
+    retain_days: Option<u32>,
+    /// Per-kind retention override, e.g. `--retain depth=3` (repeatable)
+    #[arg(long = "retain", value_name = "KIND=DAYS")]
+    retain: Vec<String>,
+    /// Report what would be compacted or deleted without touching any file
+    #[arg(long)]
+    dry_run: bool,
+}
+
+impl DataCompactArgs {
+    fn run(&self, config: &AppConfig) -> Result<()> {
+        for kind in &self.kinds {
+            if !compactable_kinds().any(|known| known == kind) {
+                bail!(
+                    "unknown flight recorder kind '{kind}' (expected one of {})",
+                    compactable_kinds().collect::<Vec<_>>().join(", ")
+                );
+            }
+        }
+        let mut retention = RetentionPolicy {
+            default_days: self.retain_days,
+            ..RetentionPolicy::default()
+        };
+        for value in &self.retain {
+            let (kind, days) = RetentionPolicy::parse_override(value)?;
+            retention.per_kind.insert(kind, days);
+        }
+        let root = self
+            .root
+            .clone()
+            .unwrap_or_else(|| config.data_path.join("flight_recorder"));
+        let options = CompactionConfig {
+            root: root.clone(),
+            kinds: self.kinds.clone(),
+            min_file_age: StdDuration::from_secs(self.min_age_secs),
+            rows_per_file: self.rows_per_file,
+            row_group_rows: self.row_group_rows,
+            zstd_level: self.zstd_level,
+            retention,
+            dry_run: self.dry_run,
+        };
+        let report = compact_flight_recorder(&options, Utc::now())?;
+        let verb = if self.dry_run {
+            "would remove"
+        } else {
+            "removed"
+        };
+        for path in &report.expired {
+            println!("{verb} expired partition {}", path.display());
+        }
+        for partition in &report.partitions {
+            if self.dry_run {
+                println!(
+                    "would compact {}/{}: {} files, {} bytes",
+                    partition.kind, partition.date, partition.input_files, partition.bytes_in
+                );
+            } else {
+                println!(
+                    "compacted {}/{}: {} -> {} files, {} -> {} rows ({} duplicates), {} -> {} bytes",
+                    partition.kind,
+                    partition.date,
+                    partition.input_files,
+                    partition.output_files,
+                    partition.rows_in,
+                    partition.rows_out,
+                    partition.duplicates(),
+                    partition.bytes_in,
+                    partition.bytes_out
+                );
+            }
+        }
+        if !report.in_flight.is_empty() {
+            println!(
+                "skipped {} file(s) still being written (younger than {}s or not yet closed)",
+                report.in_flight.len(),
+                self.min_age_secs
+            );
+        }
+        if report.partitions.is_empty() && report.expired.is_empty() {
+            println!("nothing to compact under {}", root.display());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct DataRebuildBooksArgs {
+    /// Flight recorder root with `order_books/` and `depth/` (defaults to `<data_path>/flight_recorder`)
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+    /// Output parquet path (order book schema)
+    #[arg(long)]
+    output: PathBuf,
+    /// Sampling cadence: a duration such as `100ms` or `1s`, `trade`, or `update`
+    #[arg(long, default_value = "100ms")]
+    every: String,
+    /// Only rebuild these symbols (comma separated or repeated)
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    symbols: Vec<String>,
+    /// Levels per side written for each book (all levels when omitted)
+    #[arg(long)]
+    depth: Option<usize>,
+    /// Levels per side compared against each recorded snapshot checksum
+    #[arg(long = "checksum-depth", default_value_t = 25)]
+    checksum_depth: usize,
+    /// Stop sampling a symbol on the time grid after this many seconds without updates
+    #[arg(long = "stale-after-secs", default_value_t = 60)]
+    stale_after_secs: i64,
+}
+
+impl DataRebuildBooksArgs {
+    async fn run(&self, config: &AppConfig) -> Result<()> {
+        let cadence: SampleCadence = self.every.parse().map_err(|err: String| anyhow!(err))?;
+        let root = self
+            .input
+            .clone()
+            .unwrap_or_else(|| config.data_path.join("flight_recorder"));
+        let symbols: Vec<Symbol> = self
+            .symbols
+            .iter()
+            .map(|symbol| Symbol::from(symbol.as_str()))
+            .collect();
+        let options = ReconstructionConfig {
+            cadence,
+            depth: self.depth,
+            checksum_depth: self.checksum_depth,
+            stale_after: (self.stale_after_secs > 0)
+                .then(|| Duration::seconds(self.stale_after_secs)),
+        };
+        let report = reconstruct_flight_recorder(&root, &symbols, options, &self.output)
+            .await
+            .with_context(|| format!("failed to rebuild order books from {}", root.display()))?;
+        info!(
+            "Wrote {} order books to {} ({} snapshots, {} depth updates)",
+            report.books_written,
+            self.output.display(),
+            report.snapshots,
+            report.depth_updates
+        );
+        if report.checksum_mismatches > 0 || report.crossed_books > 0 {
+            warn!(
+                checksum_mismatches = report.checksum_mismatches,
+                crossed_books = report.crossed_books,
+                skipped_updates = report.skipped_updates,
+                "reconstructed books diverged and were resynchronized from recorded snapshots"
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct DataCatalogListArgs {
+    /// Catalog root (defaults to the configured data path)
+    #[arg(long)]
+    root: Option<PathBuf>,
+    /// Only show entries for this symbol (`exchange:SYMBOL` or `SYMBOL`)
+    #[arg(long)]
+    symbol: Option<String>,
+    /// Only show entries of this kind (candles, ticks, order_books, depth)
+    #[arg(long)]
+    kind: Option<DataKind>,
+    /// List every indexed file instead of one line per dataset
+    #[arg(long)]
+    files: bool,
+}
+
+impl DataCatalogListArgs {
+    fn run(&self, config: &AppConfig) -> Result<()> {
+        let root = catalog_root(self.root.as_ref(), config);
+        let catalog = DatasetCatalog::open(&root)?;
+        let symbol = self.symbol.as_deref().map(Symbol::from);
+        let query = CatalogQuery {
+            symbol,
+            kind: self.kind,
+            ..CatalogQuery::default()
+        };
+        let entries = catalog.query(&query);
+        if entries.is_empty() {
+            println!("no datasets indexed under {}", root.display());
+            return Ok(());
+        }
+        if self.files {
+            for entry in entries {
+                println!(
+                    "{:<10} {:<14} {:<12} {:<6} {} -> {} {:>10} rows  {}",
+                    entry.exchange,
+                    entry.symbol,
+                    entry.kind,
+                    entry.interval.as_deref().unwrap_or("-"),
+                    entry.start.format("%Y-%m-%d %H:%M:%S"),
+                    entry.end.format("%Y-%m-%d %H:%M:%S"),
+                    entry.rows,
+                    entry.path.display()
+                );
+            }
+            return Ok(());
+        }
+        let matches = |summary: &tesser_data::catalog::CatalogSummary| {
+            entries.iter().any(|entry| {
+                entry.exchange == summary.exchange
+                    && entry.symbol == summary.symbol
+                    && entry.kind == summary.kind
+                    && entry.interval == summary.interval
+            })
+        };
+        for summary in catalog.summary().iter().filter(|summary| matches(summary)) {
+            println!(
+                "{:<10} {:<14} {:<12} {:<6} {} -> {} {:>10} rows {:>5} file(s)",
+                summary.exchange,
+                summary.symbol,
+                summary.kind,
+                summary.interval.as_deref().unwrap_or("-"),
+                summary.start.format("%Y-%m-%d %H:%M:%S"),
+                summary.end.format("%Y-%m-%d %H:%M:%S"),
+                summary.rows,
+                summary.files
+            );
+        }
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct DataCatalogAddArgs {
+    /// Parquet files or directories to index
+    #[arg(value_name = "PATH", required = true)]
+    paths: Vec<PathBuf>,
+    /// Catalog root (defaults to the configured data path)
+    #[arg(long)]
+    root: Option<PathBuf>,
+    /// Exchange recorded for symbols stored without an `exchange:` prefix
+    #[arg(long)]
+    exchange: Option<String>,
+    /// Data kind override when it cannot be inferred from the directory layout
+    #[arg(long)]
+    kind: Option<DataKind>,
+}
+
+impl DataCatalogAddArgs {
+    fn run(&self, config: &AppConfig) -> Result<()> {
+        let root = catalog_root(self.root.as_ref(), config);
+        let mut catalog = DatasetCatalog::open(&root)?;
+        let options = IndexOptions {
+            exchange: self.exchange.clone(),
+            kind: self.kind,
+        };
+        let mut files = 0usize;
+        for path in &self.paths {
+            files += catalog.add(path, &options)?;
+        }
+        catalog.save()?;
+        println!(
+            "indexed {files} file(s); catalog {} now holds {} entr{}",
+            root.display(),
+            catalog.entries().len(),
+            if catalog.entries().len() == 1 {
+                "y"
+            } else {
+                "ies"
+            }
+        );
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct DataCatalogVerifyArgs {
+    /// Catalog root (defaults to the configured data path)
+    #[arg(long)]
+    root: Option<PathBuf>,
+    /// Drop entries whose files no longer exist
+    #[arg(long)]
+    prune: bool,
+}
+
+impl DataCatalogVerifyArgs {
+    fn run(&self, config: &AppConfig) -> Result<()> {
+        let root = catalog_root(self.root.as_ref(), config);
+        let mut catalog = DatasetCatalog::open(&root)?;
+        let issues = catalog.verify();
+        for issue in &issues {
+            println!("{}: {}", issue.path.display(), issue.problem);
+        }
+        if self.prune {
+            let missing: Vec<PathBuf> = issues
+                .iter()
+                .filter(|issue| issue.missing)
+                .map(|issue| issue.path.clone())
+                .collect();
+            if !missing.is_empty() {
+                let removed = catalog.remove(&missing);
+                catalog.save()?;
+                println!(
+                    "removed {removed} entr{} for missing files",
+                    if removed == 1 { "y" } else { "ies" }
+                );
+            }
+        }
+        if issues.is_empty() {
+            println!(
+                "catalog {} is consistent ({} entries)",
+                root.display(),
+                catalog.entries().len()
+            );
+            return Ok(());
+        }
+        let unresolved = issues
+            .iter()
+            .filter(|issue| !(self.prune && issue.missing))
+            .count();
+        if unresolved > 0 {
+            bail!(
+                "{unresolved} catalog issue(s) found; re-run `data catalog add` for changed files"
+            );
+        }
+        Ok(())
+    }
+}
+
+fn catalog_root(root: Option<&PathBuf>, config: &AppConfig) -> PathBuf {
+    root.cloned().unwrap_or_else(|| config.data_path.clone())
+}
+
+/// Selects backtest candles from a dataset catalog and/or restricts replay to a time range.
+#[derive(Args, Clone, Default)]
+pub struct CatalogSelectionArgs {
+    /// Resolve candle files for each strategy symbol from the catalog under this root
+    #[arg(long, value_name = "DIR")]
+    catalog: Option<PathBuf>,
+    /// Candle interval to request from the catalog (required when several are indexed)
+    #[arg(long = "catalog-interval", value_name = "INTERVAL")]
+    catalog_interval: Option<Interval>,
+    /// Inclusive start of the replayed range (RFC3339 or YYYY-MM-DD)
+    #[arg(long)]
+    start: Option<String>,
+    /// Exclusive end of the replayed range (RFC3339 or YYYY-MM-DD)
+    #[arg(long)]
+    end: Option<String>,
+}
+
+impl CatalogSelectionArgs {
+    fn window(&self) -> Result<TimeWindow> {
+        let start = self.start.as_deref().map(parse_datetime).transpose()?;
+        let end = self.end.as_deref().map(parse_datetime).transpose()?;
+        if let (Some(start), Some(end)) = (start, end) {
+            if start >= end {
+                bail!("--start must be earlier than --end");
+            }
+        }
+        Ok(TimeWindow::new(start, end))
+    }
+
+    /// Candle files for `symbols`: explicit `--data` paths plus any catalog matches.
+    fn resolve(&self, explicit: &[PathBuf], symbols: &[Symbol]) -> Result<Vec<PathBuf>> {
+        let mut paths = explicit.to_vec();
+        let Some(root) = &self.catalog else {
+            return Ok(paths);
+        };
+        let catalog = DatasetCatalog::open(root)?;
+        let window = self.window()?;
+        for symbol in symbols {
+            let query = CatalogQuery {
+                symbol: Some(*symbol),
+                kind: Some(DataKind::Candles),
+                interval: self.catalog_interval.map(|interval| interval.label()),
+                window,
+            };
+            let entries = catalog.query(&query);
+            if entries.is_empty() {
+                bail!(
+                    "catalog {} has no candles for {symbol} in the requested range",
+                    root.display()
+                );
+            }
+            let mut intervals: Vec<&str> = entries
+                .iter()
+                .filter_map(|entry| entry.interval.as_deref())
+                .collect();
+            intervals.sort_unstable();
+            intervals.dedup();
+            if intervals.len() > 1 {
+                bail!(
+                    "catalog holds several intervals for {symbol} ({}); pick one with --catalog-interval",
+                    intervals.join(", ")
+                );
+            }
+            for path in catalog.files(&query) {
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+        Ok(paths)
+    }
+
+    fn is_active(&self) -> bool {
+        self.catalog.is_some()
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
 pub enum BacktestModeArg {
     Candle,
@@ -908,6 +1808,10 @@ pub enum BacktestModeArg {
 pub enum QueueModelArg {
     Conserv,
     Optimistic,
+    /// Infer queue position from depth deltas and trade prints
+    DepthInferred,
+    /// Track exact queue position using market-by-order (L3) events
+    Mbo,
 }
 
 impl From<QueueModelArg> for QueueModel {
@@ -915,6 +1819,8 @@ impl From<QueueModelArg> for QueueModel {
         match value {
             QueueModelArg::Conserv => QueueModel::Conservative,
             QueueModelArg::Optimistic => QueueModel::Optimistic,
+            QueueModelArg::DepthInferred => QueueModel::DepthInferred,
+            QueueModelArg::Mbo => QueueModel::MarketByOrder,
         }
     }
 }
@@ -926,6 +1832,8 @@ pub struct BacktestRunArgs {
     /// One or more canonical parquet files produced via `tesser-cli data normalize`
     #[arg(long = "data", value_name = "PATH", num_args = 0.., action = clap::ArgAction::Append)]
     data_paths: Vec<PathBuf>,
+    #[command(flatten)]
+    selection: CatalogSelectionArgs,
     #[arg(long, default_value_t = 500)]
     candles: usize,
     #[arg(long, default_value = "0.01")]
@@ -951,19 +1859,87 @@ pub struct BacktestRunArgs {
     /// One or more JSONL files or a flight-recorder directory containing tick/order book events (required for `--mode tick`)
     #[arg(long = "lob-data", value_name = "PATH", num_args = 0.., action = clap::ArgAction::Append)]
     lob_paths: Vec<PathBuf>,
+    /// Flight-recorder root for one venue of a consolidated feed (`EXCHANGE=DIR`, repeatable)
+    #[arg(long = "lob-venue", value_name = "EXCHANGE=DIR", action = clap::ArgAction::Append)]
+    lob_venues: Vec<String>,
+    /// Shift each --lob-venue onto the local clock using its median trade receive delay
+    #[arg(long)]
+    align_clocks: bool,
     /// Round-trip latency, in milliseconds, applied to limit order placements/cancellations during tick-mode sims
     #[arg(long = "sim-latency-ms", default_value_t = 0)]
     sim_latency_ms: u64,
+    /// Market data latency distribution (e.g. "3", "uniform:1:5", "lognormal:3:0.5", "empirical:<flight-recorder>")
+    #[arg(long = "sim-latency-market-data", value_name = "SPEC")]
+    sim_latency_market_data: Option<String>,
+    /// Order placement latency distribution; overrides --sim-latency-ms for new orders
+    #[arg(long = "sim-latency-place", value_name = "SPEC")]
+    sim_latency_place: Option<String>,
+    /// Cancel latency distribution; overrides --sim-latency-ms for cancels
+    #[arg(long = "sim-latency-cancel", value_name = "SPEC")]
+    sim_latency_cancel: Option<String>,
+    /// Amend latency distribution; overrides --sim-latency-ms for amends
+    #[arg(long = "sim-latency-amend", value_name = "SPEC")]
+    sim_latency_amend: Option<String>,
+    /// Seed used when sampling simulated latencies
+    #[arg(long = "sim-latency-seed", default_value_t = 0)]
+    sim_latency_seed: u64,
     /// Queue modeling assumption used when simulating passive fills
     #[arg(long = "sim-queue-model", value_enum, default_value = "conserv")]
     sim_queue_model: QueueModelArg,
     #[arg(long)]
     markets_file: Option<PathBuf>,
+    /// Benchmark for relative metrics: `buy-and-hold` or a candle dataset (CSV/parquet)
+    #[arg(long, value_name = "buy-and-hold|PATH")]
+    benchmark: Option<String>,
+    /// Daily observations per rolling benchmark window
+    #[arg(long = "benchmark-window", default_value_t = DEFAULT_ROLLING_WINDOW)]
+    benchmark_window: usize,
+    /// Optional CSV receiving the rolling benchmark statistics
+    #[arg(long = "benchmark-rolling-output", value_name = "PATH")]
+    benchmark_rolling_output: Option<PathBuf>,
+    /// Write a self-contained HTML tear sheet to this path
+    #[arg(long = "report-html", value_name = "PATH")]
+    report_html: Option<PathBuf>,
+}
+
+#[derive(Args)]
+pub struct BacktestRobustnessArgs {
+    #[command(flatten)]
+    backtest: BacktestRunArgs,
+    /// Number of resampled trade sequences
+    #[arg(long, default_value_t = 1000)]
+    iterations: usize,
+    /// Resampling scheme applied to the trade list
+    #[arg(long, value_enum, default_value = "bootstrap")]
+    method: ResamplingMethodArg,
+    /// Trades per block when using `--method block`
+    #[arg(long, default_value_t = 5)]
+    block_size: usize,
+    /// Seed for the resampler so results are reproducible
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Two-sided confidence level for the reported intervals
+    #[arg(long, default_value_t = 0.95)]
+    confidence: f64,
+    /// Number of configurations tried before selecting this one (defaults to the batch report row count)
+    #[arg(long)]
+    trials: Option<usize>,
+    /// CSV written by `backtest batch --output`, used to deflate the Sharpe ratio
+    #[arg(long = "batch-report", value_name = "PATH")]
+    batch_report: Option<PathBuf>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+pub enum ResamplingMethodArg {
+    Bootstrap,
+    Block,
+    Shuffle,
 }
 
 enum LobSource {
     Json(Vec<PathBuf>),
     FlightRecorder(PathBuf),
+    Consolidated(Vec<VenueSource>),
 }
 
 #[derive(Args)]
@@ -974,11 +1950,16 @@ pub struct BacktestBatchArgs {
     /// Canonical parquet paths available to every strategy
     #[arg(long = "data", value_name = "PATH", num_args = 1.., action = clap::ArgAction::Append)]
     data_paths: Vec<PathBuf>,
+    #[command(flatten)]
+    selection: CatalogSelectionArgs,
     #[arg(long, default_value = "0.01")]
     quantity: Decimal,
     /// Optional output CSV summarizing results
     #[arg(long)]
     output: Option<PathBuf>,
+    /// Write a self-contained HTML tear sheet covering every config to this path
+    #[arg(long = "report-html", value_name = "PATH")]
+    report_html: Option<PathBuf>,
     /// Symmetric slippage in basis points (1 bp = 0.01%) applied to fills
     #[arg(long, default_value = "0")]
     slippage_bps: Decimal,
@@ -996,6 +1977,12 @@ pub struct BacktestBatchArgs {
     sizer: String,
     #[arg(long)]
     markets_file: Option<PathBuf>,
+    /// Benchmark for relative metrics: `buy-and-hold` or a candle dataset (CSV/parquet)
+    #[arg(long, value_name = "buy-and-hold|PATH")]
+    benchmark: Option<String>,
+    /// Daily observations per rolling benchmark window
+    #[arg(long = "benchmark-window", default_value_t = DEFAULT_ROLLING_WINDOW)]
+    benchmark_window: usize,
 }
 
 #[derive(Args)]
@@ -1090,6 +2077,21 @@ pub struct LiveRunArgs {
     /// Directory containing compiled WASM execution plugins.
     #[arg(long = "plugins-dir")]
     plugins_dir: Option<PathBuf>,
+    /// History used to warm strategy indicators before trading (`exchange`, `catalog` or `none`)
+    #[arg(long = "warmup-source", value_enum, default_value = "exchange")]
+    warmup_source: WarmupSourceArg,
+    /// Dataset catalog root for `--warmup-source catalog` (defaults to the configured data path)
+    #[arg(long = "warmup-catalog", value_name = "DIR")]
+    warmup_catalog: Option<PathBuf>,
+    /// Candles per symbol to replay during warm-up (defaults to the strategy's lookback)
+    #[arg(long = "warmup-candles")]
+    warmup_candles: Option<usize>,
+    /// Re-apply strategy parameters whenever the strategy config file changes
+    #[arg(long = "watch-strategy-config")]
+    watch_strategy_config: bool,
+    /// Seconds between checks of the strategy config file when watching
+    #[arg(long = "watch-interval-secs", default_value_t = 2)]
+    watch_interval_secs: u64,
 }
 
 impl LiveRunArgs {
@@ -1108,6 +2110,29 @@ impl LiveRunArgs {
             .with_context(|| format!("invalid metrics address '{addr}'"))
     }
 
+    fn warmup_settings(&self, config: &AppConfig) -> WarmupSettings {
+        let source = match self.warmup_source {
+            WarmupSourceArg::Exchange => WarmupSource::Exchange,
+            WarmupSourceArg::Catalog => {
+                WarmupSource::Catalog(catalog_root(self.warmup_catalog.as_ref(), config))
+            }
+            WarmupSourceArg::None => WarmupSource::Disabled,
+        };
+        WarmupSettings {
+            source,
+            candles: self.warmup_candles,
+        }
+    }
+
+    fn strategy_reload_settings(&self) -> StrategyReloadSettings {
+        StrategyReloadSettings {
+            path: Some(self.strategy_config.clone()),
+            watch_interval: self
+                .watch_strategy_config
+                .then(|| StdDuration::from_secs(self.watch_interval_secs.max(1))),
+        }
+    }
+
     fn resolved_control_addr(&self, config: &AppConfig) -> Result<SocketAddr> {
         let addr = self
             .control_addr
@@ -1182,18 +2207,6 @@ impl LiveRunArgs {
     }
 }
 
-#[derive(Deserialize)]
-struct StrategyConfigFile {
-    #[serde(rename = "strategy_name")]
-    name: String,
-    #[serde(default = "empty_table")]
-    params: toml::Value,
-}
-
-fn empty_table() -> toml::Value {
-    toml::Value::Table(Default::default())
-}
-
 pub async fn run() -> Result<()> {
     let cli = Cli::parse();
     let config = load_config(Some(&cli.env)).context("failed to load configuration")?;
@@ -1221,6 +2234,9 @@ pub async fn run() -> Result<()> {
         Commands::Backtest {
             action: BacktestCommand::Batch(args),
         } => args.run(&config).await?,
+        Commands::Backtest {
+            action: BacktestCommand::Robustness(args),
+        } => args.run(&config).await?,
         Commands::Live {
             action: LiveCommand::Run(args),
         } => args.run(&config).await?,
@@ -1238,6 +2254,9 @@ async fn handle_data(cmd: DataCommand, config: &AppConfig) -> Result<()> {
         DataCommand::Download(args) => {
             args.run(config).await?;
         }
+        DataCommand::DownloadMetrics(args) => {
+            args.run(config).await?;
+        }
         DataCommand::DownloadTrades(args) => {
             args.run(config).await?;
         }
@@ -1247,12 +2266,25 @@ async fn handle_data(cmd: DataCommand, config: &AppConfig) -> Result<()> {
         DataCommand::Resample(args) => {
             args.run()?;
         }
+        DataCommand::Bars(args) => {
+            args.run().await?;
+        }
         DataCommand::InspectParquet(args) => {
             args.run()?;
         }
         DataCommand::Normalize(args) => {
             args.run()?;
         }
+        DataCommand::Record(args) => {
+            args.run(config).await?;
+        }
+        DataCommand::Compact(args) => args.run(config)?,
+        DataCommand::RebuildBooks(args) => args.run(config).await?,
+        DataCommand::Catalog { action } => match action {
+            DataCatalogCommand::List(args) => args.run(config)?,
+            DataCatalogCommand::Add(args) => args.run(config)?,
+            DataCatalogCommand::Verify(args) => args.run(config)?,
+        },
     }
     Ok(())
 }
@@ -1281,10 +2313,32 @@ fn handle_analyze(cmd: AnalyzeCommand) -> Result<()> {
 
 impl BacktestRunArgs {
     async fn run(&self, config: &AppConfig) -> Result<()> {
+        let report = self.execute(config).await?;
+        print_report(&report);
+        self.write_outputs(&report)
+    }
+
+    fn write_outputs(&self, report: &PerformanceReport) -> Result<()> {
+        if let Some(path) = &self.benchmark_rolling_output {
+            let benchmark = report
+                .benchmark
+                .as_ref()
+                .ok_or_else(|| anyhow!("--benchmark-rolling-output requires --benchmark"))?;
+            write_rolling_benchmark(path, benchmark)?;
+            println!("Rolling benchmark statistics written to {}", path.display());
+        }
+        if let Some(path) = &self.report_html {
+            let title = report_title(&self.strategy_config);
+            write_html_report(path, &html::render_tear_sheet(&title, report))?;
+            println!("HTML tear sheet written to {}", path.display());
+        }
+        Ok(())
+    }
+
+    async fn execute(&self, config: &AppConfig) -> Result<PerformanceReport> {
         let contents = std::fs::read_to_string(&self.strategy_config)
             .with_context(|| format!("failed to read {}", self.strategy_config.display()))?;
-        let def: StrategyConfigFile =
-            toml::from_str(&contents).context("failed to parse strategy config file")?;
+        let def = StrategyDefinition::parse(&contents)?;
         let strategy = load_strategy(&def.name, def.params)
             .with_context(|| format!("failed to configure strategy {}", def.name))?;
         let symbols = strategy.subscriptions();
@@ -1327,11 +2381,11 @@ impl BacktestRunArgs {
                 (Some(stream), None, exec_client, None)
             }
             BacktestMode::Tick => {
-                if self.lob_paths.is_empty() {
-                    bail!("--lob-data is required when --mode tick");
+                if self.lob_paths.is_empty() && self.lob_venues.is_empty() {
+                    bail!("--lob-data or --lob-venue is required when --mode tick");
                 }
                 let source = self.detect_lob_source()?;
-                let latency_ms = self.sim_latency_ms.min(i64::MAX as u64);
+                let latency = self.build_latency_model().await?;
                 let fee_model = fee_model_template.clone();
                 let execution_client = build_sim_execution_client(
                     "paper-tick",
@@ -1347,7 +2401,7 @@ impl BacktestRunArgs {
                     symbols.clone(),
                     reporting_balance(&config.backtest),
                     MatchingEngineConfig {
-                        latency: Duration::milliseconds(latency_ms as i64),
+                        latency,
                         queue_model: self.sim_queue_model.into(),
                         fee_model: fee_model.clone(),
                         cash_asset: Some(reporting_currency),
@@ -1364,6 +2418,10 @@ impl BacktestRunArgs {
                     LobSource::FlightRecorder(root) => self
                         .build_flight_recorder_stream(&root, &symbols)
                         .context("failed to initialize flight recorder stream")?,
+                    LobSource::Consolidated(venues) => self
+                        .build_consolidated_stream(venues, &symbols)
+                        .await
+                        .context("failed to initialize consolidated feed")?,
                 };
                 (None, Some(stream), execution_client, Some(engine))
             }
@@ -1381,8 +2439,10 @@ impl BacktestRunArgs {
         cfg.execution.fee_bps = self.fee_bps.max(Decimal::ZERO);
         cfg.execution.latency_candles = self.latency_candles.max(1);
         cfg.mode = mode;
+        cfg.benchmark = self.benchmark.as_deref().map(load_benchmark).transpose()?;
+        cfg.benchmark_window = self.benchmark_window;
 
-        let report = Backtester::new(
+        Backtester::new(
             cfg,
             strategy,
             execution,
@@ -1393,9 +2453,26 @@ impl BacktestRunArgs {
         )
         .run()
         .await
-        .context("backtest failed")?;
-        print_report(&report);
-        Ok(())
+        .context("backtest failed")
+    }
+
+    async fn build_latency_model(&self) -> Result<LatencyModel> {
+        let latency_ms = self.sim_latency_ms.min(i64::MAX as u64);
+        let mut model = LatencyModel::constant(Duration::milliseconds(latency_ms as i64));
+        model.seed = self.sim_latency_seed;
+        if let Some(spec) = &self.sim_latency_market_data {
+            model.market_data = parse_latency_spec(spec).await?;
+        }
+        if let Some(spec) = &self.sim_latency_place {
+            model.place = parse_latency_spec(spec).await?;
+        }
+        if let Some(spec) = &self.sim_latency_cancel {
+            model.cancel = parse_latency_spec(spec).await?;
+        }
+        if let Some(spec) = &self.sim_latency_amend {
+            model.amend = parse_latency_spec(spec).await?;
+        }
+        Ok(model)
     }
 
     fn resolve_fee_schedule(&self) -> Result<FeeScheduleConfig> {
@@ -1413,7 +2490,7 @@ impl BacktestRunArgs {
         if symbols.is_empty() {
             bail!("strategy did not declare any subscriptions");
         }
-        if self.data_paths.is_empty() {
+        if self.data_paths.is_empty() && !self.selection.is_active() {
             let mut generated = Vec::new();
             for (idx, symbol) in symbols.iter().enumerate() {
                 let offset = idx as i64 * 10;
@@ -1426,11 +2503,28 @@ impl BacktestRunArgs {
             return Ok(memory_market_stream(symbols[0], generated));
         }
 
-        ensure_parquet_inputs(&self.data_paths)?;
-        Ok(parquet_market_stream(symbols, self.data_paths.clone()))
+        let paths = self.selection.resolve(&self.data_paths, symbols)?;
+        ensure_parquet_inputs(&paths)?;
+        Ok(parquet_market_stream(
+            symbols,
+            paths,
+            self.selection.window()?,
+        ))
     }
 
     fn detect_lob_source(&self) -> Result<LobSource> {
+        if !self.lob_venues.is_empty() {
+            if !self.lob_paths.is_empty() {
+                bail!("--lob-data cannot be combined with --lob-venue");
+            }
+            let venues = self
+                .lob_venues
+                .iter()
+                .map(String::as_str)
+                .map(parse_venue_source)
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(LobSource::Consolidated(venues));
+        }
         if self.lob_paths.len() == 1 {
             let path = &self.lob_paths[0];
             if path.is_dir() {
@@ -1455,6 +2549,50 @@ impl BacktestRunArgs {
             .map(|event| event.map(MarketEvent::from));
         Ok(Box::pin(stream))
     }
+
+    async fn build_consolidated_stream(
+        &self,
+        mut venues: Vec<VenueSource>,
+        symbols: &[Symbol],
+    ) -> Result<MarketEventStream> {
+        if self.align_clocks {
+            for venue in &mut venues {
+                match measure_clock_offset(&venue.root, CLOCK_OFFSET_SAMPLES).await? {
+                    Some(offset) => {
+                        info!(
+                            "Aligning {} clock by {} ms",
+                            venue.exchange,
+                            offset.num_milliseconds()
+                        );
+                        venue.clock_offset = offset;
+                    }
+                    None => warn!(
+                        "No trades under {} to measure the {} clock offset",
+                        venue.root.display(),
+                        venue.exchange
+                    ),
+                }
+            }
+        }
+        let stream = ConsolidatedFeed::from_venues(&venues, symbols)?
+            .into_stream()
+            .map(|event| event.map(|event| MarketEvent::from(UnifiedEvent::from(event))));
+        Ok(Box::pin(stream))
+    }
+}
+
+fn parse_venue_source(spec: &str) -> Result<VenueSource> {
+    let (exchange, root) = spec
+        .split_once('=')
+        .ok_or_else(|| anyhow!("invalid --lob-venue '{spec}', expected EXCHANGE=DIR"))?;
+    let exchange: ExchangeId = exchange
+        .parse()
+        .map_err(|err| anyhow!("invalid --lob-venue '{spec}': {err}"))?;
+    let root = PathBuf::from(root);
+    if !root.is_dir() {
+        bail!("--lob-venue directory {} does not exist", root.display());
+    }
+    Ok(VenueSource::new(exchange, root))
 }
 
 fn load_fee_schedule_file(path: &Path) -> Result<FeeScheduleConfig> {
@@ -1474,9 +2612,12 @@ impl BacktestBatchArgs {
         if self.config_paths.is_empty() {
             return Err(anyhow!("provide at least one --config path"));
         }
-        if self.data_paths.is_empty() {
-            return Err(anyhow!("provide at least one --data path for batch mode"));
+        if self.data_paths.is_empty() && !self.selection.is_active() {
+            return Err(anyhow!(
+                "provide at least one --data path or a --catalog for batch mode"
+            ));
         }
+        let window = self.selection.window()?;
         let markets_path = self
             .markets_file
             .clone()
@@ -1489,7 +2630,9 @@ impl BacktestBatchArgs {
                 format!("failed to load markets from {}", markets_path.display())
             })?,
         );
+        let benchmark = self.benchmark.as_deref().map(load_benchmark).transpose()?;
         let mut aggregated = Vec::new();
+        let mut tear_sheets = Vec::new();
         let fee_schedule = if let Some(path) = &self.fee_schedule {
             load_fee_schedule_file(path)?
         } else {
@@ -1500,13 +2643,14 @@ impl BacktestBatchArgs {
         };
         let reporting_currency = AssetId::from(config.backtest.reporting_currency.as_str());
         let initial_balances = clone_initial_balances(&config.backtest);
-        ensure_parquet_inputs(&self.data_paths)?;
+        if !self.selection.is_active() {
+            ensure_parquet_inputs(&self.data_paths)?;
+        }
         for config_path in &self.config_paths {
             let contents = std::fs::read_to_string(config_path).with_context(|| {
                 format!("failed to read strategy config {}", config_path.display())
             })?;
-            let def: StrategyConfigFile =
-                toml::from_str(&contents).context("failed to parse strategy config file")?;
+            let def = StrategyDefinition::parse(&contents)?;
             let strategy = load_strategy(&def.name, def.params)
                 .with_context(|| format!("failed to configure strategy {}", def.name))?;
             let sizer = parse_sizer(&self.sizer, Some(self.quantity))?;
@@ -1515,7 +2659,9 @@ impl BacktestBatchArgs {
             if symbols.is_empty() {
                 bail!("strategy {} did not declare subscriptions", strategy.name());
             }
-            let stream = parquet_market_stream(&symbols, self.data_paths.clone());
+            let paths = self.selection.resolve(&self.data_paths, &symbols)?;
+            ensure_parquet_inputs(&paths)?;
+            let stream = parquet_market_stream(&symbols, paths, window);
             let execution_client = build_sim_execution_client(
                 &format!("paper-batch-{}", def.name),
                 &symbols,
@@ -1534,6 +2680,8 @@ impl BacktestBatchArgs {
             cfg.execution.slippage_bps = self.slippage_bps.max(Decimal::ZERO);
             cfg.execution.fee_bps = self.fee_bps.max(Decimal::ZERO);
             cfg.execution.latency_candles = self.latency_candles.max(1);
+            cfg.benchmark = benchmark.clone();
+            cfg.benchmark_window = self.benchmark_window;
 
             let report = Backtester::new(
                 cfg,
@@ -1547,19 +2695,45 @@ impl BacktestBatchArgs {
             .run()
             .await
             .with_context(|| format!("backtest failed for {}", config_path.display()))?;
+            let rolling_beta = report
+                .benchmark
+                .as_ref()
+                .and_then(|b| b.rolling_beta_range());
             aggregated.push(BatchRow {
                 config: config_path.display().to_string(),
                 signals: 0, // Legacy field, can be removed or calculated from report
                 orders: 0,  // Legacy field, can be removed or calculated from report
                 dropped_orders: 0, // Legacy field, can be removed or calculated from report
                 ending_equity: report.ending_equity,
+                sharpe_ratio: report.sharpe_ratio,
+                max_drawdown_pct: report.max_drawdown_pct,
+                total_trades: report.total_trades,
+                alpha: report.benchmark.as_ref().map(|b| b.alpha),
+                beta: report.benchmark.as_ref().map(|b| b.beta),
+                information_ratio: report.benchmark.as_ref().map(|b| b.information_ratio),
+                tracking_error: report.benchmark.as_ref().map(|b| b.tracking_error),
+                up_capture: report.benchmark.as_ref().map(|b| b.up_capture),
+                down_capture: report.benchmark.as_ref().map(|b| b.down_capture),
+                rolling_beta_min: rolling_beta.map(|(lo, _)| lo),
+                rolling_beta_max: rolling_beta.map(|(_, hi)| hi),
             });
+            if self.report_html.is_some() {
+                tear_sheets.push((report_title(config_path), report));
+            }
         }
 
         if let Some(output) = &self.output {
             write_batch_report(output, &aggregated)?;
             println!("Batch report written to {}", output.display());
         }
+        if let Some(path) = &self.report_html {
+            let sections: Vec<(&str, &PerformanceReport)> = tear_sheets
+                .iter()
+                .map(|(name, report)| (name.as_str(), report))
+                .collect();
+            write_html_report(path, &html::render_document("Backtest Batch", &sections))?;
+            println!("HTML tear sheet written to {}", path.display());
+        }
         if aggregated.is_empty() {
             return Err(anyhow!("no batch jobs executed"));
         }
@@ -1567,6 +2741,48 @@ impl BacktestBatchArgs {
     }
 }
 
+impl BacktestRobustnessArgs {
+    async fn run(&self, config: &AppConfig) -> Result<()> {
+        let method = match self.method {
+            ResamplingMethodArg::Bootstrap => ResamplingMethod::Bootstrap,
+            ResamplingMethodArg::Block => ResamplingMethod::BlockBootstrap {
+                block_size: self.block_size,
+            },
+            ResamplingMethodArg::Shuffle => ResamplingMethod::Shuffle,
+        };
+        let batch_sharpes = match &self.batch_report {
+            Some(path) => read_batch_sharpes(path)?,
+            None => Vec::new(),
+        };
+        let trials = self.trials.unwrap_or(batch_sharpes.len()).max(1);
+        let trial_sharpe_variance = (batch_sharpes.len() > 1).then(|| {
+            let count = batch_sharpes.len() as f64;
+            let mean = batch_sharpes.iter().sum::<f64>() / count;
+            batch_sharpes
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f64>()
+                / (count - 1.0)
+        });
+        let robustness_config = RobustnessConfig {
+            method,
+            iterations: self.iterations,
+            confidence: self.confidence,
+            seed: self.seed,
+            trials,
+            trial_sharpe_variance,
+        };
+
+        let report = self.backtest.execute(config).await?;
+        print_report(&report);
+        self.backtest.write_outputs(&report)?;
+        let robustness = robustness::analyze(&report, &robustness_config)
+            .context("robustness analysis failed")?;
+        println!("{robustness}");
+        Ok(())
+    }
+}
+
 async fn build_sim_execution_client(
     name_prefix: &str,
     symbols: &[Symbol],
@@ -1726,8 +2942,7 @@ impl LiveRunArgs {
 
         let contents = fs::read_to_string(&self.strategy_config)
             .with_context(|| format!("failed to read {}", self.strategy_config.display()))?;
-        let def: StrategyConfigFile =
-            toml::from_str(&contents).context("failed to parse strategy config file")?;
+        let def = StrategyDefinition::parse(&contents)?;
         let strategy = load_strategy(&def.name, def.params)
             .with_context(|| format!("failed to configure strategy {}", def.name))?;
         let symbols = strategy.subscriptions();
@@ -1793,11 +3008,15 @@ impl LiveRunArgs {
             risk: self.build_risk_config(config),
             reconciliation_interval,
             reconciliation_threshold,
+            reconciliation_policy: config.live.reconciliation_policy.clone(),
             orderbook_depth,
             record_path: Some(self.record_data.clone()),
             control_addr,
+            control_plane: config.live.control_plane.clone(),
             panic_close,
             plugins_dir,
+            warmup: self.warmup_settings(config),
+            strategy_reload: self.strategy_reload_settings(),
         };
 
         let exchange_labels: Vec<String> = named_exchanges
@@ -1842,7 +3061,7 @@ fn print_validation_summary(outcome: &ValidationOutcome) {
         summary.start.to_rfc3339(),
         summary.end.to_rfc3339()
     );
-    println!("  Interval: {}", interval_label(summary.interval));
+    println!("  Interval: {}", summary.interval);
     println!("  Missing intervals: {}", summary.missing_candles);
     println!("  Duplicate intervals: {}", summary.duplicate_candles);
     println!("  Zero-volume candles: {}", summary.zero_volume_candles);
@@ -1851,7 +3070,14 @@ fn print_validation_summary(outcome: &ValidationOutcome) {
         "  Cross-source mismatches: {}",
         summary.cross_mismatch_count
     );
+    println!("  OHLC violations: {}", summary.ohlc_violation_count);
     println!("  Repaired candles generated: {}", summary.repaired_candles);
+    println!("  Spikes clipped: {}", summary.clipped_spikes);
+    println!(
+        "  Bars replaced from reference: {}",
+        summary.replaced_from_reference
+    );
+    println!("  OHLC fixes: {}", summary.ohlc_fixes);
 
     if !outcome.gaps.is_empty() {
         println!("  Gap examples:");
@@ -1906,6 +3132,31 @@ fn print_validation_summary(outcome: &ValidationOutcome) {
             );
         }
     }
+
+    if !outcome.ohlc_violations.is_empty() {
+        println!("  OHLC violation examples:");
+        for violation in outcome.ohlc_violations.iter().take(MAX_EXAMPLES) {
+            println!(
+                "    {} ({})",
+                violation.timestamp.to_rfc3339(),
+                violation.reason
+            );
+        }
+        if outcome.ohlc_violations.len() > MAX_EXAMPLES {
+            println!(
+                "    ... {} additional violation(s) omitted",
+                outcome.ohlc_violations.len() - MAX_EXAMPLES
+            );
+        }
+    }
+
+    let clipped: Vec<_> = outcome.repairs_of(RepairAction::ClipSpike).collect();
+    if !clipped.is_empty() {
+        println!("  Clipped spike examples:");
+        for record in clipped.iter().take(MAX_EXAMPLES) {
+            println!("    {} ({})", record.timestamp.to_rfc3339(), record.detail);
+        }
+    }
 }
 
 fn print_report(report: &PerformanceReport) {
@@ -2037,12 +3288,54 @@ fn ensure_parquet_inputs(paths: &[PathBuf]) -> Result<()> {
     Ok(())
 }
 
+/// Maximum number of recorded ticks sampled when building empirical latency distributions.
+const EMPIRICAL_LATENCY_SAMPLE_LIMIT: usize = 100_000;
+/// Trades sampled per venue when measuring clock offsets for `--align-clocks`.
+const CLOCK_OFFSET_SAMPLES: usize = 10_000;
+
+/// Resolves tick parquet files from a file, directory, or flight-recorder root.
+fn resolve_tick_files(path: &Path) -> Result<Vec<PathBuf>> {
+    let ticks_dir = path.join("ticks");
+    let source = if ticks_dir.is_dir() {
+        ticks_dir
+    } else {
+        path.to_path_buf()
+    };
+    let files = collect_parquet_files(&source)?;
+    if files.is_empty() {
+        bail!("no tick parquet files found under {}", source.display());
+    }
+    Ok(files)
+}
+
+async fn parse_latency_spec(spec: &str) -> Result<LatencyDistribution> {
+    let Some(path) = spec.strip_prefix("empirical:") else {
+        return spec
+            .parse::<LatencyDistribution>()
+            .map_err(|err| anyhow!("invalid latency spec '{spec}': {err}"));
+    };
+    let source = PathBuf::from(path);
+    let files = resolve_tick_files(&source)?;
+    let samples = tick_latency_samples(files, Some(EMPIRICAL_LATENCY_SAMPLE_LIMIT))
+        .await
+        .with_context(|| format!("failed to read tick latencies from {}", source.display()))?;
+    let count = samples.len();
+    let distribution = LatencyDistribution::empirical(samples)
+        .ok_or_else(|| anyhow!("no usable latency samples in {}", source.display()))?;
+    info!(source = %source.display(), samples = count, "loaded empirical latency samples");
+    Ok(distribution)
+}
+
 fn memory_market_stream(symbol: Symbol, candles: Vec<Candle>) -> BacktestStream {
     Box::new(PaperMarketStream::from_data(symbol, Vec::new(), candles))
 }
 
-fn parquet_market_stream(symbols: &[Symbol], paths: Vec<PathBuf>) -> BacktestStream {
-    Box::new(ParquetMarketStream::with_candles(symbols.to_vec(), paths))
+fn parquet_market_stream(
+    symbols: &[Symbol],
+    paths: Vec<PathBuf>,
+    window: TimeWindow,
+) -> BacktestStream {
+    Box::new(ParquetMarketStream::with_candles(symbols.to_vec(), paths).with_time_window(window))
 }
 
 #[derive(Deserialize)]
@@ -2067,6 +3360,15 @@ enum LobEventRow {
         price: f64,
         size: f64,
     },
+    Order {
+        timestamp: String,
+        symbol: Option<String>,
+        order_id: String,
+        side: String,
+        price: f64,
+        size: f64,
+        action: MarketByOrderAction,
+    },
 }
 
 fn load_lob_events_from_paths(paths: &[PathBuf]) -> Result<Vec<MarketEvent>> {
@@ -2104,6 +3406,8 @@ fn load_lob_events_from_paths(paths: &[PathBuf]) -> Result<Vec<MarketEvent>> {
                         timestamp: ts,
                         exchange_checksum: None,
                         local_checksum: None,
+                        sequence: None,
+                        prev_sequence: None,
                     };
                     events.push(MarketEvent {
                         timestamp: ts,
@@ -2170,6 +3474,45 @@ fn load_lob_events_from_paths(paths: &[PathBuf]) -> Result<Vec<MarketEvent>> {
                         kind: MarketEventKind::Trade(tick),
                     });
                 }
+                LobEventRow::Order {
+                    timestamp,
+                    symbol,
+                    order_id,
+                    side,
+                    price,
+                    size,
+                    action,
+                } => {
+                    let ts = parse_datetime(&timestamp)?;
+                    let symbol_code = symbol.or_else(|| symbol_hint.clone()).ok_or_else(|| {
+                        anyhow!("missing symbol in order event {}", path.display())
+                    })?;
+                    let symbol = Symbol::from(symbol_code.as_str());
+                    let side = match side.to_lowercase().as_str() {
+                        "buy" | "bid" | "b" => Side::Buy,
+                        "sell" | "ask" | "s" => Side::Sell,
+                        other => bail!("unsupported order side '{other}' in {}", path.display()),
+                    };
+                    let price = Decimal::from_f64(price).ok_or_else(|| {
+                        anyhow!("invalid order price '{}' in {}", price, path.display())
+                    })?;
+                    let size = Decimal::from_f64(size).ok_or_else(|| {
+                        anyhow!("invalid order size '{}' in {}", size, path.display())
+                    })?;
+                    let update = MarketByOrderUpdate {
+                        symbol,
+                        order_id,
+                        side,
+                        price,
+                        size,
+                        action,
+                        timestamp: ts,
+                    };
+                    events.push(MarketEvent {
+                        timestamp: ts,
+                        kind: MarketEventKind::MarketByOrder(update),
+                    });
+                }
             }
         }
     }
@@ -2211,7 +3554,7 @@ fn default_output_path(
     start: DateTime<Utc>,
     end: DateTime<Utc>,
 ) -> PathBuf {
-    let interval_part = interval_label(interval);
+    let interval_part = interval.label();
     let start_part = start.format("%Y%m%d").to_string();
     let end_part = end.format("%Y%m%d").to_string();
     config
@@ -2245,18 +3588,6 @@ fn partition_path(base_dir: &Path, date: NaiveDate) -> PathBuf {
     base_dir.join(format!("{}.parquet", date.format("%Y-%m-%d")))
 }
 
-fn interval_label(interval: Interval) -> &'static str {
-    match interval {
-        Interval::OneSecond => "1s",
-        Interval::OneMinute => "1m",
-        Interval::FiveMinutes => "5m",
-        Interval::FifteenMinutes => "15m",
-        Interval::OneHour => "1h",
-        Interval::FourHours => "4h",
-        Interval::OneDay => "1d",
-    }
-}
-
 #[derive(Serialize)]
 struct CandleRow {
     symbol: String,
@@ -2298,6 +3629,105 @@ struct BatchRow {
     orders: usize,
     dropped_orders: usize,
     ending_equity: f64,
+    sharpe_ratio: f64,
+    max_drawdown_pct: f64,
+    total_trades: usize,
+    alpha: Option<f64>,
+    beta: Option<f64>,
+    information_ratio: Option<f64>,
+    tracking_error: Option<f64>,
+    up_capture: Option<f64>,
+    down_capture: Option<f64>,
+    rolling_beta_min: Option<f64>,
+    rolling_beta_max: Option<f64>,
+}
+
+/// Resolves `--benchmark` into buy-and-hold or closes loaded from a candle dataset.
+fn load_benchmark(spec: &str) -> Result<Benchmark> {
+    let spec = spec.trim();
+    if matches!(
+        spec.to_ascii_lowercase().as_str(),
+        "buy-and-hold" | "buy_and_hold" | "bnh"
+    ) {
+        return Ok(Benchmark::BuyAndHold);
+    }
+    let path = Path::new(spec);
+    let dataset = io::read_dataset(path)
+        .with_context(|| format!("failed to load benchmark {}", path.display()))?;
+    if dataset.candles.is_empty() {
+        bail!("benchmark dataset {} contains no candles", path.display());
+    }
+    Ok(Benchmark::Series(
+        dataset
+            .candles
+            .into_iter()
+            .map(|candle| (candle.timestamp, candle.close))
+            .collect(),
+    ))
+}
+
+fn report_title(strategy_config: &Path) -> String {
+    strategy_config
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| strategy_config.display().to_string())
+}
+
+fn write_html_report(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create directory {}", parent.display()))?;
+    }
+    fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+#[derive(Serialize)]
+struct RollingBenchmarkRow {
+    timestamp: String,
+    alpha: f64,
+    beta: f64,
+    tracking_error: f64,
+    information_ratio: f64,
+}
+
+fn write_rolling_benchmark(path: &Path, report: &BenchmarkReport) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create directory {}", parent.display()))?;
+    }
+    let mut writer =
+        Writer::from_path(path).with_context(|| format!("failed to create {}", path.display()))?;
+    for point in &report.rolling {
+        writer.serialize(RollingBenchmarkRow {
+            timestamp: point.timestamp.to_rfc3339(),
+            alpha: point.alpha,
+            beta: point.beta,
+            tracking_error: point.tracking_error,
+            information_ratio: point.information_ratio,
+        })?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct BatchSharpeRow {
+    #[serde(default)]
+    sharpe_ratio: Option<f64>,
+}
+
+/// Reads the annualized Sharpe ratios recorded by `backtest batch --output`.
+fn read_batch_sharpes(path: &Path) -> Result<Vec<f64>> {
+    let mut reader = csv::Reader::from_path(path)
+        .with_context(|| format!("failed to open batch report {}", path.display()))?;
+    let mut sharpes = Vec::new();
+    for row in reader.deserialize::<BatchSharpeRow>() {
+        let row = row.with_context(|| format!("invalid row in {}", path.display()))?;
+        if let Some(value) = row.sharpe_ratio.filter(|value| value.is_finite()) {
+            sharpes.push(value);
+        }
+    }
+    Ok(sharpes)
 }
 
 fn write_batch_report(path: &Path, rows: &[BatchRow]) -> Result<()> {
@@ -2362,6 +3792,13 @@ fn parse_sizer(value: &str, cli_quantity: Option<Decimal>) -> Result<Box<dyn Ord
     }
 }
 
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum WarmupSourceArg {
+    Exchange,
+    Catalog,
+    None,
+}
+
 #[derive(Clone, Copy, Debug, ValueEnum)]
 pub enum PanicModeArg {
     Market,
@@ -0,0 +1,269 @@
+//! Caller authentication and role-based permissions for the control plane.
+//!
+//! Callers authenticate with a bearer token in the `authorization` header or, when mTLS is
+//! enabled, with a client certificate whose common name is mapped to a role. Every RPC declares
+//! the minimum [`ControlRole`] it requires in [`required_role`].
+
+use anyhow::{anyhow, bail, Result};
+use openssl::nid::Nid;
+use openssl::x509::X509;
+use tesser_config::{ControlPlaneConfig, ControlRole};
+use tonic::metadata::{Ascii, MetadataValue};
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+/// Authenticated identity of a control-plane caller.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Principal {
+    pub name: String,
+    pub role: ControlRole,
+}
+
+impl Principal {
+    fn anonymous() -> Self {
+        Self {
+            name: "anonymous".into(),
+            role: ControlRole::Admin,
+        }
+    }
+}
+
+/// Minimum role required to invoke a control-plane RPC.
+///
+/// RPCs that are not listed require [`ControlRole::Admin`], so new mutating endpoints are
+/// locked down until they are classified here.
+pub fn required_role(rpc: &str) -> ControlRole {
+    match rpc {
+        "GetPortfolio"
+        | "GetOpenOrders"
+        | "GetStatus"
+        | "ListManagedTrades"
+        | "ListReconciliationActions"
+        | "Monitor" => ControlRole::ReadOnly,
+        "PlaceOrder"
+        | "CancelOrder"
+        | "CancelAlgo"
+        | "CancelAll"
+        | "FlattenPosition"
+        | "SetLiquidateOnly"
+        | "UpdateTradeExitStrategy" => ControlRole::Trader,
+        _ => ControlRole::Admin,
+    }
+}
+
+/// Resolves callers to a [`Principal`] and enforces [`required_role`].
+#[derive(Clone, Debug, Default)]
+pub struct ControlAuth {
+    enabled: bool,
+    tokens: Vec<(String, Principal)>,
+    clients: Vec<(String, ControlRole)>,
+}
+
+impl ControlAuth {
+    pub fn from_config(config: &ControlPlaneConfig) -> Result<Self> {
+        let mut tokens = Vec::with_capacity(config.tokens.len());
+        for token in &config.tokens {
+            let secret = token.resolve()?;
+            if tokens.iter().any(|(existing, _)| existing == &secret) {
+                bail!("control token '{}' duplicates another token", token.name);
+            }
+            tokens.push((
+                secret,
+                Principal {
+                    name: token.name.clone(),
+                    role: token.role,
+                },
+            ));
+        }
+        let clients = config
+            .clients
+            .iter()
+            .map(|client| (client.common_name.clone(), client.role))
+            .collect::<Vec<_>>();
+        let mtls = config
+            .tls
+            .as_ref()
+            .is_some_and(|tls| tls.client_ca_path.is_some());
+        if !clients.is_empty() && !mtls {
+            bail!("control_plane.clients requires control_plane.tls.client_ca_path");
+        }
+        Ok(Self {
+            enabled: config.auth_enabled(),
+            tokens,
+            clients,
+        })
+    }
+
+    /// Whether callers must present credentials.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Authenticate the caller and check it may invoke `rpc`.
+    #[allow(clippy::result_large_err)]
+    pub fn authorize<T>(&self, request: &Request<T>, rpc: &str) -> Result<Principal, Status> {
+        let principal = self.authenticate(request)?;
+        let required = required_role(rpc);
+        if principal.role < required {
+            return Err(Status::permission_denied(format!(
+                "{rpc} requires the {} role; '{}' has {}",
+                required.as_str(),
+                principal.name,
+                principal.role.as_str()
+            )));
+        }
+        Ok(principal)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn authenticate<T>(&self, request: &Request<T>) -> Result<Principal, Status> {
+        if !self.enabled {
+            return Ok(Principal::anonymous());
+        }
+        if let Some(header) = request.metadata().get("authorization") {
+            let token = header
+                .to_str()
+                .ok()
+                .and_then(|value| value.strip_prefix("Bearer "))
+                .map(str::trim)
+                .ok_or_else(|| Status::unauthenticated("malformed authorization header"))?;
+            return self
+                .tokens
+                .iter()
+                .find(|(secret, _)| constant_time_eq(secret.as_bytes(), token.as_bytes()))
+                .map(|(_, principal)| principal.clone())
+                .ok_or_else(|| Status::unauthenticated("invalid control-plane token"));
+        }
+        if let Some(common_name) = peer_common_name(request) {
+            return self
+                .clients
+                .iter()
+                .find(|(name, _)| name == &common_name)
+                .map(|(_, role)| Principal {
+                    name: format!("cert:{common_name}"),
+                    role: *role,
+                })
+                .ok_or_else(|| {
+                    Status::permission_denied(format!(
+                        "client certificate '{common_name}' has no control-plane role"
+                    ))
+                });
+        }
+        Err(Status::unauthenticated("missing control-plane credentials"))
+    }
+}
+
+/// Common name of the verified client certificate, when the connection uses mTLS.
+fn peer_common_name<T>(request: &Request<T>) -> Option<String> {
+    let certs = request.peer_certs()?;
+    let leaf = X509::from_der(certs.first()?.get_ref()).ok()?;
+    let entry = leaf.subject_name().entries_by_nid(Nid::COMMONNAME).next()?;
+    entry.data().as_utf8().ok().map(|name| name.to_string())
+}
+
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+    left.iter()
+        .zip(right)
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
+
+/// Client-side interceptor attaching a bearer token to every control-plane request.
+#[derive(Clone, Default)]
+pub struct BearerToken(Option<MetadataValue<Ascii>>);
+
+impl BearerToken {
+    pub fn new(token: Option<&str>) -> Result<Self> {
+        let value = match token.map(str::trim).filter(|token| !token.is_empty()) {
+            Some(token) => Some(
+                format!("Bearer {token}")
+                    .parse()
+                    .map_err(|_| anyhow!("control token contains invalid characters"))?,
+            ),
+            None => None,
+        };
+        Ok(Self(value))
+    }
+}
+
+impl Interceptor for BearerToken {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        if let Some(value) = &self.0 {
+            request
+                .metadata_mut()
+                .insert("authorization", value.clone());
+        }
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tesser_config::ControlTokenConfig;
+
+    fn auth() -> ControlAuth {
+        let config = ControlPlaneConfig {
+            tls: None,
+            tokens: vec![
+                ControlTokenConfig {
+                    name: "dashboard".into(),
+                    token: Some("view-secret".into()),
+                    token_env: None,
+                    role: ControlRole::ReadOnly,
+                },
+                ControlTokenConfig {
+                    name: "desk".into(),
+                    token: Some("trade-secret".into()),
+                    token_env: None,
+                    role: ControlRole::Trader,
+                },
+            ],
+            clients: Vec::new(),
+        };
+        ControlAuth::from_config(&config).unwrap()
+    }
+
+    fn request(token: Option<&str>) -> Request<()> {
+        let mut interceptor = BearerToken::new(token).unwrap();
+        interceptor.call(Request::new(())).unwrap()
+    }
+
+    #[test]
+    fn roles_gate_rpcs() {
+        let auth = auth();
+        let viewer = request(Some("view-secret"));
+        assert!(auth.authorize(&viewer, "GetStatus").is_ok());
+        let denied = auth.authorize(&viewer, "CancelAll").unwrap_err();
+        assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+
+        let trader = request(Some("trade-secret"));
+        let principal = auth.authorize(&trader, "PlaceOrder").unwrap();
+        assert_eq!(principal.name, "desk");
+        let denied = auth.authorize(&trader, "UpdateRiskLimits").unwrap_err();
+        assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[test]
+    fn rejects_missing_or_unknown_tokens() {
+        let auth = auth();
+        let missing = auth.authorize(&request(None), "GetStatus").unwrap_err();
+        assert_eq!(missing.code(), tonic::Code::Unauthenticated);
+        let unknown = auth
+            .authorize(&request(Some("guess")), "GetStatus")
+            .unwrap_err();
+        assert_eq!(unknown.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[test]
+    fn disabled_auth_grants_admin() {
+        let auth = ControlAuth::from_config(&ControlPlaneConfig::default()).unwrap();
+        assert!(!auth.enabled());
+        let principal = auth.authorize(&request(None), "SetStrategyPaused").unwrap();
+        assert_eq!(principal.role, ControlRole::Admin);
+        assert_eq!(required_role("SomeFutureRpc"), ControlRole::Admin);
+    }
+}
@@ -0,0 +1,84 @@
+//! Authenticated control-plane client used by `monitor`.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+use tesser_rpc::proto::control_service_client::ControlServiceClient;
+use tonic::service::interceptor::InterceptedService;
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity};
+
+use super::auth::BearerToken;
+
+pub type ControlClient = ControlServiceClient<InterceptedService<Channel, BearerToken>>;
+
+/// Credentials and trust settings for reaching a secured control plane.
+#[derive(Clone, Debug, Default)]
+pub struct ControlClientOptions {
+    /// Bearer token sent with every request.
+    pub token: Option<String>,
+    /// PEM bundle used to verify the server; enables TLS.
+    pub ca_cert: Option<PathBuf>,
+    /// Client certificate and key presented for mTLS.
+    pub client_cert: Option<PathBuf>,
+    pub client_key: Option<PathBuf>,
+    /// Server name to verify, defaulting to the host in the address.
+    pub domain: Option<String>,
+}
+
+impl ControlClientOptions {
+    pub fn tls_enabled(&self) -> bool {
+        self.ca_cert.is_some() || self.client_cert.is_some()
+    }
+
+    /// `addr` with an explicit scheme matching the transport.
+    pub fn endpoint(&self, addr: &str) -> String {
+        if addr.starts_with("http://") || addr.starts_with("https://") {
+            addr.to_string()
+        } else if self.tls_enabled() {
+            format!("https://{addr}")
+        } else {
+            format!("http://{addr}")
+        }
+    }
+
+    fn tls_config(&self) -> Result<ClientTlsConfig> {
+        let mut tls = ClientTlsConfig::new();
+        if let Some(path) = &self.ca_cert {
+            let ca =
+                fs::read(path).with_context(|| format!("failed to read {}", path.display()))?;
+            tls = tls.ca_certificate(Certificate::from_pem(ca));
+        }
+        match (&self.client_cert, &self.client_key) {
+            (Some(cert), Some(key)) => {
+                let cert =
+                    fs::read(cert).with_context(|| format!("failed to read {}", cert.display()))?;
+                let key =
+                    fs::read(key).with_context(|| format!("failed to read {}", key.display()))?;
+                tls = tls.identity(Identity::from_pem(cert, key));
+            }
+            (None, None) => {}
+            _ => {
+                return Err(anyhow!(
+                    "client certificate and key must be provided together"
+                ))
+            }
+        }
+        if let Some(domain) = &self.domain {
+            tls = tls.domain_name(domain.clone());
+        }
+        Ok(tls)
+    }
+
+    /// Connect to `endpoint` (as returned by [`Self::endpoint`]).
+    pub async fn connect(&self, endpoint: &str) -> Result<ControlClient> {
+        let mut builder = Endpoint::from_shared(endpoint.to_string())
+            .with_context(|| format!("invalid control plane address {endpoint}"))?;
+        if endpoint.starts_with("https://") {
+            builder = builder.tls_config(self.tls_config()?)?;
+        }
+        let channel = builder.connect().await?;
+        let token = BearerToken::new(self.token.as_deref())?;
+        Ok(ControlServiceClient::with_interceptor(channel, token))
+    }
+}
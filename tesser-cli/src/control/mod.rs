@@ -0,0 +1,871 @@
+//! Control-plane gRPC server exposing runtime state and operator actions.
+
+pub mod auth;
+pub mod client;
+pub mod tls;
+
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+use tracing::{debug, info, warn};
+
+use rust_decimal::Decimal;
+use tesser_config::ControlPlaneConfig;
+use tesser_core::{ExitStrategy, OrderId, OrderRequest, OrderType, Side, Symbol};
+use tesser_events::{Event as RuntimeEvent, EventBus};
+use tesser_execution::{BasicRiskChecker, OrderOrchestrator, RiskLimits};
+use tesser_rpc::conversions::{from_decimal_proto, to_decimal_proto};
+use tesser_rpc::proto::control_service_server::{ControlService, ControlServiceServer};
+use tesser_rpc::proto::{
+    self, CancelAlgoRequest, CancelAlgoResponse, CancelAllRequest, CancelAllResponse,
+    CancelOrderRequest, CancelOrderResponse, Event, FlattenPositionRequest,
+    FlattenPositionResponse, GetOpenOrdersRequest, GetOpenOrdersResponse, GetPortfolioRequest,
+    GetPortfolioResponse, GetStatusRequest, GetStatusResponse, ListManagedTradesRequest,
+    ListManagedTradesResponse, ListReconciliationActionsRequest, ListReconciliationActionsResponse,
+    ManagedTradeInfo, MonitorRequest, OrderSnapshot, PlaceOrderRequest, PlaceOrderResponse,
+    PortfolioSnapshot, ReloadStrategyRequest, ReloadStrategyResponse, SetLiquidateOnlyRequest,
+    SetLiquidateOnlyResponse, SetStrategyPausedRequest, SetStrategyPausedResponse,
+    SignalComparison, SwapStrategyRequest, SwapStrategyResponse, UpdateRiskLimitsRequest,
+    UpdateRiskLimitsResponse, UpdateTradeExitStrategyRequest, UpdateTradeExitStrategyResponse,
+};
+use tesser_strategy::PairTradeSnapshot;
+use uuid::Uuid;
+
+use self::auth::{ControlAuth, Principal};
+use crate::live::{OmsHandle, ReconciliationJournal, ShutdownSignal, StrategyHandle};
+use crate::reload::{toml_to_json, ReloadSource, StrategyDefinition, StrategyReloader};
+
+pub struct ControlPlaneComponents {
+    pub oms: OmsHandle,
+    pub orchestrator: Arc<OrderOrchestrator>,
+    pub last_data_timestamp: Arc<AtomicI64>,
+    pub event_bus: Arc<EventBus>,
+    pub strategy: StrategyHandle,
+    pub shutdown: ShutdownSignal,
+    pub reconciliation: ReconciliationJournal,
+    pub risk: Arc<BasicRiskChecker>,
+    pub reloader: Arc<StrategyReloader>,
+}
+
+/// Launch the Control Plane gRPC server alongside the live runtime.
+pub fn spawn_control_plane(
+    addr: SocketAddr,
+    config: &ControlPlaneConfig,
+    components: ControlPlaneComponents,
+) -> Result<JoinHandle<()>> {
+    let auth = ControlAuth::from_config(config)?;
+    let tls = tls::server_tls_config(config)?;
+    check_exposure(addr, config, tls.is_some())?;
+    let ControlPlaneComponents {
+        oms,
+        orchestrator,
+        last_data_timestamp,
+        event_bus,
+        strategy,
+        shutdown,
+        reconciliation,
+        risk,
+        reloader,
+    } = components;
+    let service = ControlGrpcService {
+        oms,
+        orchestrator,
+        last_data_timestamp,
+        event_bus,
+        strategy,
+        shutdown: shutdown.clone(),
+        reconciliation,
+        risk,
+        reloader,
+        auth: auth.clone(),
+    };
+    let tls_enabled = tls.is_some();
+    let mut builder = Server::builder();
+    if let Some(tls) = tls {
+        builder = builder.tls_config(tls)?;
+    }
+    info!(
+        %addr,
+        tls = tls_enabled,
+        auth = auth.enabled(),
+        "starting control plane gRPC server"
+    );
+    Ok(tokio::spawn(async move {
+        if let Err(err) = builder
+            .add_service(ControlServiceServer::new(service))
+            .serve_with_shutdown(addr, async move { shutdown.wait().await })
+            .await
+        {
+            warn!(error = %err, "control plane server exited with error");
+        }
+    }))
+}
+
+/// Refuses to accept bearer tokens in cleartext beyond localhost and warns when such an
+/// address is left unauthenticated.
+fn check_exposure(addr: SocketAddr, config: &ControlPlaneConfig, tls_enabled: bool) -> Result<()> {
+    if addr.ip().is_loopback() {
+        return Ok(());
+    }
+    if !config.tokens.is_empty() && !tls_enabled {
+        bail!(
+            "control plane at {addr} would receive bearer tokens in cleartext; configure live.control_plane.tls or bind to a loopback address"
+        );
+    }
+    if !config.auth_enabled() {
+        warn!(
+            %addr,
+            "control plane is reachable beyond localhost without authentication; configure live.control_plane tokens or client certificates"
+        );
+    }
+    Ok(())
+}
+
+struct ControlGrpcService {
+    oms: OmsHandle,
+    orchestrator: Arc<OrderOrchestrator>,
+    last_data_timestamp: Arc<AtomicI64>,
+    event_bus: Arc<EventBus>,
+    strategy: StrategyHandle,
+    shutdown: ShutdownSignal,
+    reconciliation: ReconciliationJournal,
+    risk: Arc<BasicRiskChecker>,
+    reloader: Arc<StrategyReloader>,
+    auth: ControlAuth,
+}
+
+/// Authenticated caller of a control-plane RPC, recorded in the audit log.
+struct Caller {
+    peer: Option<SocketAddr>,
+    principal: Principal,
+}
+
+impl ControlGrpcService {
+    /// Authenticate the request and check the caller's role permits `rpc`.
+    #[allow(clippy::result_large_err)]
+    fn authorize<T>(&self, request: &Request<T>, rpc: &'static str) -> Result<Caller, Status> {
+        let peer = request.remote_addr();
+        match self.auth.authorize(request, rpc) {
+            Ok(principal) => Ok(Caller { peer, principal }),
+            Err(status) => {
+                warn!(
+                    target: "audit",
+                    peer = %peer.map(|addr| addr.to_string()).unwrap_or_else(|| "unknown".into()),
+                    rpc,
+                    code = ?status.code(),
+                    error = %status.message(),
+                    "control request rejected"
+                );
+                Err(status)
+            }
+        }
+    }
+
+    fn last_data_timestamp(&self) -> Option<prost_types::Timestamp> {
+        let secs = self.last_data_timestamp.load(Ordering::SeqCst);
+        if secs <= 0 {
+            return None;
+        }
+        Some(prost_types::Timestamp {
+            seconds: secs,
+            nanos: 0,
+        })
+    }
+
+    async fn cancel_all_impl(&self) -> Result<(u32, u32)> {
+        let algo_ids: Vec<_> = self
+            .orchestrator
+            .algorithm_statuses()
+            .keys()
+            .copied()
+            .collect();
+        let mut cancelled_algorithms = 0u32;
+        for algo_id in algo_ids {
+            match self.orchestrator.cancel_algo(&algo_id).await {
+                Ok(_) => cancelled_algorithms += 1,
+                Err(err) => warn!(algo = %algo_id, error = %err, "failed to cancel algorithm"),
+            }
+        }
+
+        let open_orders = self.oms.open_orders().await;
+        let client = self.orchestrator.execution_engine().client();
+        let mut cancelled_orders = 0u32;
+        for order in open_orders {
+            let symbol = order.request.symbol;
+            match client.cancel_order(order.id.clone(), symbol).await {
+                Ok(_) => cancelled_orders += 1,
+                Err(err) => warn!(order_id = %order.id, error = %err, "failed to cancel order"),
+            }
+        }
+        Ok((cancelled_orders, cancelled_algorithms))
+    }
+
+    #[allow(clippy::result_large_err)]
+    async fn resolve_symbol(&self, code: &str) -> Result<Symbol, Status> {
+        if code.trim().is_empty() {
+            return Err(Status::invalid_argument("symbol is required"));
+        }
+        self.oms
+            .resolve_symbol(code)
+            .await
+            .map_err(|err| Status::invalid_argument(err.to_string()))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn order_request_from_proto(
+        symbol: Symbol,
+        payload: PlaceOrderRequest,
+    ) -> Result<OrderRequest, Status> {
+        let side = match proto::Side::try_from(payload.side) {
+            Ok(proto::Side::Buy) => Side::Buy,
+            Ok(proto::Side::Sell) => Side::Sell,
+            _ => return Err(Status::invalid_argument("side must be BUY or SELL")),
+        };
+        let quantity = payload
+            .quantity
+            .map(from_decimal_proto)
+            .filter(|qty| *qty > Decimal::ZERO)
+            .ok_or_else(|| Status::invalid_argument("quantity must be positive"))?;
+        let price = payload
+            .price
+            .map(from_decimal_proto)
+            .filter(|price| *price > Decimal::ZERO);
+        let order_type = match proto::OrderType::try_from(payload.order_type) {
+            Ok(proto::OrderType::Market) => OrderType::Market,
+            Ok(proto::OrderType::Limit) => OrderType::Limit,
+            Ok(proto::OrderType::Unspecified) if price.is_some() => OrderType::Limit,
+            Ok(proto::OrderType::Unspecified) => OrderType::Market,
+            _ => {
+                return Err(Status::invalid_argument(
+                    "only MARKET and LIMIT orders can be placed manually",
+                ))
+            }
+        };
+        if order_type == OrderType::Limit && price.is_none() {
+            return Err(Status::invalid_argument("limit orders require a price"));
+        }
+        let client_order_id = if payload.client_order_id.is_empty() {
+            format!("manual-{}", Uuid::new_v4())
+        } else {
+            payload.client_order_id
+        };
+        Ok(OrderRequest {
+            symbol,
+            side,
+            order_type,
+            quantity,
+            price: price.filter(|_| order_type == OrderType::Limit),
+            trigger_price: None,
+            time_in_force: None,
+            client_order_id: Some(client_order_id),
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        })
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn snapshot_to_proto(snapshot: PairTradeSnapshot) -> Result<ManagedTradeInfo, Status> {
+        let exit_strategy_json = serde_json::to_string(&snapshot.exit_strategy)
+            .map_err(|err| Status::internal(format!("failed to encode exit strategy: {err}")))?;
+        Ok(ManagedTradeInfo {
+            trade_id: snapshot.trade_id.to_string(),
+            symbol_a: snapshot.symbols[0].to_string(),
+            symbol_b: snapshot.symbols[1].to_string(),
+            direction: format!("{:?}", snapshot.direction),
+            entry_timestamp: Some(timestamp_from_datetime(snapshot.entry_timestamp)),
+            entry_z: Some(to_decimal_proto(snapshot.entry_z_score)),
+            candles_held: snapshot.candles_held,
+            exit_strategy_json,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl ControlService for ControlGrpcService {
+    type MonitorStream = ReceiverStream<Result<Event, Status>>;
+
+    async fn get_portfolio(
+        &self,
+        request: Request<GetPortfolioRequest>,
+    ) -> Result<Response<GetPortfolioResponse>, Status> {
+        self.authorize(&request, "GetPortfolio")?;
+        let snapshot: PortfolioSnapshot = self
+            .oms
+            .portfolio_state()
+            .await
+            .map(|state| state.into())
+            .unwrap_or_default();
+        Ok(Response::new(GetPortfolioResponse {
+            portfolio: Some(snapshot),
+        }))
+    }
+
+    async fn get_open_orders(
+        &self,
+        request: Request<GetOpenOrdersRequest>,
+    ) -> Result<Response<GetOpenOrdersResponse>, Status> {
+        self.authorize(&request, "GetOpenOrders")?;
+        let orders = self.oms.open_orders().await;
+        let proto_orders: Vec<OrderSnapshot> =
+            orders.into_iter().map(OrderSnapshot::from).collect();
+        Ok(Response::new(GetOpenOrdersResponse {
+            orders: proto_orders,
+        }))
+    }
+
+    async fn get_status(
+        &self,
+        request: Request<GetStatusRequest>,
+    ) -> Result<Response<GetStatusResponse>, Status> {
+        self.authorize(&request, "GetStatus")?;
+        let summary = self.oms.status().await;
+        let response = GetStatusResponse {
+            shutdown: self.shutdown.triggered(),
+            liquidate_only: summary.liquidate_only,
+            active_algorithms: self.orchestrator.active_algorithms_count() as u32,
+            last_data_timestamp: self.last_data_timestamp(),
+            equity: Some(to_decimal_proto(summary.equity)),
+            strategy_paused: self.strategy.is_paused(),
+            risk_limits: Some(risk_limits_to_proto(self.risk.limits())),
+        };
+        Ok(Response::new(response))
+    }
+
+    async fn cancel_all(
+        &self,
+        request: Request<CancelAllRequest>,
+    ) -> Result<Response<CancelAllResponse>, Status> {
+        let caller = self.authorize(&request, "CancelAll")?;
+        let result = match self.cancel_all_impl().await {
+            Ok((orders, algos)) => Ok(CancelAllResponse {
+                cancelled_orders: orders,
+                cancelled_algorithms: algos,
+            }),
+            Err(err) => Err(Status::internal(err.to_string())),
+        };
+        let detail = match &result {
+            Ok(resp) => format!(
+                "orders={} algorithms={}",
+                resp.cancelled_orders, resp.cancelled_algorithms
+            ),
+            Err(_) => String::new(),
+        };
+        audit(&caller, "cancel_all", &detail, &result);
+        result.map(Response::new)
+    }
+
+    async fn place_order(
+        &self,
+        request: Request<PlaceOrderRequest>,
+    ) -> Result<Response<PlaceOrderResponse>, Status> {
+        let caller = self.authorize(&request, "PlaceOrder")?;
+        let payload = request.into_inner();
+        let mut detail = format!("symbol={}", payload.symbol);
+        let result: Result<PlaceOrderResponse, Status> = async {
+            let symbol = self.resolve_symbol(&payload.symbol).await?;
+            let order_request = Self::order_request_from_proto(symbol, payload)?;
+            detail = format!(
+                "symbol={} side={:?} type={:?} qty={} price={}",
+                symbol,
+                order_request.side,
+                order_request.order_type,
+                order_request.quantity,
+                order_request
+                    .price
+                    .map(|price| price.to_string())
+                    .unwrap_or_else(|| "-".into()),
+            );
+            let order = self
+                .oms
+                .place_order(order_request)
+                .await
+                .map_err(|err| Status::failed_precondition(err.to_string()))?;
+            Ok(PlaceOrderResponse {
+                order: Some(OrderSnapshot::from(order)),
+            })
+        }
+        .await;
+        audit(&caller, "place_order", &detail, &result);
+        result.map(Response::new)
+    }
+
+    async fn cancel_order(
+        &self,
+        request: Request<CancelOrderRequest>,
+    ) -> Result<Response<CancelOrderResponse>, Status> {
+        let caller = self.authorize(&request, "CancelOrder")?;
+        let payload = request.into_inner();
+        let detail = format!("order_id={}", payload.order_id);
+        let result: Result<CancelOrderResponse, Status> = async {
+            let order_id: OrderId = payload.order_id.clone();
+            let order = self
+                .oms
+                .open_orders()
+                .await
+                .into_iter()
+                .find(|order| order.id == order_id)
+                .ok_or_else(|| {
+                    Status::not_found(format!("no open order with id {}", payload.order_id))
+                })?;
+            let symbol = order.request.symbol;
+            self.orchestrator
+                .execution_engine()
+                .client()
+                .cancel_order(order.id, symbol)
+                .await
+                .map_err(|err| Status::internal(err.to_string()))?;
+            Ok(CancelOrderResponse {
+                symbol: symbol.to_string(),
+            })
+        }
+        .await;
+        audit(&caller, "cancel_order", &detail, &result);
+        result.map(Response::new)
+    }
+
+    async fn cancel_algo(
+        &self,
+        request: Request<CancelAlgoRequest>,
+    ) -> Result<Response<CancelAlgoResponse>, Status> {
+        let caller = self.authorize(&request, "CancelAlgo")?;
+        let payload = request.into_inner();
+        let detail = format!("algo_id={}", payload.algo_id);
+        let result: Result<CancelAlgoResponse, Status> = async {
+            let algo_id = Uuid::parse_str(&payload.algo_id)
+                .map_err(|err| Status::invalid_argument(format!("invalid algo_id: {err}")))?;
+            if !self
+                .orchestrator
+                .algorithm_statuses()
+                .contains_key(&algo_id)
+            {
+                return Err(Status::not_found(format!("no active algorithm {algo_id}")));
+            }
+            self.orchestrator
+                .cancel_algo(&algo_id)
+                .await
+                .map_err(|err| Status::internal(err.to_string()))?;
+            Ok(CancelAlgoResponse {})
+        }
+        .await;
+        audit(&caller, "cancel_algo", &detail, &result);
+        result.map(Response::new)
+    }
+
+    async fn flatten_position(
+        &self,
+        request: Request<FlattenPositionRequest>,
+    ) -> Result<Response<FlattenPositionResponse>, Status> {
+        let caller = self.authorize(&request, "FlattenPosition")?;
+        let payload = request.into_inner();
+        let detail = format!("symbol={}", payload.symbol);
+        let result: Result<FlattenPositionResponse, Status> = async {
+            let symbol = self.resolve_symbol(&payload.symbol).await?;
+            let client = self.orchestrator.execution_engine().client();
+            let mut cancelled_orders = 0u32;
+            for order in self.oms.open_orders().await {
+                if order.request.symbol != symbol {
+                    continue;
+                }
+                match client.cancel_order(order.id.clone(), symbol).await {
+                    Ok(_) => cancelled_orders += 1,
+                    Err(err) => {
+                        warn!(order_id = %order.id, error = %err, "failed to cancel order")
+                    }
+                }
+            }
+            let order = self
+                .oms
+                .flatten_position(symbol)
+                .await
+                .map_err(|err| Status::failed_precondition(err.to_string()))?;
+            Ok(FlattenPositionResponse {
+                cancelled_orders,
+                order: order.map(OrderSnapshot::from),
+            })
+        }
+        .await;
+        audit(&caller, "flatten_position", &detail, &result);
+        result.map(Response::new)
+    }
+
+    async fn set_liquidate_only(
+        &self,
+        request: Request<SetLiquidateOnlyRequest>,
+    ) -> Result<Response<SetLiquidateOnlyResponse>, Status> {
+        let caller = self.authorize(&request, "SetLiquidateOnly")?;
+        let enabled = request.into_inner().enabled;
+        let changed = self.oms.set_liquidate_only(enabled).await;
+        let result = Ok(SetLiquidateOnlyResponse {
+            liquidate_only: self.oms.status().await.liquidate_only,
+            changed,
+        });
+        audit(
+            &caller,
+            "set_liquidate_only",
+            &format!("enabled={enabled} changed={changed}"),
+            &result,
+        );
+        result.map(Response::new)
+    }
+
+    async fn set_strategy_paused(
+        &self,
+        request: Request<SetStrategyPausedRequest>,
+    ) -> Result<Response<SetStrategyPausedResponse>, Status> {
+        let caller = self.authorize(&request, "SetStrategyPaused")?;
+        let paused = request.into_inner().paused;
+        let changed = self.strategy.set_paused(paused);
+        let result = Ok(SetStrategyPausedResponse { paused, changed });
+        audit(
+            &caller,
+            "set_strategy_paused",
+            &format!("paused={paused} changed={changed}"),
+            &result,
+        );
+        result.map(Response::new)
+    }
+
+    async fn update_risk_limits(
+        &self,
+        request: Request<UpdateRiskLimitsRequest>,
+    ) -> Result<Response<UpdateRiskLimitsResponse>, Status> {
+        let caller = self.authorize(&request, "UpdateRiskLimits")?;
+        let payload = request.into_inner();
+        let current = self.risk.limits();
+        let requested = RiskLimits {
+            max_order_quantity: payload
+                .max_order_quantity
+                .map(from_decimal_proto)
+                .unwrap_or(current.max_order_quantity),
+            max_position_quantity: payload
+                .max_position_quantity
+                .map(from_decimal_proto)
+                .unwrap_or(current.max_position_quantity),
+            max_order_notional: match payload.max_order_notional {
+                Some(value) => Some(from_decimal_proto(value)),
+                None => current.max_order_notional,
+            },
+        };
+        let limits = self.risk.set_limits(requested);
+        let result = Ok(UpdateRiskLimitsResponse {
+            limits: Some(risk_limits_to_proto(limits)),
+        });
+        audit(
+            &caller,
+            "update_risk_limits",
+            &format!(
+                "max_order_quantity={} max_position_quantity={} max_order_notional={}",
+                limits.max_order_quantity,
+                limits.max_position_quantity,
+                limits.max_order_notional.unwrap_or_default()
+            ),
+            &result,
+        );
+        result.map(Response::new)
+    }
+
+    async fn reload_strategy(
+        &self,
+        request: Request<ReloadStrategyRequest>,
+    ) -> Result<Response<ReloadStrategyResponse>, Status> {
+        let caller = self.authorize(&request, "ReloadStrategy")?;
+        let payload = request.into_inner();
+        let inline = !payload.config_toml.trim().is_empty();
+        let result: Result<ReloadStrategyResponse, Status> = async {
+            let change = if inline {
+                let definition = StrategyDefinition::parse(&payload.config_toml)
+                    .map_err(|err| Status::invalid_argument(format!("{err:#}")))?;
+                self.reloader.apply(definition, ReloadSource::Rpc).await
+            } else {
+                self.reloader.reload_file(ReloadSource::Rpc).await
+            }
+            .map_err(|err| Status::failed_precondition(format!("{err:#}")))?;
+            Ok(ReloadStrategyResponse {
+                strategy: change.strategy,
+                params_json: toml_to_json(&change.params).to_string(),
+            })
+        }
+        .await;
+        let detail = match &result {
+            Ok(resp) => format!("inline={inline} params={}", resp.params_json),
+            Err(_) => format!("inline={inline}"),
+        };
+        audit(&caller, "reload_strategy", &detail, &result);
+        result.map(Response::new)
+    }
+
+    async fn swap_strategy(
+        &self,
+        request: Request<SwapStrategyRequest>,
+    ) -> Result<Response<SwapStrategyResponse>, Status> {
+        let caller = self.authorize(&request, "SwapStrategy")?;
+        let payload = request.into_inner();
+        let dry_run = payload.dry_run;
+        let result: Result<SwapStrategyResponse, Status> = async {
+            let definition = if payload.config_toml.trim().is_empty() {
+                self.reloader.load_file()
+            } else {
+                StrategyDefinition::parse(&payload.config_toml)
+            }
+            .map_err(|err| Status::invalid_argument(format!("{err:#}")))?;
+            let swap = self
+                .reloader
+                .swap(definition, dry_run, ReloadSource::Rpc)
+                .await
+                .map_err(|err| Status::failed_precondition(format!("{err:#}")))?;
+            let comparison = swap.comparison;
+            Ok(SwapStrategyResponse {
+                previous_strategy: swap.previous,
+                strategy: swap.strategy,
+                swapped: swap.swapped,
+                comparison: Some(SignalComparison {
+                    candles: comparison.candles as u32,
+                    current_signals: comparison.current_signals as u32,
+                    candidate_signals: comparison.candidate_signals as u32,
+                    matched: comparison.matched as u32,
+                    divergences: comparison.divergences,
+                }),
+            })
+        }
+        .await;
+        let detail = match &result {
+            Ok(resp) => format!(
+                "dry_run={dry_run} previous={} strategy={} swapped={}",
+                resp.previous_strategy, resp.strategy, resp.swapped
+            ),
+            Err(_) => format!("dry_run={dry_run}"),
+        };
+        audit(&caller, "swap_strategy", &detail, &result);
+        result.map(Response::new)
+    }
+
+    async fn list_managed_trades(
+        &self,
+        request: Request<ListManagedTradesRequest>,
+    ) -> Result<Response<ListManagedTradesResponse>, Status> {
+        self.authorize(&request, "ListManagedTrades")?;
+        let snapshots = self
+            .strategy
+            .list_managed_trades()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let mut trades = Vec::with_capacity(snapshots.len());
+        for snapshot in snapshots {
+            trades.push(Self::snapshot_to_proto(snapshot)?);
+        }
+        Ok(Response::new(ListManagedTradesResponse { trades }))
+    }
+
+    async fn update_trade_exit_strategy(
+        &self,
+        request: Request<UpdateTradeExitStrategyRequest>,
+    ) -> Result<Response<UpdateTradeExitStrategyResponse>, Status> {
+        let caller = self.authorize(&request, "UpdateTradeExitStrategy")?;
+        let payload = request.into_inner();
+        let detail = format!("trade_id={}", payload.trade_id);
+        let result: Result<UpdateTradeExitStrategyResponse, Status> = async {
+            let trade_id = Uuid::parse_str(&payload.trade_id)
+                .map_err(|err| Status::invalid_argument(format!("invalid trade_id: {err}")))?;
+            let new_strategy: ExitStrategy = serde_json::from_str(&payload.new_strategy_json)
+                .map_err(|err| {
+                    Status::invalid_argument(format!("invalid exit strategy json: {err}"))
+                })?;
+            self.strategy
+                .update_exit_strategy(trade_id, new_strategy)
+                .await
+                .map_err(|err| Status::internal(err.to_string()))?;
+            Ok(UpdateTradeExitStrategyResponse {
+                success: true,
+                error_message: String::new(),
+            })
+        }
+        .await;
+        audit(&caller, "update_trade_exit_strategy", &detail, &result);
+        result.map(Response::new)
+    }
+
+    async fn list_reconciliation_actions(
+        &self,
+        request: Request<ListReconciliationActionsRequest>,
+    ) -> Result<Response<ListReconciliationActionsResponse>, Status> {
+        self.authorize(&request, "ListReconciliationActions")?;
+        let actions = self
+            .reconciliation
+            .actions()
+            .into_iter()
+            .map(|action| proto::ReconciliationAction {
+                timestamp: Some(timestamp_from_datetime(action.timestamp)),
+                kind: action.kind.as_str().to_string(),
+                target: action.target,
+                detail: action.detail,
+                success: action.success,
+            })
+            .collect();
+        Ok(Response::new(ListReconciliationActionsResponse { actions }))
+    }
+
+    async fn monitor(
+        &self,
+        request: Request<MonitorRequest>,
+    ) -> Result<Response<Self::MonitorStream>, Status> {
+        self.authorize(&request, "Monitor")?;
+        let mut stream = self.event_bus.subscribe();
+        info!("monitor subscriber connected");
+        let (tx, rx) = mpsc::channel(256);
+        tokio::spawn(async move {
+            loop {
+                match stream.recv().await {
+                    Ok(event) => {
+                        let label = event_label(&event);
+                        debug!(kind = label, "monitor captured event");
+                        if let Some(proto) = event_to_proto(event) {
+                            if tx.send(Ok(proto)).await.is_err() {
+                                warn!(kind = label, "monitor stream receiver dropped during send");
+                                break;
+                            } else {
+                                debug!(kind = label, "monitor event forwarded to client");
+                            }
+                        } else {
+                            debug!(kind = label, "monitor event skipped (no proto mapping)");
+                        }
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(lag)) => {
+                        warn!(lag, "monitor stream lagged; dropping events");
+                        continue;
+                    }
+                }
+            }
+        });
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+}
+
+/// Records an operator action on the `audit` tracing target, whether or not it succeeded.
+fn audit<T>(caller: &Caller, action: &'static str, detail: &str, result: &Result<T, Status>) {
+    let peer = caller
+        .peer
+        .map(|addr| addr.to_string())
+        .unwrap_or_else(|| "unknown".into());
+    let principal = caller.principal.name.as_str();
+    let role = caller.principal.role.as_str();
+    match result {
+        Ok(_) => info!(
+            target: "audit",
+            %peer,
+            principal,
+            role,
+            action,
+            detail,
+            "control action succeeded"
+        ),
+        Err(status) => warn!(
+            target: "audit",
+            %peer,
+            principal,
+            role,
+            action,
+            detail,
+            code = ?status.code(),
+            error = %status.message(),
+            "control action failed"
+        ),
+    }
+}
+
+fn risk_limits_to_proto(limits: RiskLimits) -> proto::RiskLimits {
+    proto::RiskLimits {
+        max_order_quantity: Some(to_decimal_proto(limits.max_order_quantity)),
+        max_position_quantity: Some(to_decimal_proto(limits.max_position_quantity)),
+        max_order_notional: Some(to_decimal_proto(
+            limits.max_order_notional.unwrap_or_default(),
+        )),
+    }
+}
+
+fn event_to_proto(event: RuntimeEvent) -> Option<proto::Event> {
+    use tesser_rpc::proto::event::Payload;
+
+    match event {
+        RuntimeEvent::Tick(evt) => Some(proto::Event {
+            payload: Some(Payload::Tick(evt.tick.into())),
+        }),
+        RuntimeEvent::Candle(evt) => Some(proto::Event {
+            payload: Some(Payload::Candle(evt.candle.into())),
+        }),
+        RuntimeEvent::Signal(evt) => Some(proto::Event {
+            payload: Some(Payload::Signal(evt.signal.into())),
+        }),
+        RuntimeEvent::Fill(evt) => Some(proto::Event {
+            payload: Some(Payload::Fill(evt.fill.into())),
+        }),
+        RuntimeEvent::OrderUpdate(evt) => Some(proto::Event {
+            payload: Some(Payload::Order(evt.order.into())),
+        }),
+        RuntimeEvent::StrategyUpdate(evt) => Some(proto::Event {
+            payload: Some(Payload::StrategyUpdate(proto::StrategyUpdate {
+                strategy: evt.strategy,
+                source: evt.source,
+                params_json: evt.params_json,
+                replaced_strategy: evt.replaced.unwrap_or_default(),
+            })),
+        }),
+        RuntimeEvent::OrderBook(book) => {
+            debug!(symbol = %book.order_book.symbol, "monitor dropping order book event");
+            None
+        }
+    }
+}
+
+fn event_label(event: &RuntimeEvent) -> &'static str {
+    match event {
+        RuntimeEvent::Tick(_) => "tick",
+        RuntimeEvent::Candle(_) => "candle",
+        RuntimeEvent::Signal(_) => "signal",
+        RuntimeEvent::Fill(_) => "fill",
+        RuntimeEvent::OrderUpdate(_) => "order",
+        RuntimeEvent::OrderBook(_) => "order_book",
+        RuntimeEvent::StrategyUpdate(_) => "strategy_update",
+    }
+}
+
+fn timestamp_from_datetime(ts: DateTime<Utc>) -> prost_types::Timestamp {
+    prost_types::Timestamp {
+        seconds: ts.timestamp(),
+        nanos: ts.timestamp_subsec_nanos() as i32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tesser_config::{ControlRole, ControlTokenConfig};
+
+    #[test]
+    fn refuses_cleartext_tokens_beyond_loopback() {
+        let config = ControlPlaneConfig {
+            tokens: vec![ControlTokenConfig {
+                name: "ops".into(),
+                token: Some("secret".into()),
+                token_env: None,
+                role: ControlRole::Admin,
+            }],
+            ..ControlPlaneConfig::default()
+        };
+        let public: SocketAddr = "0.0.0.0:50052".parse().unwrap();
+        let local: SocketAddr = "127.0.0.1:50052".parse().unwrap();
+        assert!(check_exposure(public, &config, false).is_err());
+        assert!(check_exposure(public, &config, true).is_ok());
+        assert!(check_exposure(local, &config, false).is_ok());
+        assert!(check_exposure(public, &ControlPlaneConfig::default(), false).is_ok());
+    }
+}
@@ -0,0 +1,176 @@
+//! TLS material for the control-plane server, including self-signed development certificates.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use openssl::asn1::Asn1Time;
+use openssl::bn::{BigNum, MsbOption};
+use openssl::ec::{EcGroup, EcKey};
+use openssl::hash::MessageDigest;
+use openssl::nid::Nid;
+use openssl::pkey::PKey;
+use openssl::x509::extension::{
+    BasicConstraints, ExtendedKeyUsage, KeyUsage, SubjectAlternativeName,
+};
+use openssl::x509::{X509NameBuilder, X509};
+use tesser_config::{ControlPlaneConfig, ControlTlsConfig};
+use tonic::transport::{Certificate, Identity, ServerTlsConfig};
+use tracing::info;
+
+const SELF_SIGNED_CERT: &str = "cert.pem";
+const SELF_SIGNED_KEY: &str = "key.pem";
+const SELF_SIGNED_DAYS: u32 = 365;
+
+/// Build the server TLS configuration, or `None` to serve plaintext.
+pub fn server_tls_config(config: &ControlPlaneConfig) -> Result<Option<ServerTlsConfig>> {
+    let Some(tls) = &config.tls else {
+        return Ok(None);
+    };
+    let (cert_path, key_path) = identity_paths(tls)?;
+    let cert =
+        fs::read(&cert_path).with_context(|| format!("failed to read {}", cert_path.display()))?;
+    let key =
+        fs::read(&key_path).with_context(|| format!("failed to read {}", key_path.display()))?;
+    let mut server = ServerTlsConfig::new().identity(Identity::from_pem(cert, key));
+    if let Some(ca_path) = &tls.client_ca_path {
+        let ca =
+            fs::read(ca_path).with_context(|| format!("failed to read {}", ca_path.display()))?;
+        // Token holders may still connect without a certificate when both schemes are enabled.
+        server = server
+            .client_ca_root(Certificate::from_pem(ca))
+            .client_auth_optional(!config.tokens.is_empty());
+    }
+    Ok(Some(server))
+}
+
+fn identity_paths(tls: &ControlTlsConfig) -> Result<(PathBuf, PathBuf)> {
+    match (&tls.cert_path, &tls.key_path) {
+        (Some(cert), Some(key)) => Ok((cert.clone(), key.clone())),
+        (None, None) if tls.self_signed => ensure_self_signed(&tls.self_signed_dir),
+        (None, None) => {
+            bail!("control_plane.tls needs cert_path and key_path, or self_signed = true")
+        }
+        _ => bail!("control_plane.tls.cert_path and key_path must be set together"),
+    }
+}
+
+/// Reuse the self-signed certificate in `dir`, generating one on first use.
+///
+/// Clients trust it by pointing their CA bundle at `<dir>/cert.pem`.
+pub fn ensure_self_signed(dir: &Path) -> Result<(PathBuf, PathBuf)> {
+    let cert_path = dir.join(SELF_SIGNED_CERT);
+    let key_path = dir.join(SELF_SIGNED_KEY);
+    if cert_path.exists() && key_path.exists() {
+        return Ok((cert_path, key_path));
+    }
+    fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    let (cert, key) = generate_self_signed().context("failed to generate TLS certificate")?;
+    fs::write(&cert_path, cert)
+        .with_context(|| format!("failed to write {}", cert_path.display()))?;
+    write_private(&key_path, &key)?;
+    info!(
+        cert = %cert_path.display(),
+        "generated self-signed control plane certificate"
+    );
+    Ok((cert_path, key_path))
+}
+
+/// PEM certificate and PKCS#8 key for `localhost`, `127.0.0.1` and `::1`.
+fn generate_self_signed() -> Result<(Vec<u8>, Vec<u8>)> {
+    let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1)?;
+    let key = PKey::from_ec_key(EcKey::generate(&group)?)?;
+
+    let mut name = X509NameBuilder::new()?;
+    name.append_entry_by_nid(Nid::COMMONNAME, "tesser-control-plane")?;
+    let name = name.build();
+
+    let mut serial = BigNum::new()?;
+    serial.rand(127, MsbOption::MAYBE_ZERO, false)?;
+    let serial = serial.to_asn1_integer()?;
+    let not_before = Asn1Time::days_from_now(0)?;
+    let not_after = Asn1Time::days_from_now(SELF_SIGNED_DAYS)?;
+
+    let mut builder = X509::builder()?;
+    builder.set_version(2)?;
+    builder.set_serial_number(&serial)?;
+    builder.set_subject_name(&name)?;
+    builder.set_issuer_name(&name)?;
+    builder.set_pubkey(&key)?;
+    builder.set_not_before(&not_before)?;
+    builder.set_not_after(&not_after)?;
+    builder.append_extension(BasicConstraints::new().critical().build()?)?;
+    builder.append_extension(KeyUsage::new().critical().digital_signature().build()?)?;
+    builder.append_extension(ExtendedKeyUsage::new().server_auth().build()?)?;
+    let san = SubjectAlternativeName::new()
+        .dns("localhost")
+        .ip("127.0.0.1")
+        .ip("::1")
+        .build(&builder.x509v3_context(None, None))?;
+    builder.append_extension(san)?;
+    builder.sign(&key, MessageDigest::sha256())?;
+
+    let cert = builder.build().to_pem()?;
+    let key = key.private_key_to_pem_pkcs8()?;
+    Ok((cert, key))
+}
+
+/// Create `path` readable by the owner only, so the key is never briefly world-readable.
+fn write_private(path: &Path, contents: &[u8]) -> Result<()> {
+    // A key left behind by an interrupted run is regenerated together with its certificate.
+    match fs::remove_file(path) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => {
+            return Err(err).with_context(|| format!("failed to replace {}", path.display()))
+        }
+    }
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    file.write_all(contents)
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn self_signed_certificate_is_generated_once() {
+        let dir = tempdir().unwrap();
+        let (cert_path, key_path) = ensure_self_signed(dir.path()).unwrap();
+        let first = fs::read(&cert_path).unwrap();
+        let cert = X509::from_pem(&first).unwrap();
+        let sans = cert.subject_alt_names().expect("subject alt names");
+        assert!(sans.iter().any(|name| name.dnsname() == Some("localhost")));
+        assert!(PKey::private_key_from_pem(&fs::read(&key_path).unwrap()).is_ok());
+
+        ensure_self_signed(dir.path()).unwrap();
+        assert_eq!(fs::read(&cert_path).unwrap(), first);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn private_key_is_created_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        // A stale key without its certificate is replaced rather than reused.
+        fs::write(dir.path().join(SELF_SIGNED_KEY), b"stale").unwrap();
+        let (_, key_path) = ensure_self_signed(dir.path()).unwrap();
+        let mode = fs::metadata(&key_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(PKey::private_key_from_pem(&fs::read(&key_path).unwrap()).is_ok());
+    }
+}
@@ -1,16 +1,72 @@
 use std::collections::HashMap;
 
 use anyhow::{anyhow, bail, Result};
-use chrono::{DateTime, Duration, Utc};
-use rust_decimal::{prelude::ToPrimitive, Decimal};
+use chrono::{DateTime, Utc};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use serde::Serialize;
 use tesser_core::{Candle, Interval, Symbol};
 
+/// Scale factor turning a median absolute deviation into a normal-consistent sigma.
+const MAD_TO_SIGMA: f64 = 1.4826;
+
 /// Configuration flags used when validating a dataset.
 #[derive(Clone, Copy, Debug)]
 pub struct ValidationConfig {
     pub price_jump_threshold: f64,
     pub reference_tolerance: f64,
     pub repair_missing: bool,
+    /// Clip spikes against a rolling median when set.
+    pub spike_filter: Option<SpikeFilter>,
+    /// Overwrite bars that disagree with the reference feed by the reference bar.
+    pub replace_from_reference: bool,
+    /// Widen high/low to cover open/close and zero out negative volume.
+    pub fix_ohlc: bool,
+}
+
+impl ValidationConfig {
+    /// Detection-only configuration; every repair mode is off.
+    pub fn new(price_jump_threshold: f64, reference_tolerance: f64) -> Self {
+        Self {
+            price_jump_threshold,
+            reference_tolerance,
+            repair_missing: false,
+            spike_filter: None,
+            replace_from_reference: false,
+            fix_ohlc: false,
+        }
+    }
+
+    /// Enable every repair mode, using the default spike filter.
+    #[must_use]
+    pub fn with_all_repairs(mut self) -> Self {
+        self.repair_missing = true;
+        self.spike_filter = Some(self.spike_filter.unwrap_or_default());
+        self.replace_from_reference = true;
+        self.fix_ohlc = true;
+        self
+    }
+}
+
+/// Hampel-style filter: a price is a spike when it sits more than `threshold` robust
+/// standard deviations (scaled MAD) away from the median close of the `window` bars on
+/// either side, and also moves by at least `price_jump_threshold` from that median.
+/// The second condition keeps flat stretches (MAD of zero) from flagging tick noise.
+#[derive(Clone, Copy, Debug)]
+pub struct SpikeFilter {
+    pub window: usize,
+    pub threshold: f64,
+}
+
+impl Default for SpikeFilter {
+    fn default() -> Self {
+        Self {
+            window: 10,
+            threshold: 5.0,
+        }
+    }
 }
 
 /// Aggregated statistics describing the validation results.
@@ -26,7 +82,19 @@ pub struct ValidationSummary {
     pub zero_volume_candles: usize,
     pub price_spike_count: usize,
     pub cross_mismatch_count: usize,
+    pub ohlc_violation_count: usize,
+    /// Synthetic candles inserted to fill gaps.
     pub repaired_candles: usize,
+    pub clipped_spikes: usize,
+    pub replaced_from_reference: usize,
+    pub ohlc_fixes: usize,
+}
+
+impl ValidationSummary {
+    /// Total number of bars that were inserted or modified by any repair mode.
+    pub fn total_repairs(&self) -> usize {
+        self.repaired_candles + self.clipped_spikes + self.replaced_from_reference + self.ohlc_fixes
+    }
 }
 
 /// Describes a gap in the time-series.
@@ -53,6 +121,33 @@ pub struct CrossMismatch {
     pub delta_fraction: f64,
 }
 
+/// Describes a bar whose prices or volume are internally inconsistent.
+#[derive(Clone, Debug)]
+pub struct OhlcViolation {
+    pub timestamp: DateTime<Utc>,
+    pub reason: String,
+}
+
+/// Repair applied to a single bar.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepairAction {
+    FillGap,
+    ClipSpike,
+    ReplaceFromReference,
+    FixOhlc,
+}
+
+/// Machine-readable entry of the repair log; `before` is absent for synthesized bars.
+#[derive(Clone, Debug, Serialize)]
+pub struct RepairRecord {
+    pub timestamp: DateTime<Utc>,
+    pub action: RepairAction,
+    pub before: Option<Candle>,
+    pub after: Candle,
+    pub detail: String,
+}
+
 /// Result of a validation run.
 #[derive(Clone, Debug)]
 pub struct ValidationOutcome {
@@ -60,10 +155,24 @@ pub struct ValidationOutcome {
     pub gaps: Vec<GapRecord>,
     pub price_spikes: Vec<SpikeRecord>,
     pub cross_mismatches: Vec<CrossMismatch>,
+    pub ohlc_violations: Vec<OhlcViolation>,
     pub repaired: Vec<Candle>,
+    pub repairs: Vec<RepairRecord>,
 }
 
-/// Validate a group of candles, optionally repairing missing entries.
+impl ValidationOutcome {
+    /// Repair log entries produced by `action`.
+    pub fn repairs_of(&self, action: RepairAction) -> impl Iterator<Item = &RepairRecord> + '_ {
+        self.repairs
+            .iter()
+            .filter(move |record| record.action == action)
+    }
+}
+
+/// Validate a group of candles and apply the repair modes enabled in `config`.
+///
+/// Repairs run in a fixed order: reference replacement, spike clipping, OHLC fixes and
+/// finally gap filling, so synthetic bars carry forward already-corrected closes.
 pub fn validate_dataset(
     mut candles: Vec<Candle>,
     reference: Option<Vec<Candle>>,
@@ -76,8 +185,6 @@ pub fn validate_dataset(
 
     let symbol = ensure_single_symbol(&candles)?;
     let interval = candles.first().expect("checked len").interval;
-    let interval_duration = interval.as_duration();
-    let expected_ms = interval_duration.num_milliseconds().max(1); // guard against zero-duration intervals
 
     let mut gaps = Vec::new();
     let mut price_spikes = Vec::new();
@@ -85,6 +192,15 @@ pub fn validate_dataset(
     let mut duplicate_candles = 0usize;
     let mut missing_candles = 0usize;
     let mut zero_volume_candles = 0usize;
+    let ohlc_violations: Vec<OhlcViolation> = candles
+        .iter()
+        .filter_map(|candle| {
+            ohlc_violation(candle).map(|reason| OhlcViolation {
+                timestamp: candle.timestamp,
+                reason,
+            })
+        })
+        .collect();
 
     let mut prev_close: Option<f64> = None;
     let mut prev_timestamp: Option<DateTime<Utc>> = None;
@@ -95,20 +211,18 @@ pub fn validate_dataset(
         }
         let close = candle.close.to_f64().unwrap_or(0.0);
         if let (Some(last_close), Some(last_ts)) = (prev_close, prev_timestamp) {
-            let delta: Duration = candle.timestamp - last_ts;
-            let delta_ms = delta.num_milliseconds();
-            if delta_ms < expected_ms {
+            // Period counting is calendar-aware, so monthly bars of uneven length line up.
+            let periods = interval.periods_between(last_ts, candle.timestamp);
+            if periods < 1 {
                 duplicate_candles += 1;
-            } else if delta_ms > expected_ms {
-                let missing = ((delta_ms / expected_ms) as usize).saturating_sub(1);
-                if missing > 0 {
-                    missing_candles += missing;
-                    gaps.push(GapRecord {
-                        start: last_ts,
-                        end: candle.timestamp,
-                        missing,
-                    });
-                }
+            } else if periods > 1 {
+                let missing = (periods - 1) as usize;
+                missing_candles += missing;
+                gaps.push(GapRecord {
+                    start: last_ts,
+                    end: candle.timestamp,
+                    missing,
+                });
             }
 
             let denom = last_close.abs().max(f64::EPSILON);
@@ -125,16 +239,16 @@ pub fn validate_dataset(
         prev_timestamp = Some(candle.timestamp);
     }
 
+    let mut reference_map: HashMap<i64, &Candle> = HashMap::new();
     if let Some(reference_data) = reference.as_ref() {
-        let mut map = HashMap::with_capacity(reference_data.len());
+        reference_map.reserve(reference_data.len());
         for candle in reference_data {
-            map.insert(
-                candle.timestamp.timestamp_millis(),
-                candle.close.to_f64().unwrap_or(0.0),
-            );
+            reference_map.insert(candle.timestamp.timestamp_millis(), candle);
         }
         for candle in &candles {
-            if let Some(reference_close) = map.get(&candle.timestamp.timestamp_millis()) {
+            if let Some(reference_candle) = reference_map.get(&candle.timestamp.timestamp_millis())
+            {
+                let reference_close = reference_candle.close.to_f64().unwrap_or(0.0);
                 let close = candle.close.to_f64().unwrap_or(0.0);
                 let denom = reference_close.abs().max(f64::EPSILON);
                 let diff = (close - reference_close).abs() / denom;
@@ -142,7 +256,7 @@ pub fn validate_dataset(
                     cross_mismatches.push(CrossMismatch {
                         timestamp: candle.timestamp,
                         primary_close: close,
-                        reference_close: *reference_close,
+                        reference_close,
                         delta_fraction: diff,
                     });
                 }
@@ -151,39 +265,104 @@ pub fn validate_dataset(
     }
 
     let mut repaired = candles.clone();
+    let mut repairs = Vec::new();
+    if config.replace_from_reference {
+        for mismatch in &cross_mismatches {
+            let key = mismatch.timestamp.timestamp_millis();
+            let Some(reference_candle) = reference_map.get(&key) else {
+                continue;
+            };
+            for candle in repaired
+                .iter_mut()
+                .filter(|c| c.timestamp == mismatch.timestamp)
+            {
+                let before = candle.clone();
+                *candle = Candle {
+                    symbol: before.symbol,
+                    interval: before.interval,
+                    ..(*reference_candle).clone()
+                };
+                repairs.push(RepairRecord {
+                    timestamp: candle.timestamp,
+                    action: RepairAction::ReplaceFromReference,
+                    before: Some(before),
+                    after: candle.clone(),
+                    detail: format!(
+                        "close diverged {:.4}% from reference",
+                        mismatch.delta_fraction * 100.0
+                    ),
+                });
+            }
+        }
+    }
+    let replaced_from_reference = repairs.len();
+
+    let mut clipped_spikes = 0usize;
+    if let Some(filter) = config.spike_filter {
+        let clipped = clip_spikes(&mut repaired, filter, config.price_jump_threshold);
+        clipped_spikes = clipped.len();
+        repairs.extend(clipped);
+    }
+
+    let mut ohlc_fixes = 0usize;
+    if config.fix_ohlc {
+        for candle in repaired.iter_mut() {
+            let Some(reason) = ohlc_violation(candle) else {
+                continue;
+            };
+            let before = candle.clone();
+            fix_ohlc(candle);
+            ohlc_fixes += 1;
+            repairs.push(RepairRecord {
+                timestamp: candle.timestamp,
+                action: RepairAction::FixOhlc,
+                before: Some(before),
+                after: candle.clone(),
+                detail: reason,
+            });
+        }
+    }
+
     let mut repaired_candles = 0usize;
     if config.repair_missing {
         let mut idx = 0usize;
         while idx + 1 < repaired.len() {
             let current_ts = repaired[idx].timestamp;
             let next_ts = repaired[idx + 1].timestamp;
-            let delta: Duration = next_ts - current_ts;
-            let delta_ms = delta.num_milliseconds();
-            if delta_ms > expected_ms {
-                let missing = ((delta_ms / expected_ms) as usize).saturating_sub(1);
-                if missing > 0 {
-                    for step in 1..=missing {
-                        let ts = current_ts + Duration::milliseconds(expected_ms * step as i64);
-                        let fill_price = repaired[idx].close;
-                        let fill = Candle {
-                            symbol: repaired[idx].symbol,
-                            interval,
-                            open: fill_price,
-                            high: fill_price,
-                            low: fill_price,
-                            close: fill_price,
-                            volume: Decimal::ZERO,
-                            timestamp: ts,
-                        };
-                        repaired.insert(idx + step, fill);
-                        repaired_candles += 1;
-                    }
-                    idx += missing;
+            let periods = interval.periods_between(current_ts, next_ts);
+            if periods > 1 {
+                let missing = (periods - 1) as usize;
+                for step in 1..=missing {
+                    let ts = interval
+                        .offset(current_ts, step as i64)
+                        .map_err(|err| anyhow!(err))?;
+                    let fill_price = repaired[idx].close;
+                    let fill = Candle {
+                        symbol: repaired[idx].symbol,
+                        interval,
+                        open: fill_price,
+                        high: fill_price,
+                        low: fill_price,
+                        close: fill_price,
+                        volume: Decimal::ZERO,
+                        timestamp: ts,
+                    };
+                    repairs.push(RepairRecord {
+                        timestamp: ts,
+                        action: RepairAction::FillGap,
+                        before: None,
+                        after: fill.clone(),
+                        detail: format!("carried forward close {}", fill_price),
+                    });
+                    repaired.insert(idx + step, fill);
+                    repaired_candles += 1;
                 }
+                idx += missing;
             }
             idx += 1;
         }
     }
+    repairs.sort_by_key(|record| record.timestamp);
 
     let summary = ValidationSummary {
         symbol,
@@ -196,7 +375,11 @@ pub fn validate_dataset(
         zero_volume_candles,
         price_spike_count: price_spikes.len(),
         cross_mismatch_count: cross_mismatches.len(),
+        ohlc_violation_count: ohlc_violations.len(),
         repaired_candles,
+        clipped_spikes,
+        replaced_from_reference,
+        ohlc_fixes,
     };
 
     Ok(ValidationOutcome {
@@ -204,10 +387,116 @@ pub fn validate_dataset(
         gaps,
         price_spikes,
         cross_mismatches,
+        ohlc_violations,
         repaired,
+        repairs,
     })
 }
 
+fn ohlc_violation(candle: &Candle) -> Option<String> {
+    let mut problems = Vec::new();
+    if candle.high < candle.low {
+        problems.push("high below low");
+    }
+    if candle.high < candle.open.max(candle.close) {
+        problems.push("high below open/close");
+    }
+    if candle.low > candle.open.min(candle.close) {
+        problems.push("low above open/close");
+    }
+    if candle.volume < Decimal::ZERO {
+        problems.push("negative volume");
+    }
+    if problems.is_empty() {
+        None
+    } else {
+        Some(problems.join(", "))
+    }
+}
+
+fn fix_ohlc(candle: &mut Candle) {
+    let high = candle
+        .high
+        .max(candle.low)
+        .max(candle.open)
+        .max(candle.close);
+    let low = candle
+        .low
+        .min(candle.high)
+        .min(candle.open)
+        .min(candle.close);
+    candle.high = high;
+    candle.low = low;
+    candle.volume = candle.volume.max(Decimal::ZERO);
+}
+
+/// Clip every price outside the robust band around the rolling median close. Medians are
+/// taken from the unclipped closes so a repaired bar never drags its neighbours' bands.
+fn clip_spikes(
+    candles: &mut [Candle],
+    filter: SpikeFilter,
+    min_change_fraction: f64,
+) -> Vec<RepairRecord> {
+    let window = filter.window.max(1);
+    let closes: Vec<f64> = candles
+        .iter()
+        .map(|candle| candle.close.to_f64().unwrap_or(0.0))
+        .collect();
+    let mut records = Vec::new();
+    for idx in 0..candles.len() {
+        let lo = idx.saturating_sub(window);
+        let hi = (idx + window + 1).min(closes.len());
+        let mut neighbours: Vec<f64> = closes[lo..idx]
+            .iter()
+            .chain(&closes[idx + 1..hi])
+            .copied()
+            .collect();
+        if neighbours.len() < 2 {
+            continue;
+        }
+        let center = median(&mut neighbours);
+        let mut deviations: Vec<f64> = neighbours.iter().map(|v| (v - center).abs()).collect();
+        let sigma = MAD_TO_SIGMA * median(&mut deviations);
+        let band = (filter.threshold * sigma).max(min_change_fraction * center.abs());
+        let (Some(floor), Some(ceiling)) = (
+            Decimal::from_f64(center - band),
+            Decimal::from_f64(center + band),
+        ) else {
+            continue;
+        };
+        let candle = &mut candles[idx];
+        let outside = [candle.open, candle.high, candle.low, candle.close]
+            .iter()
+            .any(|price| *price < floor || *price > ceiling);
+        if !outside {
+            continue;
+        }
+        let before = candle.clone();
+        candle.open = candle.open.clamp(floor, ceiling);
+        candle.high = candle.high.clamp(floor, ceiling);
+        candle.low = candle.low.clamp(floor, ceiling);
+        candle.close = candle.close.clamp(floor, ceiling);
+        records.push(RepairRecord {
+            timestamp: candle.timestamp,
+            action: RepairAction::ClipSpike,
+            before: Some(before),
+            after: candle.clone(),
+            detail: format!("clipped to rolling median {center:.8} +/- {band:.8}"),
+        });
+    }
+    records
+}
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
 fn ensure_single_symbol(candles: &[Candle]) -> Result<Symbol> {
     let mut iter = candles.iter();
     let first = iter
@@ -223,6 +512,7 @@ fn ensure_single_symbol(candles: &[Candle]) -> Result<Symbol> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Duration;
     use rust_decimal::prelude::FromPrimitive;
     use rust_decimal::Decimal;
 
@@ -245,9 +535,8 @@ mod tests {
     fn detects_gaps_and_repairs() {
         let candles = vec![candle_at(0, 100.0, 10.0), candle_at(2, 101.0, 11.0)];
         let cfg = ValidationConfig {
-            price_jump_threshold: 0.05,
-            reference_tolerance: 0.01,
             repair_missing: true,
+            ..ValidationConfig::new(0.05, 0.01)
         };
         let outcome = validate_dataset(candles, None, cfg).expect("ok");
         assert_eq!(outcome.summary.missing_candles, 1);
@@ -262,11 +551,7 @@ mod tests {
             candle_at(1, 150.0, 0.0),
             candle_at(2, 160.0, 12.0),
         ];
-        let cfg = ValidationConfig {
-            price_jump_threshold: 0.2,
-            reference_tolerance: 0.01,
-            repair_missing: false,
-        };
+        let cfg = ValidationConfig::new(0.2, 0.01);
         let outcome = validate_dataset(candles, None, cfg).expect("ok");
         assert_eq!(outcome.summary.zero_volume_candles, 1);
         assert_eq!(outcome.summary.price_spike_count, 1);
@@ -276,12 +561,69 @@ mod tests {
     fn detects_cross_source_mismatches() {
         let primary = vec![candle_at(0, 100.0, 1.0), candle_at(1, 102.0, 1.0)];
         let reference = vec![candle_at(0, 100.0, 1.0), candle_at(1, 100.0, 1.0)];
+        let cfg = ValidationConfig::new(0.2, 0.01);
+        let outcome = validate_dataset(primary, Some(reference), cfg).expect("ok");
+        assert_eq!(outcome.summary.cross_mismatch_count, 1);
+    }
+
+    fn bar(minute: i64, open: f64, high: f64, low: f64, close: f64) -> Candle {
+        Candle {
+            open: Decimal::from_f64(open).unwrap(),
+            high: Decimal::from_f64(high).unwrap(),
+            low: Decimal::from_f64(low).unwrap(),
+            ..candle_at(minute, close, 1.0)
+        }
+    }
+
+    #[test]
+    fn clips_spikes_against_rolling_median() {
+        let mut candles: Vec<Candle> = (0..21)
+            .map(|idx| candle_at(idx, 100.0 + (idx % 3) as f64 * 0.1, 1.0))
+            .collect();
+        candles[10] = Candle {
+            timestamp: candles[10].timestamp,
+            ..bar(10, 100.0, 180.0, 99.9, 175.0)
+        };
         let cfg = ValidationConfig {
-            price_jump_threshold: 0.2,
-            reference_tolerance: 0.01,
-            repair_missing: false,
+            spike_filter: Some(SpikeFilter::default()),
+            ..ValidationConfig::new(0.05, 0.01)
+        };
+        let outcome = validate_dataset(candles, None, cfg).expect("ok");
+        assert_eq!(outcome.summary.clipped_spikes, 1);
+        let fixed = &outcome.repaired[10];
+        assert!(fixed.close < Decimal::from(106));
+        assert!(fixed.high < Decimal::from(106));
+        let record = outcome.repairs_of(RepairAction::ClipSpike).next().unwrap();
+        assert_eq!(record.before.as_ref().unwrap().close, Decimal::from(175));
+    }
+
+    #[test]
+    fn replaces_mismatched_bars_and_fixes_ohlc() {
+        let primary = vec![
+            bar(0, 100.0, 99.0, 98.0, 100.5),
+            candle_at(1, 102.0, 1.0),
+            candle_at(2, 100.0, 1.0),
+        ];
+        let mut reference = primary.clone();
+        reference[1] = Candle {
+            timestamp: primary[1].timestamp,
+            volume: Decimal::from(7),
+            ..candle_at(1, 100.0, 1.0)
+        };
+        let cfg = ValidationConfig {
+            replace_from_reference: true,
+            fix_ohlc: true,
+            ..ValidationConfig::new(0.2, 0.01)
         };
         let outcome = validate_dataset(primary, Some(reference), cfg).expect("ok");
-        assert_eq!(outcome.summary.cross_mismatch_count, 1);
+        assert_eq!(outcome.summary.ohlc_violation_count, 1);
+        assert_eq!(outcome.summary.replaced_from_reference, 1);
+        assert_eq!(outcome.summary.total_repairs(), 2);
+        assert_eq!(outcome.repaired[0].high, Decimal::from_f64(100.5).unwrap());
+        assert_eq!(outcome.repaired[0].low, Decimal::from(98));
+        assert_eq!(outcome.repaired[1].close, Decimal::from(100));
+        assert_eq!(outcome.repaired[1].volume, Decimal::from(7));
+        let log = serde_json::to_value(&outcome.repairs).expect("serializable");
+        assert_eq!(log[1]["action"], "replace_from_reference");
     }
 }
@@ -4,9 +4,12 @@ pub mod app;
 pub mod control;
 pub mod data_validation;
 pub mod live;
+pub mod record;
+pub mod reload;
 pub mod state;
 pub mod telemetry;
 pub mod tui;
+pub mod warmup;
 
 pub use app::run as run_app;
 
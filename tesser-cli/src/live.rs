@@ -1,5 +1,5 @@
 use std::any::Any;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::{
@@ -22,7 +22,7 @@ use uuid::Uuid;
 
 use serde_json::{json, Value};
 
-fn ensure_builtin_connectors_registered() {
+pub(crate) fn ensure_builtin_connectors_registered() {
     static INIT: Once = Once::new();
     INIT.call_once(|| {
         register_connector_factory(Arc::new(PaperFactory::default()));
@@ -47,10 +47,14 @@ use tesser_broker::{
 use tesser_bybit::ws::{BybitWsExecution, BybitWsOrder, PrivateMessage};
 #[cfg(feature = "bybit")]
 use tesser_bybit::{register_factory as register_bybit_factory, BybitClient, BybitCredentials};
-use tesser_config::{AlertingConfig, ExchangeConfig, PersistenceEngine, RiskManagementConfig};
+use tesser_config::{
+    AlertSeverity, AlertingConfig, ControlPlaneConfig, ExchangeConfig, PersistenceEngine,
+    ReconciliationPolicyConfig, RiskManagementConfig,
+};
 use tesser_core::{
-    AccountBalance, AssetId, Candle, ExchangeId, ExitStrategy, Fill, Interval, Order, OrderBook,
-    OrderStatus, Position, Price, Quantity, Side, Signal, SignalKind, Symbol, Tick,
+    AccountBalance, AssetId, Candle, ExchangeId, ExitStrategy, Fill, InstrumentKind, Interval,
+    MarketByOrderUpdate, Order, OrderBook, OrderId, OrderRequest, OrderStatus, OrderType, Position,
+    Price, Quantity, Side, Signal, SignalKind, Symbol, Tick,
 };
 use tesser_data::recorder::{ParquetRecorder, RecorderConfig, RecorderHandle};
 use tesser_events::{
@@ -65,7 +69,7 @@ use tesser_execution::{
 use tesser_journal::LmdbJournal;
 use tesser_ledger::{
     entries_from_fill, FillLedgerContext, LedgerEntry, LedgerRepository, LedgerSequencer,
-    SqliteLedgerRepository,
+    LedgerType, SqliteLedgerRepository,
 };
 use tesser_markets::{InstrumentCatalog, MarketRegistry};
 use tesser_paper::{FeeScheduleConfig, PaperExecutionClient, PaperFactory};
@@ -74,9 +78,15 @@ use tesser_portfolio::{
     SqliteStateRepository, StateRepository,
 };
 use tesser_strategy::{
-    PairTradeSnapshot, PairsTradingArbitrage, Strategy, StrategyContext, StrategyError,
-    StrategyResult,
+    load_strategy, PairTradeSnapshot, PairsTradingArbitrage, Strategy, StrategyContext,
+    StrategyError, StrategyResult,
+};
+
+use crate::reload::{
+    replay_candidate, SignalComparison, SignalRecord, StrategyDefinition, StrategyReloadSettings,
+    StrategyReloader, StrategySwap,
 };
+use crate::warmup::{load_warmup_candles, replay_context, replay_warmup, WarmupSettings};
 
 use crate::alerts::{AlertDispatcher, AlertManager};
 use crate::control;
@@ -160,9 +170,22 @@ enum MarketEvent {
 #[derive(Clone)]
 pub struct StrategyHandle {
     tx: mpsc::Sender<StrategyCommand>,
+    paused: Arc<AtomicBool>,
 }
 
 impl StrategyHandle {
+    /// Whether signals raised by the strategy are currently being discarded.
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Pause or resume signal routing, returning `true` when the state changed.
+    ///
+    /// A paused strategy keeps consuming market data so its indicators stay current.
+    pub fn set_paused(&self, paused: bool) -> bool {
+        self.paused.swap(paused, Ordering::SeqCst) != paused
+    }
+
     async fn send_account_event(&self, event: StrategyAccountEvent) {
         let _ = self.tx.send(StrategyCommand::Account(event)).await;
     }
@@ -190,6 +213,41 @@ impl StrategyHandle {
             .unwrap_or_else(|_| Err(anyhow!("strategy channel closed")))
     }
 
+    /// Validate `definition` and apply its parameters to the running strategy, returning the
+    /// strategy's name.
+    pub async fn reconfigure(&self, definition: StrategyDefinition) -> anyhow::Result<String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(StrategyCommand::Request(StrategyRequest::Configure {
+                definition,
+                respond_to: tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(anyhow!("strategy channel closed")))
+    }
+
+    /// Replace the running strategy with a fresh instance built from `definition`, or only
+    /// compare their signals when `dry_run` is set.
+    pub async fn swap(
+        &self,
+        definition: StrategyDefinition,
+        dry_run: bool,
+    ) -> anyhow::Result<StrategySwap> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(StrategyCommand::Request(StrategyRequest::Swap {
+                definition,
+                dry_run,
+                respond_to: tx,
+            }))
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(anyhow!("strategy channel closed")))
+    }
+
     pub async fn update_exit_strategy(
         &self,
         trade_id: Uuid,
@@ -235,6 +293,15 @@ enum StrategyRequest {
         exit: ExitStrategy,
         respond_to: oneshot::Sender<anyhow::Result<()>>,
     },
+    Configure {
+        definition: StrategyDefinition,
+        respond_to: oneshot::Sender<anyhow::Result<String>>,
+    },
+    Swap {
+        definition: StrategyDefinition,
+        dry_run: bool,
+        respond_to: oneshot::Sender<anyhow::Result<StrategySwap>>,
+    },
 }
 
 #[derive(Clone)]
@@ -277,13 +344,125 @@ impl OmsHandle {
     }
 
     pub async fn enter_liquidate_only(&self) -> bool {
+        self.set_liquidate_only(true).await
+    }
+
+    /// Enable or disable liquidate-only mode, returning `true` when the mode changed.
+    pub async fn set_liquidate_only(&self, enabled: bool) -> bool {
         let (tx, rx) = oneshot::channel();
         let _ = self
             .tx
-            .send(OmsRequest::EnterLiquidateOnly { respond_to: tx })
+            .send(OmsRequest::SetLiquidateOnly {
+                enabled,
+                respond_to: tx,
+            })
             .await;
         rx.await.unwrap_or(false)
     }
+
+    /// Resolve `EXCHANGE:CODE` or a bare, unambiguous instrument code to a known symbol.
+    pub async fn resolve_symbol(&self, code: &str) -> Result<Symbol> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(OmsRequest::ResolveSymbol {
+                code: code.to_string(),
+                respond_to: tx,
+            })
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(anyhow!("oms channel closed")))
+    }
+
+    /// Submit a manual order through the pre-trade risk checks.
+    pub async fn place_order(&self, request: OrderRequest) -> Result<Order> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(OmsRequest::PlaceOrder {
+                request,
+                respond_to: tx,
+            })
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(anyhow!("oms channel closed")))
+    }
+
+    /// Close the local position in `symbol` with a market order; `None` when already flat.
+    pub async fn flatten_position(&self, symbol: Symbol) -> Result<Option<Order>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(OmsRequest::FlattenPosition {
+                symbol,
+                respond_to: tx,
+            })
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(anyhow!("oms channel closed")))
+    }
+
+    /// Replace the local position for `symbol` with the exchange view (`None` when flat).
+    pub(crate) async fn adopt_position(
+        &self,
+        symbol: Symbol,
+        position: Option<Position>,
+    ) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(OmsRequest::AdoptPosition {
+                symbol,
+                position,
+                respond_to: tx,
+            })
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(anyhow!("oms channel closed")))
+    }
+
+    /// Book an adjustment ledger entry moving `asset` cash by `delta`.
+    pub(crate) async fn adjust_balance(
+        &self,
+        exchange: ExchangeId,
+        asset: AssetId,
+        delta: Decimal,
+    ) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(OmsRequest::AdjustBalance {
+                exchange,
+                asset,
+                delta,
+                respond_to: tx,
+            })
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(anyhow!("oms channel closed")))
+    }
+
+    /// Start tracking exchange orders that were re-bound to their algorithms.
+    pub(crate) async fn track_orders(&self, orders: Vec<Order>) {
+        if orders.is_empty() {
+            return;
+        }
+        let _ = self.tx.send(OmsRequest::TrackOrders { orders }).await;
+    }
+
+    /// Append an informational entry (such as a parameter change) to the ledger.
+    pub(crate) async fn record_ledger_entry(&self, entry: LedgerEntry) -> Result<()> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(OmsRequest::RecordLedgerEntry {
+                entry,
+                respond_to: tx,
+            })
+            .await;
+        rx.await
+            .unwrap_or_else(|_| Err(anyhow!("oms channel closed")))
+    }
 }
 
 #[derive(Default, Clone)]
@@ -311,9 +490,40 @@ enum OmsRequest {
     PortfolioSummary {
         respond_to: oneshot::Sender<PortfolioSummary>,
     },
-    EnterLiquidateOnly {
+    SetLiquidateOnly {
+        enabled: bool,
         respond_to: oneshot::Sender<bool>,
     },
+    ResolveSymbol {
+        code: String,
+        respond_to: oneshot::Sender<Result<Symbol>>,
+    },
+    PlaceOrder {
+        request: OrderRequest,
+        respond_to: oneshot::Sender<Result<Order>>,
+    },
+    FlattenPosition {
+        symbol: Symbol,
+        respond_to: oneshot::Sender<Result<Option<Order>>>,
+    },
+    AdoptPosition {
+        symbol: Symbol,
+        position: Option<Position>,
+        respond_to: oneshot::Sender<Result<()>>,
+    },
+    AdjustBalance {
+        exchange: ExchangeId,
+        asset: AssetId,
+        delta: Decimal,
+        respond_to: oneshot::Sender<Result<()>>,
+    },
+    TrackOrders {
+        orders: Vec<Order>,
+    },
+    RecordLedgerEntry {
+        entry: LedgerEntry,
+        respond_to: oneshot::Sender<Result<()>>,
+    },
 }
 
 #[derive(Clone)]
@@ -357,7 +567,9 @@ impl PanicObserver for PanicAlertHook {
         let title = "Execution group panic close";
         let message = format!("Group {group_id} panic-closed {symbol} qty={quantity}: {reason}");
         tokio::spawn(async move {
-            alerts.notify(title, &message).await;
+            alerts
+                .notify(AlertSeverity::Critical, title, &message)
+                .await;
         });
     }
 }
@@ -401,12 +613,17 @@ const MARKET_EVENT_TIMEOUT: Duration = Duration::from_millis(10);
 const EXECUTION_GAP_THRESHOLD_SECS: i64 = 120;
 const EXECUTION_BACKFILL_INTERVAL_SECS: u64 = 60;
 const EXECUTION_FALLBACK_LOOKBACK_MINS: i64 = 30;
+/// Reconciliation repairs retained for the control plane.
+const MAX_RECONCILIATION_ACTIONS: usize = 256;
+/// Exchange orders younger than this may still be in flight to the OMS and are never orphans.
+const ORPHAN_ORDER_GRACE: chrono::Duration = chrono::Duration::seconds(30);
 
 #[async_trait::async_trait]
 trait LiveMarketStream: Send {
     async fn next_tick(&mut self) -> BrokerResult<Option<Tick>>;
     async fn next_candle(&mut self) -> BrokerResult<Option<Candle>>;
     async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>>;
+    async fn next_market_by_order(&mut self) -> BrokerResult<Option<MarketByOrderUpdate>>;
 }
 
 struct FactoryStreamAdapter {
@@ -432,12 +649,17 @@ impl LiveMarketStream for FactoryStreamAdapter {
     async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>> {
         self.inner.next_order_book().await
     }
+
+    async fn next_market_by_order(&mut self) -> BrokerResult<Option<MarketByOrderUpdate>> {
+        self.inner.next_market_by_order().await
+    }
 }
 
 struct RouterMarketStream {
     tick_rx: mpsc::Receiver<Tick>,
     candle_rx: mpsc::Receiver<Candle>,
     book_rx: mpsc::Receiver<OrderBook>,
+    mbo_rx: mpsc::Receiver<MarketByOrderUpdate>,
     tasks: Vec<JoinHandle<()>>,
 }
 
@@ -446,11 +668,13 @@ impl RouterMarketStream {
         let (tick_tx, tick_rx) = mpsc::channel(512);
         let (candle_tx, candle_rx) = mpsc::channel(512);
         let (book_tx, book_rx) = mpsc::channel(512);
+        let (mbo_tx, mbo_rx) = mpsc::channel(512);
         let mut tasks = Vec::new();
         for (name, mut stream) in streams {
             let tick_tx = tick_tx.clone();
             let candle_tx = candle_tx.clone();
             let book_tx = book_tx.clone();
+            let mbo_tx = mbo_tx.clone();
             let shutdown = shutdown.clone();
             tasks.push(tokio::spawn(async move {
                 loop {
@@ -513,6 +737,24 @@ impl RouterMarketStream {
                         }
                     }
 
+                    let update = tokio::select! {
+                        res = stream.next_market_by_order() => res,
+                        _ = shutdown.wait() => break,
+                    };
+                    match update {
+                        Ok(Some(event)) => {
+                            emitted = true;
+                            if mbo_tx.send(event).await.is_err() {
+                                break;
+                            }
+                        }
+                        Ok(None) => {}
+                        Err(err) => {
+                            warn!(exchange = %name, error = %err, "market stream market-by-order failed");
+                            break;
+                        }
+                    }
+
                     if !emitted && !shutdown.sleep(Duration::from_millis(5)).await {
                         break;
                     }
@@ -523,6 +765,7 @@ impl RouterMarketStream {
             tick_rx,
             candle_rx,
             book_rx,
+            mbo_rx,
             tasks,
         }
     }
@@ -541,6 +784,10 @@ impl LiveMarketStream for RouterMarketStream {
     async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>> {
         Ok(self.book_rx.recv().await)
     }
+
+    async fn next_market_by_order(&mut self) -> BrokerResult<Option<MarketByOrderUpdate>> {
+        Ok(self.mbo_rx.recv().await)
+    }
 }
 
 impl Drop for RouterMarketStream {
@@ -628,11 +875,15 @@ pub struct LiveSessionSettings {
     pub risk: RiskManagementConfig,
     pub reconciliation_interval: Duration,
     pub reconciliation_threshold: Decimal,
+    pub reconciliation_policy: ReconciliationPolicyConfig,
     pub orderbook_depth: usize,
     pub record_path: Option<PathBuf>,
     pub control_addr: SocketAddr,
+    pub control_plane: ControlPlaneConfig,
     pub panic_close: PanicCloseConfig,
     pub plugins_dir: Option<PathBuf>,
+    pub warmup: WarmupSettings,
+    pub strategy_reload: StrategyReloadSettings,
 }
 
 impl LiveSessionSettings {
@@ -765,14 +1016,13 @@ pub async fn run_live_with_shutdown(
     if matches!(settings.exec_backend, ExecutionBackend::Live) {
         info!(drivers = %driver_label, "live execution enabled");
     }
-    let risk_checker: Arc<dyn PreTradeRiskChecker> =
-        Arc::new(BasicRiskChecker::new(settings.risk_limits()));
+    let risk_checker = Arc::new(BasicRiskChecker::new(settings.risk_limits()));
     let execution = ExecutionEngine::new(
         execution_client.clone(),
         Box::new(FixedOrderSizer {
             quantity: settings.quantity,
         }),
-        risk_checker,
+        risk_checker.clone() as Arc<dyn PreTradeRiskChecker>,
     );
 
     let mut bootstrap = None;
@@ -805,7 +1055,8 @@ pub async fn run_live_with_shutdown(
 
     let metrics = Arc::new(LiveMetrics::new());
     let alerting_cfg = settings.alerting.clone();
-    let dispatcher = AlertDispatcher::new(alerting_cfg.webhook_url.clone());
+    let dispatcher = AlertDispatcher::from_config(&alerting_cfg)
+        .context("invalid [live.alerting] configuration")?;
     let alerts = Arc::new(AlertManager::new(
         alerting_cfg,
         dispatcher,
@@ -837,6 +1088,27 @@ pub async fn run_live_with_shutdown(
     )
     .await?;
 
+    let warmup_count = settings.warmup.candle_count(strategy.warmup_candles());
+    let warmup = if warmup_count > 0 {
+        info!(
+            candles = warmup_count,
+            source = ?settings.warmup.source,
+            "loading strategy warm-up history"
+        );
+        load_warmup_candles(
+            &settings.warmup,
+            warmup_count,
+            &symbols,
+            &exchanges,
+            settings.category.as_path(),
+            settings.interval,
+        )
+        .await
+        .context("failed to load warm-up history")?
+    } else {
+        Vec::new()
+    };
+
     let runtime = LiveRuntime::new(
         market_stream,
         strategy,
@@ -844,6 +1116,7 @@ pub async fn run_live_with_shutdown(
         routes,
         router.clone(),
         orchestrator,
+        risk_checker,
         persistence.state.clone(),
         persistence.ledger.clone(),
         persistence.ledger_seq.clone(),
@@ -855,6 +1128,7 @@ pub async fn run_live_with_shutdown(
         public_connection,
         private_connection,
         bootstrap,
+        warmup,
     )
     .await?;
     runtime.run().await
@@ -873,7 +1147,12 @@ async fn build_exchange_routes(
     let mut routes = Vec::new();
 
     for exchange in exchanges {
-        let payload = build_exchange_payload(&exchange.config, settings, &exchange.name);
+        let payload = build_exchange_payload(
+            &exchange.config,
+            settings.category.as_path(),
+            settings.orderbook_depth,
+            &exchange.name,
+        );
         let driver = exchange.config.driver.clone();
         let factory = get_connector_factory(&driver)
             .ok_or_else(|| anyhow!("driver {} is not registered", driver))?;
@@ -982,6 +1261,7 @@ struct LiveRuntime {
     metrics_task: JoinHandle<()>,
     alert_task: Option<JoinHandle<()>>,
     reconciliation_task: Option<JoinHandle<()>>,
+    strategy_watch_task: Option<JoinHandle<()>>,
     reconciliation_ctx: Option<Arc<ReconciliationContext>>,
     connection_monitors: Vec<JoinHandle<()>>,
     order_timeout_task: JoinHandle<()>,
@@ -1014,6 +1294,7 @@ impl LiveRuntime {
         exchanges: Vec<ExchangeRoute>,
         router: Option<Arc<RouterExecutionClient>>,
         orchestrator: OrderOrchestrator,
+        risk_checker: Arc<BasicRiskChecker>,
         state_repo: Arc<dyn StateRepository<Snapshot = LiveState>>,
         ledger_repo: Arc<dyn LedgerRepository>,
         ledger_seq: Arc<LedgerSequencer>,
@@ -1025,6 +1306,7 @@ impl LiveRuntime {
         public_connection: Arc<AtomicBool>,
         private_connection: Option<Arc<AtomicBool>>,
         bootstrap: Option<LiveBootstrap>,
+        warmup: Vec<Candle>,
     ) -> Result<Self> {
         let mut strategy_ctx = StrategyContext::new(settings.history);
         strategy_ctx.attach_market_registry(market_registry.clone());
@@ -1086,6 +1368,7 @@ impl LiveRuntime {
                 .restore(state)
                 .context("failed to restore strategy state")?;
         }
+        replay_warmup(strategy.as_mut(), &mut strategy_ctx, &warmup).await?;
         persisted.portfolio = Some(portfolio.snapshot());
 
         let mut market_snapshots = HashMap::new();
@@ -1221,15 +1504,30 @@ impl LiveRuntime {
         let (strategy_cmd_tx, strategy_cmd_rx) = mpsc::channel(128);
         let (oms_req_tx, oms_req_rx) = mpsc::channel(64);
 
+        let strategy_paused = Arc::new(AtomicBool::new(false));
         let strategy_handle = StrategyHandle {
             tx: strategy_cmd_tx.clone(),
+            paused: strategy_paused.clone(),
         };
         let oms_handle = OmsHandle {
             tx: oms_req_tx.clone(),
         };
 
+        let reconciliation_journal = ReconciliationJournal::default();
+        let reloader = Arc::new(StrategyReloader::new(
+            &settings.strategy_reload,
+            strategy_handle.clone(),
+            oms_handle.clone(),
+            event_bus.clone(),
+            settings.reporting_currency,
+        ));
+        let strategy_watch_task = settings
+            .strategy_reload
+            .watch_interval
+            .and_then(|interval| reloader.clone().spawn_watcher(interval, shutdown.clone()));
         let control_task = control::spawn_control_plane(
             settings.control_addr,
+            &settings.control_plane,
             control::ControlPlaneComponents {
                 oms: oms_handle.clone(),
                 orchestrator: orchestrator.clone(),
@@ -1237,16 +1535,23 @@ impl LiveRuntime {
                 event_bus: event_bus.clone(),
                 strategy: strategy_handle.clone(),
                 shutdown: shutdown.clone(),
+                reconciliation: reconciliation_journal.clone(),
+                risk: risk_checker.clone(),
+                reloader,
             },
-        );
+        )?;
         let reconciliation_ctx = (!settings.exec_backend.is_paper()).then(|| {
             Arc::new(ReconciliationContext::new(ReconciliationContextConfig {
                 client: orchestrator.execution_engine().client(),
+                orchestrator: orchestrator.clone(),
                 oms: oms_handle.clone(),
                 alerts: alerts.clone(),
                 metrics: metrics.clone(),
                 reporting_currency: settings.reporting_currency,
                 threshold: settings.reconciliation_threshold,
+                symbols: symbols.clone(),
+                policy: settings.reconciliation_policy.clone(),
+                journal: reconciliation_journal.clone(),
             }))
         });
         let reconciliation_task = reconciliation_ctx.as_ref().map(|ctx| {
@@ -1286,6 +1591,7 @@ impl LiveRuntime {
             recorder_handle.clone(),
             market_registry.clone(),
             driver_summary.clone(),
+            strategy_paused,
             shutdown.clone(),
         );
         let strategy_task = tokio::spawn(async move { strategy_actor.run().await });
@@ -1355,6 +1661,7 @@ impl LiveRuntime {
             metrics_task,
             alert_task,
             reconciliation_task,
+            strategy_watch_task,
             reconciliation_ctx,
             connection_monitors,
             order_timeout_task,
@@ -1404,6 +1711,9 @@ impl LiveRuntime {
         if let Some(handle) = self.reconciliation_task.take() {
             handle.abort();
         }
+        if let Some(handle) = self.strategy_watch_task.take() {
+            handle.abort();
+        }
         self.order_timeout_task.abort();
         for handle in self.connection_monitors.drain(..) {
             handle.abort();
@@ -1440,33 +1750,127 @@ impl LiveRuntime {
     }
 }
 
+/// Kind of repair applied by state reconciliation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReconciliationActionKind {
+    AdoptPosition,
+    AdjustBalance,
+    RebindOrder,
+    CancelOrphanedOrder,
+    EnterLiquidateOnly,
+}
+
+impl ReconciliationActionKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::AdoptPosition => "adopt_position",
+            Self::AdjustBalance => "adjust_balance",
+            Self::RebindOrder => "rebind_order",
+            Self::CancelOrphanedOrder => "cancel_orphaned_order",
+            Self::EnterLiquidateOnly => "enter_liquidate_only",
+        }
+    }
+}
+
+/// Repair performed (or attempted) by state reconciliation.
+#[derive(Clone, Debug)]
+pub struct ReconciliationAction {
+    pub timestamp: DateTime<Utc>,
+    pub kind: ReconciliationActionKind,
+    /// Symbol, asset or order id the action applied to.
+    pub target: String,
+    pub detail: String,
+    pub success: bool,
+}
+
+/// Bounded history of reconciliation repairs shared with the control plane.
+#[derive(Clone, Default)]
+pub struct ReconciliationJournal {
+    actions: Arc<std::sync::Mutex<VecDeque<ReconciliationAction>>>,
+}
+
+impl ReconciliationJournal {
+    fn record(
+        &self,
+        kind: ReconciliationActionKind,
+        target: impl Into<String>,
+        detail: impl Into<String>,
+        success: bool,
+    ) {
+        let action = ReconciliationAction {
+            timestamp: Utc::now(),
+            kind,
+            target: target.into(),
+            detail: detail.into(),
+            success,
+        };
+        if success {
+            info!(
+                action = action.kind.as_str(),
+                target = %action.target,
+                detail = %action.detail,
+                "reconciliation repair applied"
+            );
+        } else {
+            error!(
+                action = action.kind.as_str(),
+                target = %action.target,
+                detail = %action.detail,
+                "reconciliation repair failed"
+            );
+        }
+        let mut actions = self.actions.lock().unwrap();
+        if actions.len() >= MAX_RECONCILIATION_ACTIONS {
+            actions.pop_front();
+        }
+        actions.push_back(action);
+    }
+
+    /// Recorded actions, oldest first.
+    pub fn actions(&self) -> Vec<ReconciliationAction> {
+        self.actions.lock().unwrap().iter().cloned().collect()
+    }
+}
+
 struct ReconciliationContext {
     client: Arc<dyn ExecutionClient>,
+    orchestrator: Arc<OrderOrchestrator>,
     oms: OmsHandle,
     alerts: Arc<AlertManager>,
     metrics: Arc<LiveMetrics>,
     reporting_currency: AssetId,
     threshold: Decimal,
+    symbols: Vec<Symbol>,
+    policy: ReconciliationPolicyConfig,
+    journal: ReconciliationJournal,
 }
 
 struct ReconciliationContextConfig {
     client: Arc<dyn ExecutionClient>,
+    orchestrator: Arc<OrderOrchestrator>,
     oms: OmsHandle,
     alerts: Arc<AlertManager>,
     metrics: Arc<LiveMetrics>,
     reporting_currency: AssetId,
     threshold: Decimal,
+    symbols: Vec<Symbol>,
+    policy: ReconciliationPolicyConfig,
+    journal: ReconciliationJournal,
 }
 
 impl ReconciliationContext {
     fn new(config: ReconciliationContextConfig) -> Self {
         let ReconciliationContextConfig {
             client,
+            orchestrator,
             oms,
             alerts,
             metrics,
             reporting_currency,
             threshold,
+            symbols,
+            policy,
+            journal,
         } = config;
         let min_threshold = Decimal::new(1, 6); // 0.000001 as a practical floor
         let threshold = if threshold <= Decimal::ZERO {
@@ -1476,11 +1880,15 @@ impl ReconciliationContext {
         };
         Self {
             client,
+            orchestrator,
             oms,
             alerts,
             metrics,
             reporting_currency,
             threshold,
+            symbols,
+            policy,
+            journal,
         }
     }
 }
@@ -1506,9 +1914,15 @@ async fn perform_state_reconciliation(ctx: &ReconciliationContext) -> Result<()>
     let local_cash = local_summary.cash;
     let local_map = positions_to_map(local_positions);
 
+    let mut query_symbols: Vec<Symbol> = local_map.keys().cloned().collect();
+    for symbol in &ctx.symbols {
+        if !query_symbols.contains(symbol) {
+            query_symbols.push(*symbol);
+        }
+    }
     let remote_positions = ctx
         .client
-        .positions(Some(&local_map.keys().cloned().collect::<Vec<_>>()))
+        .positions(Some(&query_symbols))
         .await
         .context("failed to fetch remote positions")?;
     let remote_balances = ctx
@@ -1517,12 +1931,17 @@ async fn perform_state_reconciliation(ctx: &ReconciliationContext) -> Result<()>
         .await
         .context("failed to fetch remote balances")?;
 
+    let remote_by_symbol: HashMap<Symbol, Position> = remote_positions
+        .iter()
+        .map(|position| (position.symbol, position.clone()))
+        .collect();
     let remote_map = positions_to_map(remote_positions);
     let mut tracked_symbols: HashSet<Symbol> = HashSet::new();
     tracked_symbols.extend(remote_map.keys().cloned());
     tracked_symbols.extend(local_map.keys().cloned());
 
     let mut severe_findings = Vec::new();
+    let mut diverged_symbols = Vec::new();
     for symbol in tracked_symbols {
         let local_qty = local_map.get(&symbol).copied().unwrap_or(Decimal::ZERO);
         let remote_qty = remote_map.get(&symbol).copied().unwrap_or(Decimal::ZERO);
@@ -1551,18 +1970,23 @@ async fn perform_state_reconciliation(ctx: &ReconciliationContext) -> Result<()>
                 severe_findings.push(format!(
                     "{symbol_name} local={local_qty} remote={remote_qty} diff={diff}"
                 ));
+                diverged_symbols.push((symbol, local_qty, remote_qty));
             }
         }
     }
 
     let reporting = ctx.reporting_currency;
     let reporting_label = reporting.to_string();
-    let remote_cash = remote_balances
+    let remote_cash_balance = remote_balances
         .iter()
-        .find(|balance| balance.asset == reporting)
-        .map(|balance| balance.available)
+        .find(|balance| balance.asset == reporting);
+    // Local cash is a wallet ledger: compare against the wallet balance, not the
+    // available balance, which excludes margin locked by open positions and orders.
+    let remote_cash = remote_cash_balance
+        .map(|balance| balance.total)
         .unwrap_or_else(|| Decimal::ZERO);
     let cash_diff = (remote_cash - local_cash).abs();
+    let mut cash_divergence = None;
     ctx.metrics
         .update_balance_diff(&reporting_label, cash_diff.to_f64().unwrap_or(0.0));
     if cash_diff > Decimal::ZERO {
@@ -1586,9 +2010,23 @@ async fn perform_state_reconciliation(ctx: &ReconciliationContext) -> Result<()>
             severe_findings.push(format!(
                 "{reporting_label} balance local={local_cash} remote={remote_cash} diff={cash_diff}"
             ));
+            let exchange = remote_cash_balance
+                .map(|balance| {
+                    if balance.exchange.is_specified() {
+                        balance.exchange
+                    } else {
+                        balance.asset.exchange
+                    }
+                })
+                .unwrap_or(reporting.exchange);
+            cash_divergence = Some((exchange, remote_cash - local_cash));
         }
     }
 
+    if let Err(err) = reconcile_open_orders(ctx).await {
+        error!(error = %err, "orphaned order reconciliation failed");
+    }
+
     if severe_findings.is_empty() {
         info!("state reconciliation complete with no critical divergence");
         return Ok(());
@@ -1596,9 +2034,133 @@ async fn perform_state_reconciliation(ctx: &ReconciliationContext) -> Result<()>
 
     let alert_body = severe_findings.join("; ");
     ctx.alerts
-        .notify("State reconciliation divergence", &alert_body)
+        .notify(
+            AlertSeverity::Critical,
+            "State reconciliation divergence",
+            &alert_body,
+        )
         .await;
-    ctx.oms.enter_liquidate_only().await;
+
+    if ctx.policy.adopt_exchange_state {
+        for (symbol, local_qty, remote_qty) in diverged_symbols {
+            let result = ctx
+                .oms
+                .adopt_position(symbol, remote_by_symbol.get(&symbol).cloned())
+                .await;
+            let detail = match &result {
+                Ok(()) => format!("position {local_qty} -> {remote_qty}"),
+                Err(err) => format!("failed to adopt position {remote_qty}: {err}"),
+            };
+            ctx.journal.record(
+                ReconciliationActionKind::AdoptPosition,
+                symbol.code(),
+                detail,
+                result.is_ok(),
+            );
+        }
+        if let Some((exchange, delta)) = cash_divergence {
+            let result = ctx.oms.adjust_balance(exchange, reporting, delta).await;
+            let detail = match &result {
+                Ok(()) => format!("balance adjusted by {delta}"),
+                Err(err) => format!("failed to adjust balance by {delta}: {err}"),
+            };
+            ctx.journal.record(
+                ReconciliationActionKind::AdjustBalance,
+                reporting_label.as_str(),
+                detail,
+                result.is_ok(),
+            );
+        }
+    }
+    if ctx.policy.liquidate_only && ctx.oms.enter_liquidate_only().await {
+        ctx.journal.record(
+            ReconciliationActionKind::EnterLiquidateOnly,
+            "portfolio",
+            alert_body,
+            true,
+        );
+    }
+    Ok(())
+}
+
+/// Re-binds or cancels open exchange orders the OMS does not know about.
+async fn reconcile_open_orders(ctx: &ReconciliationContext) -> Result<()> {
+    if !ctx.policy.rebind_orders && !ctx.policy.cancel_orphaned_orders {
+        return Ok(());
+    }
+    let known: HashSet<OrderId> = ctx
+        .oms
+        .open_orders()
+        .await
+        .into_iter()
+        .map(|order| order.id)
+        .collect();
+    let cutoff = Utc::now() - ORPHAN_ORDER_GRACE;
+    let mut unknown = Vec::new();
+    for symbol in &ctx.symbols {
+        let orders = ctx
+            .client
+            .list_open_orders(*symbol)
+            .await
+            .with_context(|| format!("failed to fetch open orders for {}", symbol.code()))?;
+        unknown.extend(
+            orders
+                .into_iter()
+                .filter(|order| !known.contains(&order.id) && order.created_at <= cutoff),
+        );
+    }
+    if unknown.is_empty() {
+        return Ok(());
+    }
+    warn!(
+        count = unknown.len(),
+        "exchange reports open orders unknown to the OMS"
+    );
+
+    let mut rebound = HashSet::new();
+    if ctx.policy.rebind_orders {
+        let adopted = ctx
+            .orchestrator
+            .adopt_open_orders(unknown.clone())
+            .await
+            .context("failed to re-bind orphaned orders")?;
+        rebound.extend(adopted);
+        let tracked: Vec<Order> = unknown
+            .iter()
+            .filter(|order| rebound.contains(&order.id))
+            .cloned()
+            .collect();
+        for order in &tracked {
+            ctx.journal.record(
+                ReconciliationActionKind::RebindOrder,
+                order.id.as_str(),
+                format!("{} order re-bound to its algorithm", order.request.symbol),
+                true,
+            );
+        }
+        ctx.oms.track_orders(tracked).await;
+    }
+    if ctx.policy.cancel_orphaned_orders {
+        for order in unknown.iter().filter(|order| !rebound.contains(&order.id)) {
+            let result = ctx
+                .client
+                .cancel_order(order.id.clone(), order.request.symbol)
+                .await;
+            let detail = match &result {
+                Ok(()) => format!("cancelled orphaned {} order", order.request.symbol),
+                Err(err) => format!(
+                    "failed to cancel orphaned {} order: {err}",
+                    order.request.symbol
+                ),
+            };
+            ctx.journal.record(
+                ReconciliationActionKind::CancelOrphanedOrder,
+                order.id.as_str(),
+                detail,
+                result.is_ok(),
+            );
+        }
+    }
     Ok(())
 }
 
@@ -1627,9 +2189,10 @@ fn normalize_diff(diff: Decimal, reference: Decimal) -> Decimal {
     }
 }
 
-fn build_exchange_payload(
+pub(crate) fn build_exchange_payload(
     exchange: &ExchangeConfig,
-    settings: &LiveSessionSettings,
+    category: &str,
+    orderbook_depth: usize,
     name: &str,
 ) -> Value {
     let mut payload = serde_json::Map::new();
@@ -1640,14 +2203,11 @@ fn build_exchange_payload(
         "api_secret".into(),
         Value::String(exchange.api_secret.clone()),
     );
-    payload.insert(
-        "category".into(),
-        Value::String(settings.category.as_path().to_string()),
-    );
+    payload.insert("category".into(), Value::String(category.to_string()));
     payload.insert("exchange".into(), Value::String(name.to_string()));
     payload.insert(
         "orderbook_depth".into(),
-        Value::Number(serde_json::Number::from(settings.orderbook_depth as u64)),
+        Value::Number(serde_json::Number::from(orderbook_depth as u64)),
     );
     if let Value::Object(extra) = exchange.params.clone() {
         for (key, value) in extra {
@@ -1707,7 +2267,7 @@ impl ShutdownSignal {
         self.notify.notified().await;
     }
 
-    async fn sleep(&self, duration: Duration) -> bool {
+    pub(crate) async fn sleep(&self, duration: Duration) -> bool {
         tokio::select! {
             _ = tokio::time::sleep(duration) => true,
             _ = self.notify.notified() => false,
@@ -1879,6 +2439,11 @@ struct StrategyActor {
     recorder: Option<RecorderHandle>,
     market_registry: Arc<MarketRegistry>,
     driver_label: Arc<String>,
+    paused: Arc<AtomicBool>,
+    /// Timestamp of the first candle handled after warm-up.
+    live_since: Option<DateTime<Utc>>,
+    /// Signals raised on live candles still in the context buffer, for swap comparisons.
+    signal_log: VecDeque<SignalRecord>,
     shutdown: ShutdownSignal,
 }
 
@@ -1938,6 +2503,10 @@ impl MarketActor {
                 self.dispatch_market_event(MarketEvent::OrderBook(book))
                     .await;
             }
+            // Order-level updates are only persisted; strategies consume aggregated books.
+            if self.next_market_by_order().await? {
+                progressed = true;
+            }
             if !progressed && !self.shutdown.sleep(backoff).await {
                 break 'outer;
             }
@@ -2027,6 +2596,30 @@ impl MarketActor {
         }
     }
 
+    async fn next_market_by_order(&mut self) -> Result<bool> {
+        let update = tokio::select! {
+            res = tokio::time::timeout(MARKET_EVENT_TIMEOUT, self.stream.next_market_by_order()) => Some(res),
+            _ = self.shutdown.wait() => None,
+        };
+        match update {
+            Some(Ok(Ok(Some(update)))) => {
+                self.metrics
+                    .update_last_data_timestamp(Utc::now().timestamp() as f64);
+                self.last_data_timestamp
+                    .store(update.timestamp.timestamp(), Ordering::SeqCst);
+                self.alerts.heartbeat().await;
+                if let Some(handle) = &self.recorder {
+                    handle.record_market_by_order(update);
+                }
+                Ok(true)
+            }
+            Some(Ok(Ok(None))) => Ok(false),
+            Some(Ok(Err(err))) => Err(err.into()),
+            Some(Err(_)) => Ok(false),
+            None => Ok(false),
+        }
+    }
+
     fn dispatch_market_event(&self, event: MarketEvent) -> impl std::future::Future<Output = ()> {
         let strategy_tx = self.strategy_tx.clone();
         let oms_tx = self.oms_tx.clone();
@@ -2306,6 +2899,7 @@ impl OmsActor {
             .publish(Event::Fill(FillEvent { fill: fill.clone() }));
         self.alerts
             .notify(
+                AlertSeverity::Info,
                 "Order Filled",
                 &format!(
                     "order filled: {}@{} ({})",
@@ -2341,6 +2935,7 @@ impl OmsActor {
                 .await;
             self.alerts
                 .notify(
+                    AlertSeverity::Warning,
                     "Order rejected",
                     &format!(
                         "Order {} for {} was rejected",
@@ -2432,18 +3027,198 @@ impl OmsActor {
                 };
                 let _ = respond_to.send(summary);
             }
-            OmsRequest::EnterLiquidateOnly { respond_to } => {
-                let changed = self.portfolio.set_liquidate_only(true);
+            OmsRequest::SetLiquidateOnly {
+                enabled,
+                respond_to,
+            } => {
+                let changed = self.portfolio.set_liquidate_only(enabled);
                 if changed {
                     self.snapshot_portfolio();
                     self.persist_state(true).await;
                 }
                 let _ = respond_to.send(changed);
             }
+            OmsRequest::ResolveSymbol { code, respond_to } => {
+                let _ = respond_to.send(self.resolve_symbol(&code));
+            }
+            OmsRequest::PlaceOrder {
+                request,
+                respond_to,
+            } => {
+                let _ = respond_to.send(self.submit_order(request).await);
+            }
+            OmsRequest::FlattenPosition { symbol, respond_to } => {
+                let _ = respond_to.send(self.flatten_position(symbol).await);
+            }
+            OmsRequest::AdoptPosition {
+                symbol,
+                position,
+                respond_to,
+            } => {
+                let result = self.adopt_position(symbol, position).await;
+                let _ = respond_to.send(result);
+            }
+            OmsRequest::AdjustBalance {
+                exchange,
+                asset,
+                delta,
+                respond_to,
+            } => {
+                let entry =
+                    reconciliation_entry(exchange, asset, delta, json!({ "component": "balance" }));
+                let result = self.apply_reconciliation_entry(entry).await;
+                let _ = respond_to.send(result);
+            }
+            OmsRequest::RecordLedgerEntry {
+                mut entry,
+                respond_to,
+            } => {
+                entry.sequence = self.ledger_seq.next();
+                self.persist_ledger_entries(vec![entry]).await;
+                let _ = respond_to.send(Ok(()));
+            }
+            OmsRequest::TrackOrders { orders } => {
+                for order in orders {
+                    if !self
+                        .live_state
+                        .open_orders
+                        .iter()
+                        .any(|existing| existing.id == order.id)
+                    {
+                        self.live_state.open_orders.push(order);
+                    }
+                }
+                self.persist_state(false).await;
+            }
+        }
+    }
+
+    fn resolve_symbol(&self, code: &str) -> Result<Symbol> {
+        let code = code.trim();
+        if code.contains(':') {
+            let symbol: Symbol = code
+                .parse()
+                .map_err(|err| anyhow!("invalid symbol '{code}': {err}"))?;
+            return self
+                .market_registry
+                .get(symbol)
+                .map(|instrument| instrument.symbol)
+                .ok_or_else(|| anyhow!("unknown instrument {symbol}"));
         }
+        let mut matches = self
+            .market_registry
+            .instruments()
+            .into_iter()
+            .filter(|instrument| instrument.symbol.code().eq_ignore_ascii_case(code))
+            .map(|instrument| instrument.symbol);
+        match (matches.next(), matches.next()) {
+            (Some(symbol), None) => Ok(symbol),
+            (Some(_), Some(_)) => {
+                bail!("symbol '{code}' is listed on several venues; use EXCHANGE:{code}")
+            }
+            (None, _) => bail!("unknown instrument {code}"),
+        }
+    }
+
+    async fn submit_order(&self, request: OrderRequest) -> Result<Order> {
+        let ctx = shared_risk_context(
+            request.symbol,
+            &self.portfolio,
+            &self.market_snapshots,
+            &self.live_state,
+            &self.market_registry,
+        );
+        self.orchestrator.submit_order(request, &ctx).await
+    }
+
+    async fn flatten_position(&self, symbol: Symbol) -> Result<Option<Order>> {
+        let position = self.portfolio.signed_position_qty(symbol);
+        if position.is_zero() {
+            return Ok(None);
+        }
+        let side = if position > Decimal::ZERO {
+            Side::Sell
+        } else {
+            Side::Buy
+        };
+        let request = OrderRequest {
+            symbol,
+            side,
+            order_type: OrderType::Market,
+            quantity: position.abs(),
+            price: None,
+            trigger_price: None,
+            time_in_force: None,
+            client_order_id: Some(format!("manual-flatten-{}", Uuid::new_v4())),
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        };
+        self.submit_order(request).await.map(Some)
+    }
+
+    async fn adopt_position(&mut self, symbol: Symbol, position: Option<Position>) -> Result<()> {
+        let instrument = self
+            .market_registry
+            .get(symbol)
+            .ok_or_else(|| anyhow!("unknown instrument {symbol}"))?;
+        let local = self.portfolio.signed_position_qty(symbol);
+        let remote = position
+            .as_ref()
+            .map(position_signed_qty)
+            .unwrap_or_default();
+        // Spot holdings live in the base balance, mirroring fill accounting. Derivative
+        // positions carry no cash, so their entry only documents the change.
+        let (asset, amount) = match instrument.kind {
+            InstrumentKind::Spot => (instrument.base, remote - local),
+            InstrumentKind::LinearPerpetual | InstrumentKind::InversePerpetual => {
+                (instrument.settlement_currency, Decimal::ZERO)
+            }
+        };
+        self.portfolio.replace_position(symbol, position);
+        let entry = reconciliation_entry(
+            asset.exchange,
+            asset,
+            amount,
+            json!({
+                "component": "position",
+                "symbol": symbol.to_string(),
+                "local": local.to_string(),
+                "remote": remote.to_string(),
+            }),
+        );
+        self.apply_reconciliation_entry(entry).await
+    }
+
+    async fn apply_reconciliation_entry(&mut self, mut entry: LedgerEntry) -> Result<()> {
+        entry.sequence = self.ledger_seq.next();
+        self.portfolio
+            .apply_ledger_entries(std::slice::from_ref(&entry))
+            .context("failed to apply reconciliation adjustment")?;
+        self.persist_ledger_entries(vec![entry]).await;
+        self.snapshot_portfolio();
+        self.persist_state(false).await;
+        Ok(())
     }
 }
 
+fn reconciliation_entry(
+    exchange: ExchangeId,
+    asset: AssetId,
+    amount: Decimal,
+    meta: Value,
+) -> LedgerEntry {
+    let mut entry = LedgerEntry::new(
+        exchange,
+        asset,
+        amount,
+        LedgerType::Adjustment,
+        "reconciliation",
+    );
+    entry.meta = Some(meta);
+    entry
+}
+
 impl StrategyActor {
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -2458,6 +3233,7 @@ impl StrategyActor {
         recorder: Option<RecorderHandle>,
         market_registry: Arc<MarketRegistry>,
         driver_label: Arc<String>,
+        paused: Arc<AtomicBool>,
         shutdown: ShutdownSignal,
     ) -> Self {
         Self {
@@ -2472,6 +3248,9 @@ impl StrategyActor {
             recorder,
             market_registry,
             driver_label,
+            paused,
+            live_since: None,
+            signal_log: VecDeque::new(),
             shutdown,
         }
     }
@@ -2528,10 +3307,30 @@ impl StrategyActor {
             .await
             .context("strategy failure on candle event")?;
         log_strategy_call("candle", call_start.elapsed());
-        self.emit_signals().await;
+        self.live_since.get_or_insert(candle.timestamp);
+        let signals = self.strategy.drain_signals();
+        self.log_candle_signals(candle.timestamp, &signals);
+        self.route_signals(signals).await;
         Ok(())
     }
 
+    fn log_candle_signals(&mut self, timestamp: DateTime<Utc>, signals: &[Signal]) {
+        if let Some(oldest) = self.ctx.candles().front().map(|candle| candle.timestamp) {
+            while self
+                .signal_log
+                .front()
+                .is_some_and(|record| record.timestamp < oldest)
+            {
+                self.signal_log.pop_front();
+            }
+        }
+        self.signal_log.extend(
+            signals
+                .iter()
+                .map(|signal| SignalRecord::new(timestamp, signal)),
+        );
+    }
+
     async fn handle_order_book(&mut self, mut book: OrderBook) -> Result<()> {
         let driver_name = self.driver_label.as_str();
         let local_checksum = if let Some(cs) = book.local_checksum {
@@ -2601,11 +3400,124 @@ impl StrategyActor {
                         .map_err(|err| anyhow!(err.to_string()));
                     let _ = respond_to.send(result);
                 }
+                StrategyRequest::Configure {
+                    definition,
+                    respond_to,
+                } => {
+                    let _ = respond_to.send(self.reconfigure(definition).await);
+                }
+                StrategyRequest::Swap {
+                    definition,
+                    dry_run,
+                    respond_to,
+                } => {
+                    let _ = respond_to.send(self.swap(definition, dry_run).await);
+                }
             },
         }
         Ok(())
     }
 
+    /// Apply new parameters to the running strategy, carrying its snapshot state across.
+    async fn reconfigure(&mut self, definition: StrategyDefinition) -> Result<String> {
+        let candidate = load_strategy(&definition.name, definition.params.clone())
+            .with_context(|| format!("invalid parameters for strategy {}", definition.name))?;
+        if candidate.name() != self.strategy.name() {
+            bail!(
+                "config targets strategy '{}' but '{}' is running",
+                candidate.name(),
+                self.strategy.name()
+            );
+        }
+        if candidate.subscriptions() != self.strategy.subscriptions() {
+            bail!(
+                "strategy subscriptions cannot change mid-session; restart to trade other symbols"
+            );
+        }
+        let state = self
+            .strategy
+            .snapshot()
+            .context("failed to snapshot strategy")?;
+        self.strategy
+            .configure(definition.params)
+            .context("failed to configure strategy")?;
+        self.strategy
+            .restore(state)
+            .context("failed to restore strategy state")?;
+        // `configure` may rebuild indicators, so re-prime them from the buffered candles.
+        replay_context(self.strategy.as_mut(), &self.ctx).await?;
+        Ok(self.strategy.name().to_string())
+    }
+
+    /// Build a replacement strategy, warm it on the buffered candles and, unless `dry_run`,
+    /// switch to it with the running strategy's snapshot state.
+    ///
+    /// Only the strategy instance changes: algorithms already handed to the orchestrator keep
+    /// executing, and signals the old instance raised but had not yet routed are sent first.
+    async fn swap(
+        &mut self,
+        definition: StrategyDefinition,
+        dry_run: bool,
+    ) -> Result<StrategySwap> {
+        let mut candidate = load_strategy(&definition.name, definition.params)
+            .with_context(|| format!("failed to build strategy {}", definition.name))?;
+        let subscribed = self.strategy.subscriptions();
+        if let Some(symbol) = candidate
+            .subscriptions()
+            .into_iter()
+            .find(|symbol| !subscribed.contains(symbol))
+        {
+            bail!("{symbol} is not streamed in this session; restart to trade new symbols");
+        }
+
+        // Warm the candidate on the buffered candles and collect the signals it would have raised
+        // on the live ones; its baseline state is restored afterwards so replayed signals leave
+        // no bookkeeping behind.
+        let baseline = candidate
+            .snapshot()
+            .context("failed to snapshot candidate strategy")?;
+        let candles: Vec<Candle> = self.ctx.candles().iter().cloned().collect();
+        let (compared_candles, replayed) = replay_candidate(
+            candidate.as_mut(),
+            self.ctx.empty_like(),
+            &candles,
+            self.live_since,
+        )
+        .await?;
+        candidate
+            .restore(baseline)
+            .context("failed to reset candidate strategy after replay")?;
+        let current: Vec<SignalRecord> = self.signal_log.iter().cloned().collect();
+        let comparison = SignalComparison::compare(compared_candles, &current, &replayed);
+        let previous = self.strategy.name().to_string();
+        if dry_run {
+            return Ok(StrategySwap {
+                previous,
+                strategy: candidate.name().to_string(),
+                swapped: false,
+                comparison,
+            });
+        }
+
+        self.emit_signals().await;
+        let state = self
+            .strategy
+            .snapshot()
+            .context("failed to snapshot running strategy")?;
+        candidate
+            .restore(state)
+            .context("candidate strategy rejected the running strategy's state")?;
+        self.strategy = candidate;
+        self.signal_log.clear();
+        self.live_since = None;
+        Ok(StrategySwap {
+            previous,
+            strategy: self.strategy.name().to_string(),
+            swapped: true,
+            comparison,
+        })
+    }
+
     fn with_pairs_strategy<R>(
         &mut self,
         f: impl FnOnce(&mut PairsTradingArbitrage) -> StrategyResult<R>,
@@ -2620,10 +3532,18 @@ impl StrategyActor {
     }
 
     async fn emit_signals(&mut self) {
-        let mut signals = self.strategy.drain_signals();
+        let signals = self.strategy.drain_signals();
+        self.route_signals(signals).await;
+    }
+
+    async fn route_signals(&mut self, mut signals: Vec<Signal>) {
         if signals.is_empty() {
             return;
         }
+        if self.paused.load(Ordering::SeqCst) {
+            info!(count = signals.len(), "strategy paused; discarding signals");
+            return;
+        }
         self.metrics.inc_signals(signals.len());
         normalize_group_quantities(&mut signals, &self.market_registry);
         for signal in signals {
@@ -2722,6 +3642,7 @@ fn shared_risk_context(
 async fn alert_liquidate_only(alerts: Arc<AlertManager>) {
     alerts
         .notify(
+            AlertSeverity::Critical,
             "Max drawdown triggered",
             "Portfolio entered liquidate-only mode; new exposure blocked until review",
         )
@@ -2769,7 +3690,9 @@ fn spawn_order_timeout_monitor(
                             );
                             error!(%message);
                             alerts.order_failure(&message).await;
-                            alerts.notify("Order timeout", &message).await;
+                            alerts
+                                .notify(AlertSeverity::Warning, "Order timeout", &message)
+                                .await;
                         }
                         if broker_tx
                             .send(BrokerEvent::OrderUpdate(order))
@@ -2907,6 +3830,7 @@ fn spawn_bybit_private_stream(
                                         gap_alerted = false;
                                         alerts
                                             .notify(
+                                                AlertSeverity::Info,
                                                 "Execution feed restored",
                                                 "Bybit REST replay filled the execution gap after reconnect",
                                             )
@@ -2917,6 +3841,7 @@ fn spawn_bybit_private_stream(
                             Err(e) => {
                                 alerts
                                     .notify(
+                                        AlertSeverity::Critical,
                                         "Execution replay failed",
                                         &format!(
                                             "Failed to reconcile executions after reconnect: {e}"
@@ -2955,6 +3880,7 @@ fn spawn_bybit_private_stream(
                                             gap_alerted = true;
                                             alerts
                                                 .notify(
+                                                    AlertSeverity::Warning,
                                                     "Execution data gap",
                                                     &format!(
                                                         "No Bybit executions received for {gap_secs} seconds; attempting REST catch-up"
@@ -2978,6 +3904,7 @@ fn spawn_bybit_private_stream(
                                                         gap_alerted = false;
                                                         alerts
                                                             .notify(
+                                                                AlertSeverity::Info,
                                                                 "Execution feed restored",
                                                                 &format!("REST replay recovered {count} execution(s); feed back in sync"),
                                                             )
@@ -2988,6 +3915,7 @@ fn spawn_bybit_private_stream(
                                             Err(err) => {
                                                 alerts
                                                     .notify(
+                                                        AlertSeverity::Critical,
                                                         "Execution catch-up failed",
                                                         &format!("Execution gap catch-up failed: {err}"),
                                                     )
@@ -3065,6 +3993,7 @@ fn spawn_bybit_private_stream(
                                                                         gap_alerted = false;
                                                                         alerts
                                                                             .notify(
+                                                                                AlertSeverity::Info,
                                                                                 "Execution feed restored",
                                                                                 "Bybit execution feed resumed via websocket",
                                                                             )
@@ -3272,6 +4201,7 @@ fn spawn_binance_private_stream(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use parquet::file::reader::{FileReader, SerializedFileReader};
     use std::collections::VecDeque;
     use tesser_core::OrderBookLevel;
 
@@ -3279,6 +4209,7 @@ mod tests {
         ticks: VecDeque<Tick>,
         candles: VecDeque<Candle>,
         books: VecDeque<OrderBook>,
+        mbo: VecDeque<MarketByOrderUpdate>,
     }
 
     impl StaticStream {
@@ -3287,8 +4218,14 @@ mod tests {
                 ticks: ticks.into(),
                 candles: candles.into(),
                 books: books.into(),
+                mbo: VecDeque::new(),
             }
         }
+
+        fn with_market_by_order(mut self, updates: Vec<MarketByOrderUpdate>) -> Self {
+            self.mbo = updates.into();
+            self
+        }
     }
 
     #[async_trait::async_trait]
@@ -3304,6 +4241,10 @@ mod tests {
         async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>> {
             Ok(self.books.pop_front())
         }
+
+        async fn next_market_by_order(&mut self) -> BrokerResult<Option<MarketByOrderUpdate>> {
+            Ok(self.mbo.pop_front())
+        }
     }
 
     fn build_tick(exchange: &str, price: i64) -> Tick {
@@ -3344,17 +4285,34 @@ mod tests {
             timestamp: Utc::now(),
             exchange_checksum: None,
             local_checksum: None,
+            sequence: None,
+            prev_sequence: None,
+        }
+    }
+
+    fn build_mbo(exchange: &str, price: i64) -> MarketByOrderUpdate {
+        MarketByOrderUpdate {
+            symbol: Symbol::from(exchange),
+            order_id: format!("{exchange}-{price}"),
+            side: Side::Buy,
+            price: Decimal::from(price),
+            size: Decimal::ONE,
+            action: tesser_core::MarketByOrderAction::Add,
+            timestamp: Utc::now(),
         }
     }
 
     #[tokio::test]
     async fn router_market_stream_fans_in_events() {
         let shutdown = ShutdownSignal::new();
-        let stream_a = Box::new(StaticStream::new(
-            vec![build_tick("A", 1), build_tick("A", 2)],
-            vec![build_candle("A", 10)],
-            vec![build_book("A", 5)],
-        ));
+        let stream_a = Box::new(
+            StaticStream::new(
+                vec![build_tick("A", 1), build_tick("A", 2)],
+                vec![build_candle("A", 10)],
+                vec![build_book("A", 5)],
+            )
+            .with_market_by_order(vec![build_mbo("A", 5)]),
+        );
         let stream_b = Box::new(StaticStream::new(
             vec![build_tick("B", 3)],
             vec![build_candle("B", 20)],
@@ -3382,6 +4340,54 @@ mod tests {
         assert_eq!(book_a.bids[0].price, Decimal::from(5));
         assert_eq!(book_b.asks[0].price, Decimal::from(16));
 
+        let update = router.next_market_by_order().await.unwrap().unwrap();
+        assert_eq!(update.order_id, "A-5");
+
         shutdown.trigger();
     }
+
+    #[tokio::test]
+    async fn market_actor_records_market_by_order_updates() {
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = ParquetRecorder::spawn(RecorderConfig {
+            root: dir.path().to_path_buf(),
+            ..RecorderConfig::default()
+        })
+        .await
+        .unwrap();
+        let stream = StaticStream::new(Vec::new(), Vec::new(), Vec::new())
+            .with_market_by_order(vec![build_mbo("A", 7), build_mbo("A", 8)]);
+        let alerting = AlertingConfig::default();
+        let dispatcher = AlertDispatcher::from_config(&alerting).unwrap();
+        let alerts = Arc::new(AlertManager::new(alerting, dispatcher, None, None));
+        let (strategy_tx, _strategy_rx) = mpsc::channel(8);
+        let (oms_tx, _oms_rx) = mpsc::channel(8);
+        let shutdown = ShutdownSignal::new();
+        let actor = MarketActor::new(
+            Box::new(stream),
+            Arc::new(EventBus::new(16)),
+            Some(recorder.handle()),
+            Arc::new(LiveMetrics::new()),
+            alerts,
+            Arc::new(AtomicI64::new(0)),
+            strategy_tx,
+            oms_tx,
+            shutdown.clone(),
+        );
+        let task = tokio::spawn(actor.run());
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        shutdown.trigger();
+        task.await.unwrap().unwrap();
+        recorder.shutdown().await.unwrap();
+
+        let files = tesser_data::analytics::collect_parquet_files(&dir.path().join("mbo")).unwrap();
+        let rows: i64 = files
+            .iter()
+            .map(|path| {
+                let reader = SerializedFileReader::new(std::fs::File::open(path).unwrap()).unwrap();
+                reader.metadata().file_metadata().num_rows()
+            })
+            .sum();
+        assert_eq!(rows, 2);
+    }
 }
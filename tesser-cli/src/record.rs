@@ -0,0 +1,359 @@
+//! Standalone market data capture behind `tesser-cli data record`.
+//!
+//! Each exchange profile gets its own connector stream and capture loop feeding a shared
+//! [`ParquetRecorder`]. Trades and candles are written as they arrive; order books go through
+//! [`BookCapture`] so the recording holds periodic snapshots plus depth deltas. Stream errors,
+//! rejected books and a stream that goes silent tear the connection down and re-subscribe, which
+//! makes the exchange send a fresh snapshot. A single quiet symbol only raises a warning and
+//! restarts from a snapshot once it trades again, so illiquid markets never interrupt the rest.
+
+use std::future::Future;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use serde_json::json;
+use tesser_broker::{get_connector_factory, BrokerResult, ConnectorStream, ConnectorStreamConfig};
+use tesser_core::{Interval, Symbol};
+use tesser_data::capture::{BookCapture, CaptureConfig, CaptureOutcome, CaptureStats, GapReason};
+use tesser_data::recorder::{ParquetRecorder, RecorderConfig, RecorderHandle};
+use tracing::{info, warn};
+
+use crate::live::{
+    build_exchange_payload, ensure_builtin_connectors_registered, NamedExchange, ShutdownSignal,
+};
+
+const MIN_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// Sessions that stay up this long reset the reconnect backoff.
+const HEALTHY_SESSION: Duration = Duration::from_secs(60);
+const STATS_INTERVAL: Duration = Duration::from_secs(60);
+const IDLE_POLL: Duration = Duration::from_millis(5);
+/// Upper bound on each connector poll; some streams block until the next event.
+const POLL_TIMEOUT: Duration = Duration::from_millis(10);
+
+/// Which event families are persisted.
+#[derive(Clone, Copy, Debug)]
+pub struct RecordChannels {
+    pub trades: bool,
+    pub candles: bool,
+    pub depth: bool,
+}
+
+/// Everything needed to run a capture session.
+pub struct RecordPlan {
+    pub exchanges: Vec<NamedExchange>,
+    pub symbols: Vec<String>,
+    pub category: String,
+    pub interval: Interval,
+    pub orderbook_depth: usize,
+    pub channels: RecordChannels,
+    pub recorder: RecorderConfig,
+    pub capture: CaptureConfig,
+    /// Stop after this long; runs until Ctrl-C when unset.
+    pub duration: Option<Duration>,
+}
+
+/// Per-exchange totals printed when the session ends.
+#[derive(Clone, Debug, Default)]
+pub struct ExchangeReport {
+    pub exchange: String,
+    pub ticks: u64,
+    pub candles: u64,
+    /// Order-level (L3) updates, from venues that publish a market-by-order feed.
+    pub market_by_order: u64,
+    pub book: CaptureStats,
+    pub reconnects: u64,
+}
+
+/// Run the recorder until shutdown and return per-exchange totals.
+pub async fn run_record(plan: RecordPlan) -> Result<Vec<ExchangeReport>> {
+    if plan.exchanges.is_empty() {
+        return Err(anyhow!("provide at least one --exchange"));
+    }
+    if plan.symbols.is_empty() {
+        return Err(anyhow!("provide at least one --symbols entry"));
+    }
+    ensure_builtin_connectors_registered();
+    let root = plan.recorder.root.clone();
+    let recorder = ParquetRecorder::spawn(plan.recorder.clone()).await?;
+    let shutdown = ShutdownSignal::new();
+    if let Some(duration) = plan.duration {
+        let timer = shutdown.clone();
+        tokio::spawn(async move {
+            if timer.sleep(duration).await {
+                timer.trigger();
+            }
+        });
+    }
+    info!(
+        path = %root.display(),
+        exchanges = plan.exchanges.len(),
+        symbols = plan.symbols.len(),
+        "recording market data"
+    );
+
+    let plan = Arc::new(plan);
+    let mut tasks = Vec::new();
+    for index in 0..plan.exchanges.len() {
+        let plan = plan.clone();
+        let handle = recorder.handle();
+        let shutdown = shutdown.clone();
+        tasks.push(tokio::spawn(async move {
+            capture_exchange(&plan, &plan.exchanges[index], handle, shutdown).await
+        }));
+    }
+    let mut reports = Vec::new();
+    let mut failure = None;
+    for task in tasks {
+        match task.await {
+            Ok(Ok(report)) => reports.push(report),
+            Ok(Err(err)) => failure = Some(err),
+            Err(err) => failure = Some(anyhow!("capture task aborted: {err}")),
+        }
+    }
+    recorder.shutdown().await?;
+    match failure {
+        Some(err) => Err(err),
+        None => Ok(reports),
+    }
+}
+
+/// Why a connected session ended.
+enum SessionEnd {
+    Shutdown,
+    StreamError(String),
+    Gap(Symbol, GapReason),
+    Stalled,
+}
+
+async fn capture_exchange(
+    plan: &RecordPlan,
+    exchange: &NamedExchange,
+    recorder: RecorderHandle,
+    shutdown: ShutdownSignal,
+) -> Result<ExchangeReport> {
+    let driver = exchange.config.driver.as_str();
+    let factory = get_connector_factory(driver)
+        .ok_or_else(|| anyhow!("driver {driver} is not registered"))?;
+    let payload = build_exchange_payload(
+        &exchange.config,
+        &plan.category,
+        plan.orderbook_depth,
+        &exchange.name,
+    );
+    let mut capture = BookCapture::new(plan.capture);
+    let mut report = ExchangeReport {
+        exchange: exchange.name.clone(),
+        ..ExchangeReport::default()
+    };
+    let mut backoff = MIN_BACKOFF;
+    let mut connected_once = false;
+    while !shutdown.triggered() {
+        let stream_config = ConnectorStreamConfig {
+            ws_url: Some(exchange.config.ws_url.clone()),
+            metadata: json!({
+                "category": plan.category,
+                "symbols": plan.symbols,
+                "orderbook_depth": plan.orderbook_depth,
+            }),
+            connection_status: Some(Arc::new(AtomicBool::new(false))),
+        };
+        let connected = match factory.create_market_stream(&payload, stream_config).await {
+            Ok(mut stream) => stream
+                .subscribe(&plan.symbols, plan.interval)
+                .await
+                .map(|_| stream),
+            Err(err) => Err(err),
+        };
+        let end = match connected {
+            Ok(mut stream) => {
+                if connected_once {
+                    report.reconnects += 1;
+                }
+                connected_once = true;
+                capture.reset();
+                let started = Instant::now();
+                let end = pump(
+                    plan,
+                    stream.as_mut(),
+                    &mut capture,
+                    &mut report,
+                    &recorder,
+                    &shutdown,
+                )
+                .await;
+                if started.elapsed() >= HEALTHY_SESSION {
+                    backoff = MIN_BACKOFF;
+                }
+                end
+            }
+            Err(err) => SessionEnd::StreamError(err.to_string()),
+        };
+        match end {
+            SessionEnd::Shutdown => break,
+            SessionEnd::StreamError(error) => {
+                warn!(exchange = %exchange.name, %error, "market stream failed; reconnecting");
+            }
+            SessionEnd::Gap(symbol, reason) => {
+                warn!(
+                    exchange = %exchange.name,
+                    %symbol,
+                    %reason,
+                    "order book gap detected; re-subscribing for a fresh snapshot"
+                );
+            }
+            SessionEnd::Stalled => {
+                warn!(exchange = %exchange.name, "market stream stalled; re-subscribing");
+            }
+        }
+        if !shutdown.sleep(backoff).await {
+            break;
+        }
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+    report.book = capture.stats();
+    Ok(report)
+}
+
+async fn pump(
+    plan: &RecordPlan,
+    stream: &mut dyn ConnectorStream,
+    capture: &mut BookCapture,
+    report: &mut ExchangeReport,
+    recorder: &RecorderHandle,
+    shutdown: &ShutdownSignal,
+) -> SessionEnd {
+    let stall_timeout = plan
+        .capture
+        .stall_timeout
+        .to_std()
+        .unwrap_or(Duration::from_secs(60));
+    let mut last_event = Instant::now();
+    let mut last_stall_check = Instant::now();
+    let mut last_stats = Instant::now();
+    loop {
+        if shutdown.triggered() {
+            return SessionEnd::Shutdown;
+        }
+        let mut emitted = false;
+
+        match poll_next(stream.next_tick(), shutdown).await {
+            Polled::Event(tick) => {
+                emitted = true;
+                report.ticks += 1;
+                if plan.channels.trades {
+                    recorder.record_tick(tick);
+                }
+            }
+            Polled::Idle => {}
+            Polled::Failed(error) => return SessionEnd::StreamError(error),
+            Polled::Shutdown => return SessionEnd::Shutdown,
+        }
+
+        match poll_next(stream.next_candle(), shutdown).await {
+            Polled::Event(candle) => {
+                emitted = true;
+                report.candles += 1;
+                if plan.channels.candles {
+                    recorder.record_candle(candle);
+                }
+            }
+            Polled::Idle => {}
+            Polled::Failed(error) => return SessionEnd::StreamError(error),
+            Polled::Shutdown => return SessionEnd::Shutdown,
+        }
+
+        match poll_next(stream.next_order_book(), shutdown).await {
+            Polled::Event(book) => {
+                emitted = true;
+                match capture.ingest(&book, Utc::now()) {
+                    CaptureOutcome::Snapshot(book) if plan.channels.depth => {
+                        recorder.record_order_book(book)
+                    }
+                    CaptureOutcome::Delta(update) if plan.channels.depth => {
+                        recorder.record_depth_update(update)
+                    }
+                    CaptureOutcome::Gap(reason) => return SessionEnd::Gap(book.symbol, reason),
+                    _ => {}
+                }
+            }
+            Polled::Idle => {}
+            Polled::Failed(error) => return SessionEnd::StreamError(error),
+            Polled::Shutdown => return SessionEnd::Shutdown,
+        }
+
+        match poll_next(stream.next_market_by_order(), shutdown).await {
+            Polled::Event(update) => {
+                emitted = true;
+                report.market_by_order += 1;
+                if plan.channels.depth {
+                    recorder.record_market_by_order(update);
+                }
+            }
+            Polled::Idle => {}
+            Polled::Failed(error) => return SessionEnd::StreamError(error),
+            Polled::Shutdown => return SessionEnd::Shutdown,
+        }
+
+        if emitted {
+            last_event = Instant::now();
+        } else if last_event.elapsed() > stall_timeout {
+            return SessionEnd::Stalled;
+        }
+        if last_stall_check.elapsed() >= Duration::from_secs(1) {
+            last_stall_check = Instant::now();
+            let stalled = capture.expire_stalled(Utc::now());
+            if !stalled.is_empty() {
+                let symbols: Vec<String> = stalled.iter().map(ToString::to_string).collect();
+                warn!(
+                    exchange = %report.exchange,
+                    symbols = %symbols.join(","),
+                    "no order book updates within the stall timeout; other symbols keep recording"
+                );
+            }
+        }
+        if last_stats.elapsed() >= STATS_INTERVAL {
+            last_stats = Instant::now();
+            let book = capture.stats();
+            info!(
+                exchange = %report.exchange,
+                ticks = report.ticks,
+                candles = report.candles,
+                market_by_order = report.market_by_order,
+                snapshots = book.snapshots,
+                deltas = book.deltas,
+                gaps = book.gaps,
+                stalls = book.stalls,
+                reconnects = report.reconnects,
+                "recorder progress"
+            );
+        }
+        if !emitted && !shutdown.sleep(IDLE_POLL).await {
+            return SessionEnd::Shutdown;
+        }
+    }
+}
+
+enum Polled<T> {
+    Event(T),
+    Idle,
+    Failed(String),
+    Shutdown,
+}
+
+async fn poll_next<T>(
+    next: impl Future<Output = BrokerResult<Option<T>>>,
+    shutdown: &ShutdownSignal,
+) -> Polled<T> {
+    tokio::select! {
+        res = tokio::time::timeout(POLL_TIMEOUT, next) => match res {
+            Ok(Ok(Some(event))) => Polled::Event(event),
+            Ok(Ok(None)) | Err(_) => Polled::Idle,
+            Ok(Err(err)) => Polled::Failed(err.to_string()),
+        },
+        _ = shutdown.wait() => Polled::Shutdown,
+    }
+}
@@ -0,0 +1,574 @@
+//! Mid-session strategy reconfiguration and replacement.
+//!
+//! The strategy TOML a live session was started with can be re-applied without a restart, either
+//! on demand through the control plane's `ReloadStrategy` RPC or automatically by polling the
+//! file for changes. The new parameters are validated against a scratch instance before the
+//! live strategy's [`Strategy::configure`](tesser_strategy::Strategy::configure) is called, and
+//! its [`snapshot`](tesser_strategy::Strategy::snapshot) state is carried across the change.
+//!
+//! `SwapStrategy` goes further and replaces the running instance with a freshly built one (a
+//! different registry strategy, or a new build behind an RPC endpoint). A dry run replays the
+//! buffered candles through the candidate and compares its signals with those the running
+//! strategy actually raised, without switching. Every applied change is published on the event
+//! bus and written to the ledger.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tesser_core::{AssetId, Candle, Signal, SignalKind, Symbol};
+use tesser_events::{Event, EventBus, StrategyUpdateEvent};
+use tesser_ledger::{LedgerEntry, LedgerType};
+use tesser_strategy::{Strategy, StrategyContext};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::live::{OmsHandle, ShutdownSignal, StrategyHandle};
+
+/// Ledger reference ids of entries recording parameter changes and swaps.
+const PARAMS_LEDGER_REFERENCE: &str = "strategy_params";
+const SWAP_LEDGER_REFERENCE: &str = "strategy_swap";
+/// Mismatches listed in a [`SignalComparison`]; the counts cover all of them.
+const MAX_DIVERGENCES: usize = 20;
+
+/// Contents of a strategy config file: the registry name and its parameter table.
+#[derive(Clone, Debug, Deserialize)]
+pub struct StrategyDefinition {
+    #[serde(rename = "strategy_name")]
+    pub name: String,
+    #[serde(default = "empty_table")]
+    pub params: toml::Value,
+}
+
+fn empty_table() -> toml::Value {
+    toml::Value::Table(Default::default())
+}
+
+impl StrategyDefinition {
+    pub fn parse(contents: &str) -> Result<Self> {
+        toml::from_str(contents).context("failed to parse strategy config file")
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        Self::parse(&contents)
+    }
+}
+
+/// How a live session picks up strategy parameter changes.
+#[derive(Clone, Debug, Default)]
+pub struct StrategyReloadSettings {
+    /// Strategy config file re-read by `ReloadStrategy` and the file watcher.
+    pub path: Option<PathBuf>,
+    /// Poll `path` at this interval and apply changes automatically.
+    pub watch_interval: Option<Duration>,
+}
+
+/// What triggered a parameter change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReloadSource {
+    Rpc,
+    FileWatch,
+}
+
+impl ReloadSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Rpc => "rpc",
+            Self::FileWatch => "file_watch",
+        }
+    }
+}
+
+/// Parameters applied to the running strategy.
+#[derive(Clone, Debug)]
+pub struct StrategyParamsChange {
+    pub strategy: String,
+    pub source: ReloadSource,
+    /// Parameters in effect before the change, when known.
+    pub previous: Option<toml::Value>,
+    pub params: toml::Value,
+    /// Strategy that was replaced, for swaps.
+    pub replaced: Option<String>,
+}
+
+/// Outcome of a (possibly dry-run) strategy swap.
+#[derive(Clone, Debug)]
+pub struct StrategySwap {
+    pub previous: String,
+    pub strategy: String,
+    pub swapped: bool,
+    pub comparison: SignalComparison,
+}
+
+/// A signal reduced to what is compared between strategies.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignalRecord {
+    /// Timestamp of the candle that produced the signal.
+    pub timestamp: DateTime<Utc>,
+    pub symbol: Symbol,
+    pub kind: SignalKind,
+}
+
+impl SignalRecord {
+    pub fn new(timestamp: DateTime<Utc>, signal: &Signal) -> Self {
+        Self {
+            timestamp,
+            symbol: signal.symbol,
+            kind: signal.kind,
+        }
+    }
+
+    fn key(&self) -> (DateTime<Utc>, String, String) {
+        (
+            self.timestamp,
+            self.symbol.to_string(),
+            format!("{:?}", self.kind),
+        )
+    }
+}
+
+/// Signals of the running strategy versus a candidate over the same candles.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SignalComparison {
+    pub candles: usize,
+    pub current_signals: usize,
+    pub candidate_signals: usize,
+    pub matched: usize,
+    /// Up to [`MAX_DIVERGENCES`] mismatches in timestamp order.
+    pub divergences: Vec<String>,
+}
+
+impl SignalComparison {
+    /// Match signals by candle timestamp, symbol and kind.
+    pub fn compare(candles: usize, current: &[SignalRecord], candidate: &[SignalRecord]) -> Self {
+        let mut unmatched: BTreeMap<_, usize> = BTreeMap::new();
+        for record in current {
+            *unmatched.entry(record.key()).or_default() += 1;
+        }
+        let mut matched = 0;
+        let mut divergences = Vec::new();
+        for record in candidate {
+            match unmatched.get_mut(&record.key()) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    matched += 1;
+                }
+                _ => divergences.push((record.key(), "candidate only")),
+            }
+        }
+        for (key, count) in unmatched {
+            divergences.extend(std::iter::repeat_n((key, "current only"), count));
+        }
+        divergences.sort();
+        Self {
+            candles,
+            current_signals: current.len(),
+            candidate_signals: candidate.len(),
+            matched,
+            divergences: divergences
+                .into_iter()
+                .take(MAX_DIVERGENCES)
+                .map(|((timestamp, symbol, kind), side)| {
+                    format!("{} {symbol} {kind}: {side}", timestamp.to_rfc3339())
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Replays `candles` through `candidate` on `ctx`, pushing each candle before the strategy
+/// handles it so history reads never see later bars.
+///
+/// Returns how many candles fall at or after `live_since` and the signals raised on them.
+pub(crate) async fn replay_candidate(
+    candidate: &mut dyn Strategy,
+    mut ctx: StrategyContext,
+    candles: &[Candle],
+    live_since: Option<DateTime<Utc>>,
+) -> Result<(usize, Vec<SignalRecord>)> {
+    let mut replayed = Vec::new();
+    let mut compared = 0;
+    for candle in candles {
+        ctx.push_candle(candle.clone());
+        candidate
+            .on_candle(&ctx, candle)
+            .await
+            .context("candidate strategy failed during replay")?;
+        let signals = candidate.drain_signals();
+        if live_since.is_some_and(|since| candle.timestamp >= since) {
+            compared += 1;
+            replayed.extend(
+                signals
+                    .iter()
+                    .map(|signal| SignalRecord::new(candle.timestamp, signal)),
+            );
+        }
+    }
+    Ok((compared, replayed))
+}
+
+/// Applies parameter changes to the live strategy and records them.
+pub struct StrategyReloader {
+    strategy: StrategyHandle,
+    oms: OmsHandle,
+    bus: Arc<EventBus>,
+    path: Option<PathBuf>,
+    ledger_asset: AssetId,
+    /// Last applied parameters; the lock also serializes concurrent reloads.
+    current: Mutex<Option<toml::Value>>,
+}
+
+impl StrategyReloader {
+    pub fn new(
+        settings: &StrategyReloadSettings,
+        strategy: StrategyHandle,
+        oms: OmsHandle,
+        bus: Arc<EventBus>,
+        ledger_asset: AssetId,
+    ) -> Self {
+        let current = settings
+            .path
+            .as_deref()
+            .and_then(|path| StrategyDefinition::load(path).ok())
+            .map(|definition| definition.params);
+        Self {
+            strategy,
+            oms,
+            bus,
+            path: settings.path.clone(),
+            ledger_asset,
+            current: Mutex::new(current),
+        }
+    }
+
+    /// Load the session's strategy config file.
+    pub fn load_file(&self) -> Result<StrategyDefinition> {
+        let path = self
+            .path
+            .as_deref()
+            .ok_or_else(|| anyhow!("live session was started without a strategy config file"))?;
+        StrategyDefinition::load(path)
+    }
+
+    /// Re-read the session's strategy config file and apply it.
+    pub async fn reload_file(&self, source: ReloadSource) -> Result<StrategyParamsChange> {
+        self.apply(self.load_file()?, source).await
+    }
+
+    /// Validate `definition` and configure the running strategy with it.
+    pub async fn apply(
+        &self,
+        definition: StrategyDefinition,
+        source: ReloadSource,
+    ) -> Result<StrategyParamsChange> {
+        let mut current = self.current.lock().await;
+        let params = definition.params.clone();
+        let strategy = self.strategy.reconfigure(definition).await?;
+        let change = StrategyParamsChange {
+            strategy,
+            source,
+            previous: current.replace(params.clone()),
+            params,
+            replaced: None,
+        };
+        drop(current);
+        self.record(&change).await;
+        Ok(change)
+    }
+
+    /// Replace the running strategy with a fresh instance built from `definition`.
+    ///
+    /// With `dry_run` the candidate is only compared against the running strategy.
+    pub async fn swap(
+        &self,
+        definition: StrategyDefinition,
+        dry_run: bool,
+        source: ReloadSource,
+    ) -> Result<StrategySwap> {
+        let mut current = self.current.lock().await;
+        let params = definition.params.clone();
+        let swap = self.strategy.swap(definition, dry_run).await?;
+        info!(
+            previous = %swap.previous,
+            candidate = %swap.strategy,
+            dry_run,
+            candles = swap.comparison.candles,
+            current_signals = swap.comparison.current_signals,
+            candidate_signals = swap.comparison.candidate_signals,
+            matched = swap.comparison.matched,
+            "compared candidate strategy signals"
+        );
+        if !swap.swapped {
+            return Ok(swap);
+        }
+        let change = StrategyParamsChange {
+            strategy: swap.strategy.clone(),
+            source,
+            previous: current.replace(params.clone()),
+            params,
+            replaced: Some(swap.previous.clone()),
+        };
+        drop(current);
+        self.record(&change).await;
+        Ok(swap)
+    }
+
+    async fn record(&self, change: &StrategyParamsChange) {
+        let params = toml_to_json(&change.params);
+        info!(
+            strategy = %change.strategy,
+            replaced = ?change.replaced,
+            source = change.source.as_str(),
+            params = %params,
+            "strategy parameters updated"
+        );
+        self.bus.publish(Event::StrategyUpdate(StrategyUpdateEvent {
+            strategy: change.strategy.clone(),
+            source: change.source.as_str().to_string(),
+            params_json: params.to_string(),
+            replaced: change.replaced.clone(),
+        }));
+        let (component, reference) = if change.replaced.is_some() {
+            ("strategy_swap", SWAP_LEDGER_REFERENCE)
+        } else {
+            ("strategy_params", PARAMS_LEDGER_REFERENCE)
+        };
+        // Zero-amount adjustment so the change sits in the ledger timeline next to the fills
+        // it affects.
+        let mut entry = LedgerEntry::new(
+            self.ledger_asset.exchange,
+            self.ledger_asset,
+            Decimal::ZERO,
+            LedgerType::Adjustment,
+            reference,
+        );
+        entry.meta = Some(json!({
+            "component": component,
+            "strategy": change.strategy,
+            "replaced": change.replaced,
+            "source": change.source.as_str(),
+            "previous": change.previous.as_ref().map(toml_to_json),
+            "params": params,
+        }));
+        if let Err(err) = self.oms.record_ledger_entry(entry).await {
+            warn!(error = %err, "failed to record strategy change in ledger");
+        }
+    }
+
+    /// Poll the strategy config file and apply it whenever its contents change.
+    pub fn spawn_watcher(
+        self: Arc<Self>,
+        interval: Duration,
+        shutdown: ShutdownSignal,
+    ) -> Option<JoinHandle<()>> {
+        let path = self.path.clone()?;
+        info!(path = %path.display(), ?interval, "watching strategy config for changes");
+        Some(tokio::spawn(async move {
+            let mut last_seen = std::fs::read_to_string(&path).ok();
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {}
+                    _ = shutdown.wait() => break,
+                }
+                let contents = match tokio::fs::read_to_string(&path).await {
+                    Ok(contents) => contents,
+                    Err(err) => {
+                        warn!(path = %path.display(), error = %err, "failed to read strategy config");
+                        continue;
+                    }
+                };
+                if last_seen.as_deref() == Some(contents.as_str()) {
+                    continue;
+                }
+                last_seen = Some(contents.clone());
+                let result = match StrategyDefinition::parse(&contents) {
+                    Ok(definition) => self.apply(definition, ReloadSource::FileWatch).await,
+                    Err(err) => Err(err),
+                };
+                if let Err(err) = result {
+                    warn!(
+                        path = %path.display(),
+                        error = %format!("{err:#}"),
+                        "rejected strategy config change; keeping current parameters"
+                    );
+                }
+            }
+        }))
+    }
+}
+
+pub fn toml_to_json(value: &toml::Value) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use rust_decimal::Decimal;
+    use tesser_core::{Fill, Interval, Tick};
+    use tesser_strategy::StrategyResult;
+
+    /// Goes long after a higher close and exits after a lower one, reading the previous close
+    /// from the context history.
+    #[derive(Default)]
+    struct MomentumStrategy {
+        pending: Vec<Signal>,
+    }
+
+    #[async_trait]
+    impl Strategy for MomentumStrategy {
+        fn name(&self) -> &str {
+            "momentum-test"
+        }
+
+        fn symbol(&self) -> Symbol {
+            Symbol::from("BTCUSDT")
+        }
+
+        fn configure(&mut self, _params: toml::Value) -> StrategyResult<()> {
+            Ok(())
+        }
+
+        async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
+            Ok(())
+        }
+
+        async fn on_candle(
+            &mut self,
+            ctx: &StrategyContext,
+            candle: &Candle,
+        ) -> StrategyResult<()> {
+            let previous = ctx
+                .candles_for(candle.symbol)
+                .and_then(|history| history.iter().rev().nth(1));
+            if let Some(previous) = previous {
+                let kind = if candle.close > previous.close {
+                    SignalKind::EnterLong
+                } else {
+                    SignalKind::ExitLong
+                };
+                self.pending.push(Signal::new(candle.symbol, kind, 1.0));
+            }
+            Ok(())
+        }
+
+        async fn on_fill(&mut self, _ctx: &StrategyContext, _fill: &Fill) -> StrategyResult<()> {
+            Ok(())
+        }
+
+        fn drain_signals(&mut self) -> Vec<Signal> {
+            std::mem::take(&mut self.pending)
+        }
+    }
+
+    #[test]
+    fn parses_definition_with_default_params() {
+        let definition = StrategyDefinition::parse("strategy_name = \"SmaCross\"").unwrap();
+        assert_eq!(definition.name, "SmaCross");
+        assert!(definition.params.as_table().unwrap().is_empty());
+
+        let definition = StrategyDefinition::parse(
+            "strategy_name = \"SmaCross\"\n[params]\nfast_period = 5\nsymbol = \"BTCUSDT\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            toml_to_json(&definition.params),
+            json!({ "fast_period": 5, "symbol": "BTCUSDT" })
+        );
+    }
+
+    #[test]
+    fn comparison_matches_signals_by_candle_symbol_and_kind() {
+        let t0 = Utc::now();
+        let t1 = t0 + chrono::Duration::minutes(1);
+        let record = |timestamp, kind| SignalRecord {
+            timestamp,
+            symbol: Symbol::from("BTCUSDT"),
+            kind,
+        };
+        let current = vec![
+            record(t0, SignalKind::EnterLong),
+            record(t1, SignalKind::ExitLong),
+        ];
+        let candidate = vec![
+            record(t0, SignalKind::EnterLong),
+            record(t1, SignalKind::EnterShort),
+        ];
+        let comparison = SignalComparison::compare(2, &current, &candidate);
+        assert_eq!(comparison.matched, 1);
+        assert_eq!(comparison.current_signals, 2);
+        assert_eq!(comparison.candidate_signals, 2);
+        assert_eq!(comparison.divergences.len(), 2);
+        assert!(comparison.divergences[0].ends_with("EnterShort: candidate only"));
+        assert!(comparison.divergences[1].ends_with("ExitLong: current only"));
+
+        let identical = SignalComparison::compare(2, &current, &current);
+        assert_eq!(identical.matched, 2);
+        assert!(identical.divergences.is_empty());
+    }
+
+    #[tokio::test]
+    async fn candidate_replay_only_sees_history_up_to_each_candle() {
+        let symbol = Symbol::from("BTCUSDT");
+        let t0 = Utc::now();
+        let at = |minutes| t0 + chrono::Duration::minutes(minutes);
+        let candles: Vec<Candle> = [1, 2, 3, 2]
+            .into_iter()
+            .zip(0..)
+            .map(|(close, minutes)| {
+                let price = Decimal::from(close);
+                Candle {
+                    symbol,
+                    interval: Interval::OneMinute,
+                    open: price,
+                    high: price,
+                    low: price,
+                    close: price,
+                    volume: Decimal::ONE,
+                    timestamp: at(minutes),
+                }
+            })
+            .collect();
+        let mut candidate = MomentumStrategy::default();
+        // The buffer already holds every candle, as the live context does at swap time.
+        let (compared, replayed) = replay_candidate(
+            &mut candidate,
+            StrategyContext::new(candles.len()),
+            &candles,
+            Some(at(1)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(compared, 3);
+
+        let record = |minutes, kind| SignalRecord {
+            timestamp: at(minutes),
+            symbol,
+            kind,
+        };
+        let current = vec![
+            record(1, SignalKind::EnterLong),
+            record(2, SignalKind::EnterLong),
+            record(3, SignalKind::EnterShort),
+        ];
+        let comparison = SignalComparison::compare(compared, &current, &replayed);
+        assert_eq!(comparison.candles, 3);
+        assert_eq!(comparison.candidate_signals, 3);
+        assert_eq!(comparison.matched, 2);
+        assert_eq!(comparison.divergences.len(), 2);
+        assert!(comparison.divergences[0].ends_with("EnterShort: current only"));
+        assert!(comparison.divergences[1].ends_with("ExitLong: candidate only"));
+    }
+}
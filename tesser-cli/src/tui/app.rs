@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::str::FromStr;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
@@ -8,12 +9,15 @@ use tesser_rpc::proto::{
     self, CancelAllResponse, Event, GetStatusResponse, OrderSnapshot, PortfolioSnapshot,
 };
 
+use crate::control::client::ControlClientOptions;
+
 const LOG_CAPACITY: usize = 200;
 
 #[derive(Clone)]
 pub struct MonitorConfig {
     pub control_addr: String,
     pub tick_rate: Duration,
+    pub client: ControlClientOptions,
 }
 
 impl MonitorConfig {
@@ -21,8 +25,14 @@ impl MonitorConfig {
         Self {
             control_addr,
             tick_rate,
+            client: ControlClientOptions::default(),
         }
     }
+
+    pub fn with_client_options(mut self, client: ControlClientOptions) -> Self {
+        self.client = client;
+        self
+    }
 }
 
 pub struct MonitorApp {
@@ -249,6 +259,46 @@ impl MonitorApp {
             .unwrap_or(false)
     }
 
+    pub fn begin_prompt(&mut self) {
+        self.overlay = CommandOverlay::Prompt {
+            buffer: String::new(),
+        };
+        self.overlay_error = None;
+    }
+
+    pub fn append_prompt_char(&mut self, ch: char) {
+        if let CommandOverlay::Prompt { buffer } = &mut self.overlay {
+            buffer.push(ch);
+        }
+    }
+
+    pub fn backspace_prompt(&mut self) {
+        if let CommandOverlay::Prompt { buffer } = &mut self.overlay {
+            buffer.pop();
+        }
+    }
+
+    pub fn prompt_buffer(&self) -> Option<&str> {
+        match &self.overlay {
+            CommandOverlay::Prompt { buffer } => Some(buffer.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn liquidate_only(&self) -> bool {
+        self.status
+            .as_ref()
+            .map(|status| status.liquidate_only)
+            .unwrap_or(false)
+    }
+
+    pub fn strategy_paused(&self) -> bool {
+        self.status
+            .as_ref()
+            .map(|status| status.strategy_paused)
+            .unwrap_or(false)
+    }
+
     pub fn set_overlay_error(&mut self, msg: impl Into<String>) {
         self.overlay_error = Some(msg.into());
     }
@@ -357,6 +407,22 @@ impl LogEntry {
                     decimal_to_string(order.avg_fill_price.as_ref())
                 ),
             }),
+            Payload::StrategyUpdate(update) if !update.replaced_strategy.is_empty() => Some(Self {
+                timestamp,
+                category: LogCategory::Info,
+                message: format!(
+                    "Strategy {} replaced by {} via {}: {}",
+                    update.replaced_strategy, update.strategy, update.source, update.params_json
+                ),
+            }),
+            Payload::StrategyUpdate(update) => Some(Self {
+                timestamp,
+                category: LogCategory::Info,
+                message: format!(
+                    "Strategy {} reconfigured via {}: {}",
+                    update.strategy, update.source, update.params_json
+                ),
+            }),
             _ => None,
         }
     }
@@ -408,4 +474,190 @@ pub enum CommandOverlay {
     Hidden,
     Palette,
     Confirm { buffer: String },
+    Prompt { buffer: String },
+}
+
+/// Operator command typed into the monitor prompt.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ControlCommand {
+    PlaceOrder {
+        symbol: String,
+        side: proto::Side,
+        quantity: Decimal,
+        price: Option<Decimal>,
+    },
+    CancelOrder {
+        order_id: String,
+    },
+    CancelAlgo {
+        algo_id: String,
+    },
+    Flatten {
+        symbol: String,
+    },
+    SetLiquidateOnly(bool),
+    SetPaused(bool),
+    UpdateRiskLimits {
+        max_order_quantity: Option<Decimal>,
+        max_position_quantity: Option<Decimal>,
+        max_order_notional: Option<Decimal>,
+    },
+}
+
+pub const COMMAND_HELP: &[&str] = &[
+    "buy|sell SYMBOL QTY [PRICE]   market order, or limit when PRICE is given",
+    "cancel ORDER_ID               cancel one open order",
+    "cancel-algo ALGO_ID           cancel an execution algorithm",
+    "flatten SYMBOL                cancel orders and close the position",
+    "liquidate on|off              toggle liquidate-only mode",
+    "pause | resume                stop or restart strategy signals",
+    "risk order_qty=N position_qty=N notional=N   update limits (0 disables)",
+];
+
+impl ControlCommand {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut parts = input.split_whitespace();
+        let Some(verb) = parts.next() else {
+            return Err("empty command".into());
+        };
+        let args: Vec<&str> = parts.collect();
+        let command = match verb.to_ascii_lowercase().as_str() {
+            "buy" | "sell" => {
+                let side = if verb.eq_ignore_ascii_case("buy") {
+                    proto::Side::Buy
+                } else {
+                    proto::Side::Sell
+                };
+                let (symbol, quantity, price) = match args.as_slice() {
+                    [symbol, qty] => (symbol, parse_decimal("quantity", qty)?, None),
+                    [symbol, qty, price] => (
+                        symbol,
+                        parse_decimal("quantity", qty)?,
+                        Some(parse_decimal("price", price)?),
+                    ),
+                    _ => return Err(format!("usage: {verb} SYMBOL QTY [PRICE]")),
+                };
+                if quantity <= Decimal::ZERO {
+                    return Err("quantity must be positive".into());
+                }
+                Self::PlaceOrder {
+                    symbol: symbol.to_string(),
+                    side,
+                    quantity,
+                    price,
+                }
+            }
+            "cancel" => match args.as_slice() {
+                [order_id] => Self::CancelOrder {
+                    order_id: order_id.to_string(),
+                },
+                _ => return Err("usage: cancel ORDER_ID".into()),
+            },
+            "cancel-algo" => match args.as_slice() {
+                [algo_id] => Self::CancelAlgo {
+                    algo_id: algo_id.to_string(),
+                },
+                _ => return Err("usage: cancel-algo ALGO_ID".into()),
+            },
+            "flatten" => match args.as_slice() {
+                [symbol] => Self::Flatten {
+                    symbol: symbol.to_string(),
+                },
+                _ => return Err("usage: flatten SYMBOL".into()),
+            },
+            "liquidate" => match args.as_slice() {
+                [mode] if mode.eq_ignore_ascii_case("on") => Self::SetLiquidateOnly(true),
+                [mode] if mode.eq_ignore_ascii_case("off") => Self::SetLiquidateOnly(false),
+                _ => return Err("usage: liquidate on|off".into()),
+            },
+            "pause" if args.is_empty() => Self::SetPaused(true),
+            "resume" if args.is_empty() => Self::SetPaused(false),
+            "risk" => {
+                if args.is_empty() {
+                    return Err("usage: risk order_qty=N position_qty=N notional=N".into());
+                }
+                let mut max_order_quantity = None;
+                let mut max_position_quantity = None;
+                let mut max_order_notional = None;
+                for arg in args {
+                    let (key, value) = arg
+                        .split_once('=')
+                        .ok_or_else(|| format!("expected key=value, got '{arg}'"))?;
+                    let value = parse_decimal(key, value)?;
+                    if value < Decimal::ZERO {
+                        return Err(format!("{key} must not be negative"));
+                    }
+                    match key {
+                        "order_qty" => max_order_quantity = Some(value),
+                        "position_qty" => max_position_quantity = Some(value),
+                        "notional" => max_order_notional = Some(value),
+                        other => return Err(format!("unknown risk limit '{other}'")),
+                    }
+                }
+                Self::UpdateRiskLimits {
+                    max_order_quantity,
+                    max_position_quantity,
+                    max_order_notional,
+                }
+            }
+            _ => return Err(format!("unknown command '{input}'")),
+        };
+        Ok(command)
+    }
+}
+
+fn parse_decimal(label: &str, value: &str) -> Result<Decimal, String> {
+    Decimal::from_str(value).map_err(|_| format!("invalid {label} '{value}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_order_commands() {
+        assert_eq!(
+            ControlCommand::parse("buy BTCUSDT 0.5").unwrap(),
+            ControlCommand::PlaceOrder {
+                symbol: "BTCUSDT".into(),
+                side: proto::Side::Buy,
+                quantity: Decimal::new(5, 1),
+                price: None,
+            }
+        );
+        assert_eq!(
+            ControlCommand::parse("SELL bybit:ETHUSDT 2 3100").unwrap(),
+            ControlCommand::PlaceOrder {
+                symbol: "bybit:ETHUSDT".into(),
+                side: proto::Side::Sell,
+                quantity: Decimal::from(2),
+                price: Some(Decimal::from(3100)),
+            }
+        );
+        assert!(ControlCommand::parse("buy BTCUSDT -1").is_err());
+        assert!(ControlCommand::parse("buy BTCUSDT").is_err());
+    }
+
+    #[test]
+    fn parses_control_toggles_and_limits() {
+        assert_eq!(
+            ControlCommand::parse("liquidate off").unwrap(),
+            ControlCommand::SetLiquidateOnly(false)
+        );
+        assert_eq!(
+            ControlCommand::parse("pause").unwrap(),
+            ControlCommand::SetPaused(true)
+        );
+        assert_eq!(
+            ControlCommand::parse("risk order_qty=1.5 notional=0").unwrap(),
+            ControlCommand::UpdateRiskLimits {
+                max_order_quantity: Some(Decimal::new(15, 1)),
+                max_position_quantity: None,
+                max_order_notional: Some(Decimal::ZERO),
+            }
+        );
+        assert!(ControlCommand::parse("risk leverage=3").is_err());
+        assert!(ControlCommand::parse("liquidate maybe").is_err());
+        assert!(ControlCommand::parse("").is_err());
+    }
 }
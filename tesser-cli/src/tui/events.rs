@@ -2,7 +2,6 @@ use std::time::Duration;
 
 use crossterm::event::{Event as CrosstermEvent, EventStream, KeyEvent, KeyEventKind};
 use futures::StreamExt;
-use tesser_rpc::proto::control_service_client::ControlServiceClient;
 use tesser_rpc::proto::{
     Event, GetOpenOrdersRequest, GetPortfolioRequest, GetStatusRequest, GetStatusResponse,
     MonitorRequest, OrderSnapshot, PortfolioSnapshot,
@@ -10,7 +9,8 @@ use tesser_rpc::proto::{
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio::time::{interval, sleep, MissedTickBehavior};
-use tonic::transport::Channel;
+
+use crate::control::client::ControlClient;
 
 #[derive(Debug)]
 pub enum MonitorEvent {
@@ -50,7 +50,7 @@ pub fn spawn_input_listener(tx: mpsc::Sender<MonitorEvent>) -> JoinHandle<()> {
 }
 
 pub fn spawn_snapshot_poller(
-    client: ControlServiceClient<Channel>,
+    client: ControlClient,
     tx: mpsc::Sender<MonitorEvent>,
 ) -> JoinHandle<()> {
     tokio::spawn(async move {
@@ -127,7 +127,7 @@ pub fn spawn_snapshot_poller(
 }
 
 pub fn spawn_monitor_stream(
-    client: ControlServiceClient<Channel>,
+    client: ControlClient,
     tx: mpsc::Sender<MonitorEvent>,
 ) -> JoinHandle<()> {
     tokio::spawn(async move {
@@ -2,7 +2,7 @@ mod app;
 mod events;
 mod ui;
 
-use app::CommandOverlay;
+use app::{CommandOverlay, ControlCommand};
 pub use app::{LogCategory, LogEntry, MonitorApp, MonitorConfig};
 
 use anyhow::{anyhow, Context, Result};
@@ -13,29 +13,32 @@ use crossterm::{
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io::{stdout, Stdout};
-use tesser_rpc::proto::control_service_client::ControlServiceClient;
-use tesser_rpc::proto::CancelAllRequest;
+use tesser_rpc::conversions::to_decimal_proto;
+use tesser_rpc::proto::{
+    self, CancelAlgoRequest, CancelAllRequest, CancelOrderRequest, FlattenPositionRequest,
+    PlaceOrderRequest, SetLiquidateOnlyRequest, SetStrategyPausedRequest, UpdateRiskLimitsRequest,
+};
 use tokio::sync::mpsc;
 use tokio::time::{interval, sleep, Duration, MissedTickBehavior};
-use tonic::transport::Channel;
 
+use crate::control::client::{ControlClient, ControlClientOptions};
 use crate::tui::events::MonitorEvent;
 
 pub async fn run_monitor(config: MonitorConfig) -> Result<()> {
-    let endpoint = normalize_endpoint(&config.control_addr);
-    let client = connect_with_retry(&endpoint).await?;
+    let endpoint = config.client.endpoint(&config.control_addr);
+    let client = connect_with_retry(&endpoint, &config.client).await?;
     let mut terminal = setup_terminal().context("failed to setup terminal")?;
     let result = run_loop(&mut terminal, client, config.clone()).await;
     teardown_terminal(&mut terminal)?;
     result
 }
 
-async fn connect_with_retry(target: &str) -> Result<ControlServiceClient<Channel>> {
+async fn connect_with_retry(target: &str, options: &ControlClientOptions) -> Result<ControlClient> {
     const MAX_ATTEMPTS: usize = 30;
     const BACKOFF: Duration = Duration::from_millis(250);
     let mut last_err = None;
     for _ in 0..MAX_ATTEMPTS {
-        match ControlServiceClient::connect(target.to_string()).await {
+        match options.connect(target).await {
             Ok(client) => return Ok(client),
             Err(err) => {
                 last_err = Some(err);
@@ -69,13 +72,13 @@ fn teardown_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Resul
 
 async fn run_loop(
     terminal: &mut Terminal<CrosstermBackend<Stdout>>,
-    base_client: ControlServiceClient<Channel>,
+    base_client: ControlClient,
     config: MonitorConfig,
 ) -> Result<()> {
     let mut app = MonitorApp::new(config.clone());
     let poll_client = base_client.clone();
     let stream_client = base_client.clone();
-    let mut cancel_client = base_client;
+    let mut control_client = base_client;
 
     let (tx, mut rx) = mpsc::channel(512);
     events::spawn_input_listener(tx.clone());
@@ -95,7 +98,7 @@ async fn run_loop(
         tokio::select! {
             _ = ticker.tick() => {}
             Some(event) = rx.recv() => {
-                handle_event(event, &mut app, &mut cancel_client).await?;
+                handle_event(event, &mut app, &mut control_client).await?;
             }
             _ = &mut ctrl_c => {
                 app.request_quit();
@@ -113,10 +116,10 @@ async fn run_loop(
 async fn handle_event(
     event: MonitorEvent,
     app: &mut MonitorApp,
-    cancel_client: &mut ControlServiceClient<Channel>,
+    control_client: &mut ControlClient,
 ) -> Result<()> {
     match event {
-        MonitorEvent::Input(key) => handle_key_event(key, app, cancel_client).await?,
+        MonitorEvent::Input(key) => handle_key_event(key, app, control_client).await?,
         MonitorEvent::Status(status) => app.on_status(status),
         MonitorEvent::Portfolio(snapshot) => app.on_portfolio(snapshot),
         MonitorEvent::Orders(orders) => app.on_orders(orders),
@@ -131,9 +134,9 @@ async fn handle_event(
 async fn handle_key_event(
     key: KeyEvent,
     app: &mut MonitorApp,
-    cancel_client: &mut ControlServiceClient<Channel>,
+    control_client: &mut ControlClient,
 ) -> Result<()> {
-    if handle_overlay_key(key, app, cancel_client).await? {
+    if handle_overlay_key(key, app, control_client).await? {
         return Ok(());
     }
     match key.code {
@@ -164,7 +167,7 @@ async fn handle_key_event(
 async fn handle_overlay_key(
     key: KeyEvent,
     app: &mut MonitorApp,
-    cancel_client: &mut ControlServiceClient<Channel>,
+    control_client: &mut ControlClient,
 ) -> Result<bool> {
     use crossterm::event::KeyCode;
     match app.overlay() {
@@ -175,6 +178,24 @@ async fn handle_overlay_key(
                     app.begin_cancel_confirmation();
                     app.record_info("Confirm cancel-all by typing 'cancel all'");
                 }
+                KeyCode::Char('l') | KeyCode::Char('L') => {
+                    app.close_overlay();
+                    let enabled = !app.liquidate_only();
+                    execute_command(
+                        app,
+                        control_client,
+                        ControlCommand::SetLiquidateOnly(enabled),
+                    )
+                    .await;
+                }
+                KeyCode::Char('p') | KeyCode::Char('P') => {
+                    app.close_overlay();
+                    let paused = !app.strategy_paused();
+                    execute_command(app, control_client, ControlCommand::SetPaused(paused)).await;
+                }
+                KeyCode::Char(':') | KeyCode::Char('o') | KeyCode::Char('O') => {
+                    app.begin_prompt();
+                }
                 KeyCode::Esc | KeyCode::Char('m') | KeyCode::Char('M') => {
                     app.close_overlay();
                 }
@@ -193,7 +214,7 @@ async fn handle_overlay_key(
                 KeyCode::Enter => {
                     if app.confirmation_matches() {
                         app.close_overlay();
-                        trigger_cancel_all(app, cancel_client).await?;
+                        trigger_cancel_all(app, control_client).await?;
                     } else {
                         app.set_overlay_error("Type 'cancel all' exactly to proceed.");
                     }
@@ -209,19 +230,151 @@ async fn handle_overlay_key(
             }
             Ok(true)
         }
+        CommandOverlay::Prompt { .. } => {
+            match key.code {
+                KeyCode::Esc => {
+                    app.close_overlay();
+                }
+                KeyCode::Backspace => {
+                    app.backspace_prompt();
+                }
+                KeyCode::Enter => {
+                    let input = app.prompt_buffer().unwrap_or_default().to_string();
+                    match ControlCommand::parse(&input) {
+                        Ok(command) => {
+                            app.close_overlay();
+                            execute_command(app, control_client, command).await;
+                        }
+                        Err(err) => app.set_overlay_error(err),
+                    }
+                }
+                KeyCode::Char(ch) => {
+                    if !key.modifiers.contains(KeyModifiers::CONTROL)
+                        && !key.modifiers.contains(KeyModifiers::ALT)
+                    {
+                        app.append_prompt_char(ch);
+                    }
+                }
+                _ => {}
+            }
+            Ok(true)
+        }
+    }
+}
+
+/// Sends an operator command to the control plane and logs the outcome in the event pane.
+async fn execute_command(
+    app: &mut MonitorApp,
+    control_client: &mut ControlClient,
+    command: ControlCommand,
+) {
+    let outcome = match command {
+        ControlCommand::PlaceOrder {
+            symbol,
+            side,
+            quantity,
+            price,
+        } => {
+            let order_type = if price.is_some() {
+                proto::OrderType::Limit
+            } else {
+                proto::OrderType::Market
+            };
+            control_client
+                .place_order(PlaceOrderRequest {
+                    symbol,
+                    side: side as i32,
+                    order_type: order_type as i32,
+                    quantity: Some(to_decimal_proto(quantity)),
+                    price: price.map(to_decimal_proto),
+                    client_order_id: String::new(),
+                })
+                .await
+                .map(|response| match response.into_inner().order {
+                    Some(order) => format!("Order {} submitted for {}", order.id, order.symbol),
+                    None => "Order submitted".to_string(),
+                })
+        }
+        ControlCommand::CancelOrder { order_id } => control_client
+            .cancel_order(CancelOrderRequest {
+                order_id: order_id.clone(),
+            })
+            .await
+            .map(|_| format!("Cancel requested for order {order_id}")),
+        ControlCommand::CancelAlgo { algo_id } => control_client
+            .cancel_algo(CancelAlgoRequest {
+                algo_id: algo_id.clone(),
+            })
+            .await
+            .map(|_| format!("Algorithm {algo_id} cancelled")),
+        ControlCommand::Flatten { symbol } => control_client
+            .flatten_position(FlattenPositionRequest {
+                symbol: symbol.clone(),
+            })
+            .await
+            .map(|response| {
+                let response = response.into_inner();
+                match response.order {
+                    Some(order) => format!(
+                        "Flattening {symbol}: cancelled {} orders, submitted {}",
+                        response.cancelled_orders, order.id
+                    ),
+                    None => format!(
+                        "{symbol} already flat; cancelled {} orders",
+                        response.cancelled_orders
+                    ),
+                }
+            }),
+        ControlCommand::SetLiquidateOnly(enabled) => control_client
+            .set_liquidate_only(SetLiquidateOnlyRequest { enabled })
+            .await
+            .map(|response| {
+                let state = if response.into_inner().liquidate_only {
+                    "enabled"
+                } else {
+                    "disabled"
+                };
+                format!("Liquidate-only {state}")
+            }),
+        ControlCommand::SetPaused(paused) => control_client
+            .set_strategy_paused(SetStrategyPausedRequest { paused })
+            .await
+            .map(|_| {
+                if paused {
+                    "Strategy paused".to_string()
+                } else {
+                    "Strategy resumed".to_string()
+                }
+            }),
+        ControlCommand::UpdateRiskLimits {
+            max_order_quantity,
+            max_position_quantity,
+            max_order_notional,
+        } => control_client
+            .update_risk_limits(UpdateRiskLimitsRequest {
+                max_order_quantity: max_order_quantity.map(to_decimal_proto),
+                max_position_quantity: max_position_quantity.map(to_decimal_proto),
+                max_order_notional: max_order_notional.map(to_decimal_proto),
+            })
+            .await
+            .map(|_| "Risk limits updated".to_string()),
+    };
+    match outcome {
+        Ok(message) => app.record_info(message),
+        Err(status) => app.set_error(format!("command failed: {}", status.message())),
     }
 }
 
 async fn trigger_cancel_all(
     app: &mut MonitorApp,
-    cancel_client: &mut ControlServiceClient<Channel>,
+    control_client: &mut ControlClient,
 ) -> Result<()> {
     if app.cancel_in_progress() {
         return Ok(());
     }
     app.set_cancel_in_progress(true);
     app.record_info("Issuing CancelAll request");
-    match cancel_client.cancel_all(CancelAllRequest {}).await {
+    match control_client.cancel_all(CancelAllRequest {}).await {
         Ok(response) => app.record_cancel_result(response.into_inner()),
         Err(err) => {
             app.set_cancel_in_progress(false);
@@ -230,11 +383,3 @@ async fn trigger_cancel_all(
     }
     Ok(())
 }
-
-fn normalize_endpoint(addr: &str) -> String {
-    if addr.starts_with("http://") || addr.starts_with("https://") {
-        addr.to_string()
-    } else {
-        format!("http://{addr}")
-    }
-}
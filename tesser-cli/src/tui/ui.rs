@@ -10,7 +10,7 @@ use rust_decimal::Decimal;
 use tesser_rpc::conversions::{from_decimal_proto, from_timestamp_proto};
 use tesser_rpc::proto;
 
-use super::app::{CommandOverlay, LogCategory, MonitorApp};
+use super::app::{CommandOverlay, LogCategory, MonitorApp, COMMAND_HELP};
 
 pub fn draw(f: &mut Frame<'_>, app: &MonitorApp) {
     let layout = Layout::default()
@@ -61,6 +61,7 @@ fn render_header(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
     let status = app.status();
     let active_algos = status.map(|s| s.active_algorithms).unwrap_or_default();
     let liquidate_only = status.map(|s| s.liquidate_only).unwrap_or(false);
+    let strategy_paused = status.map(|s| s.strategy_paused).unwrap_or(false);
     let data_timestamp = status
         .and_then(|s| s.last_data_timestamp.as_ref())
         .map(|ts| from_timestamp_proto(ts.clone()));
@@ -93,6 +94,18 @@ fn render_header(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
         } else {
             Span::styled("NORMAL", Style::default().fg(Color::Green))
         },
+        Span::raw("  "),
+        label("Strategy"),
+        if strategy_paused {
+            Span::styled(
+                "PAUSED",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Span::styled("RUNNING", Style::default().fg(Color::Green))
+        },
     ]));
 
     lines.push(Line::from(vec![
@@ -387,7 +400,13 @@ fn render_help(f: &mut Frame<'_>, area: Rect) {
         Line::from(vec![
             Span::styled("In palette: ", Style::default().fg(Color::Gray)),
             key_hint("c"),
-            Span::raw(" start Cancel-All   "),
+            Span::raw(" Cancel-All   "),
+            key_hint("l"),
+            Span::raw(" toggle liquidate-only   "),
+            key_hint("p"),
+            Span::raw(" pause/resume   "),
+            key_hint(":"),
+            Span::raw(" command prompt   "),
             key_hint("Esc"),
             Span::raw(" close palette"),
         ]),
@@ -413,13 +432,28 @@ fn render_overlay(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
     match app.overlay() {
         CommandOverlay::Hidden => {}
         CommandOverlay::Palette => {
-            let chunk = centered_rect(60, 30, area);
+            let chunk = centered_rect(60, 40, area);
             let block = Block::default()
                 .title("Command Palette")
                 .borders(Borders::ALL)
                 .style(Style::default().bg(Color::Black));
+            let liquidate_action = if app.liquidate_only() {
+                "disable"
+            } else {
+                "enable"
+            };
+            let pause_action = if app.strategy_paused() {
+                "resume"
+            } else {
+                "pause"
+            };
             let lines = vec![
                 Line::from("Press 'c' to initiate Cancel All."),
+                Line::from(format!(
+                    "Press 'l' to {liquidate_action} liquidate-only mode."
+                )),
+                Line::from(format!("Press 'p' to {pause_action} the strategy.")),
+                Line::from("Press ':' (or 'o') to type a command."),
                 Line::from("Press Esc (or 'm') to close this panel."),
             ];
             let paragraph = Paragraph::new(lines)
@@ -456,6 +490,34 @@ fn render_overlay(f: &mut Frame<'_>, area: Rect, app: &MonitorApp) {
                 .block(block);
             f.render_widget(paragraph, chunk);
         }
+        CommandOverlay::Prompt { .. } => {
+            let chunk = centered_rect(70, 50, area);
+            let mut lines: Vec<Line> = COMMAND_HELP
+                .iter()
+                .map(|help| Line::from(Span::styled(*help, Style::default().fg(Color::Gray))))
+                .collect();
+            lines.push(Line::from(""));
+            let input = app.prompt_buffer().unwrap_or_default();
+            lines.push(Line::from(vec![
+                Span::styled(": ", Style::default().fg(Color::Gray)),
+                Span::styled(input.to_string(), Style::default().fg(Color::White)),
+            ]));
+            if let Some(err) = app.overlay_error() {
+                lines.push(Line::from(Span::styled(
+                    err,
+                    Style::default().fg(Color::Red),
+                )));
+            }
+            let block = Block::default()
+                .title("Command (Enter to run, Esc to close)")
+                .borders(Borders::ALL)
+                .style(Style::default().bg(Color::Black));
+            let paragraph = Paragraph::new(lines)
+                .alignment(Alignment::Left)
+                .wrap(Wrap { trim: false })
+                .block(block);
+            f.render_widget(paragraph, chunk);
+        }
     }
 }
 
@@ -0,0 +1,455 @@
+//! Strategy warm-up from historical candles before a live session starts trading.
+//!
+//! Live sessions fetch the last `N` closed candles for every subscribed symbol, where `N` is the
+//! strategy's [`Strategy::warmup_candles`] (or an explicit override), and replay them through
+//! [`Strategy::on_candle`] with signal emission suppressed. History comes either from the
+//! exchange REST API via the `tesser-data` downloaders or from a local dataset catalog.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use tesser_broker::MarketStream;
+use tesser_core::{Candle, ExchangeId, Interval, Symbol};
+use tesser_data::catalog::{CatalogQuery, DataKind, DatasetCatalog, TimeWindow};
+use tesser_data::download::{
+    BinanceDownloader, BybitDownloader, KlineRequest, MarketDataDownloader,
+};
+use tesser_data::parquet::ParquetMarketStream;
+use tesser_strategy::{Strategy, StrategyContext};
+use tracing::{debug, info, warn};
+
+use crate::live::NamedExchange;
+
+/// Where warm-up history is loaded from.
+#[derive(Clone, Debug, Default)]
+pub enum WarmupSource {
+    /// Skip warm-up and start trading on the first live candle.
+    #[default]
+    Disabled,
+    /// Download recent candles from the exchange REST API.
+    Exchange,
+    /// Read candles from the dataset catalog rooted at the given directory.
+    Catalog(PathBuf),
+}
+
+/// Warm-up behaviour for a live session.
+#[derive(Clone, Debug, Default)]
+pub struct WarmupSettings {
+    pub source: WarmupSource,
+    /// Candles per symbol to replay; defaults to the strategy's declared lookback.
+    pub candles: Option<usize>,
+}
+
+impl WarmupSettings {
+    /// Number of candles per symbol to replay for a strategy declaring `lookback`.
+    pub fn candle_count(&self, lookback: usize) -> usize {
+        match self.source {
+            WarmupSource::Disabled => 0,
+            _ => self.candles.unwrap_or(lookback),
+        }
+    }
+}
+
+/// Loads the most recent `count` closed candles for each symbol, merged in timestamp order.
+pub async fn load_warmup_candles(
+    settings: &WarmupSettings,
+    count: usize,
+    symbols: &[Symbol],
+    exchanges: &[NamedExchange],
+    category: &str,
+    interval: Interval,
+) -> Result<Vec<Candle>> {
+    if count == 0 || symbols.is_empty() {
+        return Ok(Vec::new());
+    }
+    // Only buckets that have fully closed are replayed; the open one arrives over the stream.
+    let end = interval.align(Utc::now());
+    let start = interval
+        .offset(end, -(count as i64))
+        .map_err(|err| anyhow!(err))?;
+    let mut merged = Vec::new();
+    for symbol in symbols {
+        let candles = match &settings.source {
+            WarmupSource::Disabled => return Ok(Vec::new()),
+            WarmupSource::Exchange => {
+                let Some(exchange) = exchange_for_symbol(exchanges, symbol) else {
+                    bail!(
+                        "no exchange profile matches {symbol}; qualify the symbol with its exchange \
+                         to warm it up"
+                    );
+                };
+                match download_candles(exchange, category, *symbol, interval, start, end).await? {
+                    Some(candles) => candles,
+                    None => {
+                        warn!(
+                            exchange = %exchange.name,
+                            driver = %exchange.config.driver,
+                            "driver has no historical candle endpoint; skipping warm-up"
+                        );
+                        return Ok(Vec::new());
+                    }
+                }
+            }
+            WarmupSource::Catalog(root) => {
+                catalog_candles(root, *symbol, interval, start, end).await?
+            }
+        };
+        let candles = normalize(candles, *symbol, interval, end, count);
+        if candles.len() < count {
+            warn!(
+                symbol = %symbol.code(),
+                requested = count,
+                loaded = candles.len(),
+                "warm-up history is shorter than the strategy lookback"
+            );
+        }
+        merged.extend(candles);
+    }
+    // Stable sort keeps symbols in subscription order within a timestamp.
+    merged.sort_by_key(|candle| candle.timestamp);
+    Ok(merged)
+}
+
+/// Replays `candles` through the strategy and discards every signal it raises.
+///
+/// The strategy's snapshot is taken before the replay and restored afterwards, so bookkeeping
+/// tied to (suppressed) signals, such as open pair trades, does not leak into the live session
+/// while indicator state stays warm.
+pub async fn replay_warmup(
+    strategy: &mut dyn Strategy,
+    ctx: &mut StrategyContext,
+    candles: &[Candle],
+) -> Result<()> {
+    if candles.is_empty() {
+        return Ok(());
+    }
+    let suppressed = replay_suppressed(strategy, ctx, candles).await?;
+    info!(
+        candles = candles.len(),
+        suppressed_signals = suppressed,
+        "strategy warm-up complete"
+    );
+    Ok(())
+}
+
+/// Re-primes indicators from the candles already buffered in `ctx`, e.g. after
+/// [`Strategy::configure`] rebuilt them mid-session. Signals are suppressed as in
+/// [`replay_warmup`].
+///
+/// The candles are replayed on an empty copy of `ctx`, so each one only sees the history that
+/// preceded it.
+pub async fn replay_context(strategy: &mut dyn Strategy, ctx: &StrategyContext) -> Result<()> {
+    let candles: Vec<Candle> = ctx.candles().iter().cloned().collect();
+    if candles.is_empty() {
+        return Ok(());
+    }
+    let suppressed = replay_suppressed(strategy, &mut ctx.empty_like(), &candles).await?;
+    debug!(
+        candles = candles.len(),
+        suppressed_signals = suppressed,
+        "strategy re-primed from buffered candles"
+    );
+    Ok(())
+}
+
+async fn replay_suppressed(
+    strategy: &mut dyn Strategy,
+    ctx: &mut StrategyContext,
+    candles: &[Candle],
+) -> Result<usize> {
+    let baseline = strategy
+        .snapshot()
+        .context("failed to snapshot strategy before replay")?;
+    let mut suppressed = 0usize;
+    for candle in candles {
+        ctx.push_candle(candle.clone());
+        strategy
+            .on_candle(ctx, candle)
+            .await
+            .context("strategy failure during replay")?;
+        suppressed += strategy.drain_signals().len();
+    }
+    strategy
+        .restore(baseline)
+        .context("failed to restore strategy after replay")?;
+    Ok(suppressed)
+}
+
+/// Profile serving `symbol`: the one named after its exchange, or the only profile when the
+/// symbol carries no exchange. Anything else is ambiguous and yields `None`.
+fn exchange_for_symbol<'a>(
+    exchanges: &'a [NamedExchange],
+    symbol: &Symbol,
+) -> Option<&'a NamedExchange> {
+    if let Some(exchange) = exchanges
+        .iter()
+        .find(|exchange| ExchangeId::from(exchange.name.as_str()) == symbol.exchange)
+    {
+        return Some(exchange);
+    }
+    match exchanges {
+        [only] if !symbol.exchange.is_specified() => Some(only),
+        _ => None,
+    }
+}
+
+/// Returns `None` when the exchange driver cannot serve historical candles.
+async fn download_candles(
+    exchange: &NamedExchange,
+    category: &str,
+    symbol: Symbol,
+    interval: Interval,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Option<Vec<Candle>>> {
+    let rest_url = &exchange.config.rest_url;
+    let candles = match exchange.config.driver.as_str() {
+        "bybit" | "" => {
+            let request = KlineRequest::new(category, symbol.code(), interval, start, end);
+            BybitDownloader::new(rest_url)
+                .download_klines(&request)
+                .await
+                .with_context(|| {
+                    format!("failed to download warm-up candles from {}", exchange.name)
+                })?
+        }
+        "binance" => {
+            let request = KlineRequest::new("", symbol.code(), interval, start, end);
+            BinanceDownloader::new(rest_url)
+                .download_klines(&request)
+                .await
+                .with_context(|| {
+                    format!("failed to download warm-up candles from {}", exchange.name)
+                })?
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(candles))
+}
+
+async fn catalog_candles(
+    root: &Path,
+    symbol: Symbol,
+    interval: Interval,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<Candle>> {
+    let catalog = DatasetCatalog::open(root)?;
+    let window = TimeWindow::new(Some(start), Some(end));
+    let query = CatalogQuery {
+        symbol: Some(symbol),
+        kind: Some(DataKind::Candles),
+        interval: Some(interval.label()),
+        window,
+    };
+    let paths = catalog.files(&query);
+    if paths.is_empty() {
+        warn!(
+            symbol = %symbol.code(),
+            catalog = %root.display(),
+            "catalog has no candles in the warm-up window"
+        );
+        return Ok(Vec::new());
+    }
+    let mut stream =
+        ParquetMarketStream::with_candles(vec![symbol], paths).with_time_window(window);
+    let mut candles = Vec::new();
+    while let Some(candle) = stream
+        .next_candle()
+        .await
+        .map_err(|err| anyhow!("failed to read warm-up candles: {err}"))?
+    {
+        if candle.symbol.code().eq_ignore_ascii_case(symbol.code()) {
+            candles.push(candle);
+        }
+    }
+    Ok(candles)
+}
+
+/// Keeps the last `count` closed candles, relabelled with the live symbol and interval so the
+/// strategy routes them exactly like streamed bars.
+fn normalize(
+    mut candles: Vec<Candle>,
+    symbol: Symbol,
+    interval: Interval,
+    end: DateTime<Utc>,
+    count: usize,
+) -> Vec<Candle> {
+    candles.retain(|candle| candle.timestamp < end);
+    candles.sort_by_key(|candle| candle.timestamp);
+    candles.dedup_by_key(|candle| candle.timestamp);
+    let skip = candles.len().saturating_sub(count);
+    candles
+        .into_iter()
+        .skip(skip)
+        .map(|candle| Candle {
+            symbol,
+            interval,
+            ..candle
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::Duration;
+    use rust_decimal::Decimal;
+    use serde_json::Value;
+    use tesser_config::ExchangeConfig;
+    use tesser_core::{Fill, Signal, SignalKind, Tick};
+    use tesser_strategy::StrategyResult;
+
+    /// Signals on every candle and counts itself primed once `lookback` closes are buffered.
+    struct PrimingStrategy {
+        symbol: Symbol,
+        lookback: usize,
+        primed: bool,
+        pending: Vec<Signal>,
+    }
+
+    #[async_trait]
+    impl Strategy for PrimingStrategy {
+        fn name(&self) -> &str {
+            "priming-test"
+        }
+
+        fn symbol(&self) -> Symbol {
+            self.symbol
+        }
+
+        fn configure(&mut self, _params: toml::Value) -> StrategyResult<()> {
+            Ok(())
+        }
+
+        async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
+            Ok(())
+        }
+
+        async fn on_candle(
+            &mut self,
+            ctx: &StrategyContext,
+            candle: &Candle,
+        ) -> StrategyResult<()> {
+            self.primed = ctx
+                .candles_for(candle.symbol)
+                .is_some_and(|history| history.len() >= self.lookback);
+            self.pending
+                .push(Signal::new(candle.symbol, SignalKind::EnterLong, 1.0));
+            Ok(())
+        }
+
+        async fn on_fill(&mut self, _ctx: &StrategyContext, _fill: &Fill) -> StrategyResult<()> {
+            Ok(())
+        }
+
+        fn drain_signals(&mut self) -> Vec<Signal> {
+            std::mem::take(&mut self.pending)
+        }
+    }
+
+    fn exchange(name: &str) -> NamedExchange {
+        NamedExchange {
+            name: name.into(),
+            config: ExchangeConfig {
+                rest_url: String::new(),
+                ws_url: String::new(),
+                api_key: String::new(),
+                api_secret: String::new(),
+                driver: "bybit".into(),
+                params: Value::Null,
+            },
+        }
+    }
+
+    fn candle(symbol: Symbol, ts: DateTime<Utc>, close: i64) -> Candle {
+        let price = Decimal::from(close);
+        Candle {
+            symbol,
+            interval: Interval::OneMinute,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: Decimal::ONE,
+            timestamp: ts,
+        }
+    }
+
+    #[test]
+    fn normalize_keeps_latest_closed_candles() {
+        let symbol = Symbol::from("BTCUSDT");
+        let end = Interval::OneMinute.align(Utc::now());
+        let raw = (0..6)
+            .rev()
+            .map(|idx| candle(Symbol::unspecified(), end - Duration::minutes(idx), idx))
+            .collect::<Vec<_>>();
+        let candles = normalize(raw, symbol, Interval::OneMinute, end, 3);
+        let closes: Vec<_> = candles.iter().map(|c| c.close).collect();
+        // The bar stamped at `end` is still open and must be dropped.
+        assert_eq!(
+            closes,
+            vec![Decimal::from(3), Decimal::from(2), Decimal::from(1)]
+        );
+        assert!(candles.iter().all(|c| c.symbol == symbol));
+    }
+
+    #[test]
+    fn disabled_source_requests_no_candles() {
+        let settings = WarmupSettings {
+            source: WarmupSource::Disabled,
+            candles: Some(50),
+        };
+        assert_eq!(settings.candle_count(200), 0);
+        let settings = WarmupSettings {
+            source: WarmupSource::Exchange,
+            candles: None,
+        };
+        assert_eq!(settings.candle_count(200), 200);
+    }
+
+    #[tokio::test]
+    async fn replay_primes_the_strategy_without_routing_signals() {
+        let symbol = Symbol::from("BTCUSDT");
+        let end = Interval::OneMinute.align(Utc::now());
+        let candles: Vec<_> = (1..=5)
+            .rev()
+            .map(|idx| candle(symbol, end - Duration::minutes(idx), idx))
+            .collect();
+        let mut strategy = PrimingStrategy {
+            symbol,
+            lookback: candles.len(),
+            primed: false,
+            pending: Vec::new(),
+        };
+        let mut ctx = StrategyContext::new(16);
+        replay_warmup(&mut strategy, &mut ctx, &candles)
+            .await
+            .unwrap();
+        assert!(strategy.primed);
+        assert_eq!(ctx.candles().len(), candles.len());
+        assert!(strategy.drain_signals().is_empty());
+    }
+
+    #[test]
+    fn warmup_exchange_must_match_the_symbol() {
+        let bybit = exchange("bybit_linear");
+        let binance = exchange("binance_perp");
+        let qualified = Symbol::from_code(ExchangeId::from("binance_perp"), "BTCUSDT");
+        let unknown = Symbol::from_code(ExchangeId::from("okx_swap"), "BTCUSDT");
+        let bare = Symbol::from("BTCUSDT");
+
+        let both = [bybit.clone(), binance];
+        let picked = exchange_for_symbol(&both, &qualified).map(|ex| ex.name.as_str());
+        assert_eq!(picked, Some("binance_perp"));
+        assert!(exchange_for_symbol(&both, &unknown).is_none());
+        assert!(exchange_for_symbol(&both, &bare).is_none());
+
+        let single = [bybit];
+        assert!(exchange_for_symbol(&single, &unknown).is_none());
+        let picked = exchange_for_symbol(&single, &bare).map(|ex| ex.name.as_str());
+        assert_eq!(picked, Some("bybit_linear"));
+    }
+}
@@ -111,7 +111,7 @@ fn write_canonical_parquet(path: &Path, candles: &[Candle]) -> Result<()> {
             .ok_or_else(|| anyhow::anyhow!("timestamp overflow"))?;
         timestamps.append_value(nanos);
         symbols.append_value(candle.symbol.as_ref());
-        intervals.append_value(interval_label(candle.interval));
+        intervals.append_value(candle.interval.label());
         open.append_value(decimal_to_i128(candle.open)?);
         high.append_value(decimal_to_i128(candle.high)?);
         low.append_value(decimal_to_i128(candle.low)?);
@@ -186,18 +186,6 @@ fn dual_exchange_candles() -> Vec<Candle> {
     candles
 }
 
-fn interval_label(interval: Interval) -> &'static str {
-    match interval {
-        Interval::OneSecond => "1s",
-        Interval::OneMinute => "1m",
-        Interval::FiveMinutes => "5m",
-        Interval::FifteenMinutes => "15m",
-        Interval::OneHour => "1h",
-        Interval::FourHours => "4h",
-        Interval::OneDay => "1d",
-    }
-}
-
 fn decimal_to_i128(value: Decimal) -> Result<i128> {
     let mut normalized = value;
     if normalized.scale() > CANONICAL_DECIMAL_SCALE_U32 {
@@ -0,0 +1,321 @@
+#![cfg(feature = "bybit")]
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{Duration as ChronoDuration, Utc};
+use rust_decimal::Decimal;
+use serde_json::Value as JsonValue;
+use tempfile::tempdir;
+
+use tesser_broker::{
+    register_connector_factory, BrokerError, BrokerResult, ConnectorFactory, ConnectorStream,
+    ConnectorStreamConfig, ExecutionClient,
+};
+use tesser_cli::live::NamedExchange;
+use tesser_cli::record::{run_record, ExchangeReport, RecordChannels, RecordPlan};
+use tesser_config::ExchangeConfig;
+use tesser_core::{Candle, ExchangeId, Interval, OrderBook, OrderBookLevel, Side, Symbol, Tick};
+use tesser_data::analytics::collect_parquet_files;
+use tesser_data::capture::CaptureConfig;
+use tesser_data::recorder::RecorderConfig;
+use tesser_test_utils::{AccountConfig, MockExchange, MockExchangeConfig};
+
+const SYMBOL: &str = "BTCUSDT";
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn record_captures_trades_and_candles_without_strategy() -> Result<()> {
+    let exchange_id = ExchangeId::from("bybit_linear");
+    let symbol = Symbol::from_code(exchange_id, SYMBOL);
+    let base_time = Utc::now();
+    let candles = (0..2)
+        .map(|i| Candle {
+            symbol,
+            interval: Interval::OneMinute,
+            open: Decimal::new(1_000, 0),
+            high: Decimal::new(1_010, 0),
+            low: Decimal::new(995, 0),
+            close: Decimal::new(1_005 + i, 0),
+            volume: Decimal::ONE,
+            timestamp: base_time + ChronoDuration::minutes(i),
+        })
+        .collect::<Vec<_>>();
+    let ticks = (0..4)
+        .map(|i| Tick {
+            symbol,
+            price: Decimal::new(1_005 + i, 0),
+            size: Decimal::ONE,
+            side: Side::Buy,
+            exchange_timestamp: base_time + ChronoDuration::seconds(i),
+            received_at: base_time + ChronoDuration::seconds(i),
+        })
+        .collect::<Vec<_>>();
+    let config = MockExchangeConfig::new()
+        .with_exchange(exchange_id)
+        .with_account(AccountConfig::new("test-key", "test-secret"))
+        .with_candles(candles)
+        .with_ticks(ticks);
+    let exchange = MockExchange::start(config).await?;
+
+    let temp = tempdir()?;
+    let root = temp.path().join("recording");
+    let plan = RecordPlan {
+        exchanges: vec![NamedExchange {
+            name: "bybit_linear".into(),
+            config: ExchangeConfig {
+                rest_url: exchange.rest_url(),
+                ws_url: exchange.ws_url(),
+                api_key: "test-key".into(),
+                api_secret: "test-secret".into(),
+                driver: "bybit".into(),
+                params: JsonValue::Null,
+            },
+        }],
+        symbols: vec![SYMBOL.into()],
+        category: "linear".into(),
+        interval: Interval::OneMinute,
+        orderbook_depth: 50,
+        channels: RecordChannels {
+            trades: true,
+            candles: true,
+            depth: true,
+        },
+        recorder: RecorderConfig {
+            root: root.clone(),
+            flush_interval: Duration::from_millis(200),
+            ..RecorderConfig::default()
+        },
+        capture: CaptureConfig {
+            snapshot_interval: ChronoDuration::minutes(5),
+            stall_timeout: ChronoDuration::minutes(5),
+        },
+        duration: Some(Duration::from_secs(3)),
+    };
+
+    let reports = run_record(plan).await?;
+    assert_eq!(reports.len(), 1);
+    let report = &reports[0];
+    assert_eq!(report.ticks, 4);
+    assert_eq!(report.candles, 2);
+    assert_eq!(report.book.gaps, 0);
+
+    for kind in ["ticks", "candles"] {
+        let files = collect_parquet_files(&root.join(kind))?;
+        assert!(!files.is_empty(), "expected {kind} parquet output");
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn record_resubscribes_after_a_sequence_gap() -> Result<()> {
+    let symbol = Symbol::from_code(ExchangeId::from("scripted"), SYMBOL);
+    let first_session = vec![
+        (Duration::ZERO, book(symbol, 1, Some(1), None)),
+        (Duration::ZERO, book(symbol, 2, Some(2), Some(1))),
+        // Updates 3 and 4 never arrived.
+        (Duration::ZERO, book(symbol, 3, Some(5), Some(4))),
+    ];
+    let second_session = vec![(Duration::ZERO, book(symbol, 4, Some(10), None))];
+    register_scripted("scripted-gap", vec![first_session, second_session]);
+
+    let temp = tempdir()?;
+    let root = temp.path().join("recording");
+    let reports = run_record(scripted_plan(
+        "scripted-gap",
+        vec![SYMBOL.into()],
+        &root,
+        ChronoDuration::minutes(5),
+    ))
+    .await?;
+    let report = single_report(&reports);
+    assert_eq!(report.book.gaps, 1);
+    assert_eq!(report.book.snapshots, 2);
+    assert_eq!(report.book.deltas, 1);
+    assert_eq!(report.reconnects, 1);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn record_keeps_streaming_when_one_symbol_goes_quiet() -> Result<()> {
+    let exchange = ExchangeId::from("scripted");
+    let busy = Symbol::from_code(exchange, SYMBOL);
+    let quiet = Symbol::from_code(exchange, "ETHUSDT");
+    let mut session = vec![(Duration::ZERO, book(quiet, 1, None, None))];
+    session.extend((0..25).map(|step| {
+        (
+            Duration::from_millis(step * 100),
+            book(busy, step as i64 + 1, None, None),
+        )
+    }));
+    register_scripted("scripted-stall", vec![session]);
+
+    let temp = tempdir()?;
+    let root = temp.path().join("recording");
+    let reports = run_record(scripted_plan(
+        "scripted-stall",
+        vec![SYMBOL.into(), "ETHUSDT".into()],
+        &root,
+        ChronoDuration::seconds(1),
+    ))
+    .await?;
+    let report = single_report(&reports);
+    assert_eq!(report.book.stalls, 1, "only the quiet symbol stalls");
+    assert_eq!(
+        report.reconnects, 0,
+        "a quiet symbol must not reconnect the stream"
+    );
+    assert_eq!(report.book.gaps, 0);
+    assert_eq!(report.book.snapshots, 2);
+    assert!(report.book.deltas >= 20);
+    for kind in ["order_books", "depth"] {
+        let files = collect_parquet_files(&root.join(kind))?;
+        assert!(!files.is_empty(), "expected {kind} parquet output");
+    }
+    Ok(())
+}
+
+fn scripted_plan(
+    driver: &str,
+    symbols: Vec<String>,
+    root: &Path,
+    stall_timeout: ChronoDuration,
+) -> RecordPlan {
+    RecordPlan {
+        exchanges: vec![NamedExchange {
+            name: "scripted".into(),
+            config: ExchangeConfig {
+                rest_url: "http://127.0.0.1:0".into(),
+                ws_url: "ws://127.0.0.1:0".into(),
+                api_key: String::new(),
+                api_secret: String::new(),
+                driver: driver.into(),
+                params: JsonValue::Null,
+            },
+        }],
+        symbols,
+        category: "linear".into(),
+        interval: Interval::OneMinute,
+        orderbook_depth: 50,
+        channels: RecordChannels {
+            trades: true,
+            candles: true,
+            depth: true,
+        },
+        recorder: RecorderConfig {
+            root: root.to_path_buf(),
+            flush_interval: Duration::from_millis(200),
+            ..RecorderConfig::default()
+        },
+        capture: CaptureConfig {
+            snapshot_interval: ChronoDuration::minutes(5),
+            stall_timeout,
+        },
+        duration: Some(Duration::from_secs(3)),
+    }
+}
+
+fn single_report(reports: &[ExchangeReport]) -> &ExchangeReport {
+    assert_eq!(reports.len(), 1);
+    &reports[0]
+}
+
+/// Two-level book whose bid size changes with `step`, so consecutive steps differ.
+fn book(symbol: Symbol, step: i64, sequence: Option<u64>, prev_sequence: Option<u64>) -> OrderBook {
+    OrderBook {
+        symbol,
+        bids: vec![OrderBookLevel {
+            price: Decimal::new(9_999, 0),
+            size: Decimal::new(step, 0),
+        }],
+        asks: vec![OrderBookLevel {
+            price: Decimal::new(10_001, 0),
+            size: Decimal::ONE,
+        }],
+        timestamp: Utc::now(),
+        exchange_checksum: None,
+        local_checksum: None,
+        sequence,
+        prev_sequence,
+    }
+}
+
+type Script = Vec<(Duration, OrderBook)>;
+
+fn register_scripted(name: &'static str, sessions: Vec<Script>) {
+    register_connector_factory(Arc::new(ScriptedConnector {
+        name,
+        sessions: Mutex::new(sessions.into()),
+    }));
+}
+
+/// Connector whose market streams replay one scripted list of books per connection.
+struct ScriptedConnector {
+    name: &'static str,
+    sessions: Mutex<VecDeque<Script>>,
+}
+
+#[async_trait]
+impl ConnectorFactory for ScriptedConnector {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    async fn create_execution_client(
+        &self,
+        _config: &JsonValue,
+    ) -> BrokerResult<Arc<dyn ExecutionClient>> {
+        Err(BrokerError::Other(
+            "scripted connector has no execution client".into(),
+        ))
+    }
+
+    async fn create_market_stream(
+        &self,
+        _config: &JsonValue,
+        _stream_config: ConnectorStreamConfig,
+    ) -> BrokerResult<Box<dyn ConnectorStream>> {
+        let books = self
+            .sessions
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or_default();
+        Ok(Box::new(ScriptedStream {
+            started: Instant::now(),
+            books: books.into(),
+        }))
+    }
+}
+
+/// Emits each scripted book once its offset from connection time has elapsed.
+struct ScriptedStream {
+    started: Instant,
+    books: VecDeque<(Duration, OrderBook)>,
+}
+
+#[async_trait]
+impl ConnectorStream for ScriptedStream {
+    async fn subscribe(&mut self, _symbols: &[String], _interval: Interval) -> BrokerResult<()> {
+        Ok(())
+    }
+
+    async fn next_tick(&mut self) -> BrokerResult<Option<Tick>> {
+        Ok(None)
+    }
+
+    async fn next_candle(&mut self) -> BrokerResult<Option<Candle>> {
+        Ok(None)
+    }
+
+    async fn next_order_book(&mut self) -> BrokerResult<Option<OrderBook>> {
+        match self.books.front() {
+            Some((offset, _)) if self.started.elapsed() >= *offset => {
+                Ok(self.books.pop_front().map(|(_, book)| book))
+            }
+            _ => Ok(None),
+        }
+    }
+}
@@ -28,20 +28,33 @@ use tesser_cli::live::{
     run_live_with_shutdown, ExecutionBackend, LiveSessionSettings, NamedExchange,
     PersistenceSettings, ShutdownSignal,
 };
+use tesser_cli::reload::StrategyReloadSettings;
+use tesser_cli::warmup::WarmupSettings;
 use tesser_cli::PublicChannel;
-use tesser_config::{AlertingConfig, ExchangeConfig, PersistenceEngine, RiskManagementConfig};
+use tesser_config::{
+    AlertingConfig, ControlPlaneConfig, ExchangeConfig, PersistenceEngine,
+    ReconciliationPolicyConfig, RiskManagementConfig,
+};
 use tesser_core::{
     AccountBalance, AssetId, Candle, ExchangeId, ExitStrategy, Interval, Position, Side, Signal,
     SignalKind, Symbol, Tick,
 };
 use tesser_execution::PanicCloseConfig;
+use tesser_ledger::{LedgerQuery, LedgerRepository, LedgerType, SqliteLedgerRepository};
 use tesser_portfolio::{SqliteStateRepository, StateRepository};
+use tesser_rpc::conversions::{from_decimal_proto, to_decimal_proto};
 use tesser_rpc::proto::control_service_client::ControlServiceClient;
+use tesser_rpc::proto::event::Payload;
 use tesser_rpc::proto::{
-    CancelAllRequest, GetOpenOrdersRequest, GetPortfolioRequest, GetStatusRequest,
-    ListManagedTradesRequest, UpdateTradeExitStrategyRequest,
+    self, CancelAllRequest, CancelOrderRequest, GetOpenOrdersRequest, GetPortfolioRequest,
+    GetStatusRequest, ListManagedTradesRequest, MonitorRequest, PlaceOrderRequest,
+    ReloadStrategyRequest, SetLiquidateOnlyRequest, SetStrategyPausedRequest, SwapStrategyRequest,
+    UpdateRiskLimitsRequest, UpdateTradeExitStrategyRequest,
+};
+use tesser_strategy::{
+    register_strategy_factory, PairsTradingArbitrage, Strategy, StrategyContext, StrategyError,
+    StrategyFactory, StrategyResult,
 };
-use tesser_strategy::{PairsTradingArbitrage, Strategy, StrategyContext, StrategyResult};
 use tesser_test_utils::{
     AccountConfig, AutoFillConfig, MockExchange, MockExchangeConfig, OrderFillStep, Scenario,
     ScenarioAction, ScenarioManager, ScenarioTrigger,
@@ -333,11 +346,15 @@ async fn live_run_executes_round_trip() -> Result<()> {
         risk,
         reconciliation_interval: Duration::from_secs(60),
         reconciliation_threshold: Decimal::ONE,
+        reconciliation_policy: ReconciliationPolicyConfig::default(),
         orderbook_depth: 50,
         record_path: None,
         control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_plane: ControlPlaneConfig::default(),
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        warmup: WarmupSettings::default(),
+        strategy_reload: StrategyReloadSettings::default(),
     };
     let exchange_cfg = ExchangeConfig {
         rest_url: exchange.rest_url(),
@@ -521,11 +538,15 @@ async fn live_run_executes_round_trip_multi_exchange() -> Result<()> {
         risk,
         reconciliation_interval: Duration::from_secs(60),
         reconciliation_threshold: Decimal::ONE,
+        reconciliation_policy: ReconciliationPolicyConfig::default(),
         orderbook_depth: 50,
         record_path: None,
         control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_plane: ControlPlaneConfig::default(),
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        warmup: WarmupSettings::default(),
+        strategy_reload: StrategyReloadSettings::default(),
     };
     let exchanges = vec![
         NamedExchange {
@@ -672,11 +693,15 @@ async fn pairs_trading_executes_cross_exchange_round_trip() -> Result<()> {
         risk,
         reconciliation_interval: Duration::from_secs(30),
         reconciliation_threshold: Decimal::new(1, 1),
+        reconciliation_policy: ReconciliationPolicyConfig::default(),
         orderbook_depth: 50,
         record_path: None,
         control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_plane: ControlPlaneConfig::default(),
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        warmup: WarmupSettings::default(),
+        strategy_reload: StrategyReloadSettings::default(),
     };
     let exchanges = vec![
         NamedExchange {
@@ -813,11 +838,15 @@ async fn control_plane_updates_pairs_exit_strategy() -> Result<()> {
         risk,
         reconciliation_interval: Duration::from_secs(30),
         reconciliation_threshold: Decimal::new(1, 1),
+        reconciliation_policy: ReconciliationPolicyConfig::default(),
         orderbook_depth: 50,
         record_path: None,
         control_addr,
+        control_plane: ControlPlaneConfig::default(),
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        warmup: WarmupSettings::default(),
+        strategy_reload: StrategyReloadSettings::default(),
     };
     let exchanges = vec![
         NamedExchange {
@@ -966,11 +995,15 @@ async fn control_plane_reports_status() -> Result<()> {
         risk: RiskManagementConfig::default(),
         reconciliation_interval: Duration::from_secs(1),
         reconciliation_threshold: Decimal::new(1, 3),
+        reconciliation_policy: ReconciliationPolicyConfig::default(),
         orderbook_depth: 50,
         record_path: None,
         control_addr,
+        control_plane: ControlPlaneConfig::default(),
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        warmup: WarmupSettings::default(),
+        strategy_reload: StrategyReloadSettings::default(),
     };
     let exchange_cfg = ExchangeConfig {
         rest_url: exchange.rest_url(),
@@ -1022,6 +1055,298 @@ async fn control_plane_reports_status() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn control_plane_applies_operator_actions() -> Result<()> {
+    let account = AccountConfig::new("test-key", "test-secret")
+        .with_balance(account_balance(Decimal::new(10_000, 0)));
+    let candles = vec![Candle {
+        symbol: test_symbol(),
+        interval: Interval::OneMinute,
+        open: Decimal::new(1_000, 0),
+        high: Decimal::new(1_010, 0),
+        low: Decimal::new(995, 0),
+        close: Decimal::new(1_005, 0),
+        volume: Decimal::ONE,
+        timestamp: Utc::now(),
+    }];
+    let ticks = vec![Tick {
+        symbol: test_symbol(),
+        price: Decimal::new(1_005, 0),
+        size: Decimal::ONE,
+        side: Side::Buy,
+        exchange_timestamp: Utc::now(),
+        received_at: Utc::now(),
+    }];
+    let config = MockExchangeConfig::new()
+        .with_exchange(bybit_exchange())
+        .with_account(account)
+        .with_candles(candles)
+        .with_ticks(ticks);
+    let mut exchange = MockExchange::start(config).await?;
+
+    let control_addr = next_control_addr();
+    let temp = tempdir()?;
+    let state_path = temp.path().join("live_state.db");
+    let markets_file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../config/markets.toml");
+    let settings = LiveSessionSettings {
+        category: PublicChannel::Linear,
+        interval: Interval::OneMinute,
+        quantity: Decimal::ONE,
+        slippage_bps: Decimal::ZERO,
+        fee_bps: Decimal::ZERO,
+        history: 8,
+        metrics_addr: "127.0.0.1:0".parse::<SocketAddr>().unwrap(),
+        persistence: PersistenceSettings::new(PersistenceEngine::Sqlite, state_path.clone()),
+        initial_balances: default_initial_balances(),
+        reporting_currency: usdt_asset(),
+        markets_file: Some(markets_file),
+        alerting: AlertingConfig::default(),
+        exec_backend: ExecutionBackend::Live,
+        risk: RiskManagementConfig::default(),
+        reconciliation_interval: Duration::from_secs(1),
+        reconciliation_threshold: Decimal::new(1, 3),
+        reconciliation_policy: ReconciliationPolicyConfig::default(),
+        orderbook_depth: 50,
+        record_path: None,
+        control_addr,
+        control_plane: ControlPlaneConfig::default(),
+        panic_close: PanicCloseConfig::default(),
+        plugins_dir: None,
+        warmup: WarmupSettings::default(),
+        strategy_reload: StrategyReloadSettings::default(),
+    };
+    let exchange_cfg = ExchangeConfig {
+        rest_url: exchange.rest_url(),
+        ws_url: exchange.ws_url(),
+        api_key: "test-key".into(),
+        api_secret: "test-secret".into(),
+        driver: "bybit".into(),
+        params: JsonValue::Null,
+    };
+    let exchanges = vec![NamedExchange {
+        name: "bybit_linear".into(),
+        config: exchange_cfg,
+    }];
+    let strategy: Box<dyn Strategy> = Box::new(PassiveStrategy::new(test_symbol()));
+    let shutdown = ShutdownSignal::new();
+    let run_handle = spawn_live_runtime(
+        strategy,
+        vec![test_symbol()],
+        exchanges,
+        settings,
+        shutdown.clone(),
+    );
+
+    let mut client = connect_control_client(control_addr).await?;
+
+    let paused = client
+        .set_strategy_paused(SetStrategyPausedRequest { paused: true })
+        .await?
+        .into_inner();
+    assert!(paused.paused && paused.changed);
+
+    let liquidate = client
+        .set_liquidate_only(SetLiquidateOnlyRequest { enabled: true })
+        .await?
+        .into_inner();
+    assert!(liquidate.liquidate_only && liquidate.changed);
+    let liquidate = client
+        .set_liquidate_only(SetLiquidateOnlyRequest { enabled: false })
+        .await?
+        .into_inner();
+    assert!(!liquidate.liquidate_only && liquidate.changed);
+
+    let limits = client
+        .update_risk_limits(UpdateRiskLimitsRequest {
+            max_order_quantity: Some(to_decimal_proto(Decimal::ONE)),
+            max_position_quantity: None,
+            max_order_notional: None,
+        })
+        .await?
+        .into_inner()
+        .limits
+        .expect("limits");
+    assert_eq!(
+        limits.max_order_quantity.map(from_decimal_proto),
+        Some(Decimal::ONE)
+    );
+
+    let status = client.get_status(GetStatusRequest {}).await?.into_inner();
+    assert!(status.strategy_paused);
+    assert!(!status.liquidate_only);
+
+    // Manual orders go through the pre-trade risk checks, so the tightened limit rejects this.
+    let rejected = client
+        .place_order(PlaceOrderRequest {
+            symbol: test_symbol().to_string(),
+            side: proto::Side::Buy as i32,
+            order_type: proto::OrderType::Market as i32,
+            quantity: Some(to_decimal_proto(Decimal::from(2))),
+            price: None,
+            client_order_id: String::new(),
+        })
+        .await
+        .expect_err("order above max_order_quantity");
+    assert_eq!(rejected.code(), tonic::Code::FailedPrecondition);
+
+    let missing = client
+        .cancel_order(CancelOrderRequest {
+            order_id: "does-not-exist".into(),
+        })
+        .await
+        .expect_err("unknown order");
+    assert_eq!(missing.code(), tonic::Code::NotFound);
+
+    // Parameter reloads must target the running strategy.
+    let mismatch = client
+        .reload_strategy(ReloadStrategyRequest {
+            config_toml: "strategy_name = \"SmaCross\"\n".into(),
+        })
+        .await
+        .expect_err("config for a different strategy");
+    assert_eq!(mismatch.code(), tonic::Code::FailedPrecondition);
+
+    // Swapping implementations is allowed; a dry run only reports the signal comparison.
+    let swap_config = format!(
+        "strategy_name = \"SmaCross\"\n[params]\nsymbol = \"{}\"\n",
+        test_symbol()
+    );
+    let dry_run = client
+        .swap_strategy(SwapStrategyRequest {
+            config_toml: swap_config.clone(),
+            dry_run: true,
+        })
+        .await?
+        .into_inner();
+    assert!(!dry_run.swapped);
+    assert_eq!(dry_run.previous_strategy, "passive-test");
+    assert_eq!(dry_run.strategy, "sma-cross");
+    assert!(dry_run.comparison.is_some());
+    let swapped = client
+        .swap_strategy(SwapStrategyRequest {
+            config_toml: swap_config,
+            dry_run: false,
+        })
+        .await?
+        .into_inner();
+    assert!(swapped.swapped);
+    assert_eq!(swapped.previous_strategy, "passive-test");
+
+    shutdown.trigger();
+    run_handle.await??;
+    exchange.shutdown().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reload_reconfigures_strategy_and_records_the_change() -> Result<()> {
+    register_reload_probe();
+    let mut exchange = start_quiet_exchange().await?;
+    let control_addr = next_control_addr();
+    let temp = tempdir()?;
+    let state_path = temp.path().join("live_state.db");
+    let settings =
+        reload_session_settings(&state_path, control_addr, StrategyReloadSettings::default());
+    let (strategy, log) = ReloadProbe::live(test_symbol());
+    let shutdown = ShutdownSignal::new();
+    let run_handle = spawn_live_runtime(
+        Box::new(strategy),
+        vec![test_symbol()],
+        bybit_exchanges(&exchange),
+        settings,
+        shutdown.clone(),
+    );
+
+    let mut client = connect_control_client(control_addr).await?;
+    let mut events = client.monitor(MonitorRequest {}).await?.into_inner();
+    let response = client
+        .reload_strategy(ReloadStrategyRequest {
+            config_toml: probe_config(5),
+        })
+        .await?
+        .into_inner();
+    assert_eq!(response.strategy, "reload-probe");
+    assert_eq!(
+        serde_json::from_str::<JsonValue>(&response.params_json)?,
+        json!({ "threshold": 5 })
+    );
+
+    let update = next_strategy_update(&mut events).await?;
+    assert_eq!(update.strategy, "reload-probe");
+    assert_eq!(update.source, "rpc");
+    assert!(update.replaced_strategy.is_empty());
+    {
+        let log = log.lock().unwrap();
+        assert_eq!(log.thresholds, vec![5]);
+        // `configure` reset the marker; the snapshot taken before it was restored.
+        assert_eq!(log.restored, vec![json!({ "marker": "live" })]);
+    }
+
+    shutdown.trigger();
+    run_handle.await??;
+    let ledger = SqliteLedgerRepository::new(&state_path)?;
+    let entries = ledger.query(LedgerQuery {
+        entry_type: Some(LedgerType::Adjustment),
+        ..LedgerQuery::default()
+    })?;
+    let entry = entries
+        .iter()
+        .find(|entry| entry.reference_id == "strategy_params")
+        .expect("strategy change ledger entry");
+    let meta = entry.meta.as_ref().expect("ledger meta");
+    assert_eq!(meta["strategy"], "reload-probe");
+    assert_eq!(meta["source"], "rpc");
+    assert_eq!(meta["params"], json!({ "threshold": 5 }));
+    exchange.shutdown().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reload_watcher_applies_strategy_file_edits() -> Result<()> {
+    register_reload_probe();
+    let mut exchange = start_quiet_exchange().await?;
+    let control_addr = next_control_addr();
+    let temp = tempdir()?;
+    let state_path = temp.path().join("live_state.db");
+    let config_path = temp.path().join("strategy.toml");
+    std::fs::write(&config_path, probe_config(1))?;
+    let settings = reload_session_settings(
+        &state_path,
+        control_addr,
+        StrategyReloadSettings {
+            path: Some(config_path.clone()),
+            watch_interval: Some(Duration::from_millis(100)),
+        },
+    );
+    let (strategy, log) = ReloadProbe::live(test_symbol());
+    let shutdown = ShutdownSignal::new();
+    let run_handle = spawn_live_runtime(
+        Box::new(strategy),
+        vec![test_symbol()],
+        bybit_exchanges(&exchange),
+        settings,
+        shutdown.clone(),
+    );
+
+    let mut client = connect_control_client(control_addr).await?;
+    let mut events = client.monitor(MonitorRequest {}).await?.into_inner();
+    std::fs::write(&config_path, probe_config(7))?;
+
+    let update = next_strategy_update(&mut events).await?;
+    assert_eq!(update.strategy, "reload-probe");
+    assert_eq!(update.source, "file_watch");
+    assert_eq!(
+        serde_json::from_str::<JsonValue>(&update.params_json)?,
+        json!({ "threshold": 7 })
+    );
+    assert_eq!(log.lock().unwrap().thresholds, vec![7]);
+
+    shutdown.trigger();
+    run_handle.await??;
+    exchange.shutdown().await;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn reconciliation_enters_liquidate_only_on_divergence() -> Result<()> {
     let account = AccountConfig::new("test-key", "test-secret")
@@ -1081,11 +1406,15 @@ async fn reconciliation_enters_liquidate_only_on_divergence() -> Result<()> {
         risk: RiskManagementConfig::default(),
         reconciliation_interval: Duration::from_millis(200),
         reconciliation_threshold: Decimal::new(1, 4),
+        reconciliation_policy: ReconciliationPolicyConfig::default(),
         orderbook_depth: 50,
         record_path: None,
         control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_plane: ControlPlaneConfig::default(),
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        warmup: WarmupSettings::default(),
+        strategy_reload: StrategyReloadSettings::default(),
     };
     let exchange_cfg = ExchangeConfig {
         rest_url: exchange.rest_url(),
@@ -1150,6 +1479,133 @@ async fn reconciliation_enters_liquidate_only_on_divergence() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn reconciliation_adopts_exchange_position_when_configured() -> Result<()> {
+    let account = AccountConfig::new("test-key", "test-secret")
+        .with_balance(account_balance(Decimal::new(10_000, 0)));
+    let base_time = Utc::now();
+    let candles = (0..3)
+        .map(|i| Candle {
+            symbol: test_symbol(),
+            interval: Interval::OneMinute,
+            open: Decimal::new(1_000 + i as i64, 0),
+            high: Decimal::new(1_010 + i as i64, 0),
+            low: Decimal::new(995 + i as i64, 0),
+            close: Decimal::new(1_005 + i as i64, 0),
+            volume: Decimal::ONE,
+            timestamp: base_time + ChronoDuration::minutes(i as i64),
+        })
+        .collect::<Vec<_>>();
+    let config = MockExchangeConfig::new()
+        .with_account(account)
+        .with_candles(candles);
+    let mut exchange = MockExchange::start(config).await?;
+
+    let temp = tempdir()?;
+    let state_path = temp.path().join("live_state.db");
+    let markets_file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../config/markets.toml");
+    let settings = LiveSessionSettings {
+        category: PublicChannel::Linear,
+        interval: Interval::OneMinute,
+        quantity: Decimal::ONE,
+        slippage_bps: Decimal::ZERO,
+        fee_bps: Decimal::ZERO,
+        history: 4,
+        metrics_addr: "127.0.0.1:0".parse::<SocketAddr>().unwrap(),
+        persistence: PersistenceSettings::new(PersistenceEngine::Sqlite, state_path.clone()),
+        initial_balances: default_initial_balances(),
+        reporting_currency: usdt_asset(),
+        markets_file: Some(markets_file),
+        alerting: AlertingConfig::default(),
+        exec_backend: ExecutionBackend::Live,
+        risk: RiskManagementConfig::default(),
+        reconciliation_interval: Duration::from_millis(200),
+        reconciliation_threshold: Decimal::new(1, 4),
+        reconciliation_policy: ReconciliationPolicyConfig {
+            adopt_exchange_state: true,
+            liquidate_only: false,
+            ..ReconciliationPolicyConfig::default()
+        },
+        orderbook_depth: 50,
+        record_path: None,
+        control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_plane: ControlPlaneConfig::default(),
+        panic_close: PanicCloseConfig::default(),
+        plugins_dir: None,
+        warmup: WarmupSettings::default(),
+        strategy_reload: StrategyReloadSettings::default(),
+    };
+    let exchange_cfg = ExchangeConfig {
+        rest_url: exchange.rest_url(),
+        ws_url: exchange.ws_url(),
+        api_key: "test-key".into(),
+        api_secret: "test-secret".into(),
+        driver: "bybit".into(),
+        params: JsonValue::Null,
+    };
+    let exchanges = vec![NamedExchange {
+        name: "bybit_linear".into(),
+        config: exchange_cfg,
+    }];
+    let strategy: Box<dyn Strategy> = Box::new(PassiveStrategy::new(test_symbol()));
+    let shutdown = ShutdownSignal::new();
+    let run_handle = spawn_live_runtime(
+        strategy,
+        vec![test_symbol()],
+        exchanges,
+        settings,
+        shutdown.clone(),
+    );
+
+    sleep(Duration::from_millis(300)).await;
+    exchange
+        .state()
+        .with_account_mut("test-key", |account| {
+            account.positions.insert(
+                test_symbol(),
+                Position {
+                    symbol: test_symbol(),
+                    side: Some(Side::Buy),
+                    quantity: Decimal::new(5, 0),
+                    entry_price: Some(Decimal::new(1_000, 0)),
+                    unrealized_pnl: Decimal::ZERO,
+                    updated_at: Utc::now(),
+                },
+            );
+            Ok(())
+        })
+        .await?;
+
+    let repo = SqliteStateRepository::new(state_path.clone());
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let adopted = loop {
+        let state = repo
+            .load()
+            .map_err(|err| anyhow!("failed to load state: {err}"))?;
+        let quantity = state
+            .portfolio
+            .as_ref()
+            .and_then(|snapshot| snapshot.positions.get(&test_symbol()))
+            .map(|position| position.quantity);
+        if quantity == Some(Decimal::new(5, 0)) {
+            break state;
+        }
+        if Instant::now() >= deadline {
+            return Err(anyhow!("timed out waiting for adopted position"));
+        }
+        sleep(Duration::from_millis(50)).await;
+    };
+    assert!(!adopted
+        .portfolio
+        .map(|snapshot| snapshot.liquidate_only)
+        .unwrap_or(true));
+
+    shutdown.trigger();
+    run_handle.await??;
+    exchange.shutdown().await;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn alerts_on_rejected_order() -> Result<()> {
     let _ = tracing_subscriber::fmt::try_init();
@@ -1230,11 +1686,15 @@ async fn alerts_on_rejected_order() -> Result<()> {
         risk: RiskManagementConfig::default(),
         reconciliation_interval: Duration::from_secs(1),
         reconciliation_threshold: Decimal::new(1, 3),
+        reconciliation_policy: ReconciliationPolicyConfig::default(),
         orderbook_depth: 50,
         record_path: None,
         control_addr: "127.0.0.1:0".parse().unwrap(),
+        control_plane: ControlPlaneConfig::default(),
         panic_close: PanicCloseConfig::default(),
         plugins_dir: None,
+        warmup: WarmupSettings::default(),
+        strategy_reload: StrategyReloadSettings::default(),
     };
     let exchange_cfg = ExchangeConfig {
         rest_url: exchange.rest_url(),
@@ -1360,6 +1820,194 @@ struct MultiScriptedStrategy {
     state: Arc<StrategyState>,
 }
 
+async fn start_quiet_exchange() -> Result<MockExchange> {
+    let account = AccountConfig::new("test-key", "test-secret")
+        .with_balance(account_balance(Decimal::new(10_000, 0)));
+    let config = MockExchangeConfig::new()
+        .with_exchange(bybit_exchange())
+        .with_account(account);
+    MockExchange::start(config).await
+}
+
+fn bybit_exchanges(exchange: &MockExchange) -> Vec<NamedExchange> {
+    vec![NamedExchange {
+        name: "bybit_linear".into(),
+        config: ExchangeConfig {
+            rest_url: exchange.rest_url(),
+            ws_url: exchange.ws_url(),
+            api_key: "test-key".into(),
+            api_secret: "test-secret".into(),
+            driver: "bybit".into(),
+            params: JsonValue::Null,
+        },
+    }]
+}
+
+fn reload_session_settings(
+    state_path: &Path,
+    control_addr: SocketAddr,
+    strategy_reload: StrategyReloadSettings,
+) -> LiveSessionSettings {
+    let markets_file = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../config/markets.toml");
+    LiveSessionSettings {
+        category: PublicChannel::Linear,
+        interval: Interval::OneMinute,
+        quantity: Decimal::ONE,
+        slippage_bps: Decimal::ZERO,
+        fee_bps: Decimal::ZERO,
+        history: 8,
+        metrics_addr: "127.0.0.1:0".parse::<SocketAddr>().unwrap(),
+        persistence: PersistenceSettings::new(PersistenceEngine::Sqlite, state_path.to_path_buf()),
+        initial_balances: default_initial_balances(),
+        reporting_currency: usdt_asset(),
+        markets_file: Some(markets_file),
+        alerting: AlertingConfig::default(),
+        exec_backend: ExecutionBackend::Live,
+        risk: RiskManagementConfig::default(),
+        reconciliation_interval: Duration::from_secs(60),
+        reconciliation_threshold: Decimal::ONE,
+        reconciliation_policy: ReconciliationPolicyConfig::default(),
+        orderbook_depth: 50,
+        record_path: None,
+        control_addr,
+        control_plane: ControlPlaneConfig::default(),
+        panic_close: PanicCloseConfig::default(),
+        plugins_dir: None,
+        warmup: WarmupSettings::default(),
+        strategy_reload,
+    }
+}
+
+fn probe_config(threshold: i64) -> String {
+    format!("strategy_name = \"ReloadProbe\"\n[params]\nthreshold = {threshold}\n")
+}
+
+async fn next_strategy_update(
+    events: &mut tonic::Streaming<proto::Event>,
+) -> Result<proto::StrategyUpdate> {
+    timeout(Duration::from_secs(10), async {
+        loop {
+            match events.message().await? {
+                Some(proto::Event {
+                    payload: Some(Payload::StrategyUpdate(update)),
+                }) => return Ok(update),
+                Some(_) => continue,
+                None => return Err(anyhow!("monitor stream closed")),
+            }
+        }
+    })
+    .await
+    .context("timed out waiting for a strategy update event")?
+}
+
+fn register_reload_probe() {
+    register_strategy_factory(Arc::new(ReloadProbeFactory));
+}
+
+/// What the session's [`ReloadProbe`] saw across parameter reloads.
+#[derive(Default)]
+struct ReloadLog {
+    thresholds: Vec<i64>,
+    restored: Vec<JsonValue>,
+}
+
+/// Strategy registered as `ReloadProbe` so reloads can validate against a scratch instance.
+///
+/// Only the instance handed to the live session carries a log.
+struct ReloadProbe {
+    symbol: Symbol,
+    marker: String,
+    log: Option<Arc<std::sync::Mutex<ReloadLog>>>,
+}
+
+impl ReloadProbe {
+    fn live(symbol: Symbol) -> (Self, Arc<std::sync::Mutex<ReloadLog>>) {
+        let log = Arc::new(std::sync::Mutex::new(ReloadLog::default()));
+        let probe = Self {
+            symbol,
+            marker: "live".into(),
+            log: Some(log.clone()),
+        };
+        (probe, log)
+    }
+}
+
+#[async_trait]
+impl Strategy for ReloadProbe {
+    fn name(&self) -> &str {
+        "reload-probe"
+    }
+
+    fn symbol(&self) -> Symbol {
+        self.symbol
+    }
+
+    fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
+        let threshold = params
+            .get("threshold")
+            .and_then(toml::Value::as_integer)
+            .ok_or_else(|| StrategyError::InvalidConfig("threshold is required".into()))?;
+        // Mimics a strategy rebuilding its state from scratch on configure.
+        self.marker = "configured".into();
+        if let Some(log) = &self.log {
+            log.lock().unwrap().thresholds.push(threshold);
+        }
+        Ok(())
+    }
+
+    async fn on_tick(&mut self, _ctx: &StrategyContext, _tick: &Tick) -> StrategyResult<()> {
+        Ok(())
+    }
+
+    async fn on_candle(&mut self, _ctx: &StrategyContext, _candle: &Candle) -> StrategyResult<()> {
+        Ok(())
+    }
+
+    async fn on_fill(
+        &mut self,
+        _ctx: &StrategyContext,
+        _fill: &tesser_core::Fill,
+    ) -> StrategyResult<()> {
+        Ok(())
+    }
+
+    fn drain_signals(&mut self) -> Vec<Signal> {
+        Vec::new()
+    }
+
+    fn snapshot(&self) -> StrategyResult<JsonValue> {
+        Ok(json!({ "marker": self.marker }))
+    }
+
+    fn restore(&mut self, state: JsonValue) -> StrategyResult<()> {
+        if let Some(marker) = state["marker"].as_str() {
+            self.marker = marker.to_string();
+        }
+        if let Some(log) = &self.log {
+            log.lock().unwrap().restored.push(state);
+        }
+        Ok(())
+    }
+}
+
+struct ReloadProbeFactory;
+
+impl StrategyFactory for ReloadProbeFactory {
+    fn canonical_name(&self) -> &'static str {
+        "ReloadProbe"
+    }
+
+    fn build(&self, params: toml::Value) -> StrategyResult<Box<dyn Strategy>> {
+        let mut probe = ReloadProbe {
+            symbol: test_symbol(),
+            marker: String::new(),
+            log: None,
+        };
+        probe.configure(params)?;
+        Ok(Box::new(probe))
+    }
+}
+
 struct PassiveStrategy {
     symbol: Symbol,
 }
@@ -21,8 +21,13 @@ use tesser_cli::live::{
     run_live_with_shutdown, ExecutionBackend, LiveSessionSettings, NamedExchange,
     PersistenceSettings, ShutdownSignal,
 };
+use tesser_cli::reload::StrategyReloadSettings;
+use tesser_cli::warmup::WarmupSettings;
 use tesser_cli::PublicChannel;
-use tesser_config::{AlertingConfig, ExchangeConfig, PersistenceEngine, RiskManagementConfig};
+use tesser_config::{
+    AlertingConfig, ControlPlaneConfig, ExchangeConfig, PersistenceEngine,
+    ReconciliationPolicyConfig, RiskManagementConfig,
+};
 use tesser_core::{
     AccountBalance, AssetId, Candle, ExchangeId, ExecutionHint, Interval, OrderBook,
     OrderBookLevel, Side, Signal, SignalKind, Symbol, Tick,
@@ -322,6 +327,8 @@ async fn flight_recorder_writes_order_books() -> Result<()> {
         timestamp: Utc::now(),
         exchange_checksum: Some(123),
         local_checksum: Some(123),
+        sequence: None,
+        prev_sequence: None,
     });
     sleep(Duration::from_millis(50)).await;
     drop(handle);
@@ -445,6 +452,7 @@ struct LiveTestHarness {
     shutdown: ShutdownSignal,
     run_handle: tokio::task::JoinHandle<Result<()>>,
     control_addr: SocketAddr,
+    control_plane: ControlPlaneConfig::default(),
     record_root: PathBuf,
     exchange: MockExchange,
     ready: Arc<Notify>,
@@ -522,11 +530,14 @@ impl LiveTestHarness {
             risk: RiskManagementConfig::default(),
             reconciliation_interval: Duration::from_secs(5),
             reconciliation_threshold: Decimal::new(1, 3),
+            reconciliation_policy: ReconciliationPolicyConfig::default(),
             orderbook_depth: 50,
             record_path: record_data.then(|| record_root.clone()),
             control_addr,
             panic_close: PanicCloseConfig::default(),
             plugins_dir: None,
+            warmup: WarmupSettings::default(),
+            strategy_reload: StrategyReloadSettings::default(),
         };
 
         let shutdown = ShutdownSignal::new();
@@ -74,6 +74,8 @@ pub struct LiveRuntimeConfig {
     pub metrics_addr: String,
     #[serde(default = "default_control_addr")]
     pub control_addr: String,
+    #[serde(default)]
+    pub control_plane: ControlPlaneConfig,
     #[serde(default = "default_live_log_path")]
     pub log_path: PathBuf,
     #[serde(default = "default_reconciliation_interval_secs")]
@@ -81,6 +83,8 @@ pub struct LiveRuntimeConfig {
     #[serde(default = "default_reconciliation_threshold")]
     pub reconciliation_threshold: Decimal,
     #[serde(default)]
+    pub reconciliation_policy: ReconciliationPolicyConfig,
+    #[serde(default)]
     pub alerting: AlertingConfig,
     #[serde(default)]
     pub plugins_dir: Option<PathBuf>,
@@ -88,6 +92,7 @@ pub struct LiveRuntimeConfig {
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct AlertingConfig {
+    /// Legacy generic webhook, registered as a channel named `webhook` when set.
     #[serde(default)]
     pub webhook_url: Option<String>,
     #[serde(default = "default_data_gap_secs")]
@@ -96,6 +101,249 @@ pub struct AlertingConfig {
     pub max_order_failures: u32,
     #[serde(default = "default_drawdown_limit")]
     pub max_drawdown: Decimal,
+    /// Named delivery sinks.
+    #[serde(default)]
+    pub channels: Vec<AlertChannelConfig>,
+    /// Rules selecting which channels receive an alert. Alerts matching no rule go to every
+    /// channel whose `min_severity` admits them.
+    #[serde(default)]
+    pub routes: Vec<AlertRouteConfig>,
+    /// Identical alerts (same title and message) within this window are dropped. 0 disables.
+    #[serde(default = "default_alert_dedup_window_secs")]
+    pub dedup_window_secs: u64,
+    /// Maximum alerts per title delivered within `rate_limit_window_secs`. 0 disables.
+    #[serde(default = "default_alert_rate_limit")]
+    pub rate_limit: u32,
+    #[serde(default = "default_alert_rate_limit_window_secs")]
+    pub rate_limit_window_secs: u64,
+}
+
+/// Alert urgency, ordered from least to most severe.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSeverity {
+    #[default]
+    Info,
+    Warning,
+    Critical,
+}
+
+impl AlertSeverity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+/// A named alert sink, e.g. `[[live.alerting.channels]]` with `kind = "slack"`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct AlertChannelConfig {
+    pub name: String,
+    /// Alerts below this severity are never delivered to the channel.
+    #[serde(default)]
+    pub min_severity: AlertSeverity,
+    #[serde(flatten)]
+    pub sink: AlertSinkConfig,
+}
+
+/// Transport-specific settings for an alert channel.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AlertSinkConfig {
+    /// Generic JSON `{title, message, severity, timestamp}` POST.
+    Webhook { url: String },
+    /// Slack-compatible incoming webhook.
+    Slack { webhook_url: String },
+    /// Telegram-compatible Bot API `sendMessage`.
+    Telegram {
+        bot_token: String,
+        chat_id: String,
+        #[serde(default = "default_telegram_api_url")]
+        api_url: String,
+    },
+    /// SMTP submission to a relay.
+    Smtp {
+        host: String,
+        #[serde(default = "default_smtp_port")]
+        port: u16,
+        #[serde(default)]
+        security: SmtpSecurity,
+        #[serde(default)]
+        username: Option<String>,
+        #[serde(default)]
+        password: Option<String>,
+        from: String,
+        to: Vec<String>,
+    },
+    /// PagerDuty-compatible Events API v2.
+    #[serde(rename = "pagerduty")]
+    PagerDuty {
+        routing_key: String,
+        #[serde(default = "default_pagerduty_events_url")]
+        events_url: String,
+    },
+    /// Appends one JSON line per alert to a local file.
+    File { path: PathBuf },
+}
+
+/// Transport security for SMTP alert channels.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SmtpSecurity {
+    /// Upgrade the connection with `STARTTLS` before authenticating (typically port 587).
+    #[default]
+    #[serde(rename = "starttls")]
+    StartTls,
+    /// Connect over TLS from the start (typically port 465).
+    Tls,
+    /// Cleartext; only for trusted local relays and never with credentials.
+    None,
+}
+
+/// Routes alerts to channels by title and severity.
+#[derive(Debug, Deserialize, Clone)]
+pub struct AlertRouteConfig {
+    /// Case-insensitive title prefixes; empty matches every alert.
+    #[serde(default)]
+    pub titles: Vec<String>,
+    #[serde(default)]
+    pub min_severity: AlertSeverity,
+    pub channels: Vec<String>,
+}
+
+/// Repairs applied by periodic state reconciliation.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReconciliationPolicyConfig {
+    /// Overwrite diverging local positions and cash with the exchange view.
+    #[serde(default)]
+    pub adopt_exchange_state: bool,
+    /// Re-bind open exchange orders unknown to the OMS to the algorithm that placed them.
+    #[serde(default = "default_true")]
+    pub rebind_orders: bool,
+    /// Cancel open exchange orders that are unknown to the OMS and could not be re-bound.
+    #[serde(default)]
+    pub cancel_orphaned_orders: bool,
+    /// Switch the portfolio to liquidate-only when divergence exceeds the threshold.
+    #[serde(default = "default_true")]
+    pub liquidate_only: bool,
+}
+
+/// Transport security and client authentication for the control-plane gRPC server.
+///
+/// With no tokens and no client CA configured every caller is treated as an admin, which is
+/// only safe while the server is bound to localhost.
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct ControlPlaneConfig {
+    #[serde(default)]
+    pub tls: Option<ControlTlsConfig>,
+    /// Bearer tokens accepted in the `authorization` request header.
+    #[serde(default)]
+    pub tokens: Vec<ControlTokenConfig>,
+    /// Roles granted to mTLS clients, keyed by certificate common name.
+    #[serde(default)]
+    pub clients: Vec<ControlClientConfig>,
+}
+
+impl ControlPlaneConfig {
+    /// Whether callers must present a token or client certificate.
+    pub fn auth_enabled(&self) -> bool {
+        !self.tokens.is_empty()
+            || self
+                .tls
+                .as_ref()
+                .is_some_and(|tls| tls.client_ca_path.is_some())
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ControlTlsConfig {
+    /// PEM certificate chain presented by the server.
+    #[serde(default)]
+    pub cert_path: Option<PathBuf>,
+    /// PEM private key matching `cert_path`.
+    #[serde(default)]
+    pub key_path: Option<PathBuf>,
+    /// Generate (or reuse) a self-signed localhost certificate in `self_signed_dir` when no
+    /// certificate is configured. Intended for development only.
+    #[serde(default)]
+    pub self_signed: bool,
+    #[serde(default = "default_self_signed_dir")]
+    pub self_signed_dir: PathBuf,
+    /// PEM bundle used to verify client certificates; enables mTLS.
+    #[serde(default)]
+    pub client_ca_path: Option<PathBuf>,
+}
+
+/// Permission level of a control-plane caller, ordered from least to most privileged.
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ControlRole {
+    /// Snapshots, status and the event stream.
+    #[default]
+    ReadOnly,
+    /// Order entry, cancellation, flattening and liquidate-only.
+    Trader,
+    /// Strategy and risk-limit changes.
+    Admin,
+}
+
+impl ControlRole {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ReadOnly => "read_only",
+            Self::Trader => "trader",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ControlTokenConfig {
+    /// Label recorded in the audit log for calls made with this token.
+    pub name: String,
+    #[serde(default)]
+    pub token: Option<String>,
+    /// Environment variable holding the token; takes precedence over `token`.
+    #[serde(default)]
+    pub token_env: Option<String>,
+    #[serde(default)]
+    pub role: ControlRole,
+}
+
+impl ControlTokenConfig {
+    /// Resolve the secret from `token_env` or the inline `token`.
+    pub fn resolve(&self) -> Result<String> {
+        let value = match &self.token_env {
+            Some(var) => std::env::var(var).map_err(|_| {
+                anyhow!(
+                    "environment variable {var} for control token '{}' is not set",
+                    self.name
+                )
+            })?,
+            None => self.token.clone().ok_or_else(|| {
+                anyhow!("control token '{}' has no token or token_env", self.name)
+            })?,
+        };
+        if value.trim().is_empty() {
+            return Err(anyhow!("control token '{}' is empty", self.name));
+        }
+        Ok(value.trim().to_string())
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ControlClientConfig {
+    /// Subject common name of the client certificate.
+    pub common_name: String,
+    #[serde(default)]
+    pub role: ControlRole,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -127,9 +375,11 @@ impl Default for LiveRuntimeConfig {
             persistence: PersistenceConfig::default(),
             metrics_addr: default_metrics_addr(),
             control_addr: default_control_addr(),
+            control_plane: ControlPlaneConfig::default(),
             log_path: default_live_log_path(),
             reconciliation_interval_secs: default_reconciliation_interval_secs(),
             reconciliation_threshold: default_reconciliation_threshold(),
+            reconciliation_policy: ReconciliationPolicyConfig::default(),
             alerting: AlertingConfig::default(),
             plugins_dir: None,
         }
@@ -150,6 +400,22 @@ impl Default for AlertingConfig {
             max_data_gap_secs: default_data_gap_secs(),
             max_order_failures: default_order_failure_limit(),
             max_drawdown: default_drawdown_limit(),
+            channels: Vec::new(),
+            routes: Vec::new(),
+            dedup_window_secs: default_alert_dedup_window_secs(),
+            rate_limit: default_alert_rate_limit(),
+            rate_limit_window_secs: default_alert_rate_limit_window_secs(),
+        }
+    }
+}
+
+impl Default for ReconciliationPolicyConfig {
+    fn default() -> Self {
+        Self {
+            adopt_exchange_state: false,
+            rebind_orders: true,
+            cancel_orphaned_orders: false,
+            liquidate_only: true,
         }
     }
 }
@@ -248,6 +514,10 @@ fn default_metrics_addr() -> String {
     "127.0.0.1:9100".into()
 }
 
+fn default_self_signed_dir() -> PathBuf {
+    PathBuf::from("./reports/control-tls")
+}
+
 fn default_control_addr() -> String {
     "127.0.0.1:50052".into()
 }
@@ -264,6 +534,10 @@ fn default_reconciliation_threshold() -> Decimal {
     Decimal::new(1, 3) // 0.001 == 0.1%
 }
 
+fn default_true() -> bool {
+    true
+}
+
 fn default_data_gap_secs() -> u64 {
     300
 }
@@ -276,6 +550,30 @@ fn default_drawdown_limit() -> Decimal {
     Decimal::new(3, 2)
 }
 
+fn default_alert_dedup_window_secs() -> u64 {
+    300
+}
+
+fn default_alert_rate_limit() -> u32 {
+    5
+}
+
+fn default_alert_rate_limit_window_secs() -> u64 {
+    60
+}
+
+fn default_telegram_api_url() -> String {
+    "https://api.telegram.org".into()
+}
+
+fn default_smtp_port() -> u16 {
+    25
+}
+
+fn default_pagerduty_events_url() -> String {
+    "https://events.pagerduty.com/v2/enqueue".into()
+}
+
 fn default_max_order_quantity() -> Decimal {
     Decimal::ONE
 }
@@ -0,0 +1,445 @@
+//! Information-driven bars built from trade streams.
+//!
+//! Unlike the time-based resampler in `tesser-data`, which buckets candles by wall-clock time, these
+//! builders close a bar once enough market activity has been observed: a number of trades,
+//! traded volume, traded notional, or an unexpectedly large order-flow imbalance (López de
+//! Prado, *Advances in Financial Machine Learning*, ch. 2).
+//!
+//! ```
+//! # use tesser_core::Tick;
+//! # use tesser_core::bars::{BarBuilder, BarSpec};
+//! # fn demo(ticks: Vec<Tick>) {
+//! let mut builder = BarBuilder::new("dollar:1000000".parse::<BarSpec>().unwrap());
+//! for tick in &ticks {
+//!     if let Some(bar) = builder.push(tick) {
+//!         let _ = bar.close;
+//!     }
+//! }
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use crate::{Price, Quantity, Side, Symbol, Tick};
+
+/// Default EWMA span (in bars) used to adapt imbalance-bar thresholds.
+pub const DEFAULT_IMBALANCE_SPAN: usize = 20;
+
+/// Rule deciding when a bar is complete.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BarSpec {
+    /// Close after a fixed number of trades.
+    Tick(usize),
+    /// Close once traded base volume reaches the threshold.
+    Volume(Quantity),
+    /// Close once traded notional (`price * size`) reaches the threshold.
+    Dollar(Decimal),
+    /// Close when the signed trade count deviates from its expectation.
+    TickImbalance { expected_ticks: usize, span: usize },
+    /// Close when the signed traded volume deviates from its expectation.
+    VolumeImbalance { expected_ticks: usize, span: usize },
+}
+
+impl fmt::Display for BarSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tick(count) => write!(f, "tick:{count}"),
+            Self::Volume(volume) => write!(f, "volume:{volume}"),
+            Self::Dollar(notional) => write!(f, "dollar:{notional}"),
+            Self::TickImbalance {
+                expected_ticks,
+                span,
+            } => write!(f, "tick-imbalance:{expected_ticks}:{span}"),
+            Self::VolumeImbalance {
+                expected_ticks,
+                span,
+            } => write!(f, "volume-imbalance:{expected_ticks}:{span}"),
+        }
+    }
+}
+
+impl FromStr for BarSpec {
+    type Err = String;
+
+    /// Parses `tick:1000`, `volume:50`, `dollar:1000000`, `tick-imbalance:200[:span]` or
+    /// `volume-imbalance:200[:span]`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let mut parts = value.split(':');
+        let head = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let args: Vec<&str> = parts.collect();
+        let positive_decimal = |raw: &str| -> Result<Decimal, String> {
+            let parsed = raw
+                .trim()
+                .parse::<Decimal>()
+                .or_else(|_| {
+                    raw.trim()
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(Decimal::from_f64_retain)
+                        .ok_or(())
+                })
+                .map_err(|_| format!("invalid bar threshold '{raw}'"))?;
+            if parsed <= Decimal::ZERO {
+                return Err(format!("bar threshold must be positive (got {raw})"));
+            }
+            Ok(parsed)
+        };
+        let positive_count = |raw: &str| -> Result<usize, String> {
+            match raw.trim().parse::<usize>() {
+                Ok(count) if count > 0 => Ok(count),
+                _ => Err(format!("expected a positive integer (got '{raw}')")),
+            }
+        };
+        let imbalance_args = || -> Result<(usize, usize), String> {
+            match args.as_slice() {
+                [expected] => Ok((positive_count(expected)?, DEFAULT_IMBALANCE_SPAN)),
+                [expected, span] => Ok((positive_count(expected)?, positive_count(span)?)),
+                _ => Err(format!(
+                    "bar spec '{value}' expects '<expected-ticks>[:<span>]' after '{head}'"
+                )),
+            }
+        };
+        let single_arg = || -> Result<&str, String> {
+            match args.as_slice() {
+                [arg] => Ok(*arg),
+                _ => Err(format!(
+                    "bar spec '{value}' expects one argument after '{head}'"
+                )),
+            }
+        };
+        match head.as_str() {
+            "tick" | "ticks" => Ok(Self::Tick(positive_count(single_arg()?)?)),
+            "volume" => Ok(Self::Volume(positive_decimal(single_arg()?)?)),
+            "dollar" | "notional" => Ok(Self::Dollar(positive_decimal(single_arg()?)?)),
+            "tick-imbalance" | "tick_imbalance" => {
+                let (expected_ticks, span) = imbalance_args()?;
+                Ok(Self::TickImbalance {
+                    expected_ticks,
+                    span,
+                })
+            }
+            "volume-imbalance" | "volume_imbalance" => {
+                let (expected_ticks, span) = imbalance_args()?;
+                Ok(Self::VolumeImbalance {
+                    expected_ticks,
+                    span,
+                })
+            }
+            other => Err(format!("unknown bar type '{other}'")),
+        }
+    }
+}
+
+/// Completed (or partially filled) information-driven bar.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Bar {
+    pub symbol: Symbol,
+    /// Exchange timestamp of the first trade in the bar.
+    pub start: DateTime<Utc>,
+    /// Exchange timestamp of the last trade in the bar.
+    pub end: DateTime<Utc>,
+    pub open: Price,
+    pub high: Price,
+    pub low: Price,
+    pub close: Price,
+    pub volume: Quantity,
+    /// Volume traded by aggressive buyers.
+    pub buy_volume: Quantity,
+    /// Sum of `price * size` across trades.
+    pub notional: Decimal,
+    pub ticks: usize,
+}
+
+impl Bar {
+    fn open(tick: &Tick) -> Self {
+        Self {
+            symbol: tick.symbol,
+            start: tick.exchange_timestamp,
+            end: tick.exchange_timestamp,
+            open: tick.price,
+            high: tick.price,
+            low: tick.price,
+            close: tick.price,
+            volume: Decimal::ZERO,
+            buy_volume: Decimal::ZERO,
+            notional: Decimal::ZERO,
+            ticks: 0,
+        }
+    }
+
+    fn update(&mut self, tick: &Tick) {
+        self.high = self.high.max(tick.price);
+        self.low = self.low.min(tick.price);
+        self.close = tick.price;
+        self.end = tick.exchange_timestamp;
+        self.volume += tick.size;
+        if tick.side == Side::Buy {
+            self.buy_volume += tick.size;
+        }
+        self.notional += tick.price * tick.size;
+        self.ticks += 1;
+    }
+
+    /// Volume-weighted average price of the trades in the bar.
+    pub fn vwap(&self) -> Option<Price> {
+        (!self.volume.is_zero()).then(|| self.notional / self.volume)
+    }
+}
+
+/// Streaming builder that turns trades into bars according to a [`BarSpec`].
+///
+/// Symbols are tracked independently, so interleaved multi-symbol streams are supported.
+pub struct BarBuilder {
+    spec: BarSpec,
+    states: HashMap<Symbol, SymbolState>,
+}
+
+impl BarBuilder {
+    pub fn new(spec: BarSpec) -> Self {
+        Self {
+            spec,
+            states: HashMap::new(),
+        }
+    }
+
+    /// Convenience helper that processes the entire slice and returns the completed bars.
+    ///
+    /// The trailing partial bar of every symbol is discarded.
+    pub fn build(ticks: &[Tick], spec: BarSpec) -> Vec<Bar> {
+        let mut builder = Self::new(spec);
+        ticks.iter().filter_map(|tick| builder.push(tick)).collect()
+    }
+
+    pub fn spec(&self) -> BarSpec {
+        self.spec
+    }
+
+    /// Ingest one trade, returning a bar when it completes one.
+    pub fn push(&mut self, tick: &Tick) -> Option<Bar> {
+        let spec = self.spec;
+        let state = self
+            .states
+            .entry(tick.symbol)
+            .or_insert_with(|| SymbolState::new(spec));
+        let bar = state.current.get_or_insert_with(|| Bar::open(tick));
+        bar.update(tick);
+        state.imbalance += signed_contribution(spec, tick);
+        if !state.should_close(spec) {
+            return None;
+        }
+        state.close(spec)
+    }
+
+    /// The bar currently being built for `symbol`, if any.
+    pub fn partial(&self, symbol: Symbol) -> Option<&Bar> {
+        self.states.get(&symbol)?.current.as_ref()
+    }
+
+    /// Drain partially built bars, e.g. when the input stream ends.
+    pub fn flush(&mut self) -> Vec<Bar> {
+        let mut bars: Vec<Bar> = self
+            .states
+            .values_mut()
+            .filter_map(|state| {
+                state.imbalance = 0.0;
+                state.current.take()
+            })
+            .collect();
+        bars.sort_by_key(|bar| bar.end);
+        bars
+    }
+}
+
+struct SymbolState {
+    current: Option<Bar>,
+    /// Running signed imbalance of the current bar.
+    imbalance: f64,
+    /// EWMA of bar lengths (in trades).
+    expected_ticks: f64,
+    /// EWMA of per-trade signed contributions; `None` until the first bar completes.
+    expected_contribution: Option<f64>,
+    /// EWMA of per-trade absolute contributions, used as a floor for the threshold.
+    expected_magnitude: f64,
+    max_expected_ticks: f64,
+}
+
+impl SymbolState {
+    fn new(spec: BarSpec) -> Self {
+        let initial = match spec {
+            BarSpec::TickImbalance { expected_ticks, .. }
+            | BarSpec::VolumeImbalance { expected_ticks, .. } => expected_ticks as f64,
+            _ => 0.0,
+        };
+        Self {
+            current: None,
+            imbalance: 0.0,
+            expected_ticks: initial,
+            expected_contribution: None,
+            expected_magnitude: 0.0,
+            max_expected_ticks: initial * 10.0,
+        }
+    }
+
+    fn should_close(&self, spec: BarSpec) -> bool {
+        let Some(bar) = self.current.as_ref() else {
+            return false;
+        };
+        match spec {
+            BarSpec::Tick(count) => bar.ticks >= count,
+            BarSpec::Volume(threshold) => bar.volume >= threshold,
+            BarSpec::Dollar(threshold) => bar.notional >= threshold,
+            BarSpec::TickImbalance { .. } | BarSpec::VolumeImbalance { .. } => {
+                match self.expected_contribution {
+                    // Warm-up: the first bar spans the configured expected tick count.
+                    None => bar.ticks as f64 >= self.expected_ticks,
+                    Some(expected) => {
+                        let threshold =
+                            (self.expected_ticks * expected.abs()).max(self.expected_magnitude);
+                        self.imbalance.abs() >= threshold
+                    }
+                }
+            }
+        }
+    }
+
+    fn close(&mut self, spec: BarSpec) -> Option<Bar> {
+        let bar = self.current.take()?;
+        if let BarSpec::TickImbalance { span, .. } | BarSpec::VolumeImbalance { span, .. } = spec {
+            let alpha = 2.0 / (span as f64 + 1.0);
+            let ticks = bar.ticks.max(1) as f64;
+            let per_tick = self.imbalance / ticks;
+            let magnitude = match spec {
+                BarSpec::VolumeImbalance { .. } => to_f64(bar.volume) / ticks,
+                _ => 1.0,
+            };
+            match self.expected_contribution {
+                None => {
+                    self.expected_contribution = Some(per_tick);
+                    self.expected_magnitude = magnitude;
+                }
+                Some(previous) => {
+                    self.expected_contribution = Some(previous + alpha * (per_tick - previous));
+                    self.expected_magnitude += alpha * (magnitude - self.expected_magnitude);
+                    self.expected_ticks += alpha * (ticks - self.expected_ticks);
+                }
+            }
+            self.expected_ticks = self.expected_ticks.clamp(1.0, self.max_expected_ticks);
+        }
+        self.imbalance = 0.0;
+        Some(bar)
+    }
+}
+
+fn signed_contribution(spec: BarSpec, tick: &Tick) -> f64 {
+    let sign = match tick.side {
+        Side::Buy => 1.0,
+        Side::Sell => -1.0,
+    };
+    match spec {
+        BarSpec::TickImbalance { .. } => sign,
+        BarSpec::VolumeImbalance { .. } => sign * to_f64(tick.size),
+        _ => 0.0,
+    }
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+
+    fn tick(idx: i64, price: i64, size: i64, side: Side) -> Tick {
+        let ts = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::seconds(idx);
+        Tick {
+            symbol: "BTCUSDT".into(),
+            price: Decimal::new(price, 0),
+            size: Decimal::new(size, 0),
+            side,
+            exchange_timestamp: ts,
+            received_at: ts,
+        }
+    }
+
+    #[test]
+    fn parses_specs() {
+        assert_eq!("tick:10".parse::<BarSpec>().unwrap(), BarSpec::Tick(10));
+        assert_eq!(
+            "dollar:1e6".parse::<BarSpec>().unwrap(),
+            BarSpec::Dollar(Decimal::new(1_000_000, 0))
+        );
+        assert_eq!(
+            "volume-imbalance:50".parse::<BarSpec>().unwrap(),
+            BarSpec::VolumeImbalance {
+                expected_ticks: 50,
+                span: DEFAULT_IMBALANCE_SPAN,
+            }
+        );
+        assert!("volume:0".parse::<BarSpec>().is_err());
+        assert!("range:5".parse::<BarSpec>().is_err());
+    }
+
+    #[test]
+    fn threshold_bars_aggregate_trades() {
+        let ticks: Vec<Tick> = (0..10)
+            .map(|idx| tick(idx, 100 + idx, 2, Side::Buy))
+            .collect();
+
+        let tick_bars = BarBuilder::build(&ticks, BarSpec::Tick(4));
+        assert_eq!(tick_bars.len(), 2);
+        assert_eq!(tick_bars[0].open, Decimal::new(100, 0));
+        assert_eq!(tick_bars[0].close, Decimal::new(103, 0));
+        assert_eq!(tick_bars[0].volume, Decimal::new(8, 0));
+
+        let volume_bars = BarBuilder::build(&ticks, BarSpec::Volume(Decimal::new(6, 0)));
+        assert_eq!(volume_bars.len(), 3);
+        assert!(volume_bars.iter().all(|bar| bar.ticks == 3));
+
+        // Notional per trade is ~200-218, so 1000 is crossed every five trades.
+        let mut builder = BarBuilder::new(BarSpec::Dollar(Decimal::new(1_000, 0)));
+        let dollar_bars: Vec<Bar> = ticks.iter().filter_map(|t| builder.push(t)).collect();
+        assert_eq!(dollar_bars.len(), 2);
+        assert_eq!(dollar_bars[0].ticks, 5);
+        assert_eq!(
+            dollar_bars[0].vwap().unwrap(),
+            dollar_bars[0].notional / dollar_bars[0].volume
+        );
+        assert!(builder.flush().is_empty());
+    }
+
+    #[test]
+    fn imbalance_bars_close_early_on_one_sided_flow() {
+        let spec = BarSpec::TickImbalance {
+            expected_ticks: 10,
+            span: 5,
+        };
+        let mut builder = BarBuilder::new(spec);
+        // Balanced warm-up with a slight buy skew.
+        let warmup: Vec<Tick> = (0..10)
+            .map(|idx| {
+                let side = if idx % 5 == 4 { Side::Sell } else { Side::Buy };
+                tick(idx, 100, 1, if idx % 2 == 0 { Side::Sell } else { side })
+            })
+            .collect();
+        let mut bars: Vec<Bar> = warmup.iter().filter_map(|t| builder.push(t)).collect();
+        assert_eq!(bars.len(), 1);
+        assert_eq!(bars[0].ticks, 10);
+
+        // A run of aggressive sells should trip the threshold quickly.
+        let burst: Vec<Tick> = (10..40).map(|idx| tick(idx, 99, 1, Side::Sell)).collect();
+        bars.extend(burst.iter().filter_map(|t| builder.push(t)));
+        assert!(bars.len() > 2);
+        assert!(bars[1].ticks < 10);
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::cmp::Reverse;
 use std::collections::{BTreeMap, HashMap};
-use std::fmt::Write;
+use std::fmt::{self, Write};
 use std::str::FromStr;
 
 use chrono::{DateTime, Duration, Utc};
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
 
+pub mod bars;
 mod identifiers;
 
 pub use identifiers::{AssetId, ExchangeId, IdentifierParseError, Symbol};
@@ -185,7 +186,7 @@ pub enum ExitStrategy {
 }
 
 /// The side of an order or position.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum Side {
     /// Buy the instrument.
     Buy,
@@ -232,8 +233,72 @@ pub enum TimeInForce {
     FillOrKill,
 }
 
+/// Base unit an [`Interval`] is expressed in.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum IntervalUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    /// Calendar weeks starting Monday 00:00 UTC.
+    Week,
+    /// Calendar months starting on the 1st at 00:00 UTC.
+    Month,
+}
+
+impl IntervalUnit {
+    fn suffix(self) -> &'static str {
+        match self {
+            Self::Second => "s",
+            Self::Minute => "m",
+            Self::Hour => "h",
+            Self::Day => "d",
+            Self::Week => "w",
+            Self::Month => "mo",
+        }
+    }
+
+    /// Move `ts` by `amount` units, following the calendar for months.
+    fn shift(self, ts: DateTime<Utc>, amount: i64) -> Result<DateTime<Utc>, String> {
+        let out_of_range = || format!("shifting {ts} by {amount} {self:?} units is out of range");
+        if self != Self::Month {
+            let delta = i32::try_from(amount)
+                .ok()
+                .and_then(|amount| self.nominal_duration().checked_mul(amount))
+                .ok_or_else(out_of_range)?;
+            return ts.checked_add_signed(delta).ok_or_else(out_of_range);
+        }
+        let months = u32::try_from(amount.unsigned_abs())
+            .map(chrono::Months::new)
+            .map_err(|_| out_of_range())?;
+        let shifted = if amount >= 0 {
+            ts.checked_add_months(months)
+        } else {
+            ts.checked_sub_months(months)
+        };
+        shifted.ok_or_else(out_of_range)
+    }
+
+    /// Nominal length of one unit; months are treated as 30 days.
+    fn nominal_duration(self) -> Duration {
+        match self {
+            Self::Second => Duration::seconds(1),
+            Self::Minute => Duration::minutes(1),
+            Self::Hour => Duration::hours(1),
+            Self::Day => Duration::days(1),
+            Self::Week => Duration::weeks(1),
+            Self::Month => Duration::days(30),
+        }
+    }
+}
+
 /// Interval granularity used when aggregating ticks into candles.
-#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+///
+/// The named variants cover the most common granularities; any other multiple is represented by
+/// [`Interval::Custom`]. Build intervals through [`Interval::new`] or [`FromStr`] so equivalent
+/// spellings (e.g. `60m` and `1h`) normalize to the same value. Equality compares the
+/// normalized `(count, unit)` pair, so `Custom { count: 5, unit: Minute }` equals `FiveMinutes`.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub enum Interval {
     OneSecond,
     OneMinute,
@@ -242,65 +307,341 @@ pub enum Interval {
     OneHour,
     FourHours,
     OneDay,
+    /// Arbitrary multiple of a base unit (e.g. 3m, 2h, 1w, 1M).
+    Custom {
+        count: u32,
+        unit: IntervalUnit,
+    },
 }
 
+impl PartialEq for Interval {
+    fn eq(&self, other: &Self) -> bool {
+        self.parts() == other.parts()
+    }
+}
+
+impl Eq for Interval {}
+
+impl std::hash::Hash for Interval {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.parts().hash(state);
+    }
+}
+
+/// Monday 1970-01-05 00:00 UTC, the anchor used to align weekly buckets.
+const WEEK_ANCHOR_SECS: i64 = 4 * 86_400;
+
+/// Largest unit count accepted by [`Interval::new`], which keeps every derived duration
+/// representable.
+pub const MAX_INTERVAL_COUNT: u32 = i32::MAX as u32;
+
 impl Interval {
+    /// Build an interval of `count` units, normalizing to the named variants when possible.
+    pub fn new(count: u32, unit: IntervalUnit) -> Result<Self, String> {
+        if count == 0 {
+            return Err("interval count must be positive".into());
+        }
+        if count > MAX_INTERVAL_COUNT {
+            return Err(format!(
+                "interval count must not exceed {MAX_INTERVAL_COUNT}"
+            ));
+        }
+        let (count, unit) = match unit {
+            IntervalUnit::Second if count.is_multiple_of(60) => (count / 60, IntervalUnit::Minute),
+            _ => (count, unit),
+        };
+        let (count, unit) = match unit {
+            IntervalUnit::Minute if count.is_multiple_of(60) => (count / 60, IntervalUnit::Hour),
+            _ => (count, unit),
+        };
+        let (count, unit) = match unit {
+            IntervalUnit::Hour if count.is_multiple_of(24) => (count / 24, IntervalUnit::Day),
+            _ => (count, unit),
+        };
+        Ok(match (count, unit) {
+            (1, IntervalUnit::Second) => Self::OneSecond,
+            (1, IntervalUnit::Minute) => Self::OneMinute,
+            (5, IntervalUnit::Minute) => Self::FiveMinutes,
+            (15, IntervalUnit::Minute) => Self::FifteenMinutes,
+            (1, IntervalUnit::Hour) => Self::OneHour,
+            (4, IntervalUnit::Hour) => Self::FourHours,
+            (1, IntervalUnit::Day) => Self::OneDay,
+            (count, unit) => Self::Custom { count, unit },
+        })
+    }
+
+    /// Number of base units and the unit itself.
+    #[must_use]
+    pub fn parts(self) -> (u32, IntervalUnit) {
+        match self {
+            Self::OneSecond => (1, IntervalUnit::Second),
+            Self::OneMinute => (1, IntervalUnit::Minute),
+            Self::FiveMinutes => (5, IntervalUnit::Minute),
+            Self::FifteenMinutes => (15, IntervalUnit::Minute),
+            Self::OneHour => (1, IntervalUnit::Hour),
+            Self::FourHours => (4, IntervalUnit::Hour),
+            Self::OneDay => (1, IntervalUnit::Day),
+            Self::Custom { count, unit } => (count.clamp(1, MAX_INTERVAL_COUNT), unit),
+        }
+    }
+
+    /// Canonical short label such as `1m`, `4h`, `1w` or `1mo`.
+    #[must_use]
+    pub fn label(self) -> String {
+        let (count, unit) = self.parts();
+        format!("{count}{}", unit.suffix())
+    }
+
+    /// Whether bucket boundaries follow the calendar (weeks and months) rather than epoch time.
+    #[must_use]
+    pub fn is_calendar(self) -> bool {
+        matches!(self.parts().1, IntervalUnit::Week | IntervalUnit::Month)
+    }
+
     /// Convert the interval into a chrono `Duration`.
+    ///
+    /// Monthly intervals have no fixed length; they report a nominal 30 days per month. Use
+    /// [`Interval::align`] and [`Interval::offset`] for exact calendar arithmetic.
     #[must_use]
     pub fn as_duration(self) -> Duration {
-        match self {
-            Self::OneSecond => Duration::seconds(1),
-            Self::OneMinute => Duration::minutes(1),
-            Self::FiveMinutes => Duration::minutes(5),
-            Self::FifteenMinutes => Duration::minutes(15),
-            Self::OneHour => Duration::hours(1),
-            Self::FourHours => Duration::hours(4),
-            Self::OneDay => Duration::days(1),
+        let (count, unit) = self.parts();
+        // `parts` caps the count at `MAX_INTERVAL_COUNT`, so neither step can fail.
+        i32::try_from(count)
+            .ok()
+            .and_then(|count| unit.nominal_duration().checked_mul(count))
+            .unwrap_or(Duration::MAX)
+    }
+
+    /// Start of the bucket containing `ts`.
+    ///
+    /// Sub-weekly intervals are aligned to the Unix epoch, weeks to Monday 00:00 UTC and months
+    /// to the first day of the month.
+    #[must_use]
+    pub fn align(self, ts: DateTime<Utc>) -> DateTime<Utc> {
+        let (count, unit) = self.parts();
+        match unit {
+            IntervalUnit::Month => {
+                let months = month_index(ts);
+                let aligned = months - months.rem_euclid(count as i64);
+                month_start(aligned).unwrap_or(ts)
+            }
+            IntervalUnit::Week => {
+                let step = 7 * 86_400 * count as i64;
+                let offset = (ts.timestamp() - WEEK_ANCHOR_SECS).rem_euclid(step);
+                DateTime::from_timestamp(ts.timestamp() - offset, 0).unwrap_or(ts)
+            }
+            _ => {
+                let step = self
+                    .as_duration()
+                    .num_nanoseconds()
+                    .unwrap_or(i64::MAX)
+                    .max(1);
+                let Some(nanos) = ts.timestamp_nanos_opt() else {
+                    return ts;
+                };
+                let aligned = nanos - nanos.rem_euclid(step);
+                DateTime::from_timestamp(
+                    aligned.div_euclid(1_000_000_000),
+                    aligned.rem_euclid(1_000_000_000) as u32,
+                )
+                .unwrap_or(ts)
+            }
         }
     }
 
-    /// Convert to Bybit interval identifiers.
+    /// Move `ts` forward (or backward when negative) by `periods` intervals.
+    ///
+    /// Fails when the result does not fit in a `DateTime<Utc>`.
+    pub fn offset(self, ts: DateTime<Utc>, periods: i64) -> Result<DateTime<Utc>, String> {
+        let (count, unit) = self.parts();
+        let units = periods
+            .checked_mul(i64::from(count))
+            .ok_or_else(|| format!("offsetting {ts} by {periods} x {self} is out of range"))?;
+        unit.shift(ts, units)
+    }
+
+    /// Whole intervals elapsed between two timestamps (negative when `end < start`).
     #[must_use]
-    pub fn to_bybit(self) -> &'static str {
-        match self {
-            Self::OneSecond => "1",
-            Self::OneMinute => "1",
-            Self::FiveMinutes => "5",
-            Self::FifteenMinutes => "15",
-            Self::OneHour => "60",
-            Self::FourHours => "240",
-            Self::OneDay => "D",
+    pub fn periods_between(self, start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+        let (count, unit) = self.parts();
+        if unit == IntervalUnit::Month {
+            let mut months = month_index(end) - month_index(start);
+            if months > 0
+                && IntervalUnit::Month
+                    .shift(start, months)
+                    .is_ok_and(|shifted| shifted > end)
+            {
+                months -= 1;
+            }
+            return months.div_euclid(count as i64);
         }
+        let step = self.as_duration().num_milliseconds().max(1);
+        (end - start).num_milliseconds().div_euclid(step)
     }
 
-    /// Convert the interval to Binance-compatible identifiers.
-    pub fn to_binance(self) -> &'static str {
-        match self {
-            Self::OneSecond => "1s",
-            Self::OneMinute => "1m",
-            Self::FiveMinutes => "5m",
-            Self::FifteenMinutes => "15m",
-            Self::OneHour => "1h",
-            Self::FourHours => "4h",
-            Self::OneDay => "1d",
+    /// Largest interval accepted by `supported` that evenly divides this one, used to build
+    /// unsupported intervals by resampling a finer native series.
+    pub fn resample_base(self, supported: impl Fn(Interval) -> bool) -> Option<Interval> {
+        const LADDER: [(u32, IntervalUnit); 15] = [
+            (1, IntervalUnit::Month),
+            (1, IntervalUnit::Week),
+            (1, IntervalUnit::Day),
+            (12, IntervalUnit::Hour),
+            (8, IntervalUnit::Hour),
+            (6, IntervalUnit::Hour),
+            (4, IntervalUnit::Hour),
+            (2, IntervalUnit::Hour),
+            (1, IntervalUnit::Hour),
+            (30, IntervalUnit::Minute),
+            (15, IntervalUnit::Minute),
+            (5, IntervalUnit::Minute),
+            (3, IntervalUnit::Minute),
+            (1, IntervalUnit::Minute),
+            (1, IntervalUnit::Second),
+        ];
+        LADDER
+            .iter()
+            .filter_map(|(count, unit)| Interval::new(*count, *unit).ok())
+            .find(|candidate| {
+                *candidate != self && supported(*candidate) && self.is_divisible_by(*candidate)
+            })
+    }
+
+    fn is_divisible_by(self, other: Interval) -> bool {
+        let (count, unit) = self.parts();
+        let (other_count, other_unit) = other.parts();
+        match (unit, other_unit) {
+            (IntervalUnit::Month, IntervalUnit::Month)
+            | (IntervalUnit::Week, IntervalUnit::Week) => count.is_multiple_of(other_count),
+            (IntervalUnit::Month, IntervalUnit::Week) => false,
+            (IntervalUnit::Month | IntervalUnit::Week, _) => {
+                let day = Duration::days(1).num_milliseconds();
+                let step = other.as_duration().num_milliseconds();
+                step <= day && day % step == 0
+            }
+            (_, IntervalUnit::Month | IntervalUnit::Week) => false,
+            _ => {
+                let step = other.as_duration().num_milliseconds();
+                step > 0 && self.as_duration().num_milliseconds() % step == 0
+            }
         }
     }
+
+    /// Convert to Bybit interval identifiers, if Bybit serves this interval natively.
+    #[must_use]
+    pub fn to_bybit(self) -> Option<&'static str> {
+        Some(match self.parts() {
+            (1, IntervalUnit::Minute) => "1",
+            (3, IntervalUnit::Minute) => "3",
+            (5, IntervalUnit::Minute) => "5",
+            (15, IntervalUnit::Minute) => "15",
+            (30, IntervalUnit::Minute) => "30",
+            (1, IntervalUnit::Hour) => "60",
+            (2, IntervalUnit::Hour) => "120",
+            (4, IntervalUnit::Hour) => "240",
+            (6, IntervalUnit::Hour) => "360",
+            (12, IntervalUnit::Hour) => "720",
+            (1, IntervalUnit::Day) => "D",
+            (1, IntervalUnit::Week) => "W",
+            (1, IntervalUnit::Month) => "M",
+            _ => return None,
+        })
+    }
+
+    /// Convert the interval to Binance-compatible identifiers, if Binance serves it natively.
+    #[must_use]
+    pub fn to_binance(self) -> Option<&'static str> {
+        Some(match self.parts() {
+            (1, IntervalUnit::Second) => "1s",
+            (1, IntervalUnit::Minute) => "1m",
+            (3, IntervalUnit::Minute) => "3m",
+            (5, IntervalUnit::Minute) => "5m",
+            (15, IntervalUnit::Minute) => "15m",
+            (30, IntervalUnit::Minute) => "30m",
+            (1, IntervalUnit::Hour) => "1h",
+            (2, IntervalUnit::Hour) => "2h",
+            (4, IntervalUnit::Hour) => "4h",
+            (6, IntervalUnit::Hour) => "6h",
+            (8, IntervalUnit::Hour) => "8h",
+            (12, IntervalUnit::Hour) => "12h",
+            (1, IntervalUnit::Day) => "1d",
+            (3, IntervalUnit::Day) => "3d",
+            (1, IntervalUnit::Week) => "1w",
+            (1, IntervalUnit::Month) => "1M",
+            _ => return None,
+        })
+    }
+}
+
+fn month_index(ts: DateTime<Utc>) -> i64 {
+    use chrono::Datelike;
+    (ts.year() as i64 - 1970) * 12 + ts.month0() as i64
+}
+
+fn month_start(index: i64) -> Option<DateTime<Utc>> {
+    let year = 1970 + index.div_euclid(12);
+    let month = index.rem_euclid(12) as u32 + 1;
+    chrono::NaiveDate::from_ymd_opt(i32::try_from(year).ok()?, month, 1)?
+        .and_hms_opt(0, 0, 0)
+        .map(|naive| naive.and_utc())
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.label())
+    }
 }
 
 impl FromStr for Interval {
     type Err = String;
 
+    /// Accepts `<count><unit>` (e.g. `3m`, `2h`, `12h`, `1w`, `1mo`) plus the exchange aliases
+    /// `1`, `60`, `240`, `D` and `W`. `mo`/`month` mean months and `m`/`min` mean minutes.
+    ///
+    /// An upper-case `M` is rejected: older releases parsed intervals case-insensitively, so
+    /// `1M` used to mean one minute, while exchanges use it for one month.
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        match value.to_lowercase().as_str() {
-            "1s" | "1sec" | "1second" | "1" => Ok(Self::OneSecond),
-            "1m" | "1min" | "1minute" => Ok(Self::OneMinute),
-            "5m" | "5min" | "5minutes" => Ok(Self::FiveMinutes),
-            "15m" | "15min" | "15minutes" => Ok(Self::FifteenMinutes),
-            "1h" | "60m" | "1hour" | "60" => Ok(Self::OneHour),
-            "4h" | "240m" | "4hours" | "240" => Ok(Self::FourHours),
-            "1d" | "day" | "d" => Ok(Self::OneDay),
-            other => Err(format!("unsupported interval '{other}'")),
-        }
+        let trimmed = value.trim();
+        let unsupported = || format!("unsupported interval '{trimmed}'");
+        let digits_end = trimmed
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(trimmed.len());
+        let (digits, suffix) = trimmed.split_at(digits_end);
+        let count = if digits.is_empty() {
+            1
+        } else {
+            digits
+                .parse::<u32>()
+                .ok()
+                .filter(|count| *count <= MAX_INTERVAL_COUNT)
+                .ok_or_else(|| format!("interval '{trimmed}' exceeds {MAX_INTERVAL_COUNT} units"))?
+        };
+        let unit = match suffix {
+            // Bare numbers follow the historical aliases: 1 => 1s, 60 => 1h, 240 => 4h, other
+            // values are minutes (Bybit style).
+            "" => match count {
+                1 => return Ok(Self::OneSecond),
+                _ => IntervalUnit::Minute,
+            },
+            "M" => {
+                return Err(format!(
+                    "ambiguous interval '{trimmed}': use '{count}mo' for months or '{count}m' for \
+                     minutes"
+                ))
+            }
+            "W" => IntervalUnit::Week,
+            "D" => IntervalUnit::Day,
+            other => match other.to_lowercase().as_str() {
+                "s" | "sec" | "secs" | "second" | "seconds" => IntervalUnit::Second,
+                "m" | "min" | "mins" | "minute" | "minutes" => IntervalUnit::Minute,
+                "h" | "hr" | "hour" | "hours" => IntervalUnit::Hour,
+                "d" | "day" | "days" => IntervalUnit::Day,
+                "w" | "wk" | "week" | "weeks" => IntervalUnit::Week,
+                "mo" | "mon" | "month" | "months" => IntervalUnit::Month,
+                _ => return Err(unsupported()),
+            },
+        };
+        Self::new(count, unit).map_err(|_| unsupported())
     }
 }
 
@@ -346,6 +687,12 @@ pub struct OrderBook {
     pub exchange_checksum: Option<u32>,
     #[serde(default)]
     pub local_checksum: Option<u32>,
+    /// Venue update id of the last change folded into this book, when the connector tracks one.
+    #[serde(default)]
+    pub sequence: Option<u64>,
+    /// Update id of the book this one was built on; `None` for a fresh snapshot.
+    #[serde(default)]
+    pub prev_sequence: Option<u64>,
 }
 
 impl OrderBook {
@@ -404,6 +751,74 @@ pub struct DepthUpdate {
     pub timestamp: DateTime<Utc>,
 }
 
+/// Action carried by a market-by-order (L3) update.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketByOrderAction {
+    /// A new order joined the back of the queue at its price level.
+    Add,
+    /// An existing order changed size and/or price.
+    Modify,
+    /// The order left the book without trading.
+    Cancel,
+    /// The order traded (partially or fully) against an aggressor.
+    Fill,
+}
+
+/// Order-level (L3) book update emitted by market-by-order feeds.
+///
+/// `size` always reports the quantity left resting after the event, so cancels and
+/// complete fills carry zero.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MarketByOrderUpdate {
+    pub symbol: Symbol,
+    pub order_id: String,
+    pub side: Side,
+    pub price: Price,
+    pub size: Quantity,
+    pub action: MarketByOrderAction,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Funding rate settled on a perpetual swap at `timestamp`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FundingRate {
+    pub symbol: Symbol,
+    /// Rate applied to the position notional for one funding period (e.g. `0.0001` = 1bp).
+    pub rate: Decimal,
+    /// Mark price used for settlement, when the venue reports it.
+    #[serde(default)]
+    pub mark_price: Option<Price>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Aggregate open interest of a derivative sampled at `timestamp`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct OpenInterest {
+    pub symbol: Symbol,
+    /// Sampling period of the series.
+    pub interval: Interval,
+    /// Open interest in contracts (base units for linear perps).
+    pub open_interest: Quantity,
+    /// Open interest in quote currency, when the venue reports it.
+    #[serde(default)]
+    pub open_interest_value: Option<Decimal>,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Share of accounts holding long versus short positions at `timestamp`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LongShortRatio {
+    pub symbol: Symbol,
+    /// Sampling period of the series.
+    pub interval: Interval,
+    /// Fraction of accounts net long, in `[0, 1]`.
+    pub long_ratio: Decimal,
+    /// Fraction of accounts net short, in `[0, 1]`.
+    pub short_ratio: Decimal,
+    pub timestamp: DateTime<Utc>,
+}
+
 /// Local view of an order book backed by sorted price levels.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct LocalOrderBook {
@@ -834,6 +1249,7 @@ pub enum SignalPanicBehavior {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
     use rust_decimal::prelude::FromPrimitive;
 
     #[test]
@@ -842,6 +1258,102 @@ mod tests {
         assert_eq!(Interval::FourHours.as_duration(), Duration::hours(4));
     }
 
+    #[test]
+    fn parses_and_normalizes_interval_multiples() {
+        assert_eq!("60m".parse::<Interval>().unwrap(), Interval::OneHour);
+        assert_eq!("1d".parse::<Interval>().unwrap(), Interval::OneDay);
+        assert_eq!(
+            "3m".parse::<Interval>().unwrap(),
+            Interval::Custom {
+                count: 3,
+                unit: IntervalUnit::Minute
+            }
+        );
+        assert_eq!(
+            "1mo".parse::<Interval>().unwrap().parts(),
+            (1, IntervalUnit::Month)
+        );
+        assert_eq!("1mo".parse::<Interval>().unwrap().label(), "1mo");
+        assert!("1M".parse::<Interval>().unwrap_err().contains("ambiguous"));
+        assert!("3000000000w".parse::<Interval>().is_err());
+        assert!(Interval::new(u32::MAX, IntervalUnit::Week).is_err());
+        assert_eq!("1m".parse::<Interval>().unwrap(), Interval::OneMinute);
+        assert_eq!("12h".parse::<Interval>().unwrap().label(), "12h");
+        assert_eq!(
+            Interval::Custom {
+                count: 5,
+                unit: IntervalUnit::Minute
+            },
+            Interval::FiveMinutes
+        );
+        assert!("0m".parse::<Interval>().is_err());
+        assert!("7x".parse::<Interval>().is_err());
+        assert_eq!("2h".parse::<Interval>().unwrap().to_binance(), Some("2h"));
+        assert_eq!("2h".parse::<Interval>().unwrap().to_bybit(), Some("120"));
+        assert_eq!("45m".parse::<Interval>().unwrap().to_bybit(), None);
+    }
+
+    #[test]
+    fn aligns_calendar_intervals() {
+        let ts = Utc.with_ymd_and_hms(2024, 5, 16, 13, 45, 10).unwrap();
+        let week: Interval = "1w".parse().unwrap();
+        assert_eq!(
+            week.align(ts),
+            Utc.with_ymd_and_hms(2024, 5, 13, 0, 0, 0).unwrap()
+        );
+        let month: Interval = "1mo".parse().unwrap();
+        assert_eq!(
+            month.align(ts),
+            Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap()
+        );
+        let quarter: Interval = "3mo".parse().unwrap();
+        assert_eq!(
+            quarter.align(ts),
+            Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            month
+                .offset(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(), 2)
+                .unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()
+        );
+        let widest = Interval::new(MAX_INTERVAL_COUNT, IntervalUnit::Week).unwrap();
+        assert!(widest.as_duration() > Duration::zero());
+        assert!(widest.offset(ts, 1).is_err());
+        assert!(Interval::OneMinute.offset(ts, i64::MAX).is_err());
+        assert_eq!(
+            month.periods_between(
+                Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()
+            ),
+            3
+        );
+        let thirty: Interval = "30m".parse().unwrap();
+        assert_eq!(
+            thirty.align(ts),
+            Utc.with_ymd_and_hms(2024, 5, 16, 13, 30, 0).unwrap()
+        );
+        assert_eq!(
+            "2h".parse::<Interval>()
+                .unwrap()
+                .resample_base(|i| i.to_bybit().is_some()),
+            Some(Interval::OneHour)
+        );
+        assert_eq!(
+            "45m"
+                .parse::<Interval>()
+                .unwrap()
+                .resample_base(|i| i.to_bybit().is_some()),
+            Some("15m".parse().unwrap())
+        );
+        assert_eq!(
+            "2w".parse::<Interval>()
+                .unwrap()
+                .resample_base(|i| i.to_bybit().is_some()),
+            Some("1w".parse().unwrap())
+        );
+    }
+
     #[test]
     fn position_mark_price_updates_unrealized_pnl() {
         let mut position = Position {
@@ -0,0 +1,429 @@
+//! Order book bookkeeping for standalone market data capture.
+//!
+//! Connectors surface full top-of-book snapshots. [`BookCapture`] turns them into the flight
+//! recorder layout: a full snapshot when a symbol (re)starts or at a fixed cadence, and depth
+//! deltas in between. It also flags books that cannot be trusted (checksum mismatches, crossed
+//! books, out-of-order timestamps, sequence gaps) so the caller can re-subscribe and capture a
+//! fresh snapshot.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+
+use tesser_core::{DepthUpdate, OrderBook, OrderBookLevel, Symbol};
+
+/// Cadence and liveness thresholds applied by [`BookCapture`].
+#[derive(Clone, Copy, Debug)]
+pub struct CaptureConfig {
+    /// Interval between full snapshots written for a symbol.
+    pub snapshot_interval: Duration,
+    /// A symbol is reported as stalled when no book arrived for this long.
+    pub stall_timeout: Duration,
+}
+
+impl Default for CaptureConfig {
+    fn default() -> Self {
+        Self {
+            snapshot_interval: Duration::minutes(5),
+            stall_timeout: Duration::seconds(60),
+        }
+    }
+}
+
+/// Reason why a book was rejected and the symbol needs a fresh snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GapReason {
+    /// The exchange checksum does not match the locally maintained book.
+    ChecksumMismatch { exchange: u32, local: u32 },
+    /// Best bid is at or above best ask.
+    CrossedBook,
+    /// The book is older than the previous one for the same symbol.
+    OutOfOrder,
+    /// The book was not built on the previous one: updates in between were lost.
+    SequenceGap { expected: u64, received: u64 },
+}
+
+impl fmt::Display for GapReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ChecksumMismatch { exchange, local } => {
+                write!(f, "checksum mismatch (exchange {exchange}, local {local})")
+            }
+            Self::CrossedBook => f.write_str("crossed book"),
+            Self::OutOfOrder => f.write_str("out-of-order book"),
+            Self::SequenceGap { expected, received } => {
+                write!(
+                    f,
+                    "sequence gap (expected update {expected}, book built on {received})"
+                )
+            }
+        }
+    }
+}
+
+/// What to persist for an incoming book.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CaptureOutcome {
+    /// Write the full book to `order_books/`.
+    Snapshot(OrderBook),
+    /// Write the level changes since the previous book to `depth/`.
+    Delta(DepthUpdate),
+    /// Nothing changed since the previous book.
+    Unchanged,
+    /// The book was rejected; the symbol restarts from the next snapshot.
+    Gap(GapReason),
+}
+
+/// Running totals reported by [`BookCapture::stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CaptureStats {
+    pub snapshots: u64,
+    pub deltas: u64,
+    pub gaps: u64,
+    /// Symbols that went quiet for longer than the stall timeout.
+    pub stalls: u64,
+}
+
+struct SymbolState {
+    book: OrderBook,
+    last_snapshot: DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+}
+
+/// Tracks the last book per symbol and decides between snapshots and deltas.
+pub struct BookCapture {
+    config: CaptureConfig,
+    symbols: HashMap<Symbol, SymbolState>,
+    stats: CaptureStats,
+}
+
+impl BookCapture {
+    pub fn new(config: CaptureConfig) -> Self {
+        Self {
+            config,
+            symbols: HashMap::new(),
+            stats: CaptureStats::default(),
+        }
+    }
+
+    pub fn stats(&self) -> CaptureStats {
+        self.stats
+    }
+
+    /// Classify `book`, received at wall-clock time `now`.
+    pub fn ingest(&mut self, book: &OrderBook, now: DateTime<Utc>) -> CaptureOutcome {
+        if let Some(reason) = self.validate(book) {
+            self.symbols.remove(&book.symbol);
+            self.stats.gaps += 1;
+            return CaptureOutcome::Gap(reason);
+        }
+        let interval = self.config.snapshot_interval;
+        match self.symbols.get_mut(&book.symbol) {
+            Some(state) if book.timestamp - state.last_snapshot < interval => {
+                state.last_seen = now;
+                let delta = diff_books(&state.book, book);
+                state.book = book.clone();
+                match delta {
+                    Some(delta) => {
+                        self.stats.deltas += 1;
+                        CaptureOutcome::Delta(delta)
+                    }
+                    None => CaptureOutcome::Unchanged,
+                }
+            }
+            _ => {
+                self.symbols.insert(
+                    book.symbol,
+                    SymbolState {
+                        book: book.clone(),
+                        last_snapshot: book.timestamp,
+                        last_seen: now,
+                    },
+                );
+                self.stats.snapshots += 1;
+                CaptureOutcome::Snapshot(book.clone())
+            }
+        }
+    }
+
+    /// Forget every symbol so the next book of each is written as a snapshot.
+    ///
+    /// Call after reconnecting, since books received before the outage are no longer a valid
+    /// base for deltas.
+    pub fn reset(&mut self) {
+        self.symbols.clear();
+    }
+
+    /// Symbols whose last book is older than the stall timeout.
+    pub fn stalled(&self, now: DateTime<Utc>) -> Vec<Symbol> {
+        self.symbols
+            .iter()
+            .filter(|(_, state)| now - state.last_seen > self.config.stall_timeout)
+            .map(|(symbol, _)| *symbol)
+            .collect()
+    }
+
+    /// Forget stalled symbols and return them.
+    ///
+    /// Each quiet symbol is reported once; its next book is written as a fresh snapshot.
+    pub fn expire_stalled(&mut self, now: DateTime<Utc>) -> Vec<Symbol> {
+        let stalled = self.stalled(now);
+        for symbol in &stalled {
+            self.symbols.remove(symbol);
+        }
+        self.stats.stalls += stalled.len() as u64;
+        stalled
+    }
+
+    fn validate(&self, book: &OrderBook) -> Option<GapReason> {
+        if let (Some(exchange), Some(local)) = (book.exchange_checksum, book.local_checksum) {
+            if exchange != local {
+                return Some(GapReason::ChecksumMismatch { exchange, local });
+            }
+        }
+        if let (Some(bid), Some(ask)) = (book.best_bid(), book.best_ask()) {
+            if bid.price >= ask.price {
+                return Some(GapReason::CrossedBook);
+            }
+        }
+        if let Some(state) = self.symbols.get(&book.symbol) {
+            if book.timestamp < state.book.timestamp {
+                return Some(GapReason::OutOfOrder);
+            }
+            if let (Some(expected), Some(received)) = (state.book.sequence, book.prev_sequence) {
+                if received != expected {
+                    return Some(GapReason::SequenceGap { expected, received });
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Level changes turning `previous` into `current`; removed levels carry a zero size.
+fn diff_books(previous: &OrderBook, current: &OrderBook) -> Option<DepthUpdate> {
+    let bids = diff_side(&previous.bids, &current.bids);
+    let asks = diff_side(&previous.asks, &current.asks);
+    if bids.is_empty() && asks.is_empty() {
+        return None;
+    }
+    Some(DepthUpdate {
+        symbol: current.symbol,
+        bids,
+        asks,
+        timestamp: current.timestamp,
+    })
+}
+
+fn diff_side(previous: &[OrderBookLevel], current: &[OrderBookLevel]) -> Vec<OrderBookLevel> {
+    let before: HashMap<Decimal, Decimal> = previous
+        .iter()
+        .map(|level| (level.price, level.size))
+        .collect();
+    let mut changes: Vec<OrderBookLevel> = current
+        .iter()
+        .filter(|level| before.get(&level.price) != Some(&level.size))
+        .cloned()
+        .collect();
+    changes.extend(
+        previous
+            .iter()
+            .filter(|level| !current.iter().any(|next| next.price == level.price))
+            .map(|level| OrderBookLevel {
+                price: level.price,
+                size: Decimal::ZERO,
+            }),
+    );
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn level(price: i64, size: i64) -> OrderBookLevel {
+        OrderBookLevel {
+            price: Decimal::from(price),
+            size: Decimal::from(size),
+        }
+    }
+
+    fn book(ts: DateTime<Utc>, bids: Vec<OrderBookLevel>, asks: Vec<OrderBookLevel>) -> OrderBook {
+        OrderBook {
+            symbol: Symbol::from("bybit:BTCUSDT"),
+            bids,
+            asks,
+            timestamp: ts,
+            exchange_checksum: None,
+            local_checksum: None,
+            sequence: None,
+            prev_sequence: None,
+        }
+    }
+
+    #[test]
+    fn emits_snapshot_then_deltas_and_periodic_snapshots() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut capture = BookCapture::new(CaptureConfig {
+            snapshot_interval: Duration::seconds(10),
+            stall_timeout: Duration::seconds(5),
+        });
+        let first = book(start, vec![level(99, 1), level(98, 2)], vec![level(101, 1)]);
+        assert!(matches!(
+            capture.ingest(&first, start),
+            CaptureOutcome::Snapshot(_)
+        ));
+        assert_eq!(
+            capture.ingest(&first, start),
+            CaptureOutcome::Unchanged,
+            "identical books produce no delta"
+        );
+
+        let second = book(
+            start + Duration::seconds(1),
+            vec![level(99, 3)],
+            vec![level(101, 1), level(102, 4)],
+        );
+        let CaptureOutcome::Delta(delta) = capture.ingest(&second, start) else {
+            panic!("expected delta");
+        };
+        assert_eq!(delta.bids, vec![level(99, 3), level(98, 0)]);
+        assert_eq!(delta.asks, vec![level(102, 4)]);
+
+        let later = book(
+            start + Duration::seconds(11),
+            vec![level(99, 3)],
+            vec![level(101, 1)],
+        );
+        assert!(matches!(
+            capture.ingest(&later, start),
+            CaptureOutcome::Snapshot(_)
+        ));
+        assert_eq!(
+            capture.stats(),
+            CaptureStats {
+                snapshots: 2,
+                deltas: 1,
+                gaps: 0,
+                stalls: 0
+            }
+        );
+        assert_eq!(capture.stalled(start + Duration::seconds(6)).len(), 1);
+    }
+
+    #[test]
+    fn gaps_force_a_fresh_snapshot() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut capture = BookCapture::new(CaptureConfig::default());
+        let first = book(start, vec![level(99, 1)], vec![level(101, 1)]);
+        capture.ingest(&first, start);
+
+        let mut mismatched = book(
+            start + Duration::seconds(1),
+            vec![level(99, 2)],
+            vec![level(101, 1)],
+        );
+        mismatched.exchange_checksum = Some(1);
+        mismatched.local_checksum = Some(2);
+        assert_eq!(
+            capture.ingest(&mismatched, start),
+            CaptureOutcome::Gap(GapReason::ChecksumMismatch {
+                exchange: 1,
+                local: 2
+            })
+        );
+        let resynced = book(
+            start + Duration::seconds(2),
+            vec![level(99, 2)],
+            vec![level(101, 1)],
+        );
+        assert!(matches!(
+            capture.ingest(&resynced, start),
+            CaptureOutcome::Snapshot(_)
+        ));
+
+        let crossed = book(
+            start + Duration::seconds(3),
+            vec![level(102, 1)],
+            vec![level(101, 1)],
+        );
+        assert_eq!(
+            capture.ingest(&crossed, start),
+            CaptureOutcome::Gap(GapReason::CrossedBook)
+        );
+        capture.ingest(&resynced, start);
+        let stale = book(start, vec![level(99, 2)], vec![level(101, 1)]);
+        assert_eq!(
+            capture.ingest(&stale, start),
+            CaptureOutcome::Gap(GapReason::OutOfOrder)
+        );
+        assert_eq!(capture.stats().gaps, 3);
+    }
+    #[test]
+    fn sequence_gaps_force_a_fresh_snapshot() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut capture = BookCapture::new(CaptureConfig::default());
+        let sequenced = |seconds: i64, sequence: u64, prev_sequence: Option<u64>| {
+            let mut book = book(
+                start + Duration::seconds(seconds),
+                vec![level(99, seconds + 1)],
+                vec![level(101, 1)],
+            );
+            book.sequence = Some(sequence);
+            book.prev_sequence = prev_sequence;
+            book
+        };
+        assert!(matches!(
+            capture.ingest(&sequenced(0, 10, None), start),
+            CaptureOutcome::Snapshot(_)
+        ));
+        assert!(matches!(
+            capture.ingest(&sequenced(1, 11, Some(10)), start),
+            CaptureOutcome::Delta(_)
+        ));
+        assert_eq!(
+            capture.ingest(&sequenced(2, 15, Some(14)), start),
+            CaptureOutcome::Gap(GapReason::SequenceGap {
+                expected: 11,
+                received: 14
+            })
+        );
+        assert!(matches!(
+            capture.ingest(&sequenced(3, 16, Some(15)), start),
+            CaptureOutcome::Snapshot(_)
+        ));
+        assert_eq!(capture.stats().gaps, 1);
+    }
+
+    #[test]
+    fn stalled_symbols_are_reported_once_and_resnapshotted() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut capture = BookCapture::new(CaptureConfig {
+            snapshot_interval: Duration::minutes(5),
+            stall_timeout: Duration::seconds(5),
+        });
+        let quiet = book(start, vec![level(99, 1)], vec![level(101, 1)]);
+        let mut busy = quiet.clone();
+        busy.symbol = Symbol::from("bybit:ETHUSDT");
+        capture.ingest(&quiet, start);
+        capture.ingest(&busy, start);
+
+        let later = start + Duration::seconds(6);
+        let mut busy_later = busy.clone();
+        busy_later.timestamp = later;
+        capture.ingest(&busy_later, later);
+        assert_eq!(capture.expire_stalled(later), vec![quiet.symbol]);
+        assert!(capture.expire_stalled(later).is_empty());
+        assert_eq!(capture.stats().stalls, 1);
+
+        let mut resumed = quiet.clone();
+        resumed.timestamp = later;
+        assert!(matches!(
+            capture.ingest(&resumed, later),
+            CaptureOutcome::Snapshot(_)
+        ));
+    }
+}
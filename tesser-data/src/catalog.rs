@@ -0,0 +1,777 @@
+//! Dataset catalog indexing canonical parquet partitions.
+//!
+//! The catalog keeps one entry per `(file, symbol, interval)` with the time range covered by
+//! that slice of the file. Backtests query it for `symbol + [start, end)` and hand the matching
+//! files to [`ParquetMarketStream`](crate::parquet::ParquetMarketStream), which then skips row
+//! groups outside the window using the parquet statistics.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs::{self, File};
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
+use std::time::UNIX_EPOCH;
+
+use anyhow::{anyhow, bail, Context, Result};
+use arrow::array::{Array, ArrayRef, Int64Array, StringArray, TimestampNanosecondArray};
+use chrono::{DateTime, NaiveDate, Utc};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ProjectionMask;
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::statistics::Statistics;
+use serde::{Deserialize, Serialize};
+use tesser_core::Symbol;
+
+use crate::analytics::collect_parquet_files;
+pub use crate::parquet::TimeWindow;
+
+/// File name of the persisted index, stored at the catalog root.
+pub const CATALOG_FILE: &str = "catalog.json";
+
+const UNSPECIFIED_EXCHANGE: &str = "unspecified";
+
+/// Kind of market data stored in a parquet file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataKind {
+    Candles,
+    Ticks,
+    OrderBooks,
+    Depth,
+}
+
+impl DataKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Candles => "candles",
+            Self::Ticks => "ticks",
+            Self::OrderBooks => "order_books",
+            Self::Depth => "depth",
+        }
+    }
+
+    /// Column holding the event timestamp used for range pruning.
+    pub fn timestamp_column(self) -> &'static str {
+        match self {
+            Self::Ticks => "exchange_timestamp",
+            Self::Candles | Self::OrderBooks | Self::Depth => "timestamp",
+        }
+    }
+}
+
+impl fmt::Display for DataKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DataKind {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "candles" | "candle" => Ok(Self::Candles),
+            "ticks" | "tick" | "trades" => Ok(Self::Ticks),
+            "order_books" | "order_book" | "books" => Ok(Self::OrderBooks),
+            "depth" => Ok(Self::Depth),
+            other => Err(format!(
+                "unknown data kind '{other}' (expected candles, ticks, order_books or depth)"
+            )),
+        }
+    }
+}
+
+/// Indexed slice of a parquet file holding a single symbol (and interval for candles).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogEntry {
+    /// Path relative to the catalog root, or absolute when the file lives elsewhere.
+    pub path: PathBuf,
+    pub exchange: String,
+    pub symbol: String,
+    pub kind: DataKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+    /// UTC date of the first row, used as the partition key.
+    pub date: NaiveDate,
+    pub start: DateTime<Utc>,
+    /// Timestamp of the last row (inclusive).
+    pub end: DateTime<Utc>,
+    pub rows: u64,
+    /// File size when indexed; used by `verify` to detect rewritten partitions.
+    pub bytes: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified: Option<i64>,
+}
+
+impl CatalogEntry {
+    /// Returns true when the entry holds data for `symbol`.
+    ///
+    /// Entries without a known exchange match any venue with the same code.
+    pub fn matches_symbol(&self, symbol: &Symbol) -> bool {
+        if !self.symbol.eq_ignore_ascii_case(symbol.code()) {
+            return false;
+        }
+        !symbol.exchange.is_specified()
+            || self.exchange == UNSPECIFIED_EXCHANGE
+            || self.exchange.eq_ignore_ascii_case(symbol.exchange.name())
+    }
+}
+
+/// Hints applied while indexing files whose contents do not carry the information.
+#[derive(Clone, Debug, Default)]
+pub struct IndexOptions {
+    /// Exchange recorded for symbols stored without an `exchange:` prefix.
+    pub exchange: Option<String>,
+    /// Force the data kind instead of inferring it from the directory layout and schema.
+    pub kind: Option<DataKind>,
+}
+
+/// Filter applied by [`DatasetCatalog::query`].
+#[derive(Clone, Debug, Default)]
+pub struct CatalogQuery {
+    pub symbol: Option<Symbol>,
+    pub kind: Option<DataKind>,
+    pub interval: Option<String>,
+    pub window: TimeWindow,
+}
+
+/// Problem detected by [`DatasetCatalog::verify`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatalogIssue {
+    pub path: PathBuf,
+    pub problem: String,
+    /// True when the file no longer exists.
+    pub missing: bool,
+}
+
+/// Aggregated view of the entries sharing exchange, symbol, kind and interval.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CatalogSummary {
+    pub exchange: String,
+    pub symbol: String,
+    pub kind: DataKind,
+    pub interval: Option<String>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub rows: u64,
+    pub files: usize,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct CatalogFile {
+    entries: Vec<CatalogEntry>,
+}
+
+/// Index of canonical parquet data rooted at a directory.
+#[derive(Debug)]
+pub struct DatasetCatalog {
+    root: PathBuf,
+    entries: Vec<CatalogEntry>,
+}
+
+impl DatasetCatalog {
+    /// Load the catalog stored under `root`, starting empty when none exists yet.
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self> {
+        let root = root.into();
+        let path = root.join(CATALOG_FILE);
+        let entries = if path.exists() {
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            serde_json::from_str::<CatalogFile>(&contents)
+                .with_context(|| format!("invalid catalog {}", path.display()))?
+                .entries
+        } else {
+            Vec::new()
+        };
+        Ok(Self { root, entries })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn entries(&self) -> &[CatalogEntry] {
+        &self.entries
+    }
+
+    /// Persist the index to `<root>/catalog.json`.
+    pub fn save(&self) -> Result<()> {
+        fs::create_dir_all(&self.root)
+            .with_context(|| format!("failed to create {}", self.root.display()))?;
+        let path = self.root.join(CATALOG_FILE);
+        let contents = serde_json::to_string_pretty(&CatalogFile {
+            entries: self.entries.clone(),
+        })?;
+        fs::write(&path, contents).with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Index `path` (a parquet file or a directory searched recursively).
+    ///
+    /// Files already present in the catalog are re-indexed. Returns the number of files indexed.
+    pub fn add(&mut self, path: &Path, options: &IndexOptions) -> Result<usize> {
+        let files = if path.is_dir() {
+            collect_parquet_files(path)?
+        } else if path.exists() {
+            vec![path.to_path_buf()]
+        } else {
+            bail!("{} does not exist", path.display());
+        };
+        for file in &files {
+            let key = self.relative_path(file);
+            let indexed = index_file(file, &key, options)?;
+            self.entries.retain(|entry| entry.path != key);
+            self.entries.extend(indexed);
+        }
+        self.entries.sort_by(|a, b| {
+            (
+                &a.exchange,
+                &a.symbol,
+                a.kind,
+                &a.interval,
+                a.start,
+                &a.path,
+            )
+                .cmp(&(
+                    &b.exchange,
+                    &b.symbol,
+                    b.kind,
+                    &b.interval,
+                    b.start,
+                    &b.path,
+                ))
+        });
+        Ok(files.len())
+    }
+
+    /// Entries matching every populated field of `query`.
+    pub fn query(&self, query: &CatalogQuery) -> Vec<&CatalogEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| {
+                query
+                    .symbol
+                    .as_ref()
+                    .is_none_or(|symbol| entry.matches_symbol(symbol))
+            })
+            .filter(|entry| query.kind.is_none_or(|kind| entry.kind == kind))
+            .filter(|entry| {
+                query
+                    .interval
+                    .as_deref()
+                    .is_none_or(|interval| entry.interval.as_deref() == Some(interval))
+            })
+            .filter(|entry| query.window.overlaps(entry.start, entry.end))
+            .collect()
+    }
+
+    /// Absolute, de-duplicated paths of the files matching `query`, in time order.
+    pub fn files(&self, query: &CatalogQuery) -> Vec<PathBuf> {
+        let mut files: Vec<(DateTime<Utc>, PathBuf)> = Vec::new();
+        for entry in self.query(query) {
+            let path = self.resolve(&entry.path);
+            if !files.iter().any(|(_, existing)| existing == &path) {
+                files.push((entry.start, path));
+            }
+        }
+        files.sort();
+        files.into_iter().map(|(_, path)| path).collect()
+    }
+
+    /// Resolve a catalog path against the root.
+    pub fn resolve(&self, path: &Path) -> PathBuf {
+        self.root.join(path)
+    }
+
+    /// Aggregate entries by exchange, symbol, kind and interval.
+    pub fn summary(&self) -> Vec<CatalogSummary> {
+        let mut groups: BTreeMap<(String, String, DataKind, Option<String>), CatalogSummary> =
+            BTreeMap::new();
+        for entry in &self.entries {
+            let key = (
+                entry.exchange.clone(),
+                entry.symbol.clone(),
+                entry.kind,
+                entry.interval.clone(),
+            );
+            groups
+                .entry(key)
+                .and_modify(|summary| {
+                    summary.start = summary.start.min(entry.start);
+                    summary.end = summary.end.max(entry.end);
+                    summary.rows += entry.rows;
+                    summary.files += 1;
+                })
+                .or_insert_with(|| CatalogSummary {
+                    exchange: entry.exchange.clone(),
+                    symbol: entry.symbol.clone(),
+                    kind: entry.kind,
+                    interval: entry.interval.clone(),
+                    start: entry.start,
+                    end: entry.end,
+                    rows: entry.rows,
+                    files: 1,
+                });
+        }
+        groups.into_values().collect()
+    }
+
+    /// Check every indexed file still exists, has not changed and has a readable footer.
+    pub fn verify(&self) -> Vec<CatalogIssue> {
+        let mut files: BTreeMap<&Path, (u64, u64, Option<i64>)> = BTreeMap::new();
+        for entry in &self.entries {
+            let slot =
+                files
+                    .entry(entry.path.as_path())
+                    .or_insert((0, entry.bytes, entry.modified));
+            slot.0 += entry.rows;
+        }
+        let mut issues = Vec::new();
+        for (path, (rows, bytes, modified)) in files {
+            let resolved = self.resolve(path);
+            let issue = |problem: String, missing: bool| CatalogIssue {
+                path: path.to_path_buf(),
+                problem,
+                missing,
+            };
+            let metadata = match fs::metadata(&resolved) {
+                Ok(metadata) => metadata,
+                Err(_) => {
+                    issues.push(issue("file is missing".into(), true));
+                    continue;
+                }
+            };
+            if metadata.len() != bytes {
+                issues.push(issue(
+                    format!("size changed from {bytes} to {} bytes", metadata.len()),
+                    false,
+                ));
+                continue;
+            }
+            if modified.is_some() && modified_secs(&metadata) != modified {
+                issues.push(issue("modified since it was indexed".into(), false));
+                continue;
+            }
+            match read_metadata(&resolved) {
+                Ok(parquet) => {
+                    let actual = parquet.file_metadata().num_rows().max(0) as u64;
+                    if actual != rows {
+                        issues.push(issue(
+                            format!("expected {rows} rows but footer reports {actual}"),
+                            false,
+                        ));
+                    }
+                }
+                Err(err) => issues.push(issue(format!("unreadable: {err:#}"), false)),
+            }
+        }
+        issues
+    }
+
+    /// Drop every entry referring to one of `paths`.
+    pub fn remove(&mut self, paths: &[PathBuf]) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|entry| !paths.contains(&entry.path));
+        before - self.entries.len()
+    }
+
+    fn relative_path(&self, path: &Path) -> PathBuf {
+        let absolute = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        let root = fs::canonicalize(&self.root).unwrap_or_else(|_| self.root.clone());
+        absolute
+            .strip_prefix(&root)
+            .map(Path::to_path_buf)
+            .unwrap_or(absolute)
+    }
+}
+
+fn read_metadata(path: &Path) -> Result<std::sync::Arc<ParquetMetaData>> {
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+        .with_context(|| format!("failed to read parquet metadata from {}", path.display()))?;
+    Ok(builder.metadata().clone())
+}
+
+fn modified_secs(metadata: &fs::Metadata) -> Option<i64> {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|elapsed| elapsed.as_secs() as i64)
+}
+
+/// Per `(symbol, interval)` statistics gathered from one file.
+struct Slice {
+    min: i64,
+    max: i64,
+    rows: u64,
+}
+
+fn index_file(path: &Path, key: &Path, options: &IndexOptions) -> Result<Vec<CatalogEntry>> {
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    let stat = file.metadata()?;
+    let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+        .with_context(|| format!("failed to read parquet metadata from {}", path.display()))?;
+    let schema = builder.schema().clone();
+    let kind = match options.kind {
+        Some(kind) => kind,
+        None => infer_kind(path, |name| schema.index_of(name).is_ok())
+            .ok_or_else(|| anyhow!("cannot infer data kind of {}", path.display()))?,
+    };
+    let ts_column = kind.timestamp_column();
+    let metadata = builder.metadata().clone();
+    let interval_column = (kind == DataKind::Candles).then_some("interval");
+
+    let mut slices: HashMap<(String, Option<String>), Slice> = HashMap::new();
+    let symbol = constant_string(&metadata, "symbol");
+    let interval = interval_column.map(|column| constant_string(&metadata, column));
+    let bounds = timestamp_bounds(&metadata, ts_column);
+    match (symbol, interval, bounds) {
+        (Some(symbol), None, Some((min, max)))
+        | (Some(symbol), Some(Some(_)), Some((min, max))) => {
+            let interval = interval_column.and_then(|column| constant_string(&metadata, column));
+            let rows = metadata.file_metadata().num_rows().max(0) as u64;
+            slices.insert((symbol, interval), Slice { min, max, rows });
+        }
+        _ => scan_slices(builder, kind, interval_column, &mut slices)
+            .with_context(|| format!("failed to scan {}", path.display()))?,
+    }
+
+    let hive_exchange = hive_segment(key, "exchange");
+    let mut entries = Vec::new();
+    for ((raw_symbol, interval), slice) in slices {
+        let (exchange, symbol) = match raw_symbol.split_once(':') {
+            Some((exchange, code)) => (exchange.to_ascii_lowercase(), code.to_string()),
+            None => (
+                options
+                    .exchange
+                    .clone()
+                    .or_else(|| hive_exchange.clone())
+                    .unwrap_or_else(|| UNSPECIFIED_EXCHANGE.to_string()),
+                raw_symbol,
+            ),
+        };
+        let start = nanos_to_datetime(slice.min)?;
+        entries.push(CatalogEntry {
+            path: key.to_path_buf(),
+            exchange,
+            symbol: symbol.to_ascii_uppercase(),
+            kind,
+            interval,
+            date: start.date_naive(),
+            start,
+            end: nanos_to_datetime(slice.max)?,
+            rows: slice.rows,
+            bytes: stat.len(),
+            modified: modified_secs(&stat),
+        });
+    }
+    Ok(entries)
+}
+
+/// Infer the data kind from the recorder/normalizer directory layout, falling back to the schema.
+fn infer_kind(path: &Path, has_column: impl Fn(&str) -> bool) -> Option<DataKind> {
+    let from_dir = path
+        .components()
+        .rev()
+        .find_map(|component| match component {
+            Component::Normal(name) => match name.to_str()? {
+                "candles" => Some(DataKind::Candles),
+                "ticks" => Some(DataKind::Ticks),
+                "order_books" => Some(DataKind::OrderBooks),
+                "depth" => Some(DataKind::Depth),
+                _ => None,
+            },
+            _ => None,
+        });
+    if from_dir.is_some() {
+        return from_dir;
+    }
+    if has_column("interval") {
+        Some(DataKind::Candles)
+    } else if has_column("exchange_timestamp") {
+        Some(DataKind::Ticks)
+    } else if has_column("bids") || has_column("bids_price") {
+        Some(DataKind::OrderBooks)
+    } else {
+        None
+    }
+}
+
+/// Value of a `name=value` path segment, as written by hive-style partitioning.
+fn hive_segment(path: &Path, name: &str) -> Option<String> {
+    path.components().find_map(|component| match component {
+        Component::Normal(segment) => segment
+            .to_str()?
+            .strip_prefix(name)?
+            .strip_prefix('=')
+            .map(str::to_string),
+        _ => None,
+    })
+}
+
+fn column_position(metadata: &ParquetMetaData, column: &str) -> Option<usize> {
+    let schema = metadata.file_metadata().schema_descr();
+    (0..schema.num_columns()).find(|idx| schema.column(*idx).path().string() == column)
+}
+
+/// Returns the column value when the statistics show it is constant across the file.
+fn constant_string(metadata: &ParquetMetaData, column: &str) -> Option<String> {
+    let index = column_position(metadata, column)?;
+    let mut value: Option<Vec<u8>> = None;
+    for group in metadata.row_groups() {
+        let Some(Statistics::ByteArray(stats)) = group.column(index).statistics() else {
+            return None;
+        };
+        let (min, max) = (stats.min_opt()?, stats.max_opt()?);
+        if min != max || value.as_deref().is_some_and(|seen| seen != min.data()) {
+            return None;
+        }
+        value = Some(min.data().to_vec());
+    }
+    value.and_then(|bytes| String::from_utf8(bytes).ok())
+}
+
+fn timestamp_bounds(metadata: &ParquetMetaData, column: &str) -> Option<(i64, i64)> {
+    let index = column_position(metadata, column)?;
+    let mut bounds: Option<(i64, i64)> = None;
+    for group in metadata.row_groups() {
+        let Some(Statistics::Int64(stats)) = group.column(index).statistics() else {
+            return None;
+        };
+        let (min, max) = (*stats.min_opt()?, *stats.max_opt()?);
+        bounds = Some(match bounds {
+            Some((lo, hi)) => (lo.min(min), hi.max(max)),
+            None => (min, max),
+        });
+    }
+    bounds
+}
+
+/// Read the symbol, interval and timestamp columns when statistics are not conclusive.
+fn scan_slices(
+    builder: ParquetRecordBatchReaderBuilder<File>,
+    kind: DataKind,
+    interval_column: Option<&str>,
+    slices: &mut HashMap<(String, Option<String>), Slice>,
+) -> Result<()> {
+    let schema = builder.schema().clone();
+    let symbol_idx = schema.index_of("symbol")?;
+    let ts_idx = schema.index_of(kind.timestamp_column())?;
+    let interval_idx = interval_column
+        .map(|column| schema.index_of(column))
+        .transpose()?;
+    let mut roots = vec![symbol_idx, ts_idx];
+    roots.extend(interval_idx);
+    let mask = ProjectionMask::roots(builder.parquet_schema(), roots);
+    let reader = builder.with_projection(mask).build()?;
+    for batch in reader {
+        let batch = batch?;
+        let projected = batch.schema();
+        let symbols = string_column(batch.column(projected.index_of("symbol")?))?;
+        let timestamps = batch.column(projected.index_of(kind.timestamp_column())?);
+        let intervals = interval_column
+            .map(|column| {
+                projected
+                    .index_of(column)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|idx| string_column(batch.column(idx)))
+            })
+            .transpose()?;
+        for row in 0..batch.num_rows() {
+            let Some(ts) = timestamp_nanos(timestamps, row)? else {
+                continue;
+            };
+            let key = (
+                symbols.value(row).to_string(),
+                intervals.map(|column| column.value(row).to_string()),
+            );
+            slices
+                .entry(key)
+                .and_modify(|slice| {
+                    slice.min = slice.min.min(ts);
+                    slice.max = slice.max.max(ts);
+                    slice.rows += 1;
+                })
+                .or_insert(Slice {
+                    min: ts,
+                    max: ts,
+                    rows: 1,
+                });
+        }
+    }
+    Ok(())
+}
+
+fn string_column(array: &ArrayRef) -> Result<&StringArray> {
+    array
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .ok_or_else(|| anyhow!("expected utf8 column"))
+}
+
+fn timestamp_nanos(array: &ArrayRef, row: usize) -> Result<Option<i64>> {
+    if array.is_null(row) {
+        return Ok(None);
+    }
+    if let Some(values) = array.as_any().downcast_ref::<TimestampNanosecondArray>() {
+        return Ok(Some(values.value(row)));
+    }
+    if let Some(values) = array.as_any().downcast_ref::<Int64Array>() {
+        return Ok(Some(values.value(row)));
+    }
+    Err(anyhow!(
+        "timestamp column type mismatch; expected timestamp nanoseconds or int64"
+    ))
+}
+
+fn nanos_to_datetime(nanos: i64) -> Result<DateTime<Utc>> {
+    DateTime::<Utc>::from_timestamp(
+        nanos.div_euclid(1_000_000_000),
+        nanos.rem_euclid(1_000_000_000) as u32,
+    )
+    .ok_or_else(|| anyhow!("timestamp overflow for value {nanos}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use parquet::arrow::ArrowWriter;
+    use parquet::file::properties::WriterProperties;
+    use rust_decimal::Decimal;
+    use tempfile::tempdir;
+    use tesser_broker::MarketStream;
+    use tesser_core::{Candle, Interval};
+
+    use crate::encoding::candles_to_batch;
+    use crate::parquet::{prune_row_groups, ParquetMarketStream};
+
+    fn candles(symbol: &str, start: DateTime<Utc>, count: i64) -> Vec<Candle> {
+        (0..count)
+            .map(|idx| Candle {
+                symbol: Symbol::from(symbol),
+                interval: Interval::OneHour,
+                open: Decimal::ONE,
+                high: Decimal::ONE,
+                low: Decimal::ONE,
+                close: Decimal::from(idx),
+                volume: Decimal::ONE,
+                timestamp: start + Duration::hours(idx),
+            })
+            .collect()
+    }
+
+    /// Writes `rows` with a small row-group size so pruning has something to skip.
+    fn write_candles(path: &Path, rows: &[Candle]) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let batch = candles_to_batch(rows).unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(6)
+            .build();
+        let mut writer =
+            ArrowWriter::try_new(File::create(path).unwrap(), batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn indexes_queries_and_prunes_candles() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        let day = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let btc = root.join("candles/2024-01-01/btc.parquet");
+        let eth = root.join("candles/2024-01-02/eth.parquet");
+        write_candles(&btc, &candles("BTCUSDT", day, 24));
+        write_candles(&eth, &candles("ETHUSDT", day + Duration::days(1), 24));
+
+        let mut catalog = DatasetCatalog::open(root).unwrap();
+        let options = IndexOptions {
+            exchange: Some("bybit".into()),
+            kind: None,
+        };
+        assert_eq!(catalog.add(root, &options).unwrap(), 2);
+        catalog.save().unwrap();
+
+        let catalog = DatasetCatalog::open(root).unwrap();
+        assert_eq!(catalog.entries().len(), 2);
+        let entry = &catalog.entries()[0];
+        assert_eq!(entry.exchange, "bybit");
+        assert_eq!(entry.symbol, "BTCUSDT");
+        assert_eq!(entry.kind, DataKind::Candles);
+        assert_eq!(entry.interval.as_deref(), Some("1h"));
+        assert_eq!(entry.rows, 24);
+        assert_eq!(entry.end, day + Duration::hours(23));
+
+        let window = TimeWindow::new(
+            Some(day + Duration::hours(7)),
+            Some(day + Duration::hours(13)),
+        );
+        let query = CatalogQuery {
+            symbol: Some(Symbol::from("bybit:BTCUSDT")),
+            kind: Some(DataKind::Candles),
+            interval: Some("1h".into()),
+            window,
+        };
+        let files = catalog.files(&query);
+        assert_eq!(files, vec![btc.clone()]);
+
+        let metadata = read_metadata(&btc).unwrap();
+        assert_eq!(metadata.num_row_groups(), 4);
+        assert_eq!(
+            prune_row_groups(&metadata, "timestamp", &window),
+            vec![1, 2]
+        );
+
+        let mut stream = ParquetMarketStream::with_candles(vec![Symbol::from("BTCUSDT")], files)
+            .with_time_window(window);
+        let mut closes = Vec::new();
+        while let Some(candle) = stream.next_candle().await.unwrap() {
+            closes.push(candle.close);
+        }
+        assert_eq!(closes, (7..13).map(Decimal::from).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn verify_reports_missing_and_rewritten_files() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        let day = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let first = root.join("candles/a.parquet");
+        let second = root.join("candles/b.parquet");
+        write_candles(&first, &candles("BTCUSDT", day, 4));
+        write_candles(&second, &candles("BTCUSDT", day + Duration::hours(4), 4));
+
+        let mut catalog = DatasetCatalog::open(root).unwrap();
+        catalog.add(root, &IndexOptions::default()).unwrap();
+        assert!(catalog.verify().is_empty());
+        assert_eq!(catalog.entries()[0].exchange, UNSPECIFIED_EXCHANGE);
+
+        fs::remove_file(&first).unwrap();
+        write_candles(&second, &candles("BTCUSDT", day + Duration::hours(4), 12));
+        let issues = catalog.verify();
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].missing);
+        assert!(!issues[1].missing);
+
+        let missing: Vec<PathBuf> = issues
+            .iter()
+            .filter(|issue| issue.missing)
+            .map(|issue| issue.path.clone())
+            .collect();
+        assert_eq!(catalog.remove(&missing), 1);
+        assert_eq!(catalog.entries().len(), 1);
+    }
+
+    #[test]
+    fn parses_kinds_and_hive_exchange() {
+        assert_eq!("order-books".parse::<DataKind>(), Ok(DataKind::OrderBooks));
+        assert!("bars".parse::<DataKind>().is_err());
+        assert_eq!(
+            hive_segment(
+                Path::new("exchange=binance/symbol=BTCUSDT/x.parquet"),
+                "exchange"
+            ),
+            Some("binance".to_string())
+        );
+    }
+}
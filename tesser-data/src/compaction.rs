@@ -0,0 +1,747 @@
+//! Compaction and retention for flight recorder output.
+//!
+//! [`ParquetRecorder`](crate::recorder::ParquetRecorder) rotates files at `max_rows_per_file`
+//! and on restarts, so long captures end up as thousands of small files per
+//! `<kind>/<YYYY-MM-DD>` partition. [`compact_flight_recorder`] merges the closed files of each
+//! partition, sorts them by timestamp, drops duplicate rows (overlapping captures, candle
+//! updates, signals replayed under the same id) and rewrites them with stronger compression and
+//! row groups sized for pruning.
+//!
+//! Ticks carry no trade id, and distinct trades routinely share symbol, price, size, side and
+//! exchange timestamp (one taker order filling several makers). A tick row is therefore only a
+//! duplicate when it is byte-identical to a row of *another* input file, and each identical row
+//! is kept as many times as the file holding most copies of it.
+//!
+//! Compaction is safe to run next to a live recorder: files that are still being written
+//! (recently modified, or without a readable parquet footer) are left alone, outputs are
+//! written to a hidden temporary file and renamed into place before the inputs are removed.
+//! A crash between the rename and the cleanup only leaves duplicates, which the next run drops.
+
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{anyhow, bail, Context, Result};
+use arrow::array::{ArrayRef, UInt32Array};
+use arrow::compute::{concat_batches, lexsort_to_indices, take_record_batch, SortColumn};
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use arrow::row::{RowConverter, SortField};
+use chrono::{DateTime, NaiveDate, Utc};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::{Compression, ZstdLevel};
+use parquet::file::properties::WriterProperties;
+use tracing::{debug, warn};
+
+/// Marker embedded in the name of files produced by compaction.
+const COMPACTED_MARKER: &str = "-compacted-";
+
+/// How rows of a recorder kind are ordered and de-duplicated.
+struct KindSpec {
+    kind: &'static str,
+    timestamp: &'static str,
+    /// Columns identifying a row; empty means the whole row.
+    key: &'static [&'static str],
+    /// Keep the last occurrence of a key instead of the first (e.g. candles updated in place).
+    keep_last: bool,
+    /// Equal keys within one input file are distinct records; only copies of a row found in
+    /// other input files are dropped.
+    across_files: bool,
+}
+
+const KINDS: &[KindSpec] = &[
+    KindSpec {
+        kind: "ticks",
+        timestamp: "exchange_timestamp",
+        key: &[],
+        keep_last: false,
+        across_files: true,
+    },
+    KindSpec {
+        kind: "candles",
+        timestamp: "timestamp",
+        key: &["symbol", "interval", "timestamp"],
+        keep_last: true,
+        across_files: false,
+    },
+    KindSpec {
+        kind: "fills",
+        timestamp: "timestamp",
+        key: &[
+            "order_id",
+            "side",
+            "fill_price",
+            "fill_quantity",
+            "timestamp",
+        ],
+        keep_last: false,
+        across_files: false,
+    },
+    KindSpec {
+        kind: "orders",
+        timestamp: "updated_at",
+        key: &[],
+        keep_last: false,
+        across_files: false,
+    },
+    KindSpec {
+        kind: "order_books",
+        timestamp: "timestamp",
+        key: &["symbol", "timestamp"],
+        keep_last: true,
+        across_files: false,
+    },
+    KindSpec {
+        kind: "depth",
+        timestamp: "timestamp",
+        key: &[],
+        keep_last: false,
+        across_files: false,
+    },
+    KindSpec {
+        kind: "mbo",
+        timestamp: "timestamp",
+        key: &[],
+        keep_last: false,
+        across_files: false,
+    },
+    KindSpec {
+        kind: "signals",
+        timestamp: "generated_at",
+        key: &["id"],
+        keep_last: false,
+        across_files: false,
+    },
+];
+
+/// Names of the recorder kinds understood by compaction.
+pub fn compactable_kinds() -> impl Iterator<Item = &'static str> {
+    KINDS.iter().map(|spec| spec.kind)
+}
+
+fn kind_spec(kind: &str) -> Option<&'static KindSpec> {
+    KINDS.iter().find(|spec| spec.kind == kind)
+}
+
+/// How long each kind is kept before its date partitions are deleted.
+#[derive(Clone, Debug, Default)]
+pub struct RetentionPolicy {
+    /// Applies to kinds without an explicit override; `None` keeps data forever.
+    pub default_days: Option<u32>,
+    pub per_kind: HashMap<String, u32>,
+}
+
+impl RetentionPolicy {
+    /// Retention in days for `kind`, if any.
+    pub fn days_for(&self, kind: &str) -> Option<u32> {
+        self.per_kind.get(kind).copied().or(self.default_days)
+    }
+
+    /// Parse a `kind=days` override.
+    pub fn parse_override(value: &str) -> Result<(String, u32)> {
+        let (kind, days) = value
+            .split_once('=')
+            .ok_or_else(|| anyhow!("retention override '{value}' must look like kind=days"))?;
+        let kind = kind.trim();
+        if kind_spec(kind).is_none() {
+            bail!(
+                "unknown flight recorder kind '{kind}' (expected one of {})",
+                compactable_kinds().collect::<Vec<_>>().join(", ")
+            );
+        }
+        let days = days
+            .trim()
+            .parse()
+            .with_context(|| format!("invalid retention days in '{value}'"))?;
+        Ok((kind.to_string(), days))
+    }
+}
+
+/// Options for [`compact_flight_recorder`].
+#[derive(Clone, Debug)]
+pub struct CompactionConfig {
+    /// Flight recorder root containing one directory per kind.
+    pub root: PathBuf,
+    /// Restrict compaction to these kinds; empty means every known kind.
+    pub kinds: Vec<String>,
+    /// Files modified more recently than this are assumed to be in flight.
+    pub min_file_age: Duration,
+    /// Maximum rows per compacted file.
+    pub rows_per_file: usize,
+    /// Maximum rows per row group, which bounds the granularity of time-range pruning.
+    pub row_group_rows: usize,
+    /// ZSTD level used for compacted files.
+    pub zstd_level: i32,
+    pub retention: RetentionPolicy,
+    /// Report the plan without touching any file.
+    pub dry_run: bool,
+}
+
+impl Default for CompactionConfig {
+    fn default() -> Self {
+        Self {
+            root: PathBuf::from("data/flight_recorder"),
+            kinds: Vec::new(),
+            min_file_age: Duration::from_secs(300),
+            rows_per_file: 5_000_000,
+            row_group_rows: 131_072,
+            zstd_level: 9,
+            retention: RetentionPolicy::default(),
+            dry_run: false,
+        }
+    }
+}
+
+/// Outcome for a single `<kind>/<date>` partition.
+#[derive(Clone, Debug, Default)]
+pub struct PartitionCompaction {
+    pub kind: String,
+    pub date: String,
+    pub input_files: usize,
+    pub output_files: usize,
+    pub rows_in: usize,
+    pub rows_out: usize,
+    pub bytes_in: u64,
+    pub bytes_out: u64,
+}
+
+impl PartitionCompaction {
+    pub fn duplicates(&self) -> usize {
+        self.rows_in.saturating_sub(self.rows_out)
+    }
+}
+
+/// Summary returned by [`compact_flight_recorder`].
+#[derive(Clone, Debug, Default)]
+pub struct CompactionReport {
+    pub partitions: Vec<PartitionCompaction>,
+    /// Partition directories removed by the retention policy.
+    pub expired: Vec<PathBuf>,
+    /// Files left untouched because they are still being written.
+    pub in_flight: Vec<PathBuf>,
+}
+
+/// Apply retention and compact every eligible partition under `config.root`.
+pub fn compact_flight_recorder(
+    config: &CompactionConfig,
+    now: DateTime<Utc>,
+) -> Result<CompactionReport> {
+    if !config.root.is_dir() {
+        bail!("flight recorder root {} not found", config.root.display());
+    }
+    if config.rows_per_file == 0 || config.row_group_rows == 0 {
+        bail!("rows per file and rows per row group must be positive");
+    }
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::ZSTD(
+            ZstdLevel::try_new(config.zstd_level)
+                .with_context(|| format!("invalid zstd level {}", config.zstd_level))?,
+        ))
+        .set_max_row_group_size(config.row_group_rows)
+        .build();
+    let mut report = CompactionReport::default();
+    for spec in KINDS {
+        if !config.kinds.is_empty() && !config.kinds.iter().any(|kind| kind == spec.kind) {
+            continue;
+        }
+        let dir = config.root.join(spec.kind);
+        if !dir.is_dir() {
+            continue;
+        }
+        let cutoff = config
+            .retention
+            .days_for(spec.kind)
+            .map(|days| now.date_naive() - chrono::Duration::days(i64::from(days)));
+        for (date, partition) in date_partitions(&dir)? {
+            if cutoff.is_some_and(|cutoff| date < cutoff) {
+                if !config.dry_run {
+                    fs::remove_dir_all(&partition)
+                        .with_context(|| format!("failed to remove {}", partition.display()))?;
+                }
+                report.expired.push(partition);
+                continue;
+            }
+            let PartitionFiles { closed, in_flight } =
+                closed_files(&partition, config.min_file_age)?;
+            report.in_flight.extend(in_flight);
+            let needs_work =
+                closed.len() > 1 || closed.first().is_some_and(|(path, _)| !is_compacted(path));
+            if !needs_work {
+                continue;
+            }
+            let mut outcome = PartitionCompaction {
+                kind: spec.kind.to_string(),
+                date: date.format("%Y-%m-%d").to_string(),
+                input_files: closed.len(),
+                bytes_in: closed.iter().map(|(_, bytes)| bytes).sum(),
+                ..PartitionCompaction::default()
+            };
+            if !config.dry_run {
+                let inputs: Vec<PathBuf> = closed.into_iter().map(|(path, _)| path).collect();
+                match compact_partition(spec, &partition, &inputs, config, &properties, now) {
+                    Ok(written) => {
+                        outcome.rows_in = written.rows_in;
+                        outcome.rows_out = written.rows_out;
+                        outcome.output_files = written.outputs.len();
+                        outcome.bytes_out = written.bytes_out;
+                    }
+                    Err(err) => {
+                        warn!(
+                            partition = %partition.display(),
+                            error = %err,
+                            "skipping partition that could not be compacted"
+                        );
+                        continue;
+                    }
+                }
+            }
+            report.partitions.push(outcome);
+        }
+    }
+    Ok(report)
+}
+
+struct PartitionOutput {
+    outputs: Vec<PathBuf>,
+    rows_in: usize,
+    rows_out: usize,
+    bytes_out: u64,
+}
+
+fn compact_partition(
+    spec: &KindSpec,
+    partition: &Path,
+    inputs: &[PathBuf],
+    config: &CompactionConfig,
+    properties: &WriterProperties,
+    now: DateTime<Utc>,
+) -> Result<PartitionOutput> {
+    let (schema, batches) = read_partition(inputs)?;
+    let merged = concat_batches(&schema, batches.iter().map(|(_, batch)| batch))?;
+    let origins: Vec<usize> = batches
+        .iter()
+        .flat_map(|(file, batch)| std::iter::repeat_n(*file, batch.num_rows()))
+        .collect();
+    let rows_in = merged.num_rows();
+    let order = sort_by_timestamp(&merged, spec.timestamp)?;
+    let sorted = take_record_batch(&merged, &order)?;
+    let origins: Vec<usize> = order
+        .values()
+        .iter()
+        .map(|&row| origins[row as usize])
+        .collect();
+    let deduped = deduplicate(&sorted, &origins, spec)?;
+    let rows_out = deduped.num_rows();
+
+    let stamp = now.format("%Y%m%dT%H%M%S");
+    let mut outputs = Vec::new();
+    let mut staged = Vec::new();
+    let mut bytes_out = 0;
+    for offset in (0..rows_out).step_by(config.rows_per_file) {
+        let len = config.rows_per_file.min(rows_out - offset);
+        let name = format!(
+            "{}{COMPACTED_MARKER}{stamp}-{:04}.parquet",
+            spec.kind,
+            outputs.len()
+        );
+        let temp = partition.join(format!(".{name}.tmp"));
+        if let Err(err) = write_batch(&temp, &deduped.slice(offset, len), properties) {
+            for path in staged.iter().chain([&temp]) {
+                let _ = fs::remove_file(path);
+            }
+            return Err(err);
+        }
+        bytes_out += fs::metadata(&temp)?.len();
+        staged.push(temp);
+        outputs.push(partition.join(name));
+    }
+    // Publish all outputs before deleting any input so readers never miss rows.
+    for (temp, target) in staged.iter().zip(&outputs) {
+        fs::rename(temp, target)
+            .with_context(|| format!("failed to publish {}", target.display()))?;
+    }
+    for input in inputs {
+        if outputs.contains(input) {
+            continue;
+        }
+        fs::remove_file(input).with_context(|| format!("failed to remove {}", input.display()))?;
+    }
+    debug!(
+        partition = %partition.display(),
+        inputs = inputs.len(),
+        outputs = outputs.len(),
+        rows_in,
+        rows_out,
+        "compacted flight recorder partition"
+    );
+    Ok(PartitionOutput {
+        outputs,
+        rows_in,
+        rows_out,
+        bytes_out,
+    })
+}
+
+/// Reads every input file, tagging each batch with the index of the file it came from.
+fn read_partition(inputs: &[PathBuf]) -> Result<(SchemaRef, Vec<(usize, RecordBatch)>)> {
+    let mut schema: Option<SchemaRef> = None;
+    let mut batches = Vec::new();
+    for (file_index, path) in inputs.iter().enumerate() {
+        let file =
+            File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let file_schema = builder.schema().clone();
+        match &schema {
+            Some(expected) if expected.fields() != file_schema.fields() => {
+                bail!(
+                    "{} has a different schema than its partition",
+                    path.display()
+                );
+            }
+            Some(_) => {}
+            None => schema = Some(file_schema),
+        }
+        for batch in builder.build()? {
+            batches.push((file_index, batch?));
+        }
+    }
+    let schema = schema.ok_or_else(|| anyhow!("partition has no readable files"))?;
+    Ok((schema, batches))
+}
+
+/// Row order that sorts `batch` by `column`.
+fn sort_by_timestamp(batch: &RecordBatch, column: &str) -> Result<UInt32Array> {
+    let index = batch
+        .schema()
+        .index_of(column)
+        .with_context(|| format!("missing timestamp column '{column}'"))?;
+    // Tie-break on the original position so equal timestamps keep their arrival order.
+    let position: ArrayRef = Arc::new(UInt32Array::from_iter_values(0..batch.num_rows() as u32));
+    let indices = lexsort_to_indices(
+        &[
+            SortColumn {
+                values: batch.column(index).clone(),
+                options: None,
+            },
+            SortColumn {
+                values: position,
+                options: None,
+            },
+        ],
+        None,
+    )?;
+    Ok(indices)
+}
+
+/// Drops duplicate rows of `batch`; `origins` holds the input file of every row.
+fn deduplicate(batch: &RecordBatch, origins: &[usize], spec: &KindSpec) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let columns: Vec<ArrayRef> = if spec.key.is_empty() {
+        batch.columns().to_vec()
+    } else {
+        spec.key
+            .iter()
+            .map(|name| {
+                schema
+                    .index_of(name)
+                    .map(|index| batch.column(index).clone())
+                    .with_context(|| format!("missing key column '{name}'"))
+            })
+            .collect::<Result<_>>()?
+    };
+    let converter = RowConverter::new(
+        columns
+            .iter()
+            .map(|column| SortField::new(column.data_type().clone()))
+            .collect(),
+    )?;
+    let rows = converter.convert_columns(&columns)?;
+    if spec.across_files {
+        return keep_largest_file_copy(batch, &rows, origins);
+    }
+    let mut seen: HashMap<&[u8], usize> = HashMap::with_capacity(rows.num_rows());
+    let mut keep = Vec::with_capacity(rows.num_rows());
+    for (index, row) in rows.iter().enumerate() {
+        match seen.entry(row.data()) {
+            Entry::Vacant(slot) => {
+                slot.insert(keep.len());
+                keep.push(index as u32);
+            }
+            Entry::Occupied(slot) if spec.keep_last => keep[*slot.get()] = index as u32,
+            Entry::Occupied(_) => {}
+        }
+    }
+    if keep.len() == batch.num_rows() {
+        return Ok(batch.clone());
+    }
+    // Replacing in place can break the timestamp order only within equal keys, which share a
+    // timestamp for every kind that keeps the last occurrence.
+    Ok(take_record_batch(batch, &UInt32Array::from(keep))?)
+}
+
+/// Keeps each distinct row as many times as it occurs in the input file holding most copies.
+fn keep_largest_file_copy(
+    batch: &RecordBatch,
+    rows: &arrow::row::Rows,
+    origins: &[usize],
+) -> Result<RecordBatch> {
+    let mut kept: HashMap<&[u8], usize> = HashMap::with_capacity(rows.num_rows());
+    let mut per_file: HashMap<(&[u8], usize), usize> = HashMap::with_capacity(rows.num_rows());
+    let mut keep = Vec::with_capacity(rows.num_rows());
+    for (index, row) in rows.iter().enumerate() {
+        let occurrence = per_file.entry((row.data(), origins[index])).or_default();
+        *occurrence += 1;
+        let kept = kept.entry(row.data()).or_default();
+        if *occurrence > *kept {
+            *kept = *occurrence;
+            keep.push(index as u32);
+        }
+    }
+    if keep.len() == batch.num_rows() {
+        return Ok(batch.clone());
+    }
+    Ok(take_record_batch(batch, &UInt32Array::from(keep))?)
+}
+
+fn write_batch(path: &Path, batch: &RecordBatch, properties: &WriterProperties) -> Result<()> {
+    let file =
+        File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(properties.clone()))?;
+    writer.write(batch)?;
+    let file = writer.into_inner()?;
+    file.sync_all()
+        .with_context(|| format!("failed to sync {}", path.display()))?;
+    Ok(())
+}
+
+/// `YYYY-MM-DD` sub-directories of a kind directory.
+fn date_partitions(dir: &Path) -> Result<BTreeMap<NaiveDate, PathBuf>> {
+    let mut partitions = BTreeMap::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("failed to list {}", dir.display()))? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let date = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| NaiveDate::parse_from_str(name, "%Y-%m-%d").ok());
+        if let Some(date) = date {
+            partitions.insert(date, path);
+        }
+    }
+    Ok(partitions)
+}
+
+/// Files of a partition, split by whether the recorder may still be writing them.
+#[derive(Default)]
+struct PartitionFiles {
+    /// Closed files with their size in bytes.
+    closed: Vec<(PathBuf, u64)>,
+    in_flight: Vec<PathBuf>,
+}
+
+/// Split a partition into closed files (with their size) and files still being written.
+fn closed_files(partition: &Path, min_age: Duration) -> Result<PartitionFiles> {
+    let now = SystemTime::now();
+    let mut files = PartitionFiles::default();
+    for entry in fs::read_dir(partition)
+        .with_context(|| format!("failed to list {}", partition.display()))?
+    {
+        let path = entry?.path();
+        let is_parquet = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("parquet"));
+        if !is_parquet {
+            continue;
+        }
+        let metadata = fs::metadata(&path)?;
+        let age = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| now.duration_since(modified).ok())
+            .unwrap_or_default();
+        // The recorder only writes the footer when it closes a file.
+        let readable = File::open(&path)
+            .ok()
+            .and_then(|file| ParquetRecordBatchReaderBuilder::try_new(file).ok())
+            .is_some();
+        if age < min_age || !readable {
+            files.in_flight.push(path);
+        } else {
+            files.closed.push((path, metadata.len()));
+        }
+    }
+    files.closed.sort();
+    Ok(files)
+}
+
+fn is_compacted(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.contains(COMPACTED_MARKER))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal::Decimal;
+    use tesser_core::{Side, Symbol, Tick};
+
+    use crate::encoding::ticks_to_batch;
+
+    fn tick(second: i64, price: i64, received: i64) -> Tick {
+        let base = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        Tick {
+            symbol: Symbol::from("BTCUSDT"),
+            price: Decimal::from(price),
+            size: Decimal::ONE,
+            side: Side::Buy,
+            exchange_timestamp: base + chrono::Duration::seconds(second),
+            received_at: base + chrono::Duration::seconds(received),
+        }
+    }
+
+    fn write_ticks(path: &Path, ticks: &[Tick]) {
+        let batch = ticks_to_batch(ticks).unwrap();
+        let properties = WriterProperties::builder().build();
+        write_batch(path, &batch, &properties).unwrap();
+    }
+
+    fn config(root: &Path) -> CompactionConfig {
+        CompactionConfig {
+            root: root.to_path_buf(),
+            min_file_age: Duration::ZERO,
+            ..CompactionConfig::default()
+        }
+    }
+
+    #[test]
+    fn merges_sorts_and_deduplicates_partition() {
+        let temp = tempfile::tempdir().unwrap();
+        let partition = temp.path().join("ticks").join("2024-01-02");
+        fs::create_dir_all(&partition).unwrap();
+        write_ticks(
+            &partition.join("ticks-20240102T000000-0000.parquet"),
+            &[tick(3, 103, 3), tick(1, 101, 1)],
+        );
+        // Overlapping capture of a trade already recorded plus a new one.
+        write_ticks(
+            &partition.join("ticks-20240102T000100-0001.parquet"),
+            &[tick(1, 101, 1), tick(2, 102, 61)],
+        );
+        // A file without footer, as left by a recorder that is still writing.
+        fs::write(
+            partition.join("ticks-20240102T000200-0002.parquet"),
+            b"PAR1",
+        )
+        .unwrap();
+
+        let now = Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap();
+        let report = compact_flight_recorder(&config(temp.path()), now).unwrap();
+        assert_eq!(report.partitions.len(), 1);
+        let outcome = &report.partitions[0];
+        assert_eq!((outcome.input_files, outcome.output_files), (2, 1));
+        assert_eq!((outcome.rows_in, outcome.rows_out), (4, 3));
+        assert_eq!(report.in_flight.len(), 1);
+
+        let mut files: Vec<_> = fs::read_dir(&partition)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        files.sort();
+        assert_eq!(files.len(), 2, "compacted output plus the in-flight file");
+        let compacted = files.iter().find(|path| is_compacted(path)).unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(compacted).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let batch = reader.map(|batch| batch.unwrap()).next().unwrap();
+        let expected =
+            ticks_to_batch(&[tick(1, 101, 1), tick(2, 102, 61), tick(3, 103, 3)]).unwrap();
+        assert_eq!(batch, expected);
+
+        let again = compact_flight_recorder(&config(temp.path()), now).unwrap();
+        assert!(
+            again.partitions.is_empty(),
+            "compacted partitions are stable"
+        );
+    }
+
+    #[test]
+    fn keeps_distinct_trades_sharing_price_size_and_timestamp() {
+        let temp = tempfile::tempdir().unwrap();
+        let partition = temp.path().join("ticks").join("2024-01-02");
+        fs::create_dir_all(&partition).unwrap();
+        // One taker order filling two makers at the same price and millisecond.
+        write_ticks(
+            &partition.join("ticks-a.parquet"),
+            &[tick(1, 101, 1), tick(1, 101, 1), tick(1, 101, 2)],
+        );
+        // Leftover copy of the same capture next to a re-delivery with a new receive time.
+        write_ticks(
+            &partition.join("ticks-b.parquet"),
+            &[tick(1, 101, 1), tick(1, 101, 1), tick(1, 101, 5)],
+        );
+
+        let now = Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap();
+        let report = compact_flight_recorder(&config(temp.path()), now).unwrap();
+        let outcome = &report.partitions[0];
+        assert_eq!((outcome.rows_in, outcome.rows_out), (6, 4));
+
+        let compacted = fs::read_dir(&partition)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| is_compacted(path))
+            .unwrap();
+        let batch = ParquetRecordBatchReaderBuilder::try_new(File::open(compacted).unwrap())
+            .unwrap()
+            .build()
+            .unwrap()
+            .map(|batch| batch.unwrap())
+            .next()
+            .unwrap();
+        let expected = ticks_to_batch(&[
+            tick(1, 101, 1),
+            tick(1, 101, 1),
+            tick(1, 101, 2),
+            tick(1, 101, 5),
+        ])
+        .unwrap();
+        assert_eq!(batch, expected);
+    }
+
+    #[test]
+    fn retention_removes_expired_partitions() {
+        let temp = tempfile::tempdir().unwrap();
+        for date in ["2024-01-01", "2024-01-09"] {
+            let partition = temp.path().join("ticks").join(date);
+            fs::create_dir_all(&partition).unwrap();
+            write_ticks(&partition.join("ticks-a.parquet"), &[tick(1, 101, 1)]);
+        }
+        let mut config = config(temp.path());
+        config.retention.per_kind.insert("ticks".into(), 7);
+        config.dry_run = true;
+        let now = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+        let report = compact_flight_recorder(&config, now).unwrap();
+        assert_eq!(report.expired.len(), 1);
+        assert!(temp.path().join("ticks/2024-01-01").exists(), "dry run");
+
+        config.dry_run = false;
+        compact_flight_recorder(&config, now).unwrap();
+        assert!(!temp.path().join("ticks/2024-01-01").exists());
+        assert!(temp.path().join("ticks/2024-01-09").exists());
+        assert!(RetentionPolicy::parse_override("bogus=3").is_err());
+        assert_eq!(
+            RetentionPolicy::parse_override("depth=3").unwrap(),
+            ("depth".to_string(), 3)
+        );
+    }
+}
@@ -0,0 +1,565 @@
+//! Consolidated multi-venue feeds built from several flight-recorder roots.
+//!
+//! Each recorder root holds one venue's capture, and its parquet files carry bare symbol codes
+//! (`BTCUSDT`) without the exchange. [`ConsolidatedFeed`] replays every root through its own
+//! [`UnifiedEventStream`], re-tags symbols with the venue's [`ExchangeId`], optionally shifts
+//! timestamps by a measured clock offset, and k-way merges the venues into one timeline. Book
+//! and depth events also update a [`ConsolidatedBbo`], so every book event carries the best bid
+//! and offer across all venues quoting the same symbol code.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Utc};
+use futures::{stream, Stream, StreamExt};
+use tesser_core::{ExchangeId, LocalOrderBook, OrderBookLevel, Price, Quantity, Symbol};
+
+use crate::analytics::collect_parquet_files;
+use crate::merger::{UnifiedEvent, UnifiedEventKind, UnifiedEventStream};
+use crate::parquet::TickCursor;
+
+type VenueStream = Pin<Box<dyn Stream<Item = Result<UnifiedEvent>> + Send>>;
+
+/// Flight-recorder root captured from a single venue.
+#[derive(Clone, Debug)]
+pub struct VenueSource {
+    pub exchange: ExchangeId,
+    pub root: PathBuf,
+    /// Added to every timestamp of this venue before merging.
+    pub clock_offset: Duration,
+}
+
+impl VenueSource {
+    pub fn new(exchange: ExchangeId, root: impl Into<PathBuf>) -> Self {
+        Self {
+            exchange,
+            root: root.into(),
+            clock_offset: Duration::zero(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_clock_offset(mut self, offset: Duration) -> Self {
+        self.clock_offset = offset;
+        self
+    }
+}
+
+/// Estimate how far a venue's clock trails the local receive clock.
+///
+/// Returns the median of `received_at - exchange_timestamp` over the first `samples` recorded
+/// trades, or `None` when the root holds no trades. Using it as the clock offset maps exchange
+/// timestamps onto the local clock, which absorbs both clock skew and the typical network delay
+/// of that venue. Negative samples are kept because they are exactly the skew being measured.
+pub async fn measure_clock_offset(root: &Path, samples: usize) -> Result<Option<Duration>> {
+    let paths = collect_parquet_files(&root.join("ticks"))?;
+    if paths.is_empty() {
+        return Ok(None);
+    }
+    let mut cursor = TickCursor::new(paths);
+    let mut deltas = Vec::new();
+    while let Some(tick) = cursor.next().await? {
+        deltas.push(tick.received_at - tick.exchange_timestamp);
+        if deltas.len() >= samples.max(1) {
+            break;
+        }
+    }
+    if deltas.is_empty() {
+        return Ok(None);
+    }
+    deltas.sort();
+    Ok(Some(deltas[deltas.len() / 2]))
+}
+
+/// Top-of-book level contributed by one venue.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VenueQuote {
+    pub exchange: ExchangeId,
+    pub price: Price,
+    pub size: Quantity,
+}
+
+/// Best bid and offer for a symbol code across every venue quoting it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsolidatedQuote {
+    pub code: &'static str,
+    pub bid: Option<VenueQuote>,
+    pub ask: Option<VenueQuote>,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl ConsolidatedQuote {
+    /// Ask minus bid; negative when the venues are crossed.
+    pub fn spread(&self) -> Option<Price> {
+        Some(self.ask?.price - self.bid?.price)
+    }
+
+    /// Whether one venue bids above another venue's offer.
+    pub fn is_crossed(&self) -> bool {
+        self.spread().is_some_and(|spread| spread < Price::ZERO)
+    }
+}
+
+/// Tracks per-venue books and derives the consolidated best bid and offer.
+#[derive(Default)]
+pub struct ConsolidatedBbo {
+    books: HashMap<Symbol, LocalOrderBook>,
+}
+
+impl ConsolidatedBbo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a book or depth event, returning the refreshed quote for its symbol code.
+    /// Trades and market-by-order updates leave the books untouched and return `None`.
+    pub fn apply(
+        &mut self,
+        kind: &UnifiedEventKind,
+        timestamp: DateTime<Utc>,
+    ) -> Option<ConsolidatedQuote> {
+        let symbol = match kind {
+            UnifiedEventKind::OrderBook(book) => {
+                self.books
+                    .entry(book.symbol)
+                    .or_default()
+                    .load_snapshot(&levels(&book.bids), &levels(&book.asks));
+                book.symbol
+            }
+            UnifiedEventKind::Depth(update) => {
+                self.books
+                    .entry(update.symbol)
+                    .or_default()
+                    .apply_deltas(&levels(&update.bids), &levels(&update.asks));
+                update.symbol
+            }
+            UnifiedEventKind::Trade(_) | UnifiedEventKind::MarketByOrder(_) => return None,
+        };
+        Some(self.quote(symbol.code(), timestamp))
+    }
+
+    /// Best bid and offer for `code` across every venue seen so far.
+    pub fn quote(&self, code: &'static str, timestamp: DateTime<Utc>) -> ConsolidatedQuote {
+        let mut quote = ConsolidatedQuote {
+            code,
+            bid: None,
+            ask: None,
+            timestamp,
+        };
+        for (symbol, book) in &self.books {
+            if symbol.code() != code {
+                continue;
+            }
+            if let Some((price, size)) = book.best_bid() {
+                if quote.bid.is_none_or(|best| price > best.price) {
+                    quote.bid = Some(VenueQuote {
+                        exchange: symbol.exchange,
+                        price,
+                        size,
+                    });
+                }
+            }
+            if let Some((price, size)) = book.best_ask() {
+                if quote.ask.is_none_or(|best| price < best.price) {
+                    quote.ask = Some(VenueQuote {
+                        exchange: symbol.exchange,
+                        price,
+                        size,
+                    });
+                }
+            }
+        }
+        quote
+    }
+}
+
+/// Event emitted by [`ConsolidatedFeed`].
+#[derive(Debug)]
+pub struct ConsolidatedEvent {
+    pub exchange: ExchangeId,
+    /// Clock-aligned timestamp used to order the merged feed.
+    pub timestamp: DateTime<Utc>,
+    /// Payload with its symbol re-tagged to `exchange` and timestamps clock-aligned.
+    pub kind: UnifiedEventKind,
+    /// Consolidated best bid and offer after applying a book or depth event.
+    pub quote: Option<ConsolidatedQuote>,
+}
+
+impl From<ConsolidatedEvent> for UnifiedEvent {
+    fn from(event: ConsolidatedEvent) -> Self {
+        Self {
+            timestamp: event.timestamp,
+            kind: event.kind,
+        }
+    }
+}
+
+struct VenueCursor {
+    exchange: ExchangeId,
+    clock_offset: Duration,
+    codes: HashSet<&'static str>,
+    tagged: HashMap<Symbol, Symbol>,
+    stream: VenueStream,
+    peek: Option<UnifiedEvent>,
+    exhausted: bool,
+}
+
+impl VenueCursor {
+    async fn fill(&mut self) -> Result<()> {
+        while self.peek.is_none() && !self.exhausted {
+            match self.stream.next().await {
+                Some(event) => {
+                    let event = event?;
+                    if self.codes.is_empty() || self.codes.contains(symbol_of(&event.kind).code()) {
+                        self.peek = Some(self.align(event));
+                    }
+                }
+                None => self.exhausted = true,
+            }
+        }
+        Ok(())
+    }
+
+    fn align(&mut self, mut event: UnifiedEvent) -> UnifiedEvent {
+        let exchange = self.exchange;
+        let offset = self.clock_offset;
+        let mut retag = |symbol: &mut Symbol| {
+            *symbol = *self
+                .tagged
+                .entry(*symbol)
+                .or_insert_with(|| Symbol::from_code(exchange, symbol.code()));
+        };
+        match &mut event.kind {
+            UnifiedEventKind::OrderBook(book) => {
+                retag(&mut book.symbol);
+                book.timestamp += offset;
+            }
+            UnifiedEventKind::Depth(update) => {
+                retag(&mut update.symbol);
+                update.timestamp += offset;
+            }
+            UnifiedEventKind::Trade(tick) => {
+                retag(&mut tick.symbol);
+                // `received_at` is already on the local clock.
+                tick.exchange_timestamp += offset;
+            }
+            UnifiedEventKind::MarketByOrder(update) => {
+                retag(&mut update.symbol);
+                update.timestamp += offset;
+            }
+        }
+        event.timestamp += offset;
+        event
+    }
+}
+
+/// Chronological merge of several venues' recorder roots.
+pub struct ConsolidatedFeed {
+    venues: Vec<VenueCursor>,
+    bbo: ConsolidatedBbo,
+}
+
+impl ConsolidatedFeed {
+    /// Open every venue root. `symbols` filters by symbol code for the venue they belong to;
+    /// symbols without an exchange apply to every venue, and an empty list keeps everything.
+    /// Venues none of the requested symbols apply to are skipped.
+    pub fn from_venues(venues: &[VenueSource], symbols: &[Symbol]) -> Result<Self> {
+        if venues.is_empty() {
+            return Err(anyhow!("at least one venue must be provided"));
+        }
+        let mut streams = Vec::with_capacity(venues.len());
+        for venue in venues {
+            let codes: HashSet<&'static str> = symbols
+                .iter()
+                .filter(|symbol| {
+                    !symbol.exchange.is_specified() || symbol.exchange == venue.exchange
+                })
+                .map(|symbol| symbol.code())
+                .collect();
+            if codes.is_empty() && !symbols.is_empty() {
+                continue;
+            }
+            let stream = UnifiedEventStream::from_flight_recorder(&venue.root, &[])
+                .map_err(|err| {
+                    anyhow!(
+                        "failed to open {} recorder root {}: {err}",
+                        venue.exchange,
+                        venue.root.display()
+                    )
+                })?
+                .into_stream();
+            streams.push((venue.clone(), codes, Box::pin(stream) as VenueStream));
+        }
+        if streams.is_empty() {
+            return Err(anyhow!(
+                "none of the requested symbols belong to the given venues"
+            ));
+        }
+        Ok(Self::with_streams(streams))
+    }
+
+    fn with_streams(streams: Vec<(VenueSource, HashSet<&'static str>, VenueStream)>) -> Self {
+        let venues = streams
+            .into_iter()
+            .map(|(venue, codes, stream)| VenueCursor {
+                exchange: venue.exchange,
+                clock_offset: venue.clock_offset,
+                codes,
+                tagged: HashMap::new(),
+                stream,
+                peek: None,
+                exhausted: false,
+            })
+            .collect();
+        Self {
+            venues,
+            bbo: ConsolidatedBbo::new(),
+        }
+    }
+
+    /// Convert this feed into a [`futures::Stream`] implementation.
+    pub fn into_stream(self) -> impl Stream<Item = Result<ConsolidatedEvent>> {
+        stream::unfold(self, |mut state| async move {
+            match state.next_event().await {
+                Ok(Some(event)) => Some((Ok(event), state)),
+                Ok(None) => None,
+                Err(err) => Some((Err(err), state)),
+            }
+        })
+    }
+
+    async fn next_event(&mut self) -> Result<Option<ConsolidatedEvent>> {
+        for venue in &mut self.venues {
+            venue.fill().await?;
+        }
+        // Venues listed first win ties, keeping the merge deterministic.
+        let next = self
+            .venues
+            .iter_mut()
+            .filter_map(|venue| Some((venue.peek.as_ref()?.timestamp, venue)))
+            .min_by_key(|(timestamp, _)| *timestamp);
+        let Some((_, venue)) = next else {
+            return Ok(None);
+        };
+        let bbo = &mut self.bbo;
+        Ok(venue.peek.take().map(|event| {
+            let quote = bbo.apply(&event.kind, event.timestamp);
+            ConsolidatedEvent {
+                exchange: venue.exchange,
+                timestamp: event.timestamp,
+                kind: event.kind,
+                quote,
+            }
+        }))
+    }
+}
+
+fn symbol_of(kind: &UnifiedEventKind) -> Symbol {
+    match kind {
+        UnifiedEventKind::OrderBook(book) => book.symbol,
+        UnifiedEventKind::Depth(update) => update.symbol,
+        UnifiedEventKind::Trade(tick) => tick.symbol,
+        UnifiedEventKind::MarketByOrder(update) => update.symbol,
+    }
+}
+
+fn levels(levels: &[OrderBookLevel]) -> Vec<(Price, Quantity)> {
+    levels
+        .iter()
+        .map(|level| (level.price, level.size))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{self, File};
+
+    use futures::TryStreamExt;
+    use parquet::arrow::ArrowWriter;
+    use rust_decimal::Decimal;
+    use tempfile::tempdir;
+    use tesser_core::{OrderBook, Side, Tick};
+
+    use crate::encoding::ticks_to_batch;
+
+    fn at(millis: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(millis).unwrap()
+    }
+
+    fn book(millis: i64, bid: i64, ask: i64) -> UnifiedEvent {
+        let level = |price: i64| OrderBookLevel {
+            price: Decimal::from(price),
+            size: Decimal::ONE,
+        };
+        UnifiedEvent {
+            timestamp: at(millis),
+            kind: UnifiedEventKind::OrderBook(OrderBook {
+                symbol: Symbol::from("BTCUSDT"),
+                bids: vec![level(bid)],
+                asks: vec![level(ask)],
+                timestamp: at(millis),
+                exchange_checksum: None,
+                local_checksum: None,
+                sequence: None,
+                prev_sequence: None,
+            }),
+        }
+    }
+
+    fn tick(millis: i64, code: &str) -> Tick {
+        Tick {
+            symbol: Symbol::from(code),
+            price: Decimal::from(100),
+            size: Decimal::ONE,
+            side: Side::Buy,
+            exchange_timestamp: at(millis),
+            received_at: at(millis),
+        }
+    }
+
+    fn trade(millis: i64, code: &str) -> UnifiedEvent {
+        UnifiedEvent {
+            timestamp: at(millis),
+            kind: UnifiedEventKind::Trade(tick(millis, code)),
+        }
+    }
+
+    fn write_ticks(root: &Path, ticks: &[Tick]) -> Result<()> {
+        let dir = root.join("ticks");
+        fs::create_dir_all(&dir)?;
+        let batch = ticks_to_batch(ticks)?;
+        let mut writer = ArrowWriter::try_new(
+            File::create(dir.join("part.parquet"))?,
+            batch.schema(),
+            None,
+        )?;
+        writer.write(&batch)?;
+        writer.close()?;
+        Ok(())
+    }
+
+    fn feed(venues: Vec<(VenueSource, Vec<UnifiedEvent>)>) -> ConsolidatedFeed {
+        ConsolidatedFeed::with_streams(
+            venues
+                .into_iter()
+                .map(|(venue, events)| {
+                    let stream = stream::iter(events.into_iter().map(Ok));
+                    (venue, HashSet::new(), Box::pin(stream) as VenueStream)
+                })
+                .collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn merges_venues_with_clock_offsets_and_tags() -> Result<()> {
+        let bybit = ExchangeId::register("bybit_linear");
+        let binance = ExchangeId::register("binance_perp");
+        let events: Vec<ConsolidatedEvent> = feed(vec![
+            (
+                VenueSource::new(bybit, "bybit"),
+                vec![trade(1_000, "BTCUSDT"), trade(3_000, "BTCUSDT")],
+            ),
+            (
+                VenueSource::new(binance, "binance")
+                    .with_clock_offset(Duration::milliseconds(-500)),
+                vec![trade(1_500, "BTCUSDT"), trade(2_000, "BTCUSDT")],
+            ),
+        ])
+        .into_stream()
+        .try_collect()
+        .await?;
+
+        let order: Vec<(ExchangeId, i64)> = events
+            .iter()
+            .map(|event| (event.exchange, event.timestamp.timestamp_millis()))
+            .collect();
+        // Binance's 1_500 aligns to 1_000 and loses the tie to the first-listed venue.
+        assert_eq!(
+            order,
+            vec![
+                (bybit, 1_000),
+                (binance, 1_000),
+                (binance, 1_500),
+                (bybit, 3_000)
+            ]
+        );
+        let UnifiedEventKind::Trade(tick) = &events[1].kind else {
+            panic!("expected a trade");
+        };
+        assert_eq!(tick.symbol, "binance_perp:BTCUSDT".parse().unwrap());
+        assert_eq!(tick.exchange_timestamp, at(1_000));
+        assert_eq!(tick.received_at, at(1_500));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn builds_consolidated_bbo_across_venues() -> Result<()> {
+        let bybit = ExchangeId::register("bybit_linear");
+        let binance = ExchangeId::register("binance_perp");
+        let events: Vec<ConsolidatedEvent> = feed(vec![
+            (VenueSource::new(bybit, "bybit"), vec![book(1_000, 99, 101)]),
+            (
+                VenueSource::new(binance, "binance"),
+                vec![book(2_000, 100, 102), book(3_000, 102, 103)],
+            ),
+        ])
+        .into_stream()
+        .try_collect()
+        .await?;
+
+        let quote = events[1]
+            .quote
+            .as_ref()
+            .expect("book event carries a quote");
+        assert_eq!(quote.code, "BTCUSDT");
+        assert_eq!(quote.bid.unwrap().exchange, binance);
+        assert_eq!(quote.bid.unwrap().price, Decimal::from(100));
+        assert_eq!(quote.ask.unwrap().exchange, bybit);
+        assert_eq!(quote.spread(), Some(Decimal::ONE));
+        assert!(!quote.is_crossed());
+
+        let crossed = events[2].quote.as_ref().unwrap();
+        assert_eq!(crossed.spread(), Some(Decimal::from(-1)));
+        assert!(crossed.is_crossed());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exchange_qualified_symbols_skip_other_venues() -> Result<()> {
+        let bybit = ExchangeId::register("bybit_linear");
+        let binance = ExchangeId::register("binance_perp");
+        let dir = tempdir()?;
+        let bybit_root = dir.path().join("bybit");
+        let binance_root = dir.path().join("binance");
+        write_ticks(
+            &bybit_root,
+            &[tick(1_000, "BTCUSDT"), tick(2_000, "ETHUSDT")],
+        )?;
+        write_ticks(
+            &binance_root,
+            &[tick(1_500, "BTCUSDT"), tick(2_500, "ETHUSDT")],
+        )?;
+        let venues = [
+            VenueSource::new(bybit, &bybit_root),
+            VenueSource::new(binance, &binance_root),
+        ];
+
+        let events: Vec<ConsolidatedEvent> =
+            ConsolidatedFeed::from_venues(&venues, &["binance_perp:BTCUSDT".parse()?])?
+                .into_stream()
+                .try_collect()
+                .await?;
+        let seen: Vec<(ExchangeId, &str)> = events
+            .iter()
+            .map(|event| (event.exchange, symbol_of(&event.kind).code()))
+            .collect();
+        assert_eq!(seen, vec![(binance, "BTCUSDT")]);
+
+        let unmatched = ConsolidatedFeed::from_venues(&venues, &["okx:BTCUSDT".parse()?]);
+        assert!(unmatched.is_err());
+        Ok(())
+    }
+}
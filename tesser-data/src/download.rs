@@ -9,24 +9,40 @@ use chrono::{DateTime, Days, Utc};
 use futures::StreamExt;
 use reqwest::{Client, StatusCode};
 use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde_json::Value as JsonValue;
-use tesser_core::{Candle, Interval, Side, Symbol, Tick};
+use tesser_core::{
+    Candle, FundingRate, Interval, LongShortRatio, OpenInterest, Side, Symbol, Tick,
+};
 use tokio::fs::{self, OpenOptions};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::task;
 use tracing::{debug, info};
 use zip::ZipArchive;
 
+use crate::transform::Resampler;
+
 const MAX_LIMIT: usize = 1000;
 const BYBIT_PUBLIC_BASE_URL: &str = "https://public.bybit.com/trading";
 const BINANCE_PUBLIC_BASE_URL: &str = "https://data.binance.vision/data/futures/um/daily/aggTrades";
 const NANOS_PER_SECOND: i64 = 1_000_000_000;
+const BYBIT_FUNDING_LIMIT: usize = 200;
+const BYBIT_OPEN_INTEREST_LIMIT: usize = 200;
+const BYBIT_ACCOUNT_RATIO_LIMIT: usize = 500;
+const BINANCE_FUNDING_LIMIT: usize = 1000;
+const BINANCE_FUTURES_DATA_LIMIT: usize = 500;
 
 #[async_trait]
 pub trait MarketDataDownloader {
     async fn download_klines(&self, req: &KlineRequest<'_>) -> Result<Vec<Candle>>;
     async fn download_trades(&self, req: &TradeRequest<'_>) -> Result<Vec<NormalizedTrade>>;
+    async fn download_funding_rates(&self, req: &MetricRequest<'_>) -> Result<Vec<FundingRate>>;
+    async fn download_open_interest(&self, req: &MetricRequest<'_>) -> Result<Vec<OpenInterest>>;
+    async fn download_long_short_ratios(
+        &self,
+        req: &MetricRequest<'_>,
+    ) -> Result<Vec<LongShortRatio>>;
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -115,6 +131,18 @@ impl NormalizedTrade {
     }
 }
 
+/// Price series a kline request downloads.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum KlinePrice {
+    /// Traded price, including volume.
+    #[default]
+    Trade,
+    /// Mark price used for margining and funding; volume is always zero.
+    Mark,
+    /// Underlying index price; volume is always zero.
+    Index,
+}
+
 /// Parameters for a kline download request.
 pub struct KlineRequest<'a> {
     pub category: &'a str,
@@ -123,6 +151,7 @@ pub struct KlineRequest<'a> {
     pub start: DateTime<Utc>,
     pub end: DateTime<Utc>,
     pub limit: usize,
+    pub price: KlinePrice,
 }
 
 impl<'a> KlineRequest<'a> {
@@ -140,8 +169,109 @@ impl<'a> KlineRequest<'a> {
             start,
             end,
             limit: MAX_LIMIT,
+            price: KlinePrice::Trade,
         }
     }
+
+    #[must_use]
+    pub fn with_price(mut self, price: KlinePrice) -> Self {
+        self.price = price;
+        self
+    }
+}
+
+/// Parameters for funding rate, open interest and long/short ratio downloads.
+pub struct MetricRequest<'a> {
+    pub category: &'a str,
+    pub symbol: &'a str,
+    /// Sampling period of open interest and long/short ratios; funding uses the venue schedule.
+    pub interval: Interval,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl<'a> MetricRequest<'a> {
+    pub fn new(
+        category: &'a str,
+        symbol: &'a str,
+        interval: Interval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            category,
+            symbol,
+            interval,
+            start,
+            end,
+        }
+    }
+
+    fn range_millis(&self) -> Result<(i64, i64)> {
+        let start_ms = self.start.timestamp_millis();
+        let end_ms = self.end.timestamp_millis();
+        if start_ms >= end_ms {
+            return Err(anyhow!("start must be earlier than end"));
+        }
+        Ok((start_ms, end_ms))
+    }
+}
+
+/// Build an interval the venue does not serve natively by downloading the largest native
+/// interval that divides it and resampling locally.
+async fn download_resampled<D>(
+    downloader: &D,
+    req: &KlineRequest<'_>,
+    supported: fn(Interval) -> bool,
+) -> Result<Vec<Candle>>
+where
+    D: MarketDataDownloader + Sync,
+{
+    let base = req.interval.resample_base(supported).ok_or_else(|| {
+        anyhow!(
+            "interval {} is not supported natively and cannot be resampled from a supported interval",
+            req.interval
+        )
+    })?;
+    info!(
+        interval = %req.interval,
+        base = %base,
+        "interval not served natively; resampling"
+    );
+    let base_req = KlineRequest {
+        category: req.category,
+        symbol: req.symbol,
+        interval: base,
+        start: req.interval.align(req.start),
+        end: req.end,
+        limit: req.limit,
+        price: req.price,
+    };
+    let candles = downloader.download_klines(&base_req).await?;
+    resample_complete(candles, base, req.interval)
+}
+
+/// Resample `base` candles into `target` buckets, dropping the trailing bucket when the base
+/// series ends before that bucket closes.
+fn resample_complete(
+    candles: Vec<Candle>,
+    base: Interval,
+    target: Interval,
+) -> Result<Vec<Candle>> {
+    let Some(last) = candles.iter().map(|candle| candle.timestamp).max() else {
+        return Ok(Vec::new());
+    };
+    let covered = base.offset(last, 1).map_err(|err| anyhow!(err))?;
+    let mut resampled = Resampler::resample(candles, target);
+    if let Some(tail) = resampled.last() {
+        let closes = target
+            .offset(tail.timestamp, 1)
+            .map_err(|err| anyhow!(err))?;
+        if closes > covered {
+            resampled.pop();
+        }
+    }
+    Ok(resampled)
 }
 
 /// Simple Bybit REST downloader for kline data.
@@ -172,6 +302,106 @@ impl BybitDownloader {
     pub async fn download_trades(&self, req: &TradeRequest<'_>) -> Result<Vec<NormalizedTrade>> {
         <Self as MarketDataDownloader>::download_trades(self, req).await
     }
+
+    /// Download settled funding rates from Bybit.
+    pub async fn download_funding_rates(
+        &self,
+        req: &MetricRequest<'_>,
+    ) -> Result<Vec<FundingRate>> {
+        <Self as MarketDataDownloader>::download_funding_rates(self, req).await
+    }
+
+    /// Download open interest history from Bybit.
+    pub async fn download_open_interest(
+        &self,
+        req: &MetricRequest<'_>,
+    ) -> Result<Vec<OpenInterest>> {
+        <Self as MarketDataDownloader>::download_open_interest(self, req).await
+    }
+
+    /// Download long/short account ratios from Bybit.
+    pub async fn download_long_short_ratios(
+        &self,
+        req: &MetricRequest<'_>,
+    ) -> Result<Vec<LongShortRatio>> {
+        <Self as MarketDataDownloader>::download_long_short_ratios(self, req).await
+    }
+
+    /// GET a v5 endpoint and return its `result`, failing on HTTP or API errors.
+    async fn get_result<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<Option<T>> {
+        let response = self
+            .client
+            .get(self.endpoint(path))
+            .query(params)
+            .send()
+            .await
+            .context("request to Bybit failed")?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .context("failed to read Bybit response body")?;
+        debug!(
+            "bybit {} response (status {}): {}",
+            path,
+            status,
+            truncate(&body, 512)
+        );
+        if !status.is_success() {
+            return Err(anyhow!(
+                "Bybit responded with status {}: {}",
+                status,
+                truncate(&body, 256)
+            ));
+        }
+        let response: BybitResponse<T> = serde_json::from_str(&body).map_err(|err| {
+            anyhow!(
+                "failed to parse Bybit response: {} (body snippet: {})",
+                err,
+                truncate(&body, 256)
+            )
+        })?;
+        if response.ret_code != 0 {
+            return Err(anyhow!(
+                "Bybit returned error {}: {}",
+                response.ret_code,
+                response.ret_msg
+            ));
+        }
+        Ok(response.result)
+    }
+
+    /// Follow `nextPageCursor` until the endpoint runs out of rows.
+    async fn get_cursor_pages<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<Vec<T>> {
+        let mut rows = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut page_params = params.to_vec();
+            if let Some(token) = &cursor {
+                page_params.push(("cursor", token.clone()));
+            }
+            let Some(page) = self.get_result::<BybitPage<T>>(path, &page_params).await? else {
+                break;
+            };
+            if page.list.is_empty() {
+                break;
+            }
+            rows.extend(page.list);
+            match page.next_page_cursor.filter(|token| !token.is_empty()) {
+                Some(next) if cursor.as_ref() != Some(&next) => cursor = Some(next),
+                _ => break,
+            }
+        }
+        Ok(rows)
+    }
 }
 
 #[async_trait]
@@ -183,6 +413,10 @@ impl MarketDataDownloader for BybitDownloader {
             return Err(anyhow!("start must be earlier than end"));
         }
 
+        let Some(interval_code) = req.interval.to_bybit() else {
+            return download_resampled(self, req, |interval| interval.to_bybit().is_some()).await;
+        };
+
         let mut candles = Vec::new();
         let interval_ms = req.interval.as_duration().num_milliseconds();
 
@@ -190,11 +424,11 @@ impl MarketDataDownloader for BybitDownloader {
             let limit = req.limit.min(MAX_LIMIT).to_string();
             let response = self
                 .client
-                .get(self.endpoint("v5/market/kline"))
+                .get(self.endpoint(bybit_kline_path(req.price)))
                 .query(&[
                     ("category", req.category),
                     ("symbol", req.symbol),
-                    ("interval", req.interval.to_bybit()),
+                    ("interval", interval_code),
                     ("start", &cursor.to_string()),
                     ("end", &end_ms.to_string()),
                     ("limit", &limit),
@@ -282,6 +516,107 @@ impl MarketDataDownloader for BybitDownloader {
             )),
         }
     }
+
+    async fn download_funding_rates(&self, req: &MetricRequest<'_>) -> Result<Vec<FundingRate>> {
+        let (start_ms, end_ms) = req.range_millis()?;
+        let mut rates = Vec::new();
+        // The endpoint returns the newest rows first, so walk the window backwards.
+        let mut cursor_end = end_ms;
+        loop {
+            let params = [
+                ("category", req.category.to_string()),
+                ("symbol", req.symbol.to_string()),
+                ("startTime", start_ms.to_string()),
+                ("endTime", cursor_end.to_string()),
+                ("limit", BYBIT_FUNDING_LIMIT.to_string()),
+            ];
+            let Some(page) = self
+                .get_result::<BybitPage<BybitFundingEntry>>("v5/market/funding/history", &params)
+                .await?
+            else {
+                break;
+            };
+            let count = page.list.len();
+            let mut earliest = cursor_end;
+            for entry in &page.list {
+                if let Some(rate) = parse_bybit_funding(req.symbol, entry) {
+                    let ts = rate.timestamp.timestamp_millis();
+                    earliest = earliest.min(ts);
+                    if (start_ms..=end_ms).contains(&ts) {
+                        rates.push(rate);
+                    }
+                }
+            }
+            if count < BYBIT_FUNDING_LIMIT || earliest <= start_ms || earliest >= cursor_end {
+                break;
+            }
+            cursor_end = earliest - 1;
+        }
+        rates.sort_by_key(|rate| rate.timestamp);
+        rates.dedup_by_key(|rate| rate.timestamp);
+        Ok(rates)
+    }
+
+    async fn download_open_interest(&self, req: &MetricRequest<'_>) -> Result<Vec<OpenInterest>> {
+        let (start_ms, end_ms) = req.range_millis()?;
+        let period = bybit_metric_period(req.interval).ok_or_else(|| {
+            anyhow!(
+                "Bybit open interest is not available at {} (use 5m, 15m, 30m, 1h, 4h or 1d)",
+                req.interval
+            )
+        })?;
+        let params = [
+            ("category", req.category.to_string()),
+            ("symbol", req.symbol.to_string()),
+            ("intervalTime", period.to_string()),
+            ("startTime", start_ms.to_string()),
+            ("endTime", end_ms.to_string()),
+            ("limit", BYBIT_OPEN_INTEREST_LIMIT.to_string()),
+        ];
+        let entries: Vec<BybitOpenInterestEntry> = self
+            .get_cursor_pages("v5/market/open-interest", &params)
+            .await?;
+        let mut rows: Vec<OpenInterest> = entries
+            .iter()
+            .filter_map(|entry| parse_bybit_open_interest(req.symbol, req.interval, entry))
+            .filter(|row| (start_ms..=end_ms).contains(&row.timestamp.timestamp_millis()))
+            .collect();
+        rows.sort_by_key(|row| row.timestamp);
+        rows.dedup_by_key(|row| row.timestamp);
+        Ok(rows)
+    }
+
+    async fn download_long_short_ratios(
+        &self,
+        req: &MetricRequest<'_>,
+    ) -> Result<Vec<LongShortRatio>> {
+        let (start_ms, end_ms) = req.range_millis()?;
+        let period = bybit_metric_period(req.interval).ok_or_else(|| {
+            anyhow!(
+                "Bybit long/short ratios are not available at {} (use 5m, 15m, 30m, 1h, 4h or 1d)",
+                req.interval
+            )
+        })?;
+        let params = [
+            ("category", req.category.to_string()),
+            ("symbol", req.symbol.to_string()),
+            ("period", period.to_string()),
+            ("startTime", start_ms.to_string()),
+            ("endTime", end_ms.to_string()),
+            ("limit", BYBIT_ACCOUNT_RATIO_LIMIT.to_string()),
+        ];
+        let entries: Vec<BybitAccountRatioEntry> = self
+            .get_cursor_pages("v5/market/account-ratio", &params)
+            .await?;
+        let mut rows: Vec<LongShortRatio> = entries
+            .iter()
+            .filter_map(|entry| parse_bybit_account_ratio(req.symbol, req.interval, entry))
+            .filter(|row| (start_ms..=end_ms).contains(&row.timestamp.timestamp_millis()))
+            .collect();
+        rows.sort_by_key(|row| row.timestamp);
+        rows.dedup_by_key(|row| row.timestamp);
+        Ok(rows)
+    }
 }
 
 impl BybitDownloader {
@@ -481,8 +816,9 @@ impl BybitDownloader {
     }
 }
 
+/// Parse a Bybit kline row; mark and index price klines omit the volume column.
 fn parse_entry(entry: &[String], symbol: &str, interval: Interval) -> Option<Candle> {
-    if entry.len() < 6 {
+    if entry.len() < 5 {
         return None;
     }
     let ts = entry.first()?.parse::<i64>().ok()?;
@@ -491,7 +827,10 @@ fn parse_entry(entry: &[String], symbol: &str, interval: Interval) -> Option<Can
     let high = entry.get(2)?.parse::<Decimal>().ok()?;
     let low = entry.get(3)?.parse::<Decimal>().ok()?;
     let close = entry.get(4)?.parse::<Decimal>().ok()?;
-    let volume = entry.get(5)?.parse::<Decimal>().ok()?;
+    let volume = match entry.get(5) {
+        Some(raw) => raw.parse::<Decimal>().ok()?,
+        None => Decimal::ZERO,
+    };
     Some(Candle {
         symbol: Symbol::from(symbol),
         interval,
@@ -545,6 +884,105 @@ struct BybitTradeEntry {
     time: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct BybitResponse<T> {
+    #[serde(rename = "retCode")]
+    ret_code: i64,
+    #[serde(rename = "retMsg")]
+    ret_msg: String,
+    result: Option<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BybitPage<T> {
+    #[serde(default = "Vec::new")]
+    list: Vec<T>,
+    #[serde(rename = "nextPageCursor", default)]
+    next_page_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BybitFundingEntry {
+    #[serde(rename = "fundingRate")]
+    funding_rate: String,
+    #[serde(rename = "fundingRateTimestamp")]
+    timestamp: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BybitOpenInterestEntry {
+    #[serde(rename = "openInterest")]
+    open_interest: String,
+    timestamp: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BybitAccountRatioEntry {
+    #[serde(rename = "buyRatio")]
+    buy_ratio: String,
+    #[serde(rename = "sellRatio")]
+    sell_ratio: String,
+    timestamp: String,
+}
+
+fn bybit_kline_path(price: KlinePrice) -> &'static str {
+    match price {
+        KlinePrice::Trade => "v5/market/kline",
+        KlinePrice::Mark => "v5/market/mark-price-kline",
+        KlinePrice::Index => "v5/market/index-price-kline",
+    }
+}
+
+/// Bybit `intervalTime`/`period` codes for open interest and account ratios.
+fn bybit_metric_period(interval: Interval) -> Option<&'static str> {
+    Some(match interval.to_binance()? {
+        "5m" => "5min",
+        "15m" => "15min",
+        "30m" => "30min",
+        "1h" => "1h",
+        "4h" => "4h",
+        "1d" => "1d",
+        _ => return None,
+    })
+}
+
+fn parse_bybit_funding(symbol: &str, entry: &BybitFundingEntry) -> Option<FundingRate> {
+    Some(FundingRate {
+        symbol: Symbol::from(symbol),
+        rate: entry.funding_rate.parse().ok()?,
+        mark_price: None,
+        timestamp: parse_millis(&entry.timestamp)?,
+    })
+}
+
+fn parse_bybit_open_interest(
+    symbol: &str,
+    interval: Interval,
+    entry: &BybitOpenInterestEntry,
+) -> Option<OpenInterest> {
+    Some(OpenInterest {
+        symbol: Symbol::from(symbol),
+        interval,
+        open_interest: entry.open_interest.parse().ok()?,
+        open_interest_value: None,
+        timestamp: parse_millis(&entry.timestamp)?,
+    })
+}
+
+fn parse_bybit_account_ratio(
+    symbol: &str,
+    interval: Interval,
+    entry: &BybitAccountRatioEntry,
+) -> Option<LongShortRatio> {
+    Some(LongShortRatio {
+        symbol: Symbol::from(symbol),
+        interval,
+        long_ratio: entry.buy_ratio.parse().ok()?,
+        short_ratio: entry.sell_ratio.parse().ok()?,
+        timestamp: parse_millis(&entry.timestamp)?,
+    })
+}
+
 /// Simple Binance REST downloader for kline data.
 pub struct BinanceDownloader {
     client: Client,
@@ -581,6 +1019,98 @@ impl BinanceDownloader {
         <Self as MarketDataDownloader>::download_trades(self, req).await
     }
 
+    /// Download settled funding rates (with settlement mark price) from Binance.
+    pub async fn download_funding_rates(
+        &self,
+        req: &MetricRequest<'_>,
+    ) -> Result<Vec<FundingRate>> {
+        <Self as MarketDataDownloader>::download_funding_rates(self, req).await
+    }
+
+    /// Download open interest history from Binance (the venue keeps the last 30 days).
+    pub async fn download_open_interest(
+        &self,
+        req: &MetricRequest<'_>,
+    ) -> Result<Vec<OpenInterest>> {
+        <Self as MarketDataDownloader>::download_open_interest(self, req).await
+    }
+
+    /// Download global long/short account ratios from Binance (the venue keeps the last 30 days).
+    pub async fn download_long_short_ratios(
+        &self,
+        req: &MetricRequest<'_>,
+    ) -> Result<Vec<LongShortRatio>> {
+        <Self as MarketDataDownloader>::download_long_short_ratios(self, req).await
+    }
+
+    /// GET a JSON endpoint, failing on HTTP errors.
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+    ) -> Result<T> {
+        let response = self
+            .client
+            .get(self.endpoint(path))
+            .query(params)
+            .send()
+            .await
+            .context("request to Binance failed")?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .context("failed to read Binance response body")?;
+        debug!(
+            "binance {} response (status {}): {}",
+            path,
+            status,
+            truncate(&body, 512)
+        );
+        if !status.is_success() {
+            return Err(anyhow!(
+                "Binance responded with status {}: {}",
+                status,
+                truncate(&body, 256)
+            ));
+        }
+        serde_json::from_str(&body).map_err(|err| {
+            anyhow!(
+                "failed to parse Binance response: {} (body snippet: {})",
+                err,
+                truncate(&body, 256)
+            )
+        })
+    }
+
+    /// Page through an ascending time series by advancing `startTime` past the last row.
+    async fn get_time_pages<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, String)],
+        (start_ms, end_ms): (i64, i64),
+        limit: usize,
+        timestamp: fn(&T) -> Option<i64>,
+    ) -> Result<Vec<T>> {
+        let mut rows = Vec::new();
+        let mut cursor = start_ms;
+        while cursor < end_ms {
+            let mut page_params = params.to_vec();
+            page_params.push(("startTime", cursor.to_string()));
+            page_params.push(("endTime", end_ms.to_string()));
+            page_params.push(("limit", limit.to_string()));
+            let page: Vec<T> = self.get_json(path, &page_params).await?;
+            let count = page.len();
+            let last = page.iter().filter_map(timestamp).max();
+            rows.extend(page);
+            match last {
+                Some(ts) if ts >= cursor && count >= limit => cursor = ts + 1,
+                _ => break,
+            }
+        }
+        Ok(rows)
+    }
+
     async fn fetch_agg_trades(&self, req: &TradeRequest<'_>) -> Result<Vec<NormalizedTrade>> {
         let mut cursor = req.start.timestamp_millis();
         let end_ms = req.end.timestamp_millis();
@@ -749,15 +1279,24 @@ impl MarketDataDownloader for BinanceDownloader {
         if cursor >= end_ms {
             return Err(anyhow!("start must be earlier than end"));
         }
+        let Some(interval_code) = req.interval.to_binance() else {
+            return download_resampled(self, req, |interval| interval.to_binance().is_some()).await;
+        };
         let mut candles = Vec::new();
         let interval_ms = req.interval.as_duration().num_milliseconds();
+        // Index price klines are keyed by the underlying pair rather than the contract symbol.
+        let symbol_key = if req.price == KlinePrice::Index {
+            "pair"
+        } else {
+            "symbol"
+        };
         while cursor < end_ms {
             let response = self
                 .client
-                .get(self.endpoint("fapi/v1/klines"))
+                .get(self.endpoint(binance_kline_path(req.price)))
                 .query(&[
-                    ("symbol", req.symbol),
-                    ("interval", req.interval.to_binance()),
+                    (symbol_key, req.symbol),
+                    ("interval", interval_code),
                     ("startTime", &cursor.to_string()),
                     ("endTime", &end_ms.to_string()),
                     ("limit", &req.limit.min(MAX_LIMIT).to_string()),
@@ -826,6 +1365,90 @@ impl MarketDataDownloader for BinanceDownloader {
             )),
         }
     }
+
+    async fn download_funding_rates(&self, req: &MetricRequest<'_>) -> Result<Vec<FundingRate>> {
+        let range = req.range_millis()?;
+        let params = [("symbol", req.symbol.to_string())];
+        let entries: Vec<BinanceFundingEntry> = self
+            .get_time_pages(
+                "fapi/v1/fundingRate",
+                &params,
+                range,
+                BINANCE_FUNDING_LIMIT,
+                |entry: &BinanceFundingEntry| Some(entry.funding_time),
+            )
+            .await?;
+        let mut rates: Vec<FundingRate> = entries
+            .iter()
+            .filter_map(|entry| parse_binance_funding(req.symbol, entry))
+            .collect();
+        rates.sort_by_key(|rate| rate.timestamp);
+        rates.dedup_by_key(|rate| rate.timestamp);
+        Ok(rates)
+    }
+
+    async fn download_open_interest(&self, req: &MetricRequest<'_>) -> Result<Vec<OpenInterest>> {
+        let range = req.range_millis()?;
+        let period = binance_metric_period(req.interval).ok_or_else(|| {
+            anyhow!(
+                "Binance open interest is not available at {} (use 5m, 15m, 30m, 1h, 2h, 4h, 6h, 12h or 1d)",
+                req.interval
+            )
+        })?;
+        let params = [
+            ("symbol", req.symbol.to_string()),
+            ("period", period.to_string()),
+        ];
+        let entries: Vec<BinanceOpenInterestEntry> = self
+            .get_time_pages(
+                "futures/data/openInterestHist",
+                &params,
+                range,
+                BINANCE_FUTURES_DATA_LIMIT,
+                |entry: &BinanceOpenInterestEntry| json_millis(&entry.timestamp),
+            )
+            .await?;
+        let mut rows: Vec<OpenInterest> = entries
+            .iter()
+            .filter_map(|entry| parse_binance_open_interest(req.symbol, req.interval, entry))
+            .collect();
+        rows.sort_by_key(|row| row.timestamp);
+        rows.dedup_by_key(|row| row.timestamp);
+        Ok(rows)
+    }
+
+    async fn download_long_short_ratios(
+        &self,
+        req: &MetricRequest<'_>,
+    ) -> Result<Vec<LongShortRatio>> {
+        let range = req.range_millis()?;
+        let period = binance_metric_period(req.interval).ok_or_else(|| {
+            anyhow!(
+                "Binance long/short ratios are not available at {} (use 5m, 15m, 30m, 1h, 2h, 4h, 6h, 12h or 1d)",
+                req.interval
+            )
+        })?;
+        let params = [
+            ("symbol", req.symbol.to_string()),
+            ("period", period.to_string()),
+        ];
+        let entries: Vec<BinanceLongShortEntry> = self
+            .get_time_pages(
+                "futures/data/globalLongShortAccountRatio",
+                &params,
+                range,
+                BINANCE_FUTURES_DATA_LIMIT,
+                |entry: &BinanceLongShortEntry| json_millis(&entry.timestamp),
+            )
+            .await?;
+        let mut rows: Vec<LongShortRatio> = entries
+            .iter()
+            .filter_map(|entry| parse_binance_long_short(req.symbol, req.interval, entry))
+            .collect();
+        rows.sort_by_key(|row| row.timestamp);
+        rows.dedup_by_key(|row| row.timestamp);
+        Ok(rows)
+    }
 }
 
 fn parse_binance_entry(entry: &[JsonValue], symbol: &str, interval: Interval) -> Option<Candle> {
@@ -851,6 +1474,110 @@ fn parse_binance_entry(entry: &[JsonValue], symbol: &str, interval: Interval) ->
     })
 }
 
+#[derive(Debug, Deserialize)]
+struct BinanceFundingEntry {
+    #[serde(rename = "fundingRate")]
+    funding_rate: String,
+    #[serde(rename = "fundingTime")]
+    funding_time: i64,
+    #[serde(rename = "markPrice", default)]
+    mark_price: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BinanceOpenInterestEntry {
+    #[serde(rename = "sumOpenInterest")]
+    open_interest: String,
+    #[serde(rename = "sumOpenInterestValue", default)]
+    open_interest_value: Option<String>,
+    timestamp: JsonValue,
+}
+
+#[derive(Debug, Deserialize)]
+struct BinanceLongShortEntry {
+    #[serde(rename = "longAccount")]
+    long_account: String,
+    #[serde(rename = "shortAccount")]
+    short_account: String,
+    timestamp: JsonValue,
+}
+
+fn binance_kline_path(price: KlinePrice) -> &'static str {
+    match price {
+        KlinePrice::Trade => "fapi/v1/klines",
+        KlinePrice::Mark => "fapi/v1/markPriceKlines",
+        KlinePrice::Index => "fapi/v1/indexPriceKlines",
+    }
+}
+
+/// Binance `period` codes served by the futures data endpoints.
+fn binance_metric_period(interval: Interval) -> Option<&'static str> {
+    match interval.to_binance()? {
+        code @ ("5m" | "15m" | "30m" | "1h" | "2h" | "4h" | "6h" | "12h" | "1d") => Some(code),
+        _ => None,
+    }
+}
+
+fn parse_binance_funding(symbol: &str, entry: &BinanceFundingEntry) -> Option<FundingRate> {
+    Some(FundingRate {
+        symbol: Symbol::from(symbol),
+        rate: entry.funding_rate.parse().ok()?,
+        // Older rows carry an empty mark price.
+        mark_price: entry
+            .mark_price
+            .as_deref()
+            .and_then(|raw| raw.parse::<Decimal>().ok()),
+        timestamp: DateTime::<Utc>::from_timestamp_millis(entry.funding_time)?,
+    })
+}
+
+fn parse_binance_open_interest(
+    symbol: &str,
+    interval: Interval,
+    entry: &BinanceOpenInterestEntry,
+) -> Option<OpenInterest> {
+    Some(OpenInterest {
+        symbol: Symbol::from(symbol),
+        interval,
+        open_interest: entry.open_interest.parse().ok()?,
+        open_interest_value: entry
+            .open_interest_value
+            .as_deref()
+            .and_then(|raw| raw.parse::<Decimal>().ok()),
+        timestamp: DateTime::<Utc>::from_timestamp_millis(json_millis(&entry.timestamp)?)?,
+    })
+}
+
+fn parse_binance_long_short(
+    symbol: &str,
+    interval: Interval,
+    entry: &BinanceLongShortEntry,
+) -> Option<LongShortRatio> {
+    Some(LongShortRatio {
+        symbol: Symbol::from(symbol),
+        interval,
+        long_ratio: entry.long_account.parse().ok()?,
+        short_ratio: entry.short_account.parse().ok()?,
+        timestamp: DateTime::<Utc>::from_timestamp_millis(json_millis(&entry.timestamp)?)?,
+    })
+}
+
+/// Millisecond timestamps that Binance reports either as numbers or as strings.
+fn json_millis(value: &JsonValue) -> Option<i64> {
+    match value {
+        JsonValue::Number(number) => number.as_i64(),
+        JsonValue::String(text) => text.parse().ok(),
+        _ => None,
+    }
+}
+
+fn parse_millis(value: &str) -> Option<DateTime<Utc>> {
+    value
+        .parse::<i64>()
+        .ok()
+        .and_then(DateTime::<Utc>::from_timestamp_millis)
+}
+
 #[derive(Debug, Deserialize)]
 struct BinanceAggTrade {
     #[serde(rename = "a")]
@@ -1147,6 +1874,28 @@ mod tests {
         assert_eq!(trade.tick.size, Decimal::from_str("0.042").unwrap());
     }
 
+    #[test]
+    fn resampled_downloads_drop_partial_trailing_bucket() {
+        let start = DateTime::from_timestamp(1_704_067_200, 0).unwrap();
+        let hourly: Vec<Candle> = (0..3)
+            .map(|hour| Candle {
+                symbol: "BTCUSDT".into(),
+                interval: Interval::OneHour,
+                open: Decimal::ONE,
+                high: Decimal::ONE,
+                low: Decimal::ONE,
+                close: Decimal::ONE,
+                volume: Decimal::ONE,
+                timestamp: start + chrono::Duration::hours(hour),
+            })
+            .collect();
+        let two_hours: Interval = "2h".parse().unwrap();
+        let candles = resample_complete(hourly, Interval::OneHour, two_hours).unwrap();
+        assert_eq!(candles.len(), 1);
+        assert_eq!(candles[0].timestamp, start);
+        assert_eq!(candles[0].volume, Decimal::from(2));
+    }
+
     #[test]
     fn parses_public_timestamp_fractional_seconds() {
         let ts = parse_public_timestamp("1585180700.0647").expect("timestamp");
@@ -1154,6 +1903,74 @@ mod tests {
         assert!(ts.timestamp_subsec_nanos() > 0);
     }
 
+    #[test]
+    fn parses_bybit_perp_metrics() {
+        let funding: BybitResponse<BybitPage<BybitFundingEntry>> = serde_json::from_str(
+            r#"{"retCode":0,"retMsg":"OK","result":{"category":"linear","list":[
+                {"symbol":"BTCUSDT","fundingRate":"0.0001","fundingRateTimestamp":"1672041600000"}]}}"#,
+        )
+        .unwrap();
+        let rate = parse_bybit_funding("BTCUSDT", &funding.result.unwrap().list[0]).unwrap();
+        assert_eq!(rate.rate, Decimal::from_str("0.0001").unwrap());
+        assert_eq!(rate.timestamp.timestamp_millis(), 1_672_041_600_000);
+
+        let ratios: BybitPage<BybitAccountRatioEntry> = serde_json::from_str(
+            r#"{"list":[{"symbol":"BTCUSDT","buyRatio":"0.5634","sellRatio":"0.4366","timestamp":"1672041600000"}],"nextPageCursor":""}"#,
+        )
+        .unwrap();
+        assert_eq!(ratios.next_page_cursor.as_deref(), Some(""));
+        let ratio =
+            parse_bybit_account_ratio("BTCUSDT", Interval::OneHour, &ratios.list[0]).unwrap();
+        assert_eq!(ratio.long_ratio, Decimal::from_str("0.5634").unwrap());
+        assert_eq!(ratio.short_ratio, Decimal::from_str("0.4366").unwrap());
+
+        assert_eq!(bybit_metric_period(Interval::FiveMinutes), Some("5min"));
+        assert_eq!(bybit_metric_period(Interval::OneMinute), None);
+        let mark = vec!["1672041600000", "16500.5", "16510", "16490", "16505.25"]
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let candle = parse_entry(&mark, "BTCUSDT", Interval::OneMinute).unwrap();
+        assert_eq!(candle.volume, Decimal::ZERO);
+    }
+
+    #[test]
+    fn parses_binance_perp_metrics() {
+        let funding: Vec<BinanceFundingEntry> = serde_json::from_str(
+            r#"[{"symbol":"BTCUSDT","fundingRate":"-0.00003","fundingTime":1698768000000,"markPrice":"34400.1"},
+                {"symbol":"BTCUSDT","fundingRate":"0.0001","fundingTime":1568102400000,"markPrice":""}]"#,
+        )
+        .unwrap();
+        let rates: Vec<_> = funding
+            .iter()
+            .filter_map(|entry| parse_binance_funding("BTCUSDT", entry))
+            .collect();
+        assert_eq!(
+            rates[0].mark_price,
+            Some(Decimal::from_str("34400.1").unwrap())
+        );
+        assert_eq!(rates[1].mark_price, None);
+
+        let open_interest: Vec<BinanceOpenInterestEntry> = serde_json::from_str(
+            r#"[{"symbol":"BTCUSDT","sumOpenInterest":"20403.637","sumOpenInterestValue":"150570784.07","timestamp":1583127900000}]"#,
+        )
+        .unwrap();
+        let row = parse_binance_open_interest("BTCUSDT", Interval::FiveMinutes, &open_interest[0])
+            .unwrap();
+        assert_eq!(
+            row.open_interest_value,
+            Some(Decimal::from_str("150570784.07").unwrap())
+        );
+
+        let ratios: Vec<BinanceLongShortEntry> = serde_json::from_str(
+            r#"[{"symbol":"BTCUSDT","longShortRatio":"1.8105","longAccount":"0.6442","shortAccount":"0.3558","timestamp":"1583139600000"}]"#,
+        )
+        .unwrap();
+        let ratio = parse_binance_long_short("BTCUSDT", Interval::OneHour, &ratios[0]).unwrap();
+        assert_eq!(ratio.timestamp.timestamp_millis(), 1_583_139_600_000);
+        assert_eq!(binance_metric_period(Interval::OneMinute), None);
+    }
+
     #[test]
     fn parses_binance_public_line() {
         let line = "1001,51234.5,0.010,200,205,1585180700064,true,false";
@@ -16,8 +16,9 @@ use serde_json::{json, Map};
 use tracing::warn;
 
 use tesser_core::{
-    Candle, ExecutionHint, Fill, Interval, Order, OrderBook, OrderBookLevel, OrderStatus,
-    OrderType, Signal, SignalKind, Tick, TimeInForce,
+    Candle, DepthUpdate, ExecutionHint, Fill, FundingRate, LongShortRatio, MarketByOrderAction,
+    MarketByOrderUpdate, OpenInterest, Order, OrderBook, OrderBookLevel, OrderStatus, OrderType,
+    Signal, SignalKind, Tick, TimeInForce,
 };
 
 const DECIMAL_PRECISION: u8 = 38;
@@ -138,6 +139,18 @@ static ORDER_BOOK_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
     ]))
 });
 
+static MARKET_BY_ORDER_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        timestamp_field("timestamp"),
+        Field::new("symbol", DataType::Utf8, false),
+        Field::new("order_id", DataType::Utf8, false),
+        Field::new("side", DataType::Int8, false),
+        decimal_field("price", false),
+        decimal_field("size", false),
+        Field::new("action", DataType::Utf8, false),
+    ]))
+});
+
 static SIGNAL_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
     Arc::new(Schema::new(vec![
         Field::new("id", DataType::Utf8, false),
@@ -153,6 +166,35 @@ static SIGNAL_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
     ]))
 });
 
+static FUNDING_RATE_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("symbol", DataType::Utf8, false),
+        decimal_field("rate", false),
+        decimal_field("mark_price", true),
+        timestamp_field("timestamp"),
+    ]))
+});
+
+static OPEN_INTEREST_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("symbol", DataType::Utf8, false),
+        Field::new("interval", DataType::Utf8, false),
+        decimal_field("open_interest", false),
+        decimal_field("open_interest_value", true),
+        timestamp_field("timestamp"),
+    ]))
+});
+
+static LONG_SHORT_RATIO_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("symbol", DataType::Utf8, false),
+        Field::new("interval", DataType::Utf8, false),
+        decimal_field("long_ratio", false),
+        decimal_field("short_ratio", false),
+        timestamp_field("timestamp"),
+    ]))
+});
+
 /// Returns the schema used when encoding ticks.
 pub fn tick_schema() -> SchemaRef {
     TICK_SCHEMA.clone()
@@ -183,6 +225,33 @@ pub fn order_book_schema() -> SchemaRef {
     ORDER_BOOK_SCHEMA.clone()
 }
 
+/// Returns the schema used when encoding incremental depth updates.
+///
+/// Depth deltas share the snapshot layout so the same readers can decode both.
+pub fn depth_update_schema() -> SchemaRef {
+    ORDER_BOOK_SCHEMA.clone()
+}
+
+/// Returns the schema used when encoding market-by-order (L3) updates.
+pub fn market_by_order_schema() -> SchemaRef {
+    MARKET_BY_ORDER_SCHEMA.clone()
+}
+
+/// Returns the schema used when encoding funding rate history.
+pub fn funding_rate_schema() -> SchemaRef {
+    FUNDING_RATE_SCHEMA.clone()
+}
+
+/// Returns the schema used when encoding open interest history.
+pub fn open_interest_schema() -> SchemaRef {
+    OPEN_INTEREST_SCHEMA.clone()
+}
+
+/// Returns the schema used when encoding long/short account ratios.
+pub fn long_short_ratio_schema() -> SchemaRef {
+    LONG_SHORT_RATIO_SCHEMA.clone()
+}
+
 /// Converts a slice of ticks into a [`RecordBatch`].
 pub fn ticks_to_batch(rows: &[Tick]) -> Result<RecordBatch> {
     let capacity = rows.len();
@@ -230,7 +299,7 @@ pub fn candles_to_batch(rows: &[Candle]) -> Result<RecordBatch> {
 
     for candle in rows {
         symbols.append_value(candle.symbol);
-        intervals.append_value(interval_label(candle.interval));
+        intervals.append_value(candle.interval.label());
         let open = decimal_to_i128(candle.open)?;
         opens.append_value(open);
         let high = decimal_to_i128(candle.high)?;
@@ -441,6 +510,172 @@ pub fn order_books_to_batch(rows: &[OrderBook]) -> Result<RecordBatch> {
     RecordBatch::try_new(order_book_schema(), columns).context("failed to build order book batch")
 }
 
+/// Converts a slice of depth updates into a [`RecordBatch`].
+pub fn depth_updates_to_batch(rows: &[DepthUpdate]) -> Result<RecordBatch> {
+    let capacity = rows.len();
+    let mut timestamps = timestamp_builder(capacity);
+    let mut symbols = string_builder(capacity);
+    let mut bids = level_list_builder(capacity);
+    let mut asks = level_list_builder(capacity);
+
+    for update in rows {
+        timestamps.append_value(timestamp_to_nanos(&update.timestamp));
+        symbols.append_value(update.symbol);
+        append_order_book_levels(&mut bids, &update.bids)?;
+        append_order_book_levels(&mut asks, &update.asks)?;
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(timestamps.finish()),
+        Arc::new(symbols.finish()),
+        Arc::new(bids.finish()),
+        Arc::new(asks.finish()),
+    ];
+
+    RecordBatch::try_new(depth_update_schema(), columns)
+        .context("failed to build depth update batch")
+}
+
+/// Converts a slice of funding rates into a [`RecordBatch`].
+pub fn funding_rates_to_batch(rows: &[FundingRate]) -> Result<RecordBatch> {
+    let capacity = rows.len();
+    let mut symbols = string_builder(capacity);
+    let mut rates = decimal_builder(capacity);
+    let mut mark_prices = decimal_builder(capacity);
+    let mut timestamps = timestamp_builder(capacity);
+
+    for row in rows {
+        symbols.append_value(row.symbol);
+        rates.append_value(decimal_to_i128(row.rate)?);
+        append_decimal_option(&mut mark_prices, row.mark_price)?;
+        timestamps.append_value(timestamp_to_nanos(&row.timestamp));
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(symbols.finish()),
+        Arc::new(rates.finish()),
+        Arc::new(mark_prices.finish()),
+        Arc::new(timestamps.finish()),
+    ];
+
+    RecordBatch::try_new(funding_rate_schema(), columns)
+        .context("failed to build funding rate batch")
+}
+
+/// Converts a slice of open interest samples into a [`RecordBatch`].
+pub fn open_interest_to_batch(rows: &[OpenInterest]) -> Result<RecordBatch> {
+    let capacity = rows.len();
+    let mut symbols = string_builder(capacity);
+    let mut intervals = string_builder(capacity);
+    let mut amounts = decimal_builder(capacity);
+    let mut values = decimal_builder(capacity);
+    let mut timestamps = timestamp_builder(capacity);
+
+    for row in rows {
+        symbols.append_value(row.symbol);
+        intervals.append_value(row.interval.label());
+        amounts.append_value(decimal_to_i128(row.open_interest)?);
+        append_decimal_option(&mut values, row.open_interest_value)?;
+        timestamps.append_value(timestamp_to_nanos(&row.timestamp));
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(symbols.finish()),
+        Arc::new(intervals.finish()),
+        Arc::new(amounts.finish()),
+        Arc::new(values.finish()),
+        Arc::new(timestamps.finish()),
+    ];
+
+    RecordBatch::try_new(open_interest_schema(), columns)
+        .context("failed to build open interest batch")
+}
+
+/// Converts a slice of long/short account ratios into a [`RecordBatch`].
+pub fn long_short_ratios_to_batch(rows: &[LongShortRatio]) -> Result<RecordBatch> {
+    let capacity = rows.len();
+    let mut symbols = string_builder(capacity);
+    let mut intervals = string_builder(capacity);
+    let mut longs = decimal_builder(capacity);
+    let mut shorts = decimal_builder(capacity);
+    let mut timestamps = timestamp_builder(capacity);
+
+    for row in rows {
+        symbols.append_value(row.symbol);
+        intervals.append_value(row.interval.label());
+        longs.append_value(decimal_to_i128(row.long_ratio)?);
+        shorts.append_value(decimal_to_i128(row.short_ratio)?);
+        timestamps.append_value(timestamp_to_nanos(&row.timestamp));
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(symbols.finish()),
+        Arc::new(intervals.finish()),
+        Arc::new(longs.finish()),
+        Arc::new(shorts.finish()),
+        Arc::new(timestamps.finish()),
+    ];
+
+    RecordBatch::try_new(long_short_ratio_schema(), columns)
+        .context("failed to build long/short ratio batch")
+}
+
+/// Converts a slice of market-by-order updates into a [`RecordBatch`].
+pub fn market_by_order_to_batch(rows: &[MarketByOrderUpdate]) -> Result<RecordBatch> {
+    let capacity = rows.len();
+    let mut timestamps = timestamp_builder(capacity);
+    let mut symbols = string_builder(capacity);
+    let mut order_ids = string_builder(capacity);
+    let mut sides = Int8Builder::with_capacity(capacity);
+    let mut prices = decimal_builder(capacity);
+    let mut sizes = decimal_builder(capacity);
+    let mut actions = string_builder(capacity);
+
+    for update in rows {
+        timestamps.append_value(timestamp_to_nanos(&update.timestamp));
+        symbols.append_value(update.symbol);
+        order_ids.append_value(&update.order_id);
+        sides.append_value(update.side.as_i8());
+        prices.append_value(decimal_to_i128(update.price)?);
+        sizes.append_value(decimal_to_i128(update.size)?);
+        actions.append_value(market_by_order_action_label(update.action));
+    }
+
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(timestamps.finish()),
+        Arc::new(symbols.finish()),
+        Arc::new(order_ids.finish()),
+        Arc::new(sides.finish()),
+        Arc::new(prices.finish()),
+        Arc::new(sizes.finish()),
+        Arc::new(actions.finish()),
+    ];
+
+    RecordBatch::try_new(market_by_order_schema(), columns)
+        .context("failed to build market-by-order batch")
+}
+
+/// Returns the canonical label stored for a market-by-order action.
+pub fn market_by_order_action_label(action: MarketByOrderAction) -> &'static str {
+    match action {
+        MarketByOrderAction::Add => "add",
+        MarketByOrderAction::Modify => "modify",
+        MarketByOrderAction::Cancel => "cancel",
+        MarketByOrderAction::Fill => "fill",
+    }
+}
+
+/// Parses a market-by-order action label produced by [`market_by_order_action_label`].
+pub fn parse_market_by_order_action(value: &str) -> Result<MarketByOrderAction> {
+    match value.to_ascii_lowercase().as_str() {
+        "add" | "new" | "open" => Ok(MarketByOrderAction::Add),
+        "modify" | "change" | "update" => Ok(MarketByOrderAction::Modify),
+        "cancel" | "delete" | "done" => Ok(MarketByOrderAction::Cancel),
+        "fill" | "trade" | "match" => Ok(MarketByOrderAction::Fill),
+        other => Err(anyhow!("unsupported market-by-order action '{other}'")),
+    }
+}
+
 fn level_list_builder(capacity: usize) -> ListBuilder<StructBuilder> {
     let fields = order_book_level_fields();
     let struct_builder = StructBuilder::from_fields(fields.clone(), capacity);
@@ -530,18 +765,6 @@ fn timestamp_to_nanos(ts: &DateTime<Utc>) -> i64 {
         .unwrap_or_else(|| ts.timestamp_micros() * 1_000)
 }
 
-fn interval_label(interval: Interval) -> &'static str {
-    match interval {
-        Interval::OneSecond => "1s",
-        Interval::OneMinute => "1m",
-        Interval::FiveMinutes => "5m",
-        Interval::FifteenMinutes => "15m",
-        Interval::OneHour => "1h",
-        Interval::FourHours => "4h",
-        Interval::OneDay => "1d",
-    }
-}
-
 fn order_type_label(order_type: OrderType) -> &'static str {
     match order_type {
         OrderType::Market => "market",
@@ -0,0 +1,433 @@
+//! Trade and order-book mappings that normalize vendor dumps into flight-recorder parquet.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context, Result};
+use arrow::record_batch::RecordBatch;
+use chrono::{DateTime, Utc};
+use parquet::arrow::ArrowWriter;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_json::Value;
+use tesser_core::{DepthUpdate, OrderBook, OrderBookLevel, Side, Symbol, Tick};
+
+use super::source::{describe_location, SourceRecord};
+use super::{datetime_from_ns, MappingConfig, Partitioning, TimestampField, ValueField};
+use crate::encoding::{depth_updates_to_batch, order_books_to_batch, ticks_to_batch};
+
+/// Column mapping for trade prints, written with the flight-recorder `tick_schema`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TradeFieldMapping {
+    /// Exchange timestamp of the print.
+    pub timestamp: TimestampField,
+    /// Local receive timestamp; defaults to the exchange timestamp.
+    #[serde(default)]
+    pub received_at: Option<TimestampField>,
+    pub price: ValueField,
+    pub size: ValueField,
+    pub side: SideField,
+}
+
+/// Column mapping for L2 depth, written with `order_book_schema`.
+///
+/// Two layouts are supported:
+/// - one price level per row (`side`, `price`, `size`), as in Tardis `incremental_book_L2`
+///   dumps; consecutive rows sharing a timestamp form one update;
+/// - one update per row with `bids`/`asks` arrays of `[price, size]` pairs (or
+///   `{"price", "size"}` objects), as in most vendor NDJSON feeds.
+///
+/// Rows flagged by `snapshot` are written as full books under `order_books/`; all other rows
+/// become incremental updates under `depth/`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DepthFieldMapping {
+    pub timestamp: TimestampField,
+    #[serde(default)]
+    pub side: Option<SideField>,
+    #[serde(default)]
+    pub price: Option<ValueField>,
+    #[serde(default)]
+    pub size: Option<ValueField>,
+    #[serde(default)]
+    pub bids: Option<ValueField>,
+    #[serde(default)]
+    pub asks: Option<ValueField>,
+    /// Boolean field marking rows that belong to a full snapshot.
+    #[serde(default)]
+    pub snapshot: Option<ValueField>,
+}
+
+/// Field describing a trade aggressor or book side.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SideField {
+    #[serde(default)]
+    pub col: Option<usize>,
+    #[serde(default)]
+    pub key: Option<String>,
+    /// Interpret the value as a Binance-style `is_buyer_maker` flag (`true` = sell aggressor).
+    #[serde(default)]
+    pub buyer_maker: bool,
+}
+
+impl SideField {
+    fn parse(&self, record: &SourceRecord) -> Result<Side> {
+        let location = describe_location(self.col, self.key.as_deref());
+        let raw = record
+            .text(self.col, self.key.as_deref())
+            .ok_or_else(|| anyhow!("row missing side {location}"))?;
+        let value = raw.trim().to_ascii_lowercase();
+        if self.buyer_maker {
+            return match parse_flag(&value) {
+                Some(true) => Ok(Side::Sell),
+                Some(false) => Ok(Side::Buy),
+                None => bail!("invalid buyer-maker flag '{raw}' in {location}"),
+            };
+        }
+        match value.as_str() {
+            "buy" | "b" | "bid" | "bids" | "1" => Ok(Side::Buy),
+            "sell" | "s" | "ask" | "asks" | "-1" => Ok(Side::Sell),
+            _ => bail!("invalid side '{raw}' in {location}"),
+        }
+    }
+}
+
+impl DepthFieldMapping {
+    fn is_level_per_row(&self) -> Result<bool> {
+        match (
+            self.side.is_some() && self.price.is_some() && self.size.is_some(),
+            self.bids.is_some() || self.asks.is_some(),
+        ) {
+            (true, false) => Ok(true),
+            (false, true) => Ok(false),
+            (true, true) => bail!("depth mapping must use either side/price/size or bids/asks"),
+            (false, false) => {
+                bail!("depth mapping requires side/price/size columns or bids/asks arrays")
+            }
+        }
+    }
+
+    fn is_snapshot(&self, record: &SourceRecord) -> Result<bool> {
+        let Some(field) = &self.snapshot else {
+            return Ok(false);
+        };
+        let raw = field.text(record, "snapshot")?;
+        parse_flag(&raw.trim().to_ascii_lowercase())
+            .ok_or_else(|| anyhow!("invalid snapshot flag '{raw}'"))
+    }
+}
+
+fn parse_flag(value: &str) -> Option<bool> {
+    match value {
+        "true" | "t" | "1" | "yes" => Some(true),
+        "false" | "f" | "0" | "no" | "" => Some(false),
+        _ => None,
+    }
+}
+
+pub(crate) fn load_trades(
+    mapping: &MappingConfig,
+    fields: &TradeFieldMapping,
+    path: &Path,
+    symbol: Symbol,
+) -> Result<Vec<Tick>> {
+    let mut ticks = Vec::new();
+    for (idx, record) in mapping.records(path)?.enumerate() {
+        let record = record?;
+        let row = idx + 1;
+        let exchange_timestamp = datetime_from_ns(fields.timestamp.parse(&record)?)
+            .with_context(|| format!("invalid timestamp on row {row} of {}", path.display()))?;
+        let received_at = match &fields.received_at {
+            Some(field) => datetime_from_ns(field.parse(&record)?)?,
+            None => exchange_timestamp,
+        };
+        let price = fields
+            .price
+            .parse_decimal(&record, "price")
+            .with_context(|| format!("invalid price on row {row} of {}", path.display()))?;
+        let size = fields
+            .size
+            .parse_decimal(&record, "size")
+            .with_context(|| format!("invalid size on row {row} of {}", path.display()))?;
+        if price <= Decimal::ZERO || size <= Decimal::ZERO {
+            bail!("row {row} failed validation: non-positive price {price} or size {size}");
+        }
+        let side = fields
+            .side
+            .parse(&record)
+            .with_context(|| format!("invalid side on row {row} of {}", path.display()))?;
+        ticks.push(Tick {
+            symbol,
+            price,
+            size,
+            side,
+            exchange_timestamp,
+            received_at,
+        });
+    }
+    ticks.sort_by_key(|tick| tick.exchange_timestamp);
+    Ok(ticks)
+}
+
+/// Depth events decoded from one source file.
+#[derive(Default)]
+pub(crate) struct DepthEvents {
+    pub snapshots: Vec<OrderBook>,
+    pub updates: Vec<DepthUpdate>,
+}
+
+impl DepthEvents {
+    pub(crate) fn len(&self) -> usize {
+        self.snapshots.len() + self.updates.len()
+    }
+
+    fn push(&mut self, symbol: Symbol, group: PendingGroup) {
+        if group.bids.is_empty() && group.asks.is_empty() {
+            return;
+        }
+        if group.snapshot {
+            self.snapshots.push(OrderBook {
+                symbol,
+                bids: group.bids,
+                asks: group.asks,
+                timestamp: group.timestamp,
+                exchange_checksum: None,
+                local_checksum: None,
+                sequence: None,
+                prev_sequence: None,
+            });
+        } else {
+            self.updates.push(DepthUpdate {
+                symbol,
+                bids: group.bids,
+                asks: group.asks,
+                timestamp: group.timestamp,
+            });
+        }
+    }
+}
+
+struct PendingGroup {
+    timestamp: DateTime<Utc>,
+    snapshot: bool,
+    bids: Vec<OrderBookLevel>,
+    asks: Vec<OrderBookLevel>,
+}
+
+pub(crate) fn load_depth(
+    mapping: &MappingConfig,
+    fields: &DepthFieldMapping,
+    path: &Path,
+    symbol: Symbol,
+) -> Result<DepthEvents> {
+    let level_columns = if fields.is_level_per_row()? {
+        let (Some(side), Some(price), Some(size)) = (&fields.side, &fields.price, &fields.size)
+        else {
+            bail!("depth mapping requires side, price and size columns");
+        };
+        Some((side, price, size))
+    } else {
+        None
+    };
+    let mut events = DepthEvents::default();
+    let mut pending: Option<PendingGroup> = None;
+    for (idx, record) in mapping.records(path)?.enumerate() {
+        let record = record?;
+        let row = idx + 1;
+        let context = || format!("invalid depth row {row} of {}", path.display());
+        let timestamp = datetime_from_ns(fields.timestamp.parse(&record).with_context(context)?)?;
+        let snapshot = fields.is_snapshot(&record).with_context(context)?;
+        let Some((side, price, size)) = level_columns else {
+            let bids = parse_levels(fields.bids.as_ref(), &record, "bids").with_context(context)?;
+            let asks = parse_levels(fields.asks.as_ref(), &record, "asks").with_context(context)?;
+            events.push(
+                symbol,
+                PendingGroup {
+                    timestamp,
+                    snapshot,
+                    bids,
+                    asks,
+                },
+            );
+            continue;
+        };
+        let side = side.parse(&record).with_context(context)?;
+        let level = OrderBookLevel {
+            price: price
+                .parse_decimal(&record, "price")
+                .with_context(context)?,
+            size: size.parse_decimal(&record, "size").with_context(context)?,
+        };
+        let group = match pending.take() {
+            Some(group) if group.timestamp == timestamp && group.snapshot == snapshot => group,
+            previous => {
+                if let Some(group) = previous {
+                    events.push(symbol, group);
+                }
+                PendingGroup {
+                    timestamp,
+                    snapshot,
+                    bids: Vec::new(),
+                    asks: Vec::new(),
+                }
+            }
+        };
+        let group = pending.insert(group);
+        match side {
+            Side::Buy => group.bids.push(level),
+            Side::Sell => group.asks.push(level),
+        }
+    }
+    if let Some(group) = pending.take() {
+        events.push(symbol, group);
+    }
+    // Stable sorts keep same-timestamp groups in file order.
+    events.snapshots.sort_by_key(|book| book.timestamp);
+    events.updates.sort_by_key(|update| update.timestamp);
+    Ok(events)
+}
+
+fn parse_levels(
+    field: Option<&ValueField>,
+    record: &SourceRecord,
+    label: &str,
+) -> Result<Vec<OrderBookLevel>> {
+    let Some(field) = field else {
+        return Ok(Vec::new());
+    };
+    let Some(value) = record.json(field.col, field.key.as_deref()) else {
+        return Ok(Vec::new());
+    };
+    let Value::Array(entries) = value.as_ref() else {
+        bail!("{label} field is not an array");
+    };
+    entries
+        .iter()
+        .map(|entry| {
+            let (price, size) = match entry {
+                Value::Array(pair) if pair.len() >= 2 => (&pair[0], &pair[1]),
+                Value::Object(map) => (
+                    map.get("price")
+                        .ok_or_else(|| anyhow!("{label} level missing price"))?,
+                    map.get("size")
+                        .or_else(|| map.get("amount"))
+                        .or_else(|| map.get("quantity"))
+                        .ok_or_else(|| anyhow!("{label} level missing size"))?,
+                ),
+                other => bail!("unsupported {label} level {other}"),
+            };
+            Ok(OrderBookLevel {
+                price: json_decimal(price, label)?,
+                size: json_decimal(size, label)?,
+            })
+        })
+        .collect()
+}
+
+fn json_decimal(value: &Value, label: &str) -> Result<Decimal> {
+    let text = match value {
+        Value::String(text) => text.clone(),
+        Value::Number(number) => number.to_string(),
+        other => bail!("invalid {label} value {other}"),
+    };
+    text.trim()
+        .parse::<Decimal>()
+        .or_else(|_| Decimal::from_scientific(text.trim()))
+        .map_err(|err| anyhow!("invalid {label} value '{text}': {err}"))
+}
+
+/// Write events under `<output>/<kind>/<date>/` using the flight-recorder file layout.
+pub(crate) fn write_events<T>(
+    rows: &[T],
+    output: &Path,
+    kind: &str,
+    source: &Path,
+    partitioning: Partitioning,
+    timestamp: impl Fn(&T) -> DateTime<Utc>,
+    encode: impl Fn(&[T]) -> Result<RecordBatch>,
+) -> Result<()>
+where
+    T: Clone,
+{
+    let mut partitions: BTreeMap<String, Vec<T>> = BTreeMap::new();
+    for row in rows {
+        let ts = timestamp(row);
+        let key = match partitioning {
+            Partitioning::Daily => ts.format("%Y-%m-%d").to_string(),
+            Partitioning::Monthly => ts.format("%Y-%m").to_string(),
+        };
+        partitions.entry(key).or_default().push(row.clone());
+    }
+    let stem = source
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default()
+        .split('.')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    // Inputs sharing a stem in different directories must not overwrite each other.
+    let full_path = source
+        .canonicalize()
+        .unwrap_or_else(|_| source.to_path_buf());
+    let source_hash = crc32fast::hash(full_path.to_string_lossy().as_bytes());
+    for (counter, (partition, records)) in partitions.into_iter().enumerate() {
+        let dir = output.join(kind).join(&partition);
+        fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+        let file_path = dir.join(format!(
+            "{kind}-{stem}-{source_hash:08x}-{counter:04}.parquet"
+        ));
+        let batch = encode(&records)?;
+        let file = File::create(&file_path)
+            .with_context(|| format!("failed to create {}", file_path.display()))?;
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+    }
+    Ok(())
+}
+
+pub(crate) fn write_trades(
+    ticks: &[Tick],
+    output: &Path,
+    source: &Path,
+    partitioning: Partitioning,
+) -> Result<()> {
+    write_events(
+        ticks,
+        output,
+        "ticks",
+        source,
+        partitioning,
+        |tick| tick.exchange_timestamp,
+        ticks_to_batch,
+    )
+}
+
+pub(crate) fn write_depth(
+    events: &DepthEvents,
+    output: &Path,
+    source: &Path,
+    partitioning: Partitioning,
+) -> Result<()> {
+    write_events(
+        &events.snapshots,
+        output,
+        "order_books",
+        source,
+        partitioning,
+        |book| book.timestamp,
+        order_books_to_batch,
+    )?;
+    write_events(
+        &events.updates,
+        output,
+        "depth",
+        source,
+        partitioning,
+        |update| update.timestamp,
+        depth_updates_to_batch,
+    )
+}
@@ -1,6 +1,9 @@
+mod events;
+mod source;
+
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fs::{self, File};
-use std::io::BufReader;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -10,46 +13,91 @@ use arrow::array::{ArrayRef, Decimal128Builder, Int64Builder, StringBuilder};
 use arrow::datatypes::{DataType, SchemaRef};
 use arrow::record_batch::RecordBatch;
 use chrono::{DateTime, Datelike, Utc};
-use csv::StringRecord;
 use glob::glob;
 use parquet::arrow::ArrowWriter;
-use rust_decimal::prelude::RoundingStrategy;
+use rust_decimal::prelude::{RoundingStrategy, ToPrimitive};
 use rust_decimal::Decimal;
 use serde::Deserialize;
+use tesser_core::Symbol;
 use tracing::info;
 
+pub use self::events::{DepthFieldMapping, SideField, TradeFieldMapping};
+use self::source::{describe_location, read_records, RecordIter, SourceRecord};
+pub use self::source::{Compression, SourceFormat};
 use crate::schema::{
     canonical_candle_schema, CANONICAL_DECIMAL_PRECISION, CANONICAL_DECIMAL_SCALE,
     CANONICAL_DECIMAL_SCALE_U32,
 };
 
-/// Strategy that controls how normalized candles are partitioned.
+/// Strategy that controls how normalized output is partitioned.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Partitioning {
     Daily,
     Monthly,
 }
 
-/// Declarative mapping describing how a raw CSV file should be parsed.
+/// Kind of market data described by a mapping.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MappingKind {
+    /// OHLCV rows written with the canonical candle schema (`[fields]`).
+    #[default]
+    Candles,
+    /// Trade prints written as flight-recorder ticks (`[trades]`).
+    Trades,
+    /// L2 snapshots and deltas written as flight-recorder order books (`[depth]`).
+    Depth,
+}
+
+/// Declarative mapping describing how a raw CSV or NDJSON file should be parsed.
 #[derive(Debug, Clone, Deserialize)]
 pub struct MappingConfig {
+    #[serde(default)]
+    pub kind: MappingKind,
+    #[serde(default)]
+    pub format: SourceFormat,
+    #[serde(default)]
+    pub compression: Compression,
+    #[serde(default)]
     pub csv: CsvConfig,
-    pub fields: FieldMapping,
+    #[serde(default)]
+    pub fields: Option<FieldMapping>,
+    #[serde(default)]
+    pub trades: Option<TradeFieldMapping>,
+    #[serde(default)]
+    pub depth: Option<DepthFieldMapping>,
     #[serde(default = "MappingConfig::default_interval")]
     pub interval: String,
 }
 
 impl MappingConfig {
+    /// Mapping for OHLCV candles using the default source format detection.
+    pub fn candles(csv: CsvConfig, fields: FieldMapping, interval: impl Into<String>) -> Self {
+        Self {
+            kind: MappingKind::Candles,
+            format: SourceFormat::Auto,
+            compression: Compression::Auto,
+            csv,
+            fields: Some(fields),
+            trades: None,
+            depth: None,
+            interval: interval.into(),
+        }
+    }
+
     fn default_interval() -> String {
         "1m".to_string()
     }
+
+    fn records(&self, path: &Path) -> Result<RecordIter> {
+        read_records(path, self.format, self.compression, &self.csv)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
 pub struct CsvConfig {
-    #[serde(default)]
     delimiter: Option<String>,
-    #[serde(default = "CsvConfig::default_has_header")]
     has_header: bool,
 }
 
@@ -61,10 +109,6 @@ impl CsvConfig {
             .unwrap_or(b',')
     }
 
-    fn default_has_header() -> bool {
-        true
-    }
-
     fn has_header(&self) -> bool {
         self.has_header
     }
@@ -90,9 +134,13 @@ pub struct FieldMapping {
     pub volume: Option<ValueField>,
 }
 
+/// Timestamp location: a CSV column index (`col`) or an NDJSON key path (`key`).
 #[derive(Debug, Clone, Deserialize)]
 pub struct TimestampField {
-    pub col: usize,
+    #[serde(default)]
+    pub col: Option<usize>,
+    #[serde(default)]
+    pub key: Option<String>,
     #[serde(default)]
     pub unit: TimestampUnit,
     #[serde(default)]
@@ -100,13 +148,14 @@ pub struct TimestampField {
 }
 
 impl TimestampField {
-    fn parse(&self, record: &StringRecord) -> Result<i64> {
+    fn parse(&self, record: &SourceRecord) -> Result<i64> {
+        let location = describe_location(self.col, self.key.as_deref());
         let raw = record
-            .get(self.col)
-            .ok_or_else(|| anyhow!("row missing timestamp column {}", self.col))?
-            .trim();
+            .text(self.col, self.key.as_deref())
+            .ok_or_else(|| anyhow!("row missing timestamp {location}"))?;
+        let raw = raw.trim();
         if raw.is_empty() {
-            bail!("timestamp column {} is empty", self.col);
+            bail!("timestamp {location} is empty");
         }
         match self.format {
             TimestampFormat::Unix => self.parse_unix(raw),
@@ -115,13 +164,17 @@ impl TimestampField {
     }
 
     fn parse_unix(&self, raw: &str) -> Result<i64> {
-        let value: i64 = raw
-            .parse()
-            .with_context(|| format!("invalid timestamp '{raw}'"))?;
-        let nanos = value
-            .checked_mul(self.unit.multiplier())
-            .ok_or_else(|| anyhow!("timestamp overflow for value {value}"))?;
-        Ok(nanos)
+        if let Ok(value) = raw.parse::<i64>() {
+            return value
+                .checked_mul(self.unit.multiplier())
+                .ok_or_else(|| anyhow!("timestamp overflow for value {value}"));
+        }
+        // Fractional epochs such as `1700000000.123` (seconds) are common in NDJSON dumps.
+        let value = Decimal::from_str(raw).with_context(|| format!("invalid timestamp '{raw}'"))?;
+        value
+            .checked_mul(Decimal::from(self.unit.multiplier()))
+            .and_then(|nanos| nanos.trunc().to_i64())
+            .ok_or_else(|| anyhow!("timestamp overflow for value {raw}"))
     }
 
     fn parse_rfc3339(raw: &str) -> Result<i64> {
@@ -164,25 +217,61 @@ pub enum TimestampFormat {
     Rfc3339,
 }
 
+/// Value location: a CSV column index (`col`) or an NDJSON key path (`key`, e.g. `data.p`).
 #[derive(Debug, Clone, Deserialize)]
 pub struct ValueField {
-    pub col: usize,
+    #[serde(default)]
+    pub col: Option<usize>,
+    #[serde(default)]
+    pub key: Option<String>,
 }
 
 impl ValueField {
-    fn parse_decimal(&self, record: &StringRecord, label: &str) -> Result<Decimal> {
-        let raw = record
-            .get(self.col)
-            .ok_or_else(|| anyhow!("row missing {label} column {}", self.col))?
-            .trim();
+    /// Field read from a CSV column (or an index into an NDJSON array row).
+    pub fn column(col: usize) -> Self {
+        Self {
+            col: Some(col),
+            key: None,
+        }
+    }
+
+    /// Field read from an NDJSON key path.
+    pub fn key(key: impl Into<String>) -> Self {
+        Self {
+            col: None,
+            key: Some(key.into()),
+        }
+    }
+
+    fn text<'a>(&self, record: &'a SourceRecord, label: &str) -> Result<Cow<'a, str>> {
+        record.text(self.col, self.key.as_deref()).ok_or_else(|| {
+            anyhow!(
+                "row missing {label} {}",
+                describe_location(self.col, self.key.as_deref())
+            )
+        })
+    }
+
+    fn parse_decimal(&self, record: &SourceRecord, label: &str) -> Result<Decimal> {
+        let raw = self.text(record, label)?;
+        let raw = raw.trim();
         if raw.is_empty() {
-            bail!("{label} column {} is empty", self.col);
+            bail!(
+                "{label} {} is empty",
+                describe_location(self.col, self.key.as_deref())
+            );
         }
-        Decimal::from_str(raw).map_err(|err| anyhow!("invalid {} value '{}': {err}", label, raw))
+        Decimal::from_str(raw)
+            .or_else(|_| Decimal::from_scientific(raw))
+            .map_err(|err| anyhow!("invalid {} value '{}': {err}", label, raw))
     }
 }
 
-/// ETL pipeline that converts arbitrary CSVs into the canonical Arrow schema.
+/// ETL pipeline that converts arbitrary CSV/NDJSON dumps into canonical parquet.
+///
+/// Candles use the canonical candle schema; trades and depth are written with the
+/// flight-recorder layout (`ticks/`, `order_books/`, `depth/`) consumed by
+/// [`crate::merger::UnifiedEventStream`] and tick-mode backtests.
 pub struct Pipeline {
     mapping: MappingConfig,
 }
@@ -199,17 +288,16 @@ impl Pipeline {
         symbol: &str,
         partitioning: Partitioning,
     ) -> Result<usize> {
+        self.validate()?;
         let mut total_rows = 0usize;
         let mut matched = false;
         for entry in glob(pattern).with_context(|| format!("invalid source glob {pattern}"))? {
             let path = entry?;
             matched = true;
-            let rows = self.load_rows(&path, symbol)?;
-            if rows.is_empty() {
+            let count = self.normalize_file(&path, output, symbol, partitioning)?;
+            if count == 0 {
                 continue;
             }
-            let count = rows.len();
-            self.write_partitions(rows, output, partitioning)?;
             total_rows += count;
             info!(path = %path.display(), rows = count, "normalized source file");
         }
@@ -219,44 +307,81 @@ impl Pipeline {
         Ok(total_rows)
     }
 
+    fn validate(&self) -> Result<()> {
+        let (present, section) = match self.mapping.kind {
+            MappingKind::Candles => (self.mapping.fields.is_some(), "fields"),
+            MappingKind::Trades => (self.mapping.trades.is_some(), "trades"),
+            MappingKind::Depth => (self.mapping.depth.is_some(), "depth"),
+        };
+        if !present {
+            bail!(
+                "mapping kind {:?} requires a [{section}] section",
+                self.mapping.kind
+            );
+        }
+        Ok(())
+    }
+
+    fn normalize_file(
+        &self,
+        path: &Path,
+        output: &Path,
+        symbol: &str,
+        partitioning: Partitioning,
+    ) -> Result<usize> {
+        match self.mapping.kind {
+            MappingKind::Candles => {
+                let rows = self.load_rows(path, symbol)?;
+                let count = rows.len();
+                if count > 0 {
+                    self.write_partitions(rows, output, partitioning)?;
+                }
+                Ok(count)
+            }
+            MappingKind::Trades => {
+                let Some(fields) = self.mapping.trades.as_ref() else {
+                    bail!("mapping kind trades requires a [trades] section");
+                };
+                let ticks = events::load_trades(&self.mapping, fields, path, Symbol::from(symbol))?;
+                events::write_trades(&ticks, output, path, partitioning)?;
+                Ok(ticks.len())
+            }
+            MappingKind::Depth => {
+                let Some(fields) = self.mapping.depth.as_ref() else {
+                    bail!("mapping kind depth requires a [depth] section");
+                };
+                let depth = events::load_depth(&self.mapping, fields, path, Symbol::from(symbol))?;
+                events::write_depth(&depth, output, path, partitioning)?;
+                Ok(depth.len())
+            }
+        }
+    }
+
     fn load_rows(&self, path: &Path, symbol: &str) -> Result<Vec<CanonicalCandle>> {
         let interval_label = self.mapping.interval.clone();
-        let file = File::open(path)
-            .with_context(|| format!("failed to open source file {}", path.display()))?;
-        let mut reader = csv::ReaderBuilder::new()
-            .delimiter(self.mapping.csv.delimiter())
-            .has_headers(self.mapping.csv.has_header())
-            .from_reader(BufReader::new(file));
+        let Some(fields) = self.mapping.fields.as_ref() else {
+            bail!("mapping kind candles requires a [fields] section");
+        };
         let mut rows = Vec::new();
-        for (idx, record) in reader.records().enumerate() {
-            let record = record.with_context(|| format!("failed to read record {}", idx + 1))?;
-            let timestamp = self
-                .mapping
-                .fields
+        for (idx, record) in self.mapping.records(path)?.enumerate() {
+            let record = record?;
+            let timestamp = fields
                 .timestamp
                 .parse(&record)
                 .with_context(|| format!("invalid timestamp in {}", path.display()))?;
-            let open = self
-                .mapping
-                .fields
+            let open = fields
                 .open
                 .parse_decimal(&record, "open")
                 .with_context(|| format!("invalid open price in {}", path.display()))?;
-            let high = self
-                .mapping
-                .fields
+            let high = fields
                 .high
                 .parse_decimal(&record, "high")
                 .with_context(|| format!("invalid high price in {}", path.display()))?;
-            let low = self
-                .mapping
-                .fields
+            let low = fields
                 .low
                 .parse_decimal(&record, "low")
                 .with_context(|| format!("invalid low price in {}", path.display()))?;
-            let close = self
-                .mapping
-                .fields
+            let close = fields
                 .close
                 .parse_decimal(&record, "close")
                 .with_context(|| format!("invalid close price in {}", path.display()))?;
@@ -268,7 +393,7 @@ impl Pipeline {
                     low
                 );
             }
-            let volume = if let Some(field) = &self.mapping.fields.volume {
+            let volume = if let Some(field) = &fields.volume {
                 let parsed = field
                     .parse_decimal(&record, "volume")
                     .with_context(|| format!("invalid volume in {}", path.display()))?;
@@ -457,22 +582,23 @@ mod tests {
 ",
         )
         .unwrap();
-        let mapping = MappingConfig {
-            csv: CsvConfig::default(),
-            fields: FieldMapping {
+        let mapping = MappingConfig::candles(
+            CsvConfig::default(),
+            FieldMapping {
                 timestamp: TimestampField {
-                    col: 0,
+                    col: Some(0),
+                    key: None,
                     unit: TimestampUnit::Milliseconds,
                     format: TimestampFormat::Unix,
                 },
-                open: ValueField { col: 1 },
-                high: ValueField { col: 2 },
-                low: ValueField { col: 3 },
-                close: ValueField { col: 4 },
-                volume: Some(ValueField { col: 5 }),
+                open: ValueField::column(1),
+                high: ValueField::column(2),
+                low: ValueField::column(3),
+                close: ValueField::column(4),
+                volume: Some(ValueField::column(5)),
             },
-            interval: "1m".into(),
-        };
+            "1m",
+        );
         let pipeline = Pipeline::new(mapping);
         let output = dir.path().join("lake");
         let rows = pipeline
@@ -496,22 +622,23 @@ mod tests {
             "ts,open,high,low,close,vol\n2024-01-01T00:00:00Z,100,110,90,105,12\n",
         )
         .unwrap();
-        let mapping = MappingConfig {
-            csv: CsvConfig::default(),
-            fields: FieldMapping {
+        let mapping = MappingConfig::candles(
+            CsvConfig::default(),
+            FieldMapping {
                 timestamp: TimestampField {
-                    col: 0,
+                    col: Some(0),
+                    key: None,
                     unit: TimestampUnit::Milliseconds,
                     format: TimestampFormat::Rfc3339,
                 },
-                open: ValueField { col: 1 },
-                high: ValueField { col: 2 },
-                low: ValueField { col: 3 },
-                close: ValueField { col: 4 },
-                volume: Some(ValueField { col: 5 }),
+                open: ValueField::column(1),
+                high: ValueField::column(2),
+                low: ValueField::column(3),
+                close: ValueField::column(4),
+                volume: Some(ValueField::column(5)),
             },
-            interval: "1m".into(),
-        };
+            "1m",
+        );
         let pipeline = Pipeline::new(mapping);
         let output = dir.path().join("lake");
         let rows = pipeline
@@ -526,6 +653,239 @@ mod tests {
         assert!(count_files(&output) > 0);
     }
 
+    #[tokio::test]
+    async fn pipeline_normalizes_gzip_ndjson_trades() {
+        use std::io::Write;
+
+        use flate2::write::GzEncoder;
+        use futures::StreamExt;
+
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("agg_trades.ndjson.gz");
+        let mut encoder =
+            GzEncoder::new(File::create(&src).unwrap(), flate2::Compression::default());
+        writeln!(
+            encoder,
+            r#"{{"T":1700000000000,"p":"100.5","q":"0.2","m":true}}"#
+        )
+        .unwrap();
+        writeln!(encoder).unwrap();
+        writeln!(
+            encoder,
+            r#"{{"T":1700000001000,"p":101,"q":0.5,"m":false}}"#
+        )
+        .unwrap();
+        encoder.finish().unwrap();
+
+        let mapping: MappingConfig = toml::from_str(
+            r#"
+kind = "trades"
+
+[trades]
+timestamp = { key = "T" }
+price = { key = "p" }
+size = { key = "q" }
+side = { key = "m", buyer_maker = true }
+"#,
+        )
+        .unwrap();
+        let output = dir.path().join("recorder");
+        let rows = Pipeline::new(mapping)
+            .run(
+                src.to_str().unwrap(),
+                &output,
+                "BTCUSDT",
+                Partitioning::Daily,
+            )
+            .unwrap();
+        assert_eq!(rows, 2);
+
+        // Flight-recorder parquet stores the bare market code, as live recordings do.
+        let symbol = Symbol::from("BTCUSDT");
+        let events: Vec<_> =
+            crate::merger::UnifiedEventStream::from_flight_recorder(&output, &[symbol])
+                .unwrap()
+                .into_stream()
+                .collect()
+                .await;
+        let ticks: Vec<_> = events
+            .into_iter()
+            .map(|event| match event.unwrap().kind {
+                crate::merger::UnifiedEventKind::Trade(tick) => tick,
+                other => panic!("unexpected event {other:?}"),
+            })
+            .collect();
+        assert_eq!(ticks.len(), 2);
+        assert_eq!(ticks[0].side, tesser_core::Side::Sell);
+        assert_eq!(ticks[0].price, Decimal::from_str("100.5").unwrap());
+        assert_eq!(ticks[1].side, tesser_core::Side::Buy);
+        assert_eq!(ticks[1].size, Decimal::from_str("0.5").unwrap());
+    }
+
+    #[test]
+    fn pipeline_keeps_inputs_sharing_a_file_stem() {
+        let dir = tempdir().unwrap();
+        for venue in ["a", "b"] {
+            let venue_dir = dir.path().join(venue);
+            fs::create_dir_all(&venue_dir).unwrap();
+            fs::write(
+                venue_dir.join("trades.csv"),
+                "ts,price,size,side\n1700000000000,100,1,buy\n",
+            )
+            .unwrap();
+        }
+        let mapping: MappingConfig = toml::from_str(
+            r#"
+kind = "trades"
+
+[trades]
+timestamp = { col = 0 }
+price = { col = 1 }
+size = { col = 2 }
+side = { col = 3 }
+"#,
+        )
+        .unwrap();
+        let output = dir.path().join("recorder");
+        let pattern = dir.path().join("*").join("trades.csv");
+        let rows = Pipeline::new(mapping)
+            .run(
+                pattern.to_str().unwrap(),
+                &output,
+                "BTCUSDT",
+                Partitioning::Daily,
+            )
+            .unwrap();
+        assert_eq!(rows, 2);
+        assert_eq!(count_files(&output.join("ticks")), 2);
+    }
+
+    #[test]
+    fn load_depth_orders_updates_by_timestamp() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("book.csv");
+        fs::write(
+            &src,
+            "timestamp,is_snapshot,side,price,amount
+500,false,bid,99,1
+100,false,bid,98,2
+100,false,ask,101,3
+900,false,ask,100,4
+",
+        )
+        .unwrap();
+        let mapping: MappingConfig = toml::from_str(
+            r#"
+kind = "depth"
+
+[depth]
+timestamp = { col = 0, unit = "milliseconds" }
+snapshot = { col = 1 }
+side = { col = 2 }
+price = { col = 3 }
+size = { col = 4 }
+"#,
+        )
+        .unwrap();
+        let fields = mapping.depth.clone().unwrap();
+        let events = events::load_depth(&mapping, &fields, &src, Symbol::from("BTCUSDT")).unwrap();
+        let timestamps: Vec<_> = events
+            .updates
+            .iter()
+            .map(|update| update.timestamp.timestamp_millis())
+            .collect();
+        assert_eq!(timestamps, vec![100, 500, 900]);
+        assert_eq!(events.updates[0].bids.len(), 1);
+        assert_eq!(events.updates[0].asks.len(), 1);
+    }
+
+    #[test]
+    fn pipeline_groups_zstd_level_rows_into_books_and_deltas() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("incremental_book_L2.csv.zst");
+        let csv = "exchange,symbol,timestamp,local_timestamp,is_snapshot,side,price,amount
+binance,BTCUSDT,1700000000000000,1700000000001000,true,bid,99,1
+binance,BTCUSDT,1700000000000000,1700000000001000,true,ask,101,2
+binance,BTCUSDT,1700000000500000,1700000000501000,false,bid,99,0
+binance,BTCUSDT,1700000000500000,1700000000501000,false,bid,98,3
+binance,BTCUSDT,1700000000900000,1700000000901000,false,ask,100.5,1
+";
+        fs::write(&src, zstd::encode_all(csv.as_bytes(), 0).unwrap()).unwrap();
+
+        let mapping: MappingConfig = toml::from_str(
+            r#"
+kind = "depth"
+
+[depth]
+timestamp = { col = 2, unit = "microseconds" }
+snapshot = { col = 4 }
+side = { col = 5 }
+price = { col = 6 }
+size = { col = 7 }
+"#,
+        )
+        .unwrap();
+        let fields = mapping.depth.clone().unwrap();
+        let events = events::load_depth(&mapping, &fields, &src, Symbol::from("BTCUSDT")).unwrap();
+        assert_eq!(events.snapshots.len(), 1);
+        assert_eq!(events.snapshots[0].bids.len(), 1);
+        assert_eq!(events.snapshots[0].asks.len(), 1);
+        assert_eq!(events.updates.len(), 2);
+        assert_eq!(events.updates[0].bids.len(), 2);
+        assert_eq!(events.updates[0].bids[0].size, Decimal::ZERO);
+        assert_eq!(events.updates[1].asks.len(), 1);
+
+        let output = dir.path().join("recorder");
+        let rows = Pipeline::new(mapping)
+            .run(
+                src.to_str().unwrap(),
+                &output,
+                "BTCUSDT",
+                Partitioning::Daily,
+            )
+            .unwrap();
+        assert_eq!(rows, 3);
+        assert_eq!(count_files(&output.join("order_books")), 1);
+        assert_eq!(count_files(&output.join("depth")), 1);
+    }
+
+    #[test]
+    fn depth_mapping_reads_nested_level_arrays() {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("depth.jsonl");
+        fs::write(
+            &src,
+            r#"{"ts":1700000000.25,"data":{"b":[["99","1"]],"a":[["101","2"],["102","1"]]}}
+{"ts":1700000001,"data":{"b":[{"price":99.5,"size":0.4}],"a":[]}}
+"#,
+        )
+        .unwrap();
+        let mapping: MappingConfig = toml::from_str(
+            r#"
+kind = "depth"
+
+[depth]
+timestamp = { key = "ts", unit = "seconds" }
+bids = { key = "data.b" }
+asks = { key = "data.a" }
+"#,
+        )
+        .unwrap();
+        let fields = mapping.depth.clone().unwrap();
+        let events = events::load_depth(&mapping, &fields, &src, Symbol::from("BTCUSDT")).unwrap();
+        assert!(events.snapshots.is_empty());
+        assert_eq!(events.updates.len(), 2);
+        assert_eq!(events.updates[0].asks.len(), 2);
+        assert_eq!(
+            events.updates[0].timestamp.timestamp_millis(),
+            1_700_000_000_250
+        );
+        assert_eq!(
+            events.updates[1].bids[0].price,
+            Decimal::from_str("99.5").unwrap()
+        );
+    }
+
     fn count_files(root: &Path) -> usize {
         fn visit(dir: &Path, total: &mut usize) {
             if let Ok(entries) = fs::read_dir(dir) {
@@ -0,0 +1,203 @@
+//! Record readers shared by every mapping kind: CSV or NDJSON, optionally compressed.
+
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use csv::StringRecord;
+use flate2::read::MultiGzDecoder;
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::CsvConfig;
+
+/// Layout of the rows inside a source file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceFormat {
+    /// Pick NDJSON for `.ndjson`/`.jsonl`/`.json` files and CSV otherwise.
+    #[default]
+    Auto,
+    Csv,
+    /// One JSON object (or array) per line.
+    Ndjson,
+}
+
+/// Compression applied to the source file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    /// Detect from the `.gz`/`.zst` extension.
+    #[default]
+    Auto,
+    None,
+    Gzip,
+    Zstd,
+}
+
+/// Single row read from a source file.
+pub(crate) enum SourceRecord {
+    Csv(StringRecord),
+    Json(Value),
+}
+
+impl SourceRecord {
+    /// Resolve a field by CSV column index or NDJSON key path (e.g. `data.price` or `bids.0`).
+    ///
+    /// NDJSON rows that are plain arrays can be addressed by `col` as well.
+    pub(crate) fn text(&self, col: Option<usize>, key: Option<&str>) -> Option<Cow<'_, str>> {
+        match self {
+            Self::Csv(record) => col.and_then(|idx| record.get(idx)).map(Cow::Borrowed),
+            Self::Json(_) => match self.json(col, key)? {
+                Cow::Borrowed(Value::String(text)) => Some(Cow::Borrowed(text.as_str())),
+                other => json_scalar(other.as_ref()).map(Cow::Owned),
+            },
+        }
+    }
+
+    /// Resolve a field as a JSON value; CSV cells are parsed as embedded JSON.
+    pub(crate) fn json(&self, col: Option<usize>, key: Option<&str>) -> Option<Cow<'_, Value>> {
+        match self {
+            Self::Csv(record) => {
+                let raw = record.get(col?)?.trim();
+                serde_json::from_str(raw).ok().map(Cow::Owned)
+            }
+            Self::Json(value) => {
+                let found = match (key, col) {
+                    (Some(path), _) => lookup_path(value, path)?,
+                    (None, Some(idx)) => value.as_array()?.get(idx)?,
+                    (None, None) => return None,
+                };
+                Some(Cow::Borrowed(found))
+            }
+        }
+    }
+}
+
+fn lookup_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |current, segment| match current {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => segment.parse::<usize>().ok().and_then(|idx| items.get(idx)),
+            _ => None,
+        })
+}
+
+fn json_scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Human-readable location used in error messages.
+pub(crate) fn describe_location(col: Option<usize>, key: Option<&str>) -> String {
+    match (key, col) {
+        (Some(path), _) => format!("key '{path}'"),
+        (None, Some(idx)) => format!("column {idx}"),
+        (None, None) => "unset field".to_string(),
+    }
+}
+
+pub(crate) type RecordIter = Box<dyn Iterator<Item = Result<SourceRecord>>>;
+
+/// Open `path` and iterate its rows according to the configured format and compression.
+pub(crate) fn read_records(
+    path: &Path,
+    format: SourceFormat,
+    compression: Compression,
+    csv_config: &CsvConfig,
+) -> Result<RecordIter> {
+    let file = File::open(path)
+        .with_context(|| format!("failed to open source file {}", path.display()))?;
+    let (compression, stem) = resolve_compression(path, compression);
+    let reader: Box<dyn Read> = match compression {
+        Compression::Gzip => Box::new(MultiGzDecoder::new(BufReader::new(file))),
+        Compression::Zstd => Box::new(
+            zstd::Decoder::new(file)
+                .with_context(|| format!("failed to open zstd stream {}", path.display()))?,
+        ),
+        Compression::None | Compression::Auto => Box::new(file),
+    };
+    let format = match format {
+        SourceFormat::Auto => detect_format(&stem),
+        explicit => explicit,
+    };
+    let display = path.display().to_string();
+    match format {
+        SourceFormat::Ndjson => {
+            let lines = BufReader::new(reader).lines().enumerate();
+            Ok(Box::new(lines.filter_map(move |(idx, line)| {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(err) => {
+                        return Some(Err(anyhow!(
+                            "failed to read line {} of {display}: {err}",
+                            idx + 1
+                        )))
+                    }
+                };
+                let trimmed = line.trim();
+                if trimmed.is_empty() {
+                    return None;
+                }
+                Some(
+                    serde_json::from_str(trimmed)
+                        .map(SourceRecord::Json)
+                        .with_context(|| format!("invalid JSON on line {} of {display}", idx + 1)),
+                )
+            })))
+        }
+        SourceFormat::Csv | SourceFormat::Auto => {
+            let records = csv::ReaderBuilder::new()
+                .delimiter(csv_config.delimiter())
+                .has_headers(csv_config.has_header())
+                .from_reader(reader)
+                .into_records()
+                .enumerate();
+            Ok(Box::new(records.map(move |(idx, record)| {
+                record
+                    .map(SourceRecord::Csv)
+                    .with_context(|| format!("failed to read record {} of {display}", idx + 1))
+            })))
+        }
+    }
+}
+
+/// Resolve the compression codec and return the file name with the codec extension stripped.
+fn resolve_compression(path: &Path, compression: Compression) -> (Compression, String) {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let (detected, stem) = if let Some(stem) = name.strip_suffix(".gz") {
+        (Compression::Gzip, stem.to_string())
+    } else if let Some(stem) = name
+        .strip_suffix(".zst")
+        .or_else(|| name.strip_suffix(".zstd"))
+    {
+        (Compression::Zstd, stem.to_string())
+    } else {
+        (Compression::None, name.clone())
+    };
+    match compression {
+        Compression::Auto => (detected, stem),
+        explicit => (explicit, stem),
+    }
+}
+
+fn detect_format(name: &str) -> SourceFormat {
+    if [".ndjson", ".jsonl", ".json"]
+        .iter()
+        .any(|ext| name.ends_with(ext))
+    {
+        SourceFormat::Ndjson
+    } else {
+        SourceFormat::Csv
+    }
+}
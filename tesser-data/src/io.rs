@@ -14,9 +14,12 @@ use parquet::basic::{Compression, ZstdLevel};
 use parquet::file::properties::WriterProperties;
 use rust_decimal::Decimal;
 
-use tesser_core::{Candle, Interval, Symbol, Tick};
+use tesser_core::{Candle, FundingRate, Interval, LongShortRatio, OpenInterest, Symbol, Tick};
 
-use crate::encoding::{candles_to_batch, ticks_to_batch};
+use crate::encoding::{
+    candles_to_batch, funding_rates_to_batch, long_short_ratios_to_batch, open_interest_to_batch,
+    ticks_to_batch,
+};
 
 /// Canonical formats supported by `read_dataset`/`write_dataset`.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -67,6 +70,58 @@ pub fn write_dataset(path: &Path, format: DatasetFormat, candles: &[Candle]) ->
     }
 }
 
+/// Persist funding rate history to parquet using [`crate::encoding::funding_rate_schema`].
+pub fn write_funding_rates(path: &Path, rows: &[FundingRate]) -> Result<()> {
+    write_batch_parquet(path, &funding_rates_to_batch(rows)?)
+}
+
+/// Load funding rate history written by [`write_funding_rates`].
+pub fn read_funding_rates(path: &Path) -> Result<Vec<FundingRate>> {
+    let mut rows = read_parquet_rows(path, |batch| {
+        let columns = FundingColumns::from_batch(batch)?;
+        (0..batch.num_rows())
+            .map(|row| columns.decode(batch, row))
+            .collect()
+    })?;
+    rows.sort_by_key(|row| row.timestamp);
+    Ok(rows)
+}
+
+/// Persist open interest history to parquet using [`crate::encoding::open_interest_schema`].
+pub fn write_open_interest(path: &Path, rows: &[OpenInterest]) -> Result<()> {
+    write_batch_parquet(path, &open_interest_to_batch(rows)?)
+}
+
+/// Load open interest history written by [`write_open_interest`].
+pub fn read_open_interest(path: &Path) -> Result<Vec<OpenInterest>> {
+    let mut rows = read_parquet_rows(path, |batch| {
+        let columns = OpenInterestColumns::from_batch(batch)?;
+        (0..batch.num_rows())
+            .map(|row| columns.decode(batch, row))
+            .collect()
+    })?;
+    rows.sort_by_key(|row| row.timestamp);
+    Ok(rows)
+}
+
+/// Persist long/short account ratios to parquet using
+/// [`crate::encoding::long_short_ratio_schema`].
+pub fn write_long_short_ratios(path: &Path, rows: &[LongShortRatio]) -> Result<()> {
+    write_batch_parquet(path, &long_short_ratios_to_batch(rows)?)
+}
+
+/// Load long/short account ratios written by [`write_long_short_ratios`].
+pub fn read_long_short_ratios(path: &Path) -> Result<Vec<LongShortRatio>> {
+    let mut rows = read_parquet_rows(path, |batch| {
+        let columns = LongShortColumns::from_batch(batch)?;
+        (0..batch.num_rows())
+            .map(|row| columns.decode(batch, row))
+            .collect()
+    })?;
+    rows.sort_by_key(|row| row.timestamp);
+    Ok(rows)
+}
+
 /// Helper for normalizing and persisting tick data to parquet files.
 pub struct TicksWriter {
     path: PathBuf,
@@ -222,39 +277,46 @@ fn write_csv(path: &Path, candles: &[Candle]) -> Result<()> {
 }
 
 fn write_parquet(path: &Path, candles: &[Candle]) -> Result<()> {
-    if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent)
-            .with_context(|| format!("failed to create directory {}", parent.display()))?;
-    }
-    let batch = candles_to_batch(candles)?;
-    let file =
-        File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
-    let props = WriterProperties::builder()
-        .set_compression(Compression::ZSTD(ZstdLevel::default()))
-        .build();
-    let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
-    writer.write(&batch)?;
-    writer.close()?;
-    Ok(())
+    write_batch_parquet(path, &candles_to_batch(candles)?)
 }
 
 fn write_ticks_parquet(path: &Path, ticks: &[Tick]) -> Result<()> {
+    write_batch_parquet(path, &ticks_to_batch(ticks)?)
+}
+
+fn write_batch_parquet(path: &Path, batch: &RecordBatch) -> Result<()> {
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)
             .with_context(|| format!("failed to create directory {}", parent.display()))?;
     }
-    let batch = ticks_to_batch(ticks)?;
     let file =
         File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
     let props = WriterProperties::builder()
         .set_compression(Compression::ZSTD(ZstdLevel::default()))
         .build();
     let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props))?;
-    writer.write(&batch)?;
+    writer.write(batch)?;
     writer.close()?;
     Ok(())
 }
 
+fn read_parquet_rows<T>(
+    path: &Path,
+    mut decode: impl FnMut(&RecordBatch) -> Result<Vec<T>>,
+) -> Result<Vec<T>> {
+    let file = File::open(path)
+        .with_context(|| format!("failed to open parquet file {}", path.display()))?;
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file)?
+        .with_batch_size(1024)
+        .build()?;
+    let mut rows = Vec::new();
+    for batch in reader {
+        let batch = batch?;
+        rows.extend(decode(&batch).with_context(|| format!("invalid rows in {}", path.display()))?);
+    }
+    Ok(rows)
+}
+
 fn parse_decimal(value: Option<&str>, column: &str, path: &Path) -> Result<Decimal> {
     let text = value.ok_or_else(|| anyhow!("missing {column} column in {}", path.display()))?;
     Decimal::from_str(text)
@@ -333,6 +395,93 @@ impl CandleColumns {
     }
 }
 
+struct FundingColumns {
+    symbol: usize,
+    rate: usize,
+    mark_price: usize,
+    timestamp: usize,
+}
+
+impl FundingColumns {
+    fn from_batch(batch: &RecordBatch) -> Result<Self> {
+        Ok(Self {
+            symbol: column_index(batch, "symbol")?,
+            rate: column_index(batch, "rate")?,
+            mark_price: column_index(batch, "mark_price")?,
+            timestamp: column_index(batch, "timestamp")?,
+        })
+    }
+
+    fn decode(&self, batch: &RecordBatch, row: usize) -> Result<FundingRate> {
+        Ok(FundingRate {
+            symbol: Symbol::from(string_value(batch, self.symbol, row)?.as_str()),
+            rate: decimal_value(batch, self.rate, row)?,
+            mark_price: optional_decimal_value(batch, self.mark_price, row)?,
+            timestamp: timestamp_value(batch, self.timestamp, row)?,
+        })
+    }
+}
+
+struct OpenInterestColumns {
+    symbol: usize,
+    interval: usize,
+    open_interest: usize,
+    open_interest_value: usize,
+    timestamp: usize,
+}
+
+impl OpenInterestColumns {
+    fn from_batch(batch: &RecordBatch) -> Result<Self> {
+        Ok(Self {
+            symbol: column_index(batch, "symbol")?,
+            interval: column_index(batch, "interval")?,
+            open_interest: column_index(batch, "open_interest")?,
+            open_interest_value: column_index(batch, "open_interest_value")?,
+            timestamp: column_index(batch, "timestamp")?,
+        })
+    }
+
+    fn decode(&self, batch: &RecordBatch, row: usize) -> Result<OpenInterest> {
+        Ok(OpenInterest {
+            symbol: Symbol::from(string_value(batch, self.symbol, row)?.as_str()),
+            interval: interval_value(batch, self.interval, row)?,
+            open_interest: decimal_value(batch, self.open_interest, row)?,
+            open_interest_value: optional_decimal_value(batch, self.open_interest_value, row)?,
+            timestamp: timestamp_value(batch, self.timestamp, row)?,
+        })
+    }
+}
+
+struct LongShortColumns {
+    symbol: usize,
+    interval: usize,
+    long_ratio: usize,
+    short_ratio: usize,
+    timestamp: usize,
+}
+
+impl LongShortColumns {
+    fn from_batch(batch: &RecordBatch) -> Result<Self> {
+        Ok(Self {
+            symbol: column_index(batch, "symbol")?,
+            interval: column_index(batch, "interval")?,
+            long_ratio: column_index(batch, "long_ratio")?,
+            short_ratio: column_index(batch, "short_ratio")?,
+            timestamp: column_index(batch, "timestamp")?,
+        })
+    }
+
+    fn decode(&self, batch: &RecordBatch, row: usize) -> Result<LongShortRatio> {
+        Ok(LongShortRatio {
+            symbol: Symbol::from(string_value(batch, self.symbol, row)?.as_str()),
+            interval: interval_value(batch, self.interval, row)?,
+            long_ratio: decimal_value(batch, self.long_ratio, row)?,
+            short_ratio: decimal_value(batch, self.short_ratio, row)?,
+            timestamp: timestamp_value(batch, self.timestamp, row)?,
+        })
+    }
+}
+
 fn column_index(batch: &RecordBatch, name: &str) -> Result<usize> {
     batch
         .schema()
@@ -368,6 +517,22 @@ fn decimal_value(batch: &RecordBatch, column: usize, row: usize) -> Result<Decim
     ))
 }
 
+fn optional_decimal_value(
+    batch: &RecordBatch,
+    column: usize,
+    row: usize,
+) -> Result<Option<Decimal>> {
+    if batch.column(column).is_null(row) {
+        return Ok(None);
+    }
+    decimal_value(batch, column, row).map(Some)
+}
+
+fn interval_value(batch: &RecordBatch, column: usize, row: usize) -> Result<Interval> {
+    let raw = string_value(batch, column, row)?;
+    Interval::from_str(&raw).map_err(|err| anyhow!("{raw}: {err}"))
+}
+
 fn timestamp_value(batch: &RecordBatch, column: usize, row: usize) -> Result<DateTime<Utc>> {
     let array = batch
         .column(column)
@@ -433,6 +598,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn round_trip_perp_metrics() -> Result<()> {
+        let temp = tempdir()?;
+        let base = Utc.timestamp_millis_opt(1_700_000_000_000).unwrap();
+        let funding = vec![
+            FundingRate {
+                symbol: Symbol::from("BTCUSDT"),
+                rate: Decimal::new(1, 4),
+                mark_price: Some(Decimal::new(3_450_010, 2)),
+                timestamp: base,
+            },
+            FundingRate {
+                symbol: Symbol::from("BTCUSDT"),
+                rate: Decimal::new(-3, 5),
+                mark_price: None,
+                timestamp: base + Duration::hours(8),
+            },
+        ];
+        let path = temp.path().join("funding.parquet");
+        write_funding_rates(&path, &funding)?;
+        assert_eq!(read_funding_rates(&path)?, funding);
+
+        let open_interest = vec![OpenInterest {
+            symbol: Symbol::from("BTCUSDT"),
+            interval: Interval::FiveMinutes,
+            open_interest: Decimal::new(20_403_637, 3),
+            open_interest_value: None,
+            timestamp: base,
+        }];
+        let path = temp.path().join("open_interest.parquet");
+        write_open_interest(&path, &open_interest)?;
+        assert_eq!(read_open_interest(&path)?, open_interest);
+
+        let ratios = vec![LongShortRatio {
+            symbol: Symbol::from("BTCUSDT"),
+            interval: Interval::OneHour,
+            long_ratio: Decimal::new(6442, 4),
+            short_ratio: Decimal::new(3558, 4),
+            timestamp: base,
+        }];
+        let path = temp.path().join("long_short.parquet");
+        write_long_short_ratios(&path, &ratios)?;
+        assert_eq!(read_long_short_ratios(&path)?, ratios);
+        Ok(())
+    }
+
     #[test]
     fn ticks_writer_dedupes_trade_ids_and_payloads() -> Result<()> {
         let temp = tempdir()?;
@@ -1,12 +1,17 @@
 //! Data utilities including streaming and historical downloads.
 
 pub mod analytics;
+pub mod capture;
+pub mod catalog;
+pub mod compaction;
+pub mod consolidated;
 pub mod download;
 pub mod encoding;
 pub mod etl;
 pub mod io;
 pub mod merger;
 pub mod parquet;
+pub mod reconstruct;
 pub mod recorder;
 pub mod schema;
 pub mod transform;
@@ -6,11 +6,11 @@ use std::{
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use futures::{stream, Stream};
-use tesser_core::{DepthUpdate, OrderBook, Symbol, Tick};
+use tesser_core::{DepthUpdate, MarketByOrderUpdate, OrderBook, Symbol, Tick};
 
 use crate::{
     analytics::collect_parquet_files,
-    parquet::{DepthCursor, OrderBookCursor, TickCursor},
+    parquet::{DepthCursor, MarketByOrderCursor, OrderBookCursor, TickCursor},
 };
 
 #[derive(Clone, Copy)]
@@ -18,6 +18,7 @@ enum Source {
     Tick,
     Book,
     Depth,
+    MarketByOrder,
 }
 
 /// Unified event emitted by the merged parquet cursors.
@@ -33,6 +34,7 @@ pub enum UnifiedEventKind {
     OrderBook(OrderBook),
     Depth(DepthUpdate),
     Trade(Tick),
+    MarketByOrder(MarketByOrderUpdate),
 }
 
 /// Builder that merges heterogeneous parquet cursors into a single chronological stream.
@@ -44,6 +46,8 @@ pub struct UnifiedEventStream {
     book_peek: Option<OrderBook>,
     depth: Option<DepthCursor>,
     depth_peek: Option<DepthUpdate>,
+    mbo: Option<MarketByOrderCursor>,
+    mbo_peek: Option<MarketByOrderUpdate>,
 }
 
 impl UnifiedEventStream {
@@ -53,7 +57,8 @@ impl UnifiedEventStream {
         let tick_paths = collect_parquet_files(&root.join("ticks"))?;
         let book_paths = collect_first_existing(root, &["order_books", "books"])?;
         let depth_paths = collect_first_existing(root, &["depth", "depth_updates"])?;
-        Self::from_paths(symbols, tick_paths, book_paths, depth_paths)
+        let mbo_paths = collect_first_existing(root, &["mbo", "market_by_order"])?;
+        Self::from_paths(symbols, tick_paths, book_paths, depth_paths, mbo_paths)
     }
 
     /// Construct a stream from explicit parquet path lists.
@@ -62,8 +67,13 @@ impl UnifiedEventStream {
         tick_paths: Vec<PathBuf>,
         order_book_paths: Vec<PathBuf>,
         depth_paths: Vec<PathBuf>,
+        mbo_paths: Vec<PathBuf>,
     ) -> Result<Self> {
-        if tick_paths.is_empty() && order_book_paths.is_empty() && depth_paths.is_empty() {
+        if tick_paths.is_empty()
+            && order_book_paths.is_empty()
+            && depth_paths.is_empty()
+            && mbo_paths.is_empty()
+        {
             return Err(anyhow!("at least one parquet data source must be provided"));
         }
         Ok(Self {
@@ -74,6 +84,8 @@ impl UnifiedEventStream {
             book_peek: None,
             depth: (!depth_paths.is_empty()).then(|| DepthCursor::new(depth_paths)),
             depth_peek: None,
+            mbo: (!mbo_paths.is_empty()).then(|| MarketByOrderCursor::new(mbo_paths)),
+            mbo_peek: None,
         })
     }
 
@@ -92,6 +104,7 @@ impl UnifiedEventStream {
         self.ensure_tick().await?;
         self.ensure_book().await?;
         self.ensure_depth().await?;
+        self.ensure_mbo().await?;
 
         let mut candidate: Option<(DateTime<Utc>, Source)> = None;
 
@@ -104,6 +117,9 @@ impl UnifiedEventStream {
         if let Some(update) = self.depth_peek.as_ref() {
             candidate = pick_candidate(candidate, update.timestamp, Source::Depth);
         }
+        if let Some(update) = self.mbo_peek.as_ref() {
+            candidate = pick_candidate(candidate, update.timestamp, Source::MarketByOrder);
+        }
 
         let Some((_, source)) = candidate else {
             return Ok(None);
@@ -140,6 +156,16 @@ impl UnifiedEventStream {
                     kind: UnifiedEventKind::Depth(update),
                 }
             }
+            Source::MarketByOrder => {
+                let update = self
+                    .mbo_peek
+                    .take()
+                    .expect("market-by-order candidate must be populated");
+                UnifiedEvent {
+                    timestamp: update.timestamp,
+                    kind: UnifiedEventKind::MarketByOrder(update),
+                }
+            }
         };
         Ok(Some(event))
     }
@@ -197,6 +223,24 @@ impl UnifiedEventStream {
         }
         Ok(())
     }
+
+    async fn ensure_mbo(&mut self) -> Result<()> {
+        if self.mbo_peek.is_some() {
+            return Ok(());
+        }
+        let filter = self.symbols.clone();
+        let allow_all = filter.is_empty();
+        let Some(cursor) = self.mbo.as_mut() else {
+            return Ok(());
+        };
+        while let Some(update) = cursor.next().await? {
+            if allow_all || filter.contains(&update.symbol) {
+                self.mbo_peek = Some(update);
+                break;
+            }
+        }
+        Ok(())
+    }
 }
 
 fn pick_candidate(
@@ -4,10 +4,12 @@ use std::pin::Pin;
 use std::str::FromStr;
 
 use anyhow::{anyhow, Context, Result};
+use arrow::array::BooleanArray;
 use arrow::array::{
     Array, Decimal128Array, Int64Array, Int8Array, ListArray, StringArray, StructArray,
     TimestampNanosecondArray,
 };
+use arrow::compute::filter_record_batch;
 use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
 use async_trait::async_trait;
@@ -15,14 +17,20 @@ use chrono::{DateTime, Utc};
 use futures::StreamExt;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
 use parquet::arrow::ParquetRecordBatchStreamBuilder;
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::statistics::Statistics;
 use rust_decimal::Decimal;
 use tokio::fs::File;
 
 use tesser_broker::{BrokerError, BrokerInfo, BrokerResult, MarketStream};
 use tesser_core::{
-    Candle, DepthUpdate, Interval, LocalOrderBook, OrderBook, OrderBookLevel, Side, Symbol, Tick,
+    Candle, DepthUpdate, Interval, LocalOrderBook, MarketByOrderUpdate, OrderBook, OrderBookLevel,
+    Side, Symbol, Tick,
 };
 
+use crate::encoding::parse_market_by_order_action;
+use crate::transform::bars::{Bar, BarBuilder, BarSpec};
+
 const DEFAULT_BATCH_SIZE: usize = 4_096;
 
 /// Market stream backed by on-disk parquet files (flight recorder output).
@@ -97,6 +105,101 @@ impl ParquetMarketStream {
     pub fn with_depth_updates(symbols: Vec<Symbol>, depth_paths: Vec<PathBuf>) -> Self {
         Self::new(symbols, Vec::new(), Vec::new(), Vec::new(), depth_paths)
     }
+
+    /// Restrict replay to `window`, skipping row groups whose timestamp statistics fall outside it.
+    pub fn with_time_window(mut self, window: TimeWindow) -> Self {
+        if let Some(cursor) = self.ticks.as_mut() {
+            cursor.loader.window = window;
+        }
+        if let Some(cursor) = self.candles.as_mut() {
+            cursor.loader.window = window;
+        }
+        if let Some(cursor) = self.order_books.as_mut() {
+            cursor.loader.window = window;
+        }
+        if let Some(cursor) = self.depth_updates.as_mut() {
+            cursor.loader.window = window;
+        }
+        self
+    }
+}
+
+/// Half-open `[start, end)` time range used to prune parquet reads.
+///
+/// Either bound may be omitted, in which case the range is open on that side.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TimeWindow {
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+}
+
+impl TimeWindow {
+    pub fn new(start: Option<DateTime<Utc>>, end: Option<DateTime<Utc>>) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns true when neither bound is set.
+    pub fn is_unbounded(&self) -> bool {
+        self.start.is_none() && self.end.is_none()
+    }
+
+    pub fn contains(&self, ts: DateTime<Utc>) -> bool {
+        self.start.is_none_or(|start| ts >= start) && self.end.is_none_or(|end| ts < end)
+    }
+
+    /// Returns true when the inclusive range `[min, max]` intersects the window.
+    pub fn overlaps(&self, min: DateTime<Utc>, max: DateTime<Utc>) -> bool {
+        self.start.is_none_or(|start| max >= start) && self.end.is_none_or(|end| min < end)
+    }
+
+    fn contains_nanos(&self, nanos: i64) -> bool {
+        self.start
+            .is_none_or(|start| nanos >= start.timestamp_nanos_opt().unwrap_or(i64::MIN))
+            && self
+                .end
+                .is_none_or(|end| nanos < end.timestamp_nanos_opt().unwrap_or(i64::MAX))
+    }
+
+    fn overlaps_nanos(&self, min: i64, max: i64) -> bool {
+        self.start
+            .is_none_or(|start| max >= start.timestamp_nanos_opt().unwrap_or(i64::MIN))
+            && self
+                .end
+                .is_none_or(|end| min < end.timestamp_nanos_opt().unwrap_or(i64::MAX))
+    }
+}
+
+/// Indices of the row groups whose `column` statistics overlap `window`.
+///
+/// Row groups without usable statistics are always kept.
+pub fn prune_row_groups(
+    metadata: &ParquetMetaData,
+    column: &str,
+    window: &TimeWindow,
+) -> Vec<usize> {
+    let total = metadata.num_row_groups();
+    if window.is_unbounded() {
+        return (0..total).collect();
+    }
+    let schema = metadata.file_metadata().schema_descr();
+    let Some(index) =
+        (0..schema.num_columns()).find(|idx| schema.column(*idx).path().string() == column)
+    else {
+        return (0..total).collect();
+    };
+    metadata
+        .row_groups()
+        .iter()
+        .enumerate()
+        .filter(|(_, group)| match group.column(index).statistics() {
+            Some(Statistics::Int64(stats)) => match (stats.min_opt(), stats.max_opt()) {
+                (Some(min), Some(max)) => window.overlaps_nanos(*min, *max),
+                _ => true,
+            },
+            _ => true,
+        })
+        .map(|(idx, _)| idx)
+        .collect()
 }
 
 #[async_trait]
@@ -150,6 +253,56 @@ impl MarketStream for ParquetMarketStream {
     }
 }
 
+/// Collect feed latencies (`received_at - exchange_timestamp`) from recorded ticks.
+///
+/// Negative delays caused by clock skew are skipped. At most `limit` samples are returned
+/// when a limit is provided.
+pub async fn tick_latency_samples(
+    paths: Vec<PathBuf>,
+    limit: Option<usize>,
+) -> Result<Vec<chrono::Duration>> {
+    let mut cursor = TickCursor::new(paths);
+    let mut samples = Vec::new();
+    while let Some(tick) = cursor.next().await? {
+        let delay = tick.received_at - tick.exchange_timestamp;
+        if delay < chrono::Duration::zero() {
+            continue;
+        }
+        samples.push(delay);
+        if limit.is_some_and(|limit| samples.len() >= limit) {
+            break;
+        }
+    }
+    Ok(samples)
+}
+
+/// Aggregate recorded ticks into information-driven bars.
+///
+/// When `symbol` is provided, ticks for other symbols are skipped. Partially built bars are
+/// appended at the end when `include_partial` is set.
+pub async fn bars_from_ticks(
+    paths: Vec<PathBuf>,
+    spec: BarSpec,
+    symbol: Option<Symbol>,
+    include_partial: bool,
+) -> Result<Vec<Bar>> {
+    let mut cursor = TickCursor::new(paths);
+    let mut builder = BarBuilder::new(spec);
+    let mut bars = Vec::new();
+    while let Some(tick) = cursor.next().await? {
+        if symbol.is_some_and(|symbol| symbol != tick.symbol) {
+            continue;
+        }
+        if let Some(bar) = builder.push(&tick) {
+            bars.push(bar);
+        }
+    }
+    if include_partial {
+        bars.extend(builder.flush());
+    }
+    Ok(bars)
+}
+
 fn map_err(err: anyhow::Error) -> BrokerError {
     BrokerError::Other(err.to_string())
 }
@@ -164,7 +317,7 @@ unsafe impl Sync for TickCursor {}
 impl TickCursor {
     pub(crate) fn new(paths: Vec<PathBuf>) -> Self {
         Self {
-            loader: BatchLoader::new(paths),
+            loader: BatchLoader::new(paths, "exchange_timestamp"),
             columns: None,
         }
     }
@@ -198,7 +351,7 @@ unsafe impl Sync for CandleCursor {}
 impl CandleCursor {
     fn new(paths: Vec<PathBuf>) -> Self {
         Self {
-            loader: BatchLoader::new(paths),
+            loader: BatchLoader::new(paths, "timestamp"),
             columns: None,
         }
     }
@@ -232,7 +385,7 @@ unsafe impl Sync for OrderBookCursor {}
 impl OrderBookCursor {
     pub(crate) fn new(paths: Vec<PathBuf>) -> Self {
         Self {
-            loader: BatchLoader::new(paths),
+            loader: BatchLoader::new(paths, "timestamp"),
             columns: None,
         }
     }
@@ -266,7 +419,7 @@ unsafe impl Sync for DepthCursor {}
 impl DepthCursor {
     pub(crate) fn new(paths: Vec<PathBuf>) -> Self {
         Self {
-            loader: BatchLoader::new(paths),
+            loader: BatchLoader::new(paths, "timestamp"),
             columns: None,
         }
     }
@@ -290,6 +443,40 @@ impl DepthCursor {
     }
 }
 
+pub(crate) struct MarketByOrderCursor {
+    loader: BatchLoader,
+    columns: Option<MarketByOrderColumns>,
+}
+
+unsafe impl Sync for MarketByOrderCursor {}
+
+impl MarketByOrderCursor {
+    pub(crate) fn new(paths: Vec<PathBuf>) -> Self {
+        Self {
+            loader: BatchLoader::new(paths, "timestamp"),
+            columns: None,
+        }
+    }
+
+    pub(crate) async fn next(&mut self) -> Result<Option<MarketByOrderUpdate>> {
+        loop {
+            if !self.loader.ensure_batch().await? {
+                return Ok(None);
+            }
+            if let Some(schema) = self.loader.take_schema_update() {
+                self.columns = Some(MarketByOrderColumns::from_schema(&schema)?);
+            }
+            if let Some((batch, row)) = self.loader.next_row() {
+                let columns = self
+                    .columns
+                    .as_ref()
+                    .ok_or_else(|| anyhow!("market-by-order schema not initialized"))?;
+                return decode_market_by_order(&batch, row, columns).map(Some);
+            }
+        }
+    }
+}
+
 struct BatchLoader {
     files: VecDeque<PathBuf>,
     stream: Option<Pin<Box<ParquetRecordBatchStream<File>>>>,
@@ -297,12 +484,14 @@ struct BatchLoader {
     row_index: usize,
     schema_update: Option<SchemaRef>,
     batch_size: usize,
+    timestamp_column: &'static str,
+    window: TimeWindow,
 }
 
 unsafe impl Sync for BatchLoader {}
 
 impl BatchLoader {
-    fn new(mut paths: Vec<PathBuf>) -> Self {
+    fn new(mut paths: Vec<PathBuf>, timestamp_column: &'static str) -> Self {
         paths.sort();
         Self {
             files: paths.into(),
@@ -311,6 +500,8 @@ impl BatchLoader {
             row_index: 0,
             schema_update: None,
             batch_size: DEFAULT_BATCH_SIZE,
+            timestamp_column,
+            window: TimeWindow::default(),
         }
     }
 
@@ -327,7 +518,7 @@ impl BatchLoader {
                 match stream.next().await {
                     Some(Ok(batch)) => {
                         self.row_index = 0;
-                        self.batch = Some(batch);
+                        self.batch = Some(self.filter_window(batch)?);
                         continue;
                     }
                     Some(Err(err)) => return Err(err.into()),
@@ -354,6 +545,35 @@ impl BatchLoader {
         self.schema_update.take()
     }
 
+    /// Drop rows outside the configured window; batches pass through untouched when unbounded.
+    fn filter_window(&self, batch: RecordBatch) -> Result<RecordBatch> {
+        if self.window.is_unbounded() {
+            return Ok(batch);
+        }
+        let Ok(column) = batch.schema().index_of(self.timestamp_column) else {
+            return Ok(batch);
+        };
+        let values = batch.column(column);
+        let mask: BooleanArray =
+            if let Some(array) = values.as_any().downcast_ref::<TimestampNanosecondArray>() {
+                array
+                    .iter()
+                    .map(|value| Some(value.is_some_and(|nanos| self.window.contains_nanos(nanos))))
+                    .collect()
+            } else if let Some(array) = values.as_any().downcast_ref::<Int64Array>() {
+                array
+                    .iter()
+                    .map(|value| Some(value.is_some_and(|nanos| self.window.contains_nanos(nanos))))
+                    .collect()
+            } else {
+                return Err(anyhow!(
+                    "column {} type mismatch; expected timestamp nanoseconds or int64",
+                    self.timestamp_column
+                ));
+            };
+        filter_record_batch(&batch, &mask).map_err(Into::into)
+    }
+
     async fn open_next_stream(&mut self) -> Result<bool> {
         let Some(path) = self.files.pop_front() else {
             return Ok(false);
@@ -365,6 +585,10 @@ impl BatchLoader {
             .await
             .with_context(|| format!("failed to read parquet metadata from {}", path.display()))?;
         builder = builder.with_batch_size(self.batch_size);
+        if !self.window.is_unbounded() {
+            let groups = prune_row_groups(builder.metadata(), self.timestamp_column, &self.window);
+            builder = builder.with_row_groups(groups);
+        }
         let schema = builder.schema().clone();
         let stream = builder
             .build()
@@ -461,6 +685,31 @@ impl DepthColumns {
     }
 }
 
+#[derive(Clone, Copy)]
+struct MarketByOrderColumns {
+    timestamp: usize,
+    symbol: usize,
+    order_id: usize,
+    side: usize,
+    price: usize,
+    size: usize,
+    action: usize,
+}
+
+impl MarketByOrderColumns {
+    fn from_schema(schema: &SchemaRef) -> Result<Self> {
+        Ok(Self {
+            timestamp: column_index(schema, "timestamp")?,
+            symbol: column_index(schema, "symbol")?,
+            order_id: column_index(schema, "order_id")?,
+            side: column_index(schema, "side")?,
+            price: column_index(schema, "price")?,
+            size: column_index(schema, "size")?,
+            action: column_index(schema, "action")?,
+        })
+    }
+}
+
 enum LevelColumn {
     Struct { column: usize },
     Split { price: usize, size: usize },
@@ -558,6 +807,8 @@ fn decode_order_book(
         timestamp,
         exchange_checksum: None,
         local_checksum: None,
+        sequence: None,
+        prev_sequence: None,
     })
 }
 
@@ -574,6 +825,23 @@ fn decode_depth_update(
     })
 }
 
+fn decode_market_by_order(
+    batch: &RecordBatch,
+    row: usize,
+    columns: &MarketByOrderColumns,
+) -> Result<MarketByOrderUpdate> {
+    let action_raw = string_value(batch, columns.action, row)?;
+    Ok(MarketByOrderUpdate {
+        symbol: symbol_value(batch, columns.symbol, row)?,
+        order_id: string_value(batch, columns.order_id, row)?,
+        side: side_value(batch, columns.side, row)?,
+        price: decimal_value(batch, columns.price, row)?,
+        size: decimal_value(batch, columns.size, row)?,
+        action: parse_market_by_order_action(&action_raw)?,
+        timestamp: timestamp_value(batch, columns.timestamp, row)?,
+    })
+}
+
 fn string_value(batch: &RecordBatch, column: usize, row: usize) -> Result<String> {
     let array = as_array::<StringArray>(batch, column)?;
     if array.is_null(row) {
@@ -786,6 +1054,8 @@ fn snapshot_from_state(
         timestamp,
         exchange_checksum: None,
         local_checksum: None,
+        sequence: None,
+        prev_sequence: None,
     })
 }
 
@@ -831,6 +1101,8 @@ mod tests {
                 timestamp: Utc::now(),
                 exchange_checksum: None,
                 local_checksum: None,
+                sequence: None,
+                prev_sequence: None,
             })
             .collect::<Vec<_>>();
         order_books_to_batch(&books)
@@ -0,0 +1,528 @@
+//! Rebuild full order book states from recorded snapshots and depth deltas.
+//!
+//! The flight recorder stores a full snapshot per symbol every few minutes and depth deltas in
+//! between, which is compact but awkward for feature engineering. [`BookReconstructor`] replays
+//! both through a [`LocalOrderBook`] and samples the book at a fixed cadence, after every trade
+//! or after every update. Each recorded snapshot is also a checkpoint: the reconstructed book is
+//! compared against the snapshot checksum and resynchronized from it when they diverge. Depth
+//! updates received while a symbol is out of sync (before its first snapshot, or after a crossed
+//! book) are dropped until the next snapshot.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use futures::StreamExt;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::{Compression, ZstdLevel};
+use parquet::file::properties::WriterProperties;
+use tracing::warn;
+
+use tesser_core::{LocalOrderBook, OrderBook, OrderBookLevel, Side, Symbol};
+
+use crate::analytics::collect_parquet_files;
+use crate::encoding::{order_book_schema, order_books_to_batch};
+use crate::merger::{UnifiedEvent, UnifiedEventKind, UnifiedEventStream};
+
+const WRITE_BATCH_ROWS: usize = 8_192;
+
+/// When reconstructed books are emitted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SampleCadence {
+    /// On a fixed grid aligned to the Unix epoch, shared by every symbol.
+    Interval(Duration),
+    /// After every recorded trade of the symbol.
+    Trade,
+    /// After every snapshot or depth update applied to the symbol.
+    Update,
+}
+
+impl FromStr for SampleCadence {
+    type Err = String;
+
+    /// Parses `trade`, `update` or a duration such as `100ms`, `1s`, `5m` or `1h`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim().to_ascii_lowercase();
+        match value.as_str() {
+            "trade" | "trades" => return Ok(Self::Trade),
+            "update" | "updates" => return Ok(Self::Update),
+            _ => {}
+        }
+        let split = value
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(value.len());
+        let (count, unit) = value.split_at(split);
+        let count: i64 = count
+            .parse()
+            .map_err(|_| format!("invalid sample cadence '{value}'"))?;
+        let interval = match unit {
+            "ms" => Duration::milliseconds(count),
+            "s" | "" => Duration::seconds(count),
+            "m" => Duration::minutes(count),
+            "h" => Duration::hours(count),
+            _ => {
+                return Err(format!(
+                    "unsupported sample cadence unit '{unit}' (expected ms, s, m or h)"
+                ))
+            }
+        };
+        if interval <= Duration::zero() {
+            return Err(format!("sample cadence must be positive (got '{value}')"));
+        }
+        Ok(Self::Interval(interval))
+    }
+}
+
+/// Options for [`BookReconstructor`].
+#[derive(Clone, Copy, Debug)]
+pub struct ReconstructionConfig {
+    pub cadence: SampleCadence,
+    /// Levels per side written for each book; `None` keeps every level.
+    pub depth: Option<usize>,
+    /// Levels per side covered by the checksum compared at each recorded snapshot.
+    pub checksum_depth: usize,
+    /// Interval sampling skips symbols without any update for this long.
+    pub stale_after: Option<Duration>,
+}
+
+impl Default for ReconstructionConfig {
+    fn default() -> Self {
+        Self {
+            cadence: SampleCadence::Interval(Duration::milliseconds(100)),
+            depth: None,
+            checksum_depth: 25,
+            stale_after: Some(Duration::seconds(60)),
+        }
+    }
+}
+
+/// Counters describing a reconstruction run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReconstructionReport {
+    pub books_written: usize,
+    pub snapshots: usize,
+    pub depth_updates: usize,
+    /// Depth updates dropped because their symbol had no valid base snapshot.
+    pub skipped_updates: usize,
+    /// Snapshots whose checksum disagreed with the reconstructed book.
+    pub checksum_mismatches: usize,
+    /// Depth updates that left the book crossed, forcing a resync.
+    pub crossed_books: usize,
+}
+
+#[derive(Default)]
+struct SymbolBook {
+    book: LocalOrderBook,
+    synced: bool,
+    last_update: Option<DateTime<Utc>>,
+}
+
+/// Replays snapshots, depth deltas and trades into sampled full-book states.
+pub struct BookReconstructor {
+    config: ReconstructionConfig,
+    books: HashMap<Symbol, SymbolBook>,
+    next_sample: Option<DateTime<Utc>>,
+    report: ReconstructionReport,
+}
+
+impl BookReconstructor {
+    pub fn new(config: ReconstructionConfig) -> Self {
+        Self {
+            config,
+            books: HashMap::new(),
+            next_sample: None,
+            report: ReconstructionReport::default(),
+        }
+    }
+
+    pub fn report(&self) -> ReconstructionReport {
+        self.report
+    }
+
+    /// Apply `event` and return the books sampled up to and including it, in time order.
+    ///
+    /// Events must arrive in timestamp order, as produced by [`UnifiedEventStream`].
+    pub fn apply(&mut self, event: &UnifiedEvent) -> Vec<OrderBook> {
+        let mut out = self.sample_grid(event.timestamp);
+        match &event.kind {
+            UnifiedEventKind::OrderBook(snapshot) => {
+                self.apply_snapshot(snapshot);
+                if self.config.cadence == SampleCadence::Update {
+                    out.extend(self.sample(&snapshot.symbol, snapshot.timestamp));
+                }
+            }
+            UnifiedEventKind::Depth(update) => {
+                self.report.depth_updates += 1;
+                let state = self.books.entry(update.symbol).or_default();
+                if !state.synced {
+                    self.report.skipped_updates += 1;
+                    return out;
+                }
+                for level in &update.bids {
+                    state.book.apply_delta(Side::Buy, level.price, level.size);
+                }
+                for level in &update.asks {
+                    state.book.apply_delta(Side::Sell, level.price, level.size);
+                }
+                state.last_update = Some(update.timestamp);
+                if let (Some((bid, _)), Some((ask, _))) =
+                    (state.book.best_bid(), state.book.best_ask())
+                {
+                    if bid >= ask {
+                        warn!(
+                            symbol = %update.symbol,
+                            timestamp = %update.timestamp,
+                            "depth update crossed the book; waiting for the next snapshot"
+                        );
+                        state.synced = false;
+                        self.report.crossed_books += 1;
+                        return out;
+                    }
+                }
+                if self.config.cadence == SampleCadence::Update {
+                    out.extend(self.sample(&update.symbol, update.timestamp));
+                }
+            }
+            UnifiedEventKind::Trade(tick) => {
+                if self.config.cadence == SampleCadence::Trade {
+                    out.extend(self.sample(&tick.symbol, tick.exchange_timestamp));
+                }
+            }
+            UnifiedEventKind::MarketByOrder(_) => {}
+        }
+        out
+    }
+
+    fn apply_snapshot(&mut self, snapshot: &OrderBook) {
+        self.report.snapshots += 1;
+        let checksum_depth = self.config.checksum_depth;
+        let state = self.books.entry(snapshot.symbol).or_default();
+        if state.synced {
+            // The venue checksum covers the venue's own depth (50 levels on Bybit, for example),
+            // so recompute both sides over the same `checksum_depth` levels instead.
+            let expected = snapshot.computed_checksum(Some(checksum_depth));
+            let local = state.book.checksum(checksum_depth);
+            if expected != local {
+                warn!(
+                    symbol = %snapshot.symbol,
+                    timestamp = %snapshot.timestamp,
+                    expected,
+                    local,
+                    "reconstructed book diverged from snapshot; resynchronizing"
+                );
+                self.report.checksum_mismatches += 1;
+            }
+        }
+        let levels = |levels: &[OrderBookLevel]| {
+            levels
+                .iter()
+                .map(|level| (level.price, level.size))
+                .collect::<Vec<_>>()
+        };
+        state
+            .book
+            .load_snapshot(&levels(&snapshot.bids), &levels(&snapshot.asks));
+        state.synced = true;
+        state.last_update = Some(snapshot.timestamp);
+    }
+
+    /// Emit every grid point strictly before `now`.
+    fn sample_grid(&mut self, now: DateTime<Utc>) -> Vec<OrderBook> {
+        let SampleCadence::Interval(interval) = self.config.cadence else {
+            return Vec::new();
+        };
+        let mut out = Vec::new();
+        let mut next = match self.next_sample {
+            Some(next) => next,
+            None => align_up(now, interval),
+        };
+        while next < now {
+            let mut symbols: Vec<Symbol> = self.books.keys().copied().collect();
+            symbols.sort_by_key(|symbol| symbol.code());
+            let before = out.len();
+            for symbol in symbols {
+                out.extend(self.sample(&symbol, next));
+            }
+            if out.len() == before {
+                // Nothing is live, so no book changes until `now`; skip the idle grid points.
+                next = align_up(now, interval);
+                break;
+            }
+            next += interval;
+        }
+        self.next_sample = Some(next);
+        out
+    }
+
+    fn sample(&mut self, symbol: &Symbol, timestamp: DateTime<Utc>) -> Option<OrderBook> {
+        let state = self.books.get(symbol)?;
+        if !state.synced || state.book.is_empty() {
+            return None;
+        }
+        if let (SampleCadence::Interval(_), Some(stale_after), Some(last_update)) = (
+            self.config.cadence,
+            self.config.stale_after,
+            state.last_update,
+        ) {
+            if timestamp - last_update > stale_after {
+                return None;
+            }
+        }
+        let depth = self.config.depth.unwrap_or(usize::MAX);
+        let side = |levels: Vec<_>| {
+            levels
+                .into_iter()
+                .map(|(price, size)| OrderBookLevel { price, size })
+                .collect()
+        };
+        self.report.books_written += 1;
+        Some(OrderBook {
+            symbol: *symbol,
+            bids: side(state.book.bids().take(depth).collect()),
+            asks: side(state.book.asks().take(depth).collect()),
+            timestamp,
+            exchange_checksum: None,
+            local_checksum: Some(state.book.checksum(self.config.checksum_depth)),
+            sequence: None,
+            prev_sequence: None,
+        })
+    }
+}
+
+/// First grid point at or after `ts`.
+fn align_up(ts: DateTime<Utc>, interval: Duration) -> DateTime<Utc> {
+    match ts.duration_trunc(interval) {
+        Ok(floor) if floor == ts => floor,
+        Ok(floor) => floor + interval,
+        Err(_) => ts,
+    }
+}
+
+/// Reconstruct books from a flight recorder root and write them to `output` using
+/// [`order_book_schema`].
+///
+/// Trades are only read when sampling on [`SampleCadence::Trade`].
+pub async fn reconstruct_flight_recorder(
+    root: &Path,
+    symbols: &[Symbol],
+    config: ReconstructionConfig,
+    output: &Path,
+) -> Result<ReconstructionReport> {
+    let book_paths = collect_parquet_files(&root.join("order_books"))?;
+    if book_paths.is_empty() {
+        bail!(
+            "no order book snapshots found under {}",
+            root.join("order_books").display()
+        );
+    }
+    let depth_paths = collect_parquet_files(&root.join("depth"))?;
+    let tick_paths = if config.cadence == SampleCadence::Trade {
+        collect_parquet_files(&root.join("ticks"))?
+    } else {
+        Vec::new()
+    };
+    let stream =
+        UnifiedEventStream::from_paths(symbols, tick_paths, book_paths, depth_paths, Vec::new())?;
+    reconstruct_order_books(stream, config, output).await
+}
+
+/// Reconstruct books from `stream` and write them to `output` using [`order_book_schema`].
+pub async fn reconstruct_order_books(
+    stream: UnifiedEventStream,
+    config: ReconstructionConfig,
+    output: &Path,
+) -> Result<ReconstructionReport> {
+    if let Some(parent) = output
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let file =
+        File::create(output).with_context(|| format!("failed to create {}", output.display()))?;
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::ZSTD(ZstdLevel::default()))
+        .build();
+    let mut writer = ArrowWriter::try_new(file, order_book_schema(), Some(properties))?;
+    let mut reconstructor = BookReconstructor::new(config);
+    let mut pending = Vec::new();
+    let mut events = Box::pin(stream.into_stream());
+    while let Some(event) = events.next().await {
+        pending.extend(reconstructor.apply(&event?));
+        if pending.len() >= WRITE_BATCH_ROWS {
+            writer.write(&order_books_to_batch(&pending)?)?;
+            pending.clear();
+        }
+    }
+    if !pending.is_empty() {
+        writer.write(&order_books_to_batch(&pending)?)?;
+    }
+    writer.close()?;
+    Ok(reconstructor.report())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal::Decimal;
+    use tesser_core::{DepthUpdate, Tick};
+
+    fn at(millis: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap() + Duration::milliseconds(millis)
+    }
+
+    fn levels(raw: &[(i64, i64)]) -> Vec<OrderBookLevel> {
+        raw.iter()
+            .map(|&(price, size)| OrderBookLevel {
+                price: Decimal::from(price),
+                size: Decimal::from(size),
+            })
+            .collect()
+    }
+
+    fn snapshot(millis: i64, bids: &[(i64, i64)], asks: &[(i64, i64)]) -> UnifiedEvent {
+        UnifiedEvent {
+            timestamp: at(millis),
+            kind: UnifiedEventKind::OrderBook(OrderBook {
+                symbol: Symbol::from("BTCUSDT"),
+                bids: levels(bids),
+                asks: levels(asks),
+                timestamp: at(millis),
+                exchange_checksum: None,
+                local_checksum: None,
+                sequence: None,
+                prev_sequence: None,
+            }),
+        }
+    }
+
+    fn depth(millis: i64, bids: &[(i64, i64)], asks: &[(i64, i64)]) -> UnifiedEvent {
+        UnifiedEvent {
+            timestamp: at(millis),
+            kind: UnifiedEventKind::Depth(DepthUpdate {
+                symbol: Symbol::from("BTCUSDT"),
+                bids: levels(bids),
+                asks: levels(asks),
+                timestamp: at(millis),
+            }),
+        }
+    }
+
+    fn trade(millis: i64) -> UnifiedEvent {
+        UnifiedEvent {
+            timestamp: at(millis),
+            kind: UnifiedEventKind::Trade(Tick {
+                symbol: Symbol::from("BTCUSDT"),
+                price: Decimal::from(100),
+                size: Decimal::ONE,
+                side: Side::Buy,
+                exchange_timestamp: at(millis),
+                received_at: at(millis),
+            }),
+        }
+    }
+
+    fn run(config: ReconstructionConfig, events: &[UnifiedEvent]) -> Vec<OrderBook> {
+        let mut reconstructor = BookReconstructor::new(config);
+        events
+            .iter()
+            .flat_map(|event| reconstructor.apply(event))
+            .collect()
+    }
+
+    #[test]
+    fn samples_on_fixed_grid() {
+        let config = ReconstructionConfig {
+            cadence: "100ms".parse().unwrap(),
+            ..ReconstructionConfig::default()
+        };
+        let books = run(
+            config,
+            &[
+                // Dropped: no snapshot yet.
+                depth(10, &[(99, 9)], &[]),
+                snapshot(20, &[(99, 1), (98, 2)], &[(101, 1)]),
+                depth(150, &[(99, 0), (100, 3)], &[]),
+                depth(310, &[], &[(101, 4)]),
+            ],
+        );
+        let stamps: Vec<_> = books.iter().map(|book| book.timestamp).collect();
+        assert_eq!(stamps, vec![at(100), at(200), at(300)]);
+        assert_eq!(books[0].bids, levels(&[(99, 1), (98, 2)]));
+        assert_eq!(books[1].bids, levels(&[(100, 3), (98, 2)]));
+        assert_eq!(books[2].asks, levels(&[(101, 1)]));
+    }
+
+    #[test]
+    fn samples_on_trades_and_resyncs_on_checksum_mismatch() {
+        let config = ReconstructionConfig {
+            cadence: SampleCadence::Trade,
+            depth: Some(1),
+            ..ReconstructionConfig::default()
+        };
+        let mut reconstructor = BookReconstructor::new(config);
+        let events = [
+            trade(0),
+            snapshot(10, &[(99, 1), (98, 2)], &[(101, 1)]),
+            depth(20, &[(98, 5)], &[]),
+            trade(30),
+            // The exchange book lost a level we never saw a delta for.
+            snapshot(40, &[(98, 5)], &[(101, 1)]),
+            trade(50),
+            // Crossing update desynchronizes until the next snapshot.
+            depth(60, &[(102, 1)], &[]),
+            trade(70),
+            depth(80, &[(97, 1)], &[]),
+        ];
+        let books: Vec<_> = events
+            .iter()
+            .flat_map(|event| reconstructor.apply(event))
+            .collect();
+        assert_eq!(books.len(), 2);
+        assert_eq!(books[0].bids, levels(&[(99, 1)]));
+        assert_eq!(books[1].bids, levels(&[(98, 5)]));
+        let report = reconstructor.report();
+        assert_eq!(report.checksum_mismatches, 1);
+        assert_eq!(report.crossed_books, 1);
+        assert_eq!(report.skipped_updates, 1);
+        assert_eq!(report.books_written, 2);
+    }
+
+    #[test]
+    fn ignores_venue_checksums_computed_at_another_depth() {
+        let mut reconstructor = BookReconstructor::new(ReconstructionConfig {
+            checksum_depth: 1,
+            ..ReconstructionConfig::default()
+        });
+        let mut venue_snapshot = |millis, checksum| {
+            let mut event = snapshot(millis, &[(99, 1), (98, 2)], &[(101, 1), (102, 2)]);
+            if let UnifiedEventKind::OrderBook(book) = &mut event.kind {
+                book.exchange_checksum = Some(checksum);
+            }
+            reconstructor.apply(&event);
+        };
+        venue_snapshot(10, 0xdead_beef);
+        venue_snapshot(20, 0x0bad_f00d);
+        assert_eq!(reconstructor.report().checksum_mismatches, 0);
+    }
+
+    #[test]
+    fn parses_cadence() {
+        assert_eq!(
+            "250ms".parse::<SampleCadence>(),
+            Ok(SampleCadence::Interval(Duration::milliseconds(250)))
+        );
+        assert_eq!("trade".parse::<SampleCadence>(), Ok(SampleCadence::Trade));
+        assert_eq!(
+            "updates".parse::<SampleCadence>(),
+            Ok(SampleCadence::Update)
+        );
+        assert!("0s".parse::<SampleCadence>().is_err());
+        assert!("5d".parse::<SampleCadence>().is_err());
+    }
+}
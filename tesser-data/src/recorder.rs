@@ -15,10 +15,11 @@ use tokio::task::JoinHandle;
 use tokio::time::{interval, MissedTickBehavior};
 use tracing::{debug, warn};
 
-use tesser_core::{Candle, Fill, Order, OrderBook, Signal, Tick};
+use tesser_core::{Candle, DepthUpdate, Fill, MarketByOrderUpdate, Order, OrderBook, Signal, Tick};
 
 use crate::encoding::{
-    candle_schema, candles_to_batch, fill_schema, fills_to_batch, order_book_schema,
+    candle_schema, candles_to_batch, depth_update_schema, depth_updates_to_batch, fill_schema,
+    fills_to_batch, market_by_order_schema, market_by_order_to_batch, order_book_schema,
     order_books_to_batch, order_schema, orders_to_batch, signal_schema, signals_to_batch,
     tick_schema, ticks_to_batch,
 };
@@ -131,6 +132,20 @@ impl RecorderHandle {
         );
     }
 
+    /// Enqueues an incremental depth update for recording.
+    pub fn record_depth_update(&self, update: DepthUpdate) {
+        self.enqueue(RecorderMessage::Depth(update), "depth", &DEPTH_SATURATION);
+    }
+
+    /// Enqueues a market-by-order (L3) update for recording.
+    pub fn record_market_by_order(&self, update: MarketByOrderUpdate) {
+        self.enqueue(
+            RecorderMessage::MarketByOrder(update),
+            "market_by_order",
+            &MARKET_BY_ORDER_SATURATION,
+        );
+    }
+
     /// Enqueues a signal for recording.
     pub fn record_signal(&self, signal: Signal) {
         self.enqueue(
@@ -161,6 +176,8 @@ enum RecorderMessage {
     Fill(Fill),
     Order(Order),
     OrderBook(OrderBook),
+    Depth(DepthUpdate),
+    MarketByOrder(MarketByOrderUpdate),
     Signal(Signal),
 }
 
@@ -169,6 +186,8 @@ static CANDLE_SATURATION: AtomicBool = AtomicBool::new(false);
 static FILL_SATURATION: AtomicBool = AtomicBool::new(false);
 static ORDER_SATURATION: AtomicBool = AtomicBool::new(false);
 static ORDER_BOOK_SATURATION: AtomicBool = AtomicBool::new(false);
+static DEPTH_SATURATION: AtomicBool = AtomicBool::new(false);
+static MARKET_BY_ORDER_SATURATION: AtomicBool = AtomicBool::new(false);
 static SIGNAL_SATURATION: AtomicBool = AtomicBool::new(false);
 
 struct FlightRecorderWorker {
@@ -178,6 +197,8 @@ struct FlightRecorderWorker {
     fill_sink: DataSink<FillEncoder>,
     order_sink: DataSink<OrderEncoder>,
     book_sink: DataSink<OrderBookEncoder>,
+    depth_sink: DataSink<DepthEncoder>,
+    mbo_sink: DataSink<MarketByOrderEncoder>,
     signal_sink: DataSink<SignalEncoder>,
     flush_interval: Duration,
 }
@@ -195,6 +216,8 @@ impl FlightRecorderWorker {
         let fill_dir = ensure_subdir(&config.root, FillEncoder::KIND).await?;
         let order_dir = ensure_subdir(&config.root, OrderEncoder::KIND).await?;
         let book_dir = ensure_subdir(&config.root, OrderBookEncoder::KIND).await?;
+        let depth_dir = ensure_subdir(&config.root, DepthEncoder::KIND).await?;
+        let mbo_dir = ensure_subdir(&config.root, MarketByOrderEncoder::KIND).await?;
         let signal_dir = ensure_subdir(&config.root, SignalEncoder::KIND).await?;
 
         Ok(Self {
@@ -234,6 +257,20 @@ impl FlightRecorderWorker {
                 config.flush_interval,
                 writer_props.clone(),
             ),
+            depth_sink: DataSink::new(
+                depth_dir,
+                config.max_buffered_rows,
+                config.max_rows_per_file,
+                config.flush_interval,
+                writer_props.clone(),
+            ),
+            mbo_sink: DataSink::new(
+                mbo_dir,
+                config.max_buffered_rows,
+                config.max_rows_per_file,
+                config.flush_interval,
+                writer_props.clone(),
+            ),
             signal_sink: DataSink::new(
                 signal_dir,
                 config.max_buffered_rows,
@@ -263,6 +300,8 @@ impl FlightRecorderWorker {
                     self.fill_sink.maybe_flush_due_time().await?;
                     self.order_sink.maybe_flush_due_time().await?;
                     self.book_sink.maybe_flush_due_time().await?;
+                    self.depth_sink.maybe_flush_due_time().await?;
+                    self.mbo_sink.maybe_flush_due_time().await?;
                     self.signal_sink.maybe_flush_due_time().await?;
                 }
             }
@@ -273,6 +312,8 @@ impl FlightRecorderWorker {
         self.fill_sink.shutdown().await?;
         self.order_sink.shutdown().await?;
         self.book_sink.shutdown().await?;
+        self.depth_sink.shutdown().await?;
+        self.mbo_sink.shutdown().await?;
         self.signal_sink.shutdown().await?;
         Ok(())
     }
@@ -284,6 +325,8 @@ impl FlightRecorderWorker {
             RecorderMessage::Fill(fill) => self.fill_sink.push(fill).await?,
             RecorderMessage::Order(order) => self.order_sink.push(order).await?,
             RecorderMessage::OrderBook(book) => self.book_sink.push(book).await?,
+            RecorderMessage::Depth(update) => self.depth_sink.push(update).await?,
+            RecorderMessage::MarketByOrder(update) => self.mbo_sink.push(update).await?,
             RecorderMessage::Signal(signal) => self.signal_sink.push(signal).await?,
         }
         Ok(())
@@ -312,6 +355,8 @@ struct CandleEncoder;
 struct FillEncoder;
 struct OrderEncoder;
 struct OrderBookEncoder;
+struct DepthEncoder;
+struct MarketByOrderEncoder;
 struct SignalEncoder;
 
 impl SinkEncoder for TickEncoder {
@@ -591,6 +636,40 @@ impl SinkEncoder for OrderBookEncoder {
     }
 }
 
+impl SinkEncoder for DepthEncoder {
+    type Record = DepthUpdate;
+    const KIND: &'static str = "depth";
+
+    fn schema() -> Arc<arrow::datatypes::Schema> {
+        depth_update_schema()
+    }
+
+    fn encode(records: &[Self::Record]) -> Result<arrow::record_batch::RecordBatch> {
+        depth_updates_to_batch(records)
+    }
+
+    fn partition_for(record: &Self::Record) -> NaiveDate {
+        record.timestamp.date_naive()
+    }
+}
+
+impl SinkEncoder for MarketByOrderEncoder {
+    type Record = MarketByOrderUpdate;
+    const KIND: &'static str = "mbo";
+
+    fn schema() -> Arc<arrow::datatypes::Schema> {
+        market_by_order_schema()
+    }
+
+    fn encode(records: &[Self::Record]) -> Result<arrow::record_batch::RecordBatch> {
+        market_by_order_to_batch(records)
+    }
+
+    fn partition_for(record: &Self::Record) -> NaiveDate {
+        record.timestamp.date_naive()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
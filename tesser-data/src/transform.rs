@@ -1,3 +1,5 @@
+pub use tesser_core::bars;
+
 use std::cmp::Ordering;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
@@ -11,6 +13,9 @@ use tesser_core::{Candle, Interval, Symbol};
 
 /// Resamples a chronologically ordered candle stream into coarser intervals.
 ///
+/// Buckets follow [`Interval::align`], so weekly output starts on Monday and monthly output on
+/// the first of the month.
+///
 /// Typical usage collects candles from either an in-memory vector or an async stream:
 /// ```
 /// # use tesser_core::{Candle, Interval};
@@ -22,7 +27,6 @@ use tesser_core::{Candle, Interval, Symbol};
 /// ```
 pub struct Resampler {
     interval: Interval,
-    interval_nanos: i64,
     active: HashMap<Symbol, Bucket>,
     output: Vec<Candle>,
 }
@@ -30,13 +34,8 @@ pub struct Resampler {
 impl Resampler {
     /// Create a new resampler targeting the provided [`Interval`].
     pub fn new(interval: Interval) -> Self {
-        let interval_nanos = interval
-            .as_duration()
-            .num_nanoseconds()
-            .expect("interval nanoseconds fit into i64");
         Self {
             interval,
-            interval_nanos,
             active: HashMap::new(),
             output: Vec::new(),
         }
@@ -67,7 +66,7 @@ impl Resampler {
 
     /// Ingest one candle into the resampler. Completed buckets are pushed into the output buffer.
     pub fn push(&mut self, candle: Candle) {
-        let bucket_start = align_timestamp(candle.timestamp, self.interval_nanos);
+        let bucket_start = self.interval.align(candle.timestamp);
         let symbol = candle.symbol;
         match self.active.entry(symbol) {
             Entry::Vacant(slot) => {
@@ -178,17 +177,6 @@ impl Bucket {
     }
 }
 
-fn align_timestamp(ts: DateTime<Utc>, step_nanos: i64) -> DateTime<Utc> {
-    let timestamp = ts
-        .timestamp_nanos_opt()
-        .expect("timestamp fits into i64 nanoseconds");
-    let aligned = timestamp - timestamp.rem_euclid(step_nanos);
-    let secs = aligned.div_euclid(1_000_000_000);
-    let nanos = aligned.rem_euclid(1_000_000_000) as u32;
-    DateTime::<Utc>::from_timestamp(secs, nanos)
-        .expect("aligned timestamp within chrono supported range")
-}
-
 #[cfg(test)]
 mod tests {
     use chrono::{Duration, TimeZone, Timelike, Utc};
@@ -229,4 +217,36 @@ mod tests {
         assert_eq!(resampled[0].timestamp.minute(), 0);
         assert_eq!(resampled[1].timestamp.minute(), 5);
     }
+
+    #[test]
+    fn resamples_into_calendar_buckets() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 29, 0, 0, 0).unwrap();
+        let candles: Vec<_> = (0..14)
+            .map(|day| Candle {
+                interval: Interval::OneDay,
+                timestamp: start + Duration::days(day),
+                ..candle_at(0, day)
+            })
+            .collect();
+        let weekly = Resampler::resample(candles.clone(), "1w".parse().unwrap());
+        assert_eq!(weekly.len(), 2);
+        assert_eq!(
+            weekly[1].timestamp,
+            Utc.with_ymd_and_hms(2024, 2, 5, 0, 0, 0).unwrap()
+        );
+        assert_eq!(weekly[0].close, Decimal::new(6, 0));
+
+        let monthly = Resampler::resample(candles, "1mo".parse().unwrap());
+        assert_eq!(monthly.len(), 2);
+        assert_eq!(
+            monthly[0].timestamp,
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            monthly[1].timestamp,
+            Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(monthly[0].close, Decimal::new(2, 0));
+        assert_eq!(monthly[1].volume, Decimal::new(110, 0));
+    }
 }
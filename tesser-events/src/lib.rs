@@ -32,6 +32,19 @@ pub struct OrderUpdateEvent {
     pub order: Order,
 }
 
+/// Emitted when the running strategy's parameters or implementation change mid-session.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StrategyUpdateEvent {
+    pub strategy: String,
+    /// What triggered the change, e.g. `rpc` or `file_watch`.
+    pub source: String,
+    /// JSON-encoded parameters now in effect.
+    pub params_json: String,
+    /// Strategy that was replaced, for swaps.
+    #[serde(default)]
+    pub replaced: Option<String>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Event {
     Tick(TickEvent),
@@ -40,6 +53,7 @@ pub enum Event {
     Signal(SignalEvent),
     Fill(FillEvent),
     OrderUpdate(OrderUpdateEvent),
+    StrategyUpdate(StrategyUpdateEvent),
 }
 
 pub struct EventBus {
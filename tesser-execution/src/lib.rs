@@ -13,7 +13,7 @@ pub use wasm::{WasmAlgorithm, WasmAlgorithmState, WasmPluginEngine};
 
 use anyhow::{bail, Context};
 use rust_decimal::Decimal;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use tesser_broker::{BrokerError, BrokerResult, ExecutionClient};
 use tesser_core::{
     AssetId, ExchangeId, InstrumentKind, Order, OrderRequest, OrderType, OrderUpdateRequest, Price,
@@ -206,24 +206,37 @@ impl RiskLimits {
 
 /// Simple risk checker enforcing fat-finger order size limits plus position caps.
 pub struct BasicRiskChecker {
-    limits: RiskLimits,
+    limits: RwLock<RiskLimits>,
 }
 
 impl BasicRiskChecker {
     /// Build a new checker with the provided limits.
     pub fn new(limits: RiskLimits) -> Self {
         Self {
-            limits: limits.sanitized(),
+            limits: RwLock::new(limits.sanitized()),
         }
     }
+
+    /// Limits currently enforced.
+    pub fn limits(&self) -> RiskLimits {
+        *self.limits.read().unwrap()
+    }
+
+    /// Replace the enforced limits; subsequent checks use the new values.
+    pub fn set_limits(&self, limits: RiskLimits) -> RiskLimits {
+        let limits = limits.sanitized();
+        *self.limits.write().unwrap() = limits;
+        limits
+    }
 }
 
 impl PreTradeRiskChecker for BasicRiskChecker {
     fn check(&self, request: &OrderRequest, ctx: &RiskContext) -> Result<(), RiskError> {
+        let limits = self.limits();
         let qty = request.quantity.abs();
         let position = ctx.signed_position_qty;
         let position_abs = position.abs();
-        let max_order = self.limits.max_order_quantity;
+        let max_order = limits.max_order_quantity;
         if max_order > Decimal::ZERO && qty > max_order {
             return Err(RiskError::MaxOrderSize {
                 quantity: qty,
@@ -247,7 +260,7 @@ impl PreTradeRiskChecker for BasicRiskChecker {
             _ => positive_last_price(),
         };
 
-        if let Some(limit) = self.limits.max_order_notional {
+        if let Some(limit) = limits.max_order_notional {
             if let Some(price) = reference_price {
                 let notional = qty * price;
                 if notional > limit {
@@ -261,7 +274,7 @@ impl PreTradeRiskChecker for BasicRiskChecker {
             Side::Sell => position - qty,
         };
 
-        let max_position = self.limits.max_position_quantity;
+        let max_position = limits.max_position_quantity;
         if max_position > Decimal::ZERO && projected_position.abs() > max_position {
             return Err(RiskError::MaxPositionExposure {
                 projected: projected_position,
@@ -416,6 +429,41 @@ mod tests {
         assert!(matches!(result, Err(RiskError::LiquidateOnly)));
     }
 
+    #[test]
+    fn updated_limits_apply_to_subsequent_checks() {
+        let checker = BasicRiskChecker::new(RiskLimits {
+            max_order_quantity: Decimal::from(5),
+            max_position_quantity: Decimal::ZERO,
+            max_order_notional: None,
+        });
+        let order = OrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: Side::Buy,
+            order_type: OrderType::Market,
+            quantity: Decimal::from(2),
+            price: None,
+            trigger_price: None,
+            time_in_force: None,
+            client_order_id: None,
+            take_profit: None,
+            stop_loss: None,
+            display_quantity: None,
+        };
+        let ctx = RiskContext::default();
+        assert!(checker.check(&order, &ctx).is_ok());
+        let applied = checker.set_limits(RiskLimits {
+            max_order_quantity: Decimal::ONE,
+            max_position_quantity: Decimal::from(-3),
+            max_order_notional: Some(Decimal::ZERO),
+        });
+        assert_eq!(applied.max_position_quantity, Decimal::ZERO);
+        assert!(applied.max_order_notional.is_none());
+        assert!(matches!(
+            checker.check(&order, &ctx),
+            Err(RiskError::MaxOrderSize { .. })
+        ));
+    }
+
     #[test]
     fn liquidate_only_allows_position_reduction() {
         let checker = BasicRiskChecker::new(RiskLimits {
@@ -732,6 +780,16 @@ impl ExecutionEngine {
         Ok(order)
     }
 
+    /// Submit a fully specified order, such as an operator's manual order, through the
+    /// pre-trade risk checks.
+    pub async fn submit_order(
+        &self,
+        request: OrderRequest,
+        ctx: &RiskContext,
+    ) -> BrokerResult<Order> {
+        self.send_order(request, ctx).await
+    }
+
     pub async fn amend_order(&self, request: OrderUpdateRequest) -> BrokerResult<Order> {
         let order = self.client.amend_order(request).await?;
         info!(
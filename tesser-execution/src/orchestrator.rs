@@ -17,8 +17,8 @@ use crate::wasm::{WasmAlgorithm, WasmAlgorithmState, WasmPluginEngine};
 use crate::{ExecutionEngine, PanicCloseConfig, PanicCloseMode, PanicObserver, RiskContext};
 use serde_json::Value;
 use tesser_core::{
-    ExecutionHint, Fill, Order, OrderRequest, OrderStatus, OrderType, Price, Quantity, Side,
-    Signal, SignalPanicBehavior, Symbol, Tick, TimeInForce,
+    ExecutionHint, Fill, Order, OrderId, OrderRequest, OrderStatus, OrderType, Price, Quantity,
+    Side, Signal, SignalPanicBehavior, Symbol, Tick, TimeInForce,
 };
 
 /// Maps order IDs to their parent algorithm IDs for routing fills.
@@ -244,9 +244,14 @@ impl OrderOrchestrator {
         Ok(())
     }
 
-    async fn adopt_open_orders(&self, open_orders: Vec<Order>) -> Result<()> {
+    /// Bind in-flight exchange orders to the algorithms that issued them.
+    ///
+    /// Orders are matched through the algorithm id embedded in their client order id; orders
+    /// without a live parent algorithm are skipped. Returns the ids of the adopted orders.
+    pub async fn adopt_open_orders(&self, open_orders: Vec<Order>) -> Result<Vec<OrderId>> {
+        let mut adopted = Vec::new();
         if open_orders.is_empty() {
-            return Ok(());
+            return Ok(adopted);
         }
 
         let mut touched_algorithms = HashSet::new();
@@ -302,6 +307,7 @@ impl OrderOrchestrator {
                 status = ?order.status,
                 "adopted in-flight child order"
             );
+            adopted.push(order.id);
         }
 
         for algo_id in touched_algorithms {
@@ -314,7 +320,7 @@ impl OrderOrchestrator {
             }
         }
 
-        Ok(())
+        Ok(adopted)
     }
 
     fn instantiate_algorithm(
@@ -1360,6 +1366,14 @@ impl OrderOrchestrator {
         Ok(())
     }
 
+    /// Submit a fully specified order outside any algorithm, subject to the risk checks.
+    pub async fn submit_order(&self, request: OrderRequest, ctx: &RiskContext) -> Result<Order> {
+        self.update_risk_context(request.symbol, *ctx);
+        let order = self.execution_engine.submit_order(request, ctx).await?;
+        self.register_pending(&order);
+        Ok(order)
+    }
+
     /// Get the number of active algorithms.
     pub fn active_algorithms_count(&self) -> usize {
         let algorithms = self.algorithms.lock().unwrap();
@@ -1,6 +1,12 @@
-//! Combinators that allow indicators to be chained together.
+//! Combinators that allow indicators to be chained, fanned out and transformed.
 
-use crate::core::Indicator;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+use rust_decimal::Decimal;
+
+use crate::core::{Indicator, IndicatorError, Input, PairInput};
 
 /// Chains two indicators together, feeding the output of the first into the second.
 pub struct PipedIndicator<First, Second> {
@@ -33,3 +39,416 @@ where
         self.second.reset();
     }
 }
+
+/// Applies a function to every output of the wrapped indicator.
+pub struct Map<Inner, F> {
+    inner: Inner,
+    map: F,
+}
+
+impl<Inner, F> Map<Inner, F> {
+    /// Creates a new mapped indicator.
+    pub fn new(inner: Inner, map: F) -> Self {
+        Self { inner, map }
+    }
+}
+
+impl<Inner, F, Out> Indicator for Map<Inner, F>
+where
+    Inner: Indicator,
+    F: FnMut(Inner::Output) -> Out,
+{
+    type Input = Inner::Input;
+    type Output = Out;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        self.inner.next(input).map(&mut self.map)
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+/// Feeds every input into each indicator of a tuple and returns their outputs as a tuple.
+///
+/// All indicators are updated on every input; the tuple is only returned once every member
+/// has produced a value.
+pub struct Fanout<T> {
+    indicators: T,
+}
+
+impl<T> Fanout<T> {
+    /// Creates a fan-out over a tuple of two to six indicators sharing the same input type.
+    pub fn new(indicators: T) -> Self {
+        Self { indicators }
+    }
+}
+
+macro_rules! impl_fanout {
+    ($($member:ident),+) => {
+        impl<In, $($member),+> Indicator for Fanout<($($member,)+)>
+        where
+            In: Clone,
+            $($member: Indicator<Input = In>,)+
+        {
+            type Input = In;
+            type Output = ($($member::Output,)+);
+
+            #[allow(non_snake_case)]
+            fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+                let ($($member,)+) = &mut self.indicators;
+                $(let $member = $member.next(input.clone());)+
+                Some(($($member?,)+))
+            }
+
+            #[allow(non_snake_case)]
+            fn reset(&mut self) {
+                let ($($member,)+) = &mut self.indicators;
+                $($member.reset();)+
+            }
+        }
+    };
+}
+
+impl_fanout!(A, B);
+impl_fanout!(A, B, C);
+impl_fanout!(A, B, C, D);
+impl_fanout!(A, B, C, D, E);
+impl_fanout!(A, B, C, D, E, F);
+
+/// Advances two indicators on two separate streams in lockstep.
+///
+/// Takes `(left_input, right_input)` and returns `(left_output, right_output)` once both sides
+/// have produced a value.
+pub struct Zip<Left, Right> {
+    left: Left,
+    right: Right,
+}
+
+impl<Left, Right> Zip<Left, Right> {
+    /// Creates a new zipped indicator.
+    pub fn new(left: Left, right: Right) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<Left, Right> Indicator for Zip<Left, Right>
+where
+    Left: Indicator,
+    Right: Indicator,
+{
+    type Input = (Left::Input, Right::Input);
+    type Output = (Left::Output, Right::Output);
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let left = self.left.next(input.0);
+        let right = self.right.next(input.1);
+        Some((left?, right?))
+    }
+
+    fn reset(&mut self) {
+        self.left.reset();
+        self.right.reset();
+    }
+}
+
+/// Delays a stream by `period` updates, returning the value seen `period` inputs ago.
+#[derive(Debug, Clone)]
+pub struct Lag<T> {
+    period: usize,
+    window: VecDeque<T>,
+}
+
+impl<T> Lag<T> {
+    /// Creates a lag of `period` updates.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period("Lag", period));
+        }
+        Ok(Self {
+            period,
+            window: VecDeque::with_capacity(period + 1),
+        })
+    }
+}
+
+impl<T> Indicator for Lag<T> {
+    type Input = T;
+    type Output = T;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        self.window.push_back(input);
+        if self.window.len() > self.period {
+            self.window.pop_front()
+        } else {
+            None
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+/// Difference between the current value and the value `period` updates ago.
+#[derive(Debug, Clone)]
+pub struct Diff<I = Decimal> {
+    lag: Lag<Decimal>,
+    marker: PhantomData<I>,
+}
+
+impl<I> Diff<I>
+where
+    I: Input,
+{
+    /// Creates a new difference over `period` updates.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period("Diff", period));
+        }
+        Ok(Self {
+            lag: Lag::new(period)?,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<I> Indicator for Diff<I>
+where
+    I: Input,
+{
+    type Input = I;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let value = input.value();
+        let previous = self.lag.next(value)?;
+        Some(value - previous)
+    }
+
+    fn reset(&mut self) {
+        self.lag.reset();
+    }
+}
+
+/// Percentage change between the current value and the value `period` updates ago.
+///
+/// Returns `None` when the earlier value is zero.
+#[derive(Debug, Clone)]
+pub struct RateOfChange<I = Decimal> {
+    lag: Lag<Decimal>,
+    marker: PhantomData<I>,
+}
+
+impl<I> RateOfChange<I>
+where
+    I: Input,
+{
+    /// Creates a new rate of change over `period` updates.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period("RateOfChange", period));
+        }
+        Ok(Self {
+            lag: Lag::new(period)?,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<I> Indicator for RateOfChange<I>
+where
+    I: Input,
+{
+    type Input = I;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let value = input.value();
+        let previous = self.lag.next(value)?;
+        if previous.is_zero() {
+            return None;
+        }
+        Some(Decimal::ONE_HUNDRED * (value - previous) / previous)
+    }
+
+    fn reset(&mut self) {
+        self.lag.reset();
+    }
+}
+
+/// Relationship change reported by [`Crossover`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossEvent {
+    /// The first series moved from below to above the second.
+    CrossedAbove,
+    /// The first series moved from above to below the second.
+    CrossedBelow,
+    /// No crossing on this update.
+    NoCross,
+}
+
+impl CrossEvent {
+    /// Returns `true` for either crossing direction.
+    pub fn is_cross(self) -> bool {
+        !matches!(self, Self::NoCross)
+    }
+}
+
+/// Detects when the first leg of a `(a, b)` pair crosses the second.
+///
+/// Touches are not crossings: the comparison remembers the last side on which `a` was strictly
+/// above or below `b`, so `a` dipping onto `b` and returning does not fire. The first update
+/// only establishes the starting side and returns `None`.
+#[derive(Debug, Clone)]
+pub struct Crossover<P = (Decimal, Decimal)> {
+    side: Option<Ordering>,
+    started: bool,
+    marker: PhantomData<P>,
+}
+
+impl<P> Crossover<P>
+where
+    P: PairInput,
+{
+    /// Creates a new crossover detector.
+    pub fn new() -> Self {
+        Self {
+            side: None,
+            started: false,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<P> Default for Crossover<P>
+where
+    P: PairInput,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P> Indicator for Crossover<P>
+where
+    P: PairInput,
+{
+    type Input = P;
+    type Output = CrossEvent;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let (a, b) = input.pair();
+        let ordering = a.cmp(&b);
+        let started = std::mem::replace(&mut self.started, true);
+        if ordering == Ordering::Equal {
+            return started.then_some(CrossEvent::NoCross);
+        }
+        let previous = self.side.replace(ordering);
+        if !started {
+            return None;
+        }
+        Some(match (previous, ordering) {
+            (Some(Ordering::Less), Ordering::Greater) => CrossEvent::CrossedAbove,
+            (Some(Ordering::Greater), Ordering::Less) => CrossEvent::CrossedBelow,
+            _ => CrossEvent::NoCross,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.side = None;
+        self.started = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::*;
+    use crate::indicators::{Ema, Sma};
+
+    fn dec(value: i64) -> Decimal {
+        Decimal::from(value)
+    }
+
+    #[test]
+    fn fanout_updates_every_member() {
+        let mut fanout = Fanout::new((Sma::<Decimal>::new(2).unwrap(), Lag::new(1).unwrap()));
+        assert_eq!(fanout.next(dec(2)), None);
+        assert_eq!(fanout.next(dec(4)), Some((dec(3), dec(2))));
+        assert_eq!(fanout.next(dec(6)), Some((dec(5), dec(4))));
+        fanout.reset();
+        assert_eq!(fanout.next(dec(1)), None);
+    }
+
+    #[test]
+    fn zip_and_map_compose() {
+        let mut spread = Zip::new(
+            Sma::<Decimal>::new(1).unwrap(),
+            Ema::<Decimal>::new(1).unwrap(),
+        )
+        .map(|(a, b)| a - b);
+        assert_eq!(spread.next((dec(10), dec(4))), Some(dec(6)));
+        assert_eq!(spread.next((dec(7), dec(9))), Some(dec(-2)));
+    }
+
+    #[test]
+    fn lag_diff_and_roc() {
+        let mut lag = Lag::new(2).unwrap();
+        let mut diff = Diff::<Decimal>::new(2).unwrap();
+        let mut roc = RateOfChange::<Decimal>::new(2).unwrap();
+        let mut outputs = Vec::new();
+        for value in [10, 12, 15, 9] {
+            outputs.push((
+                lag.next(dec(value)),
+                diff.next(dec(value)),
+                roc.next(dec(value)),
+            ));
+        }
+        assert_eq!(outputs[1], (None, None, None));
+        assert_eq!(outputs[2], (Some(dec(10)), Some(dec(5)), Some(dec(50))));
+        assert_eq!(outputs[3], (Some(dec(12)), Some(dec(-3)), Some(dec(-25))));
+    }
+
+    #[test]
+    fn crossover_ignores_touches() {
+        let mut cross = Crossover::new();
+        let pairs = [(1, 2), (2, 2), (1, 2), (3, 2), (2, 2), (3, 2), (1, 2)];
+        let events: Vec<_> = pairs
+            .into_iter()
+            .map(|(a, b)| cross.next((dec(a), dec(b))))
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                None,
+                Some(CrossEvent::NoCross),
+                Some(CrossEvent::NoCross),
+                Some(CrossEvent::CrossedAbove),
+                Some(CrossEvent::NoCross),
+                Some(CrossEvent::NoCross),
+                Some(CrossEvent::CrossedBelow),
+            ]
+        );
+    }
+
+    #[test]
+    fn boxed_indicators_are_interchangeable() {
+        let mut features: Vec<crate::BoxedIndicator<Decimal, Decimal>> = vec![
+            Sma::<Decimal>::new(2).unwrap().boxed(),
+            Diff::<Decimal>::new(1).unwrap().boxed(),
+        ];
+        let outputs: Vec<_> = [dec(1), dec(3)]
+            .into_iter()
+            .flat_map(|value| {
+                features
+                    .iter_mut()
+                    .map(|feature| feature.next(value))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(outputs, vec![None, None, Some(dec(2)), Some(dec(2))]);
+    }
+}
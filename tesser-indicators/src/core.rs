@@ -4,7 +4,7 @@ use rust_decimal::{prelude::FromPrimitive, Decimal};
 use tesser_core::Candle;
 use thiserror::Error;
 
-use crate::combinators::PipedIndicator;
+use crate::combinators::{Map, PipedIndicator};
 
 /// Provides a uniform way to extract a `Decimal` value from different input types.
 pub trait Input {
@@ -39,6 +39,34 @@ where
     }
 }
 
+/// Provides a uniform way to extract an `(x, y)` observation from paired inputs.
+///
+/// `x` is the independent leg and `y` the dependent one, so regressions such as
+/// [`OlsBeta`](crate::indicators::OlsBeta) estimate `y = alpha + beta * x`.
+pub trait PairInput {
+    /// Returns the `(x, y)` decimal pair for the input.
+    fn pair(&self) -> (Decimal, Decimal);
+}
+
+impl<X, Y> PairInput for (X, Y)
+where
+    X: Input,
+    Y: Input,
+{
+    fn pair(&self) -> (Decimal, Decimal) {
+        (self.0.value(), self.1.value())
+    }
+}
+
+impl<T> PairInput for &T
+where
+    T: PairInput + ?Sized,
+{
+    fn pair(&self) -> (Decimal, Decimal) {
+        T::pair(self)
+    }
+}
+
 /// Common configuration errors emitted by indicators.
 #[derive(Debug, Error, PartialEq)]
 pub enum IndicatorError {
@@ -60,6 +88,9 @@ pub enum IndicatorError {
         /// Provided parameter value.
         value: Decimal,
     },
+    /// Returned when a runtime indicator specification is inconsistent.
+    #[error("invalid indicator spec: {0}")]
+    InvalidSpec(String),
 }
 
 impl IndicatorError {
@@ -80,8 +111,8 @@ impl IndicatorError {
 
 /// Core abstraction implemented by every indicator in the library.
 pub trait Indicator {
-    /// Input type accepted by the indicator.
-    type Input: Input;
+    /// Input type accepted by the indicator, usually an [`Input`] or [`PairInput`] implementor.
+    type Input;
     /// Value produced after each update.
     type Output;
 
@@ -99,6 +130,42 @@ pub trait Indicator {
     {
         PipedIndicator::new(self, next)
     }
+
+    /// Transforms every output with `map`, e.g. to select one line of a multi-output indicator.
+    fn map<F, Out>(self, map: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Output) -> Out,
+    {
+        Map::new(self, map)
+    }
+
+    /// Erases the concrete type so heterogeneous indicators can be stored together.
+    fn boxed(self) -> BoxedIndicator<Self::Input, Self::Output>
+    where
+        Self: Sized + Send + Sync + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+/// Type-erased indicator, typically assembled at runtime from configuration.
+pub type BoxedIndicator<I, O> = Box<dyn Indicator<Input = I, Output = O> + Send + Sync>;
+
+impl<T> Indicator for Box<T>
+where
+    T: Indicator + ?Sized,
+{
+    type Input = T::Input;
+    type Output = T::Output;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        (**self).next(input)
+    }
+
+    fn reset(&mut self) {
+        (**self).reset();
+    }
 }
 
 fn decimal_from_f64(value: f64) -> Decimal {
@@ -119,7 +186,7 @@ mod tests {
     use rust_decimal::Decimal;
     use tesser_core::{Candle, Interval, Symbol};
 
-    use super::{decimal_from_f64, Input};
+    use super::{decimal_from_f64, Input, PairInput};
 
     #[test]
     fn converts_f64_to_decimal() {
@@ -145,6 +212,15 @@ mod tests {
         assert_eq!(decimal.round_dp(1), Decimal::from_str("12.5").unwrap());
     }
 
+    #[test]
+    fn tuple_pair_input_extracts_both_legs() {
+        let pair = (Decimal::from(2), 3.5_f64);
+        assert_eq!(
+            pair.pair(),
+            (Decimal::from(2), Decimal::from_str("3.5").unwrap())
+        );
+    }
+
     #[test]
     fn reference_input_delegates_to_inner_type() {
         let price = Decimal::from(100);
@@ -0,0 +1,192 @@
+//! Average Directional Index (ADX) with the directional movement lines (DMI).
+
+use rust_decimal::Decimal;
+use tesser_core::Candle;
+
+use crate::core::{decimal_from_usize, Indicator, IndicatorError};
+
+/// Directional movement snapshot, all values on a 0–100 scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdxOutput {
+    /// Positive directional indicator (+DI).
+    pub plus_di: Decimal,
+    /// Negative directional indicator (-DI).
+    pub minus_di: Decimal,
+    /// Directional index of the current bar.
+    pub dx: Decimal,
+    /// Wilder-smoothed average of `dx`.
+    pub adx: Decimal,
+}
+
+/// Wilder's Average Directional Index.
+///
+/// True range and directional movement are smoothed with Wilder's running sums over `period`
+/// bars; the ADX is the Wilder average of the DX series. The first output arrives after
+/// `2 * period` bars.
+#[derive(Debug, Clone)]
+pub struct Adx {
+    period: usize,
+    divisor: Decimal,
+    prev: Option<(Decimal, Decimal, Decimal)>,
+    warmup_count: usize,
+    smoothed_tr: Decimal,
+    smoothed_plus: Decimal,
+    smoothed_minus: Decimal,
+    dx_sum: Decimal,
+    dx_count: usize,
+    adx: Option<Decimal>,
+}
+
+impl Adx {
+    /// Creates a new ADX over `period` bars.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period("ADX", period));
+        }
+        Ok(Self {
+            period,
+            divisor: decimal_from_usize(period),
+            prev: None,
+            warmup_count: 0,
+            smoothed_tr: Decimal::ZERO,
+            smoothed_plus: Decimal::ZERO,
+            smoothed_minus: Decimal::ZERO,
+            dx_sum: Decimal::ZERO,
+            dx_count: 0,
+            adx: None,
+        })
+    }
+
+    fn directional_index(&self) -> (Decimal, Decimal, Decimal) {
+        if self.smoothed_tr.is_zero() {
+            return (Decimal::ZERO, Decimal::ZERO, Decimal::ZERO);
+        }
+        let plus_di = Decimal::ONE_HUNDRED * self.smoothed_plus / self.smoothed_tr;
+        let minus_di = Decimal::ONE_HUNDRED * self.smoothed_minus / self.smoothed_tr;
+        let total = plus_di + minus_di;
+        let dx = if total.is_zero() {
+            Decimal::ZERO
+        } else {
+            Decimal::ONE_HUNDRED * (plus_di - minus_di).abs() / total
+        };
+        (plus_di, minus_di, dx)
+    }
+}
+
+impl Indicator for Adx {
+    type Input = Candle;
+    type Output = AdxOutput;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let (prev_high, prev_low, prev_close) =
+            self.prev.replace((input.high, input.low, input.close))?;
+
+        let true_range = (input.high - input.low)
+            .max((input.high - prev_close).abs())
+            .max((input.low - prev_close).abs());
+        let up_move = input.high - prev_high;
+        let down_move = prev_low - input.low;
+        let plus_dm = if up_move > down_move && up_move > Decimal::ZERO {
+            up_move
+        } else {
+            Decimal::ZERO
+        };
+        let minus_dm = if down_move > up_move && down_move > Decimal::ZERO {
+            down_move
+        } else {
+            Decimal::ZERO
+        };
+
+        if self.warmup_count < self.period {
+            self.smoothed_tr += true_range;
+            self.smoothed_plus += plus_dm;
+            self.smoothed_minus += minus_dm;
+            self.warmup_count += 1;
+            if self.warmup_count < self.period {
+                return None;
+            }
+        } else {
+            self.smoothed_tr += true_range - self.smoothed_tr / self.divisor;
+            self.smoothed_plus += plus_dm - self.smoothed_plus / self.divisor;
+            self.smoothed_minus += minus_dm - self.smoothed_minus / self.divisor;
+        }
+
+        let (plus_di, minus_di, dx) = self.directional_index();
+        let adx = match self.adx {
+            Some(current) => (current * (self.divisor - Decimal::ONE) + dx) / self.divisor,
+            None => {
+                self.dx_sum += dx;
+                self.dx_count += 1;
+                if self.dx_count < self.period {
+                    return None;
+                }
+                self.dx_sum / self.divisor
+            }
+        };
+        self.adx = Some(adx);
+        Some(AdxOutput {
+            plus_di,
+            minus_di,
+            dx,
+            adx,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.prev = None;
+        self.warmup_count = 0;
+        self.smoothed_tr = Decimal::ZERO;
+        self.smoothed_plus = Decimal::ZERO;
+        self.smoothed_minus = Decimal::ZERO;
+        self.dx_sum = Decimal::ZERO;
+        self.dx_count = 0;
+        self.adx = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::indicators::testing::{assert_close, candle_series, candles, dec};
+
+    #[test]
+    fn adx_matches_hand_computed_wilder_values() {
+        let bars = candles(&[
+            (10.0, 10.0, 9.0, 9.5, 1.0),
+            (9.5, 11.0, 9.5, 10.5, 1.0),
+            (10.5, 12.0, 10.0, 11.5, 1.0),
+            (11.5, 11.5, 9.0, 9.5, 1.0),
+        ]);
+        let mut adx = Adx::new(2).unwrap();
+        let outputs: Vec<_> = bars.into_iter().map(|bar| adx.next(bar)).collect();
+        assert!(outputs[..3].iter().all(Option::is_none));
+
+        // TR: 1.5, 2.0, 2.5; +DM: 1, 1, 0; -DM: 0, 0, 1.
+        // Bar 2: sums TR 3.5, +DM 2, -DM 0 -> +DI 400/7, -DI 0, DX 100.
+        // Bar 3: TR 3.5 - 1.75 + 2.5 = 4.25, +DM 1, -DM 1 -> DX 0, ADX (100 + 0) / 2.
+        let last = outputs[3].unwrap();
+        assert_close(last.plus_di, dec(100.0) / dec(4.25), Decimal::new(1, 20));
+        assert_close(last.minus_di, dec(100.0) / dec(4.25), Decimal::new(1, 20));
+        assert_eq!(last.dx, Decimal::ZERO);
+        assert_eq!(last.adx, dec(50.0));
+    }
+
+    proptest! {
+        #[test]
+        fn adx_lines_stay_in_range(series in candle_series(80), period in 1usize..8) {
+            let mut adx = Adx::new(period).unwrap();
+            for (idx, candle) in series.iter().enumerate() {
+                let output = adx.next(candle.clone());
+                prop_assert_eq!(output.is_some(), idx + 1 >= 2 * period);
+                if let Some(output) = output {
+                    for value in [output.plus_di, output.minus_di, output.dx, output.adx] {
+                        prop_assert!(value >= Decimal::ZERO);
+                        prop_assert!(value <= Decimal::ONE_HUNDRED + Decimal::new(1, 20));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -6,6 +6,7 @@ use tesser_core::Candle;
 use crate::core::{Indicator, IndicatorError};
 
 /// Average True Range indicator.
+#[derive(Debug, Clone)]
 pub struct Atr {
     period: usize,
     prev_close: Option<Decimal>,
@@ -0,0 +1,95 @@
+//! Rolling Pearson correlation of two series.
+
+use std::marker::PhantomData;
+
+use rust_decimal::{Decimal, MathematicalOps};
+
+use crate::core::{Indicator, IndicatorError, PairInput};
+use crate::indicators::moments::PairedMoments;
+
+/// Pearson correlation of the last `period` `(x, y)` observations.
+///
+/// Returns `None` while warming up and whenever either leg is flat over the window.
+#[derive(Debug, Clone)]
+pub struct Correlation<P = (Decimal, Decimal)> {
+    moments: PairedMoments,
+    marker: PhantomData<P>,
+}
+
+impl<P> Correlation<P>
+where
+    P: PairInput,
+{
+    /// Creates a new rolling correlation over `period` observations.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period < 2 {
+            return Err(IndicatorError::invalid_period("Correlation", period));
+        }
+        Ok(Self {
+            moments: PairedMoments::new(period),
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<P> Indicator for Correlation<P>
+where
+    P: PairInput,
+{
+    type Input = P;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let (x, y) = input.pair();
+        if !self.moments.push(x, y) {
+            return None;
+        }
+        let denominator = (self.moments.m2_x() * self.moments.m2_y()).sqrt()?;
+        if denominator.is_zero() {
+            return None;
+        }
+        let correlation = self.moments.co_moment() / denominator;
+        Some(correlation.clamp(Decimal::NEGATIVE_ONE, Decimal::ONE))
+    }
+
+    fn reset(&mut self) {
+        self.moments.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::Correlation;
+    use crate::Indicator;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn matches_reference_correlation() {
+        let mut corr = Correlation::new(5).unwrap();
+        let xs = ["1", "2", "3", "4", "5"];
+        let ys = ["2", "4", "5", "4", "5"];
+        let mut output = None;
+        for (x, y) in xs.iter().zip(ys) {
+            output = corr.next((dec(x), dec(y)));
+        }
+        // numpy.corrcoef([1, 2, 3, 4, 5], [2, 4, 5, 4, 5])[0, 1]
+        assert!((output.unwrap() - dec("0.7745966692")).abs() < dec("0.0000000001"));
+    }
+
+    #[test]
+    fn perfectly_inverse_series() {
+        let mut corr = Correlation::new(3).unwrap();
+        corr.next((1.0, 3.0));
+        corr.next((2.0, 2.0));
+        assert_eq!(corr.next((3.0, 1.0)), Some(Decimal::NEGATIVE_ONE));
+        corr.reset();
+        assert_eq!(corr.next((3.0, 1.0)), None);
+    }
+}
@@ -0,0 +1,113 @@
+//! Donchian Channels tracking the rolling high/low range.
+
+use rust_decimal::Decimal;
+use tesser_core::Candle;
+
+use crate::core::{Indicator, IndicatorError};
+use crate::indicators::extrema::RollingExtrema;
+
+/// Output value of the Donchian Channels indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DonchianChannelsOutput {
+    /// Highest high over the window.
+    pub upper: Decimal,
+    /// Midpoint of the channel.
+    pub middle: Decimal,
+    /// Lowest low over the window.
+    pub lower: Decimal,
+}
+
+/// Highest high and lowest low over the last `period` bars (including the current one).
+#[derive(Debug, Clone)]
+pub struct DonchianChannels {
+    range: RollingExtrema,
+}
+
+impl DonchianChannels {
+    /// Creates new Donchian Channels over `period` bars.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period("DonchianChannels", period));
+        }
+        Ok(Self {
+            range: RollingExtrema::new(period),
+        })
+    }
+}
+
+impl Indicator for DonchianChannels {
+    type Input = Candle;
+    type Output = DonchianChannelsOutput;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        if !self.range.push(input.high, input.low) {
+            return None;
+        }
+        let upper = self.range.highest()?;
+        let lower = self.range.lowest()?;
+        Some(DonchianChannelsOutput {
+            upper,
+            middle: (upper + lower) / Decimal::TWO,
+            lower,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.range.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::indicators::testing::{candle_series, candles, dec};
+
+    #[test]
+    fn donchian_drops_expired_extremes() {
+        let bars = candles(&[
+            (10.0, 15.0, 9.0, 10.0, 1.0),
+            (10.0, 12.0, 8.0, 11.0, 1.0),
+            (11.0, 13.0, 10.0, 12.0, 1.0),
+            (12.0, 12.5, 11.0, 12.0, 1.0),
+        ]);
+        let mut donchian = DonchianChannels::new(2).unwrap();
+        let outputs: Vec<_> = bars.into_iter().map(|bar| donchian.next(bar)).collect();
+        assert!(outputs[0].is_none());
+        assert_eq!(
+            outputs[1].map(|out| (out.upper, out.lower)),
+            Some((dec(15.0), dec(8.0)))
+        );
+        assert_eq!(
+            outputs[2].map(|out| (out.upper, out.lower)),
+            Some((dec(13.0), dec(8.0)))
+        );
+        assert_eq!(
+            outputs[3],
+            Some(DonchianChannelsOutput {
+                upper: dec(13.0),
+                middle: dec(11.5),
+                lower: dec(10.0),
+            })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn donchian_matches_window_scan(series in candle_series(80), period in 1usize..15) {
+            let mut donchian = DonchianChannels::new(period).unwrap();
+            for (idx, candle) in series.iter().enumerate() {
+                let output = donchian.next(candle.clone());
+                if idx + 1 < period {
+                    prop_assert!(output.is_none());
+                    continue;
+                }
+                let window = &series[idx + 1 - period..=idx];
+                let output = output.unwrap();
+                prop_assert_eq!(output.upper, window.iter().map(|c| c.high).max().unwrap());
+                prop_assert_eq!(output.lower, window.iter().map(|c| c.low).min().unwrap());
+            }
+        }
+    }
+}
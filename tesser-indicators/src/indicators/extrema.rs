@@ -0,0 +1,72 @@
+//! Rolling high/low tracker shared by the channel and oscillator indicators.
+//!
+//! Monotonic deques keep the window maximum and minimum available in amortized O(1) per
+//! update instead of rescanning the window.
+
+use std::collections::VecDeque;
+
+use rust_decimal::Decimal;
+
+/// Highest high and lowest low over the last `period` bars.
+#[derive(Debug, Clone)]
+pub(crate) struct RollingExtrema {
+    period: usize,
+    seen: usize,
+    maxima: VecDeque<(usize, Decimal)>,
+    minima: VecDeque<(usize, Decimal)>,
+}
+
+impl RollingExtrema {
+    pub(crate) fn new(period: usize) -> Self {
+        Self {
+            period,
+            seen: 0,
+            maxima: VecDeque::with_capacity(period),
+            minima: VecDeque::with_capacity(period),
+        }
+    }
+
+    /// Adds a bar's high and low. Returns whether `period` bars have been observed.
+    pub(crate) fn push(&mut self, high: Decimal, low: Decimal) -> bool {
+        let index = self.seen;
+        self.seen += 1;
+
+        while self.maxima.back().is_some_and(|(_, value)| *value <= high) {
+            self.maxima.pop_back();
+        }
+        self.maxima.push_back((index, high));
+        while self.minima.back().is_some_and(|(_, value)| *value >= low) {
+            self.minima.pop_back();
+        }
+        self.minima.push_back((index, low));
+
+        if self.seen > self.period {
+            let oldest = self.seen - self.period;
+            while self.maxima.front().is_some_and(|(idx, _)| *idx < oldest) {
+                self.maxima.pop_front();
+            }
+            while self.minima.front().is_some_and(|(idx, _)| *idx < oldest) {
+                self.minima.pop_front();
+            }
+        }
+        self.is_full()
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.seen >= self.period
+    }
+
+    pub(crate) fn highest(&self) -> Option<Decimal> {
+        self.maxima.front().map(|(_, value)| *value)
+    }
+
+    pub(crate) fn lowest(&self) -> Option<Decimal> {
+        self.minima.front().map(|(_, value)| *value)
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.seen = 0;
+        self.maxima.clear();
+        self.minima.clear();
+    }
+}
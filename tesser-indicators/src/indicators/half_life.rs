@@ -0,0 +1,109 @@
+//! Ornstein-Uhlenbeck half-life of mean reversion.
+
+use std::marker::PhantomData;
+
+use rust_decimal::{Decimal, MathematicalOps};
+
+use crate::core::{Indicator, IndicatorError, Input};
+use crate::indicators::moments::PairedMoments;
+
+/// Estimates how many updates a series needs to close half of its distance to the mean.
+///
+/// Over the last `period` changes it regresses `s[t] - s[t-1]` on `s[t-1]`; with slope
+/// `lambda` the discrete AR(1) coefficient is `1 + lambda` and the half-life is
+/// `-ln(2) / ln(1 + lambda)`. Returns `None` while warming up and whenever the series is not
+/// mean reverting (`lambda >= 0` or `lambda <= -1`).
+#[derive(Debug, Clone)]
+pub struct HalfLife<I = Decimal> {
+    moments: PairedMoments,
+    previous: Option<Decimal>,
+    marker: PhantomData<I>,
+}
+
+impl<I> HalfLife<I>
+where
+    I: Input,
+{
+    /// Creates a new half-life estimator over `period` consecutive changes.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period < 2 {
+            return Err(IndicatorError::invalid_period("HalfLife", period));
+        }
+        Ok(Self {
+            moments: PairedMoments::new(period),
+            previous: None,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<I> Indicator for HalfLife<I>
+where
+    I: Input,
+{
+    type Input = I;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let value = input.value();
+        let previous = self.previous.replace(value)?;
+        if !self.moments.push(previous, value - previous) {
+            return None;
+        }
+        let m2 = self.moments.m2_x();
+        if m2.is_zero() {
+            return None;
+        }
+        let lambda = self.moments.co_moment() / m2;
+        let persistence = Decimal::ONE + lambda;
+        if persistence <= Decimal::ZERO || persistence >= Decimal::ONE {
+            return None;
+        }
+        let decay = persistence.checked_ln()?;
+        Some(-Decimal::TWO.ln() / decay)
+    }
+
+    fn reset(&mut self) {
+        self.moments.reset();
+        self.previous = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::HalfLife;
+    use crate::Indicator;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn recovers_ar1_half_life() {
+        // Deviations from 10 halve every update, so the half-life is exactly one update.
+        let mut half_life = HalfLife::new(6).unwrap();
+        let mut level = dec("74");
+        let mut output = None;
+        for _ in 0..8 {
+            output = half_life.next(level);
+            level = dec("10") + (level - dec("10")) * dec("0.5");
+        }
+        assert!((output.unwrap() - Decimal::ONE).abs() < dec("0.0000001"));
+    }
+
+    #[test]
+    fn trending_series_has_no_half_life() {
+        let mut half_life = HalfLife::new(3).unwrap();
+        let mut output = Some(Decimal::ZERO);
+        for value in [1, 2, 4, 8, 16, 32] {
+            output = half_life.next(Decimal::from(value));
+        }
+        assert_eq!(output, None);
+        half_life.reset();
+        assert_eq!(half_life.next(Decimal::ONE), None);
+    }
+}
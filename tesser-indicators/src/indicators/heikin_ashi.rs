@@ -0,0 +1,89 @@
+//! Heikin-Ashi candle transform.
+
+use rust_decimal::Decimal;
+use tesser_core::Candle;
+
+use crate::core::Indicator;
+
+/// Converts regular candles into Heikin-Ashi candles.
+///
+/// The close is the bar's OHLC average and the open is the midpoint of the previous
+/// Heikin-Ashi body (the first bar uses its own `(open + close) / 2`). High and low extend to
+/// cover the new body. Symbol, interval, volume and timestamp pass through unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct HeikinAshi {
+    prev_body: Option<(Decimal, Decimal)>,
+}
+
+impl HeikinAshi {
+    /// Creates a new Heikin-Ashi transform.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Indicator for HeikinAshi {
+    type Input = Candle;
+    type Output = Candle;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let close = (input.open + input.high + input.low + input.close) / Decimal::from(4);
+        let open = match self.prev_body {
+            Some((prev_open, prev_close)) => (prev_open + prev_close) / Decimal::TWO,
+            None => (input.open + input.close) / Decimal::TWO,
+        };
+        self.prev_body = Some((open, close));
+        Some(Candle {
+            open,
+            high: input.high.max(open).max(close),
+            low: input.low.min(open).min(close),
+            close,
+            ..input
+        })
+    }
+
+    fn reset(&mut self) {
+        self.prev_body = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::indicators::testing::{candle_series, candles, dec};
+
+    #[test]
+    fn heikin_ashi_matches_reference() {
+        let bars = candles(&[(10.0, 14.0, 8.0, 12.0, 3.0), (12.0, 13.0, 11.0, 11.0, 2.0)]);
+        let mut ha = HeikinAshi::new();
+        let outputs: Vec<_> = bars.into_iter().filter_map(|bar| ha.next(bar)).collect();
+
+        assert_eq!(outputs[0].open, dec(11.0));
+        assert_eq!(outputs[0].close, dec(11.0));
+        assert_eq!(outputs[0].high, dec(14.0));
+        assert_eq!(outputs[0].low, dec(8.0));
+
+        // Open is the midpoint of the previous HA body (11, 11); close is 47 / 4.
+        assert_eq!(outputs[1].open, dec(11.0));
+        assert_eq!(outputs[1].close, dec(11.75));
+        assert_eq!(outputs[1].high, dec(13.0));
+        assert_eq!(outputs[1].low, dec(11.0));
+        assert_eq!(outputs[1].volume, dec(2.0));
+    }
+
+    proptest! {
+        #[test]
+        fn heikin_ashi_candles_are_well_formed(series in candle_series(60)) {
+            let mut ha = HeikinAshi::new();
+            for candle in series {
+                let out = ha.next(candle.clone()).unwrap();
+                prop_assert!(out.high >= out.open.max(out.close));
+                prop_assert!(out.low <= out.open.min(out.close));
+                prop_assert!(out.high >= candle.high && out.low <= candle.low);
+                prop_assert_eq!(out.timestamp, candle.timestamp);
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Kalman-filter estimate of a time-varying hedge ratio.
+
+use std::marker::PhantomData;
+
+use rust_decimal::{Decimal, MathematicalOps};
+
+use crate::core::{Indicator, IndicatorError, PairInput};
+
+/// Output value of the Kalman hedge ratio filter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KalmanHedgeOutput {
+    /// Filtered slope of `y` on `x`.
+    pub beta: Decimal,
+    /// Filtered intercept.
+    pub alpha: Decimal,
+    /// One-step forecast error of `y` before the update; this is the tradable spread.
+    pub spread: Decimal,
+    /// Standard deviation of the forecast error, for standardizing `spread`.
+    pub spread_std: Decimal,
+}
+
+/// Tracks `y = alpha + beta * x` with a random-walk state, as popularized for pairs trading.
+///
+/// `delta` sets how fast the coefficients may drift (process noise `delta / (1 - delta)`) and
+/// `observation_variance` is the measurement noise of `y`. Every update costs a fixed number
+/// of 2x2 matrix operations, and a value is produced from the first observation onwards.
+#[derive(Debug, Clone)]
+pub struct KalmanHedgeRatio<P = (Decimal, Decimal)> {
+    process_variance: Decimal,
+    observation_variance: Decimal,
+    /// State estimate `[beta, alpha]`.
+    state: [Decimal; 2],
+    /// State covariance.
+    covariance: [[Decimal; 2]; 2],
+    marker: PhantomData<P>,
+}
+
+impl<P> KalmanHedgeRatio<P>
+where
+    P: PairInput,
+{
+    /// Creates a new filter; `delta` must lie in `(0, 1)` and `observation_variance` be positive.
+    pub fn new(delta: Decimal, observation_variance: Decimal) -> Result<Self, IndicatorError> {
+        if delta <= Decimal::ZERO || delta >= Decimal::ONE {
+            return Err(IndicatorError::invalid_parameter(
+                "KalmanHedgeRatio",
+                "delta",
+                delta,
+            ));
+        }
+        if observation_variance <= Decimal::ZERO {
+            return Err(IndicatorError::invalid_parameter(
+                "KalmanHedgeRatio",
+                "observation_variance",
+                observation_variance,
+            ));
+        }
+        Ok(Self {
+            process_variance: delta / (Decimal::ONE - delta),
+            observation_variance,
+            state: [Decimal::ZERO; 2],
+            covariance: [[Decimal::ZERO; 2]; 2],
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns the current `(beta, alpha)` estimate.
+    pub fn coefficients(&self) -> (Decimal, Decimal) {
+        (self.state[0], self.state[1])
+    }
+}
+
+impl<P> Indicator for KalmanHedgeRatio<P>
+where
+    P: PairInput,
+{
+    type Input = P;
+    type Output = KalmanHedgeOutput;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let (x, y) = input.pair();
+        let observation = [x, Decimal::ONE];
+
+        // Predict: the coefficients follow a random walk.
+        let mut prior = self.covariance;
+        prior[0][0] += self.process_variance;
+        prior[1][1] += self.process_variance;
+
+        // prior * H'
+        let gain_numerator = [
+            prior[0][0] * observation[0] + prior[0][1] * observation[1],
+            prior[1][0] * observation[0] + prior[1][1] * observation[1],
+        ];
+        let forecast = self.state[0] * observation[0] + self.state[1] * observation[1];
+        let forecast_variance = observation[0] * gain_numerator[0]
+            + observation[1] * gain_numerator[1]
+            + self.observation_variance;
+        let error = y - forecast;
+        let gain = [
+            gain_numerator[0] / forecast_variance,
+            gain_numerator[1] / forecast_variance,
+        ];
+
+        // Update: state += K e, covariance = prior - K (H prior).
+        self.state[0] += gain[0] * error;
+        self.state[1] += gain[1] * error;
+        // H * prior equals (prior * H')' because the covariance is symmetric.
+        for (row, gain) in gain.iter().enumerate() {
+            for (col, numerator) in gain_numerator.iter().enumerate() {
+                self.covariance[row][col] = prior[row][col] - gain * numerator;
+            }
+        }
+
+        Some(KalmanHedgeOutput {
+            beta: self.state[0],
+            alpha: self.state[1],
+            spread: error,
+            spread_std: forecast_variance.sqrt().unwrap_or(Decimal::ZERO),
+        })
+    }
+
+    fn reset(&mut self) {
+        self.state = [Decimal::ZERO; 2];
+        self.covariance = [[Decimal::ZERO; 2]; 2];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::KalmanHedgeRatio;
+    use crate::Indicator;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn converges_to_stable_hedge_ratio() {
+        let mut kalman = KalmanHedgeRatio::new(dec("0.0001"), dec("0.001")).unwrap();
+        let mut output = None;
+        for step in 0..400 {
+            // Oscillating x keeps both coefficients identifiable.
+            let x = Decimal::from(10 + 3 * (step % 7) - 2 * (step % 5));
+            output = kalman.next((x, dec("0.8") * x + dec("5")));
+        }
+        let output = output.unwrap();
+        assert!((output.beta - dec("0.8")).abs() < dec("0.01"), "{output:?}");
+        assert!((output.alpha - dec("5")).abs() < dec("1"), "{output:?}");
+        assert!(output.spread.abs() < dec("0.01"), "{output:?}");
+        assert!(output.spread_std > Decimal::ZERO);
+    }
+
+    #[test]
+    fn validates_parameters_and_resets() {
+        assert!(KalmanHedgeRatio::<(Decimal, Decimal)>::new(Decimal::ONE, dec("1")).is_err());
+        assert!(KalmanHedgeRatio::<(Decimal, Decimal)>::new(dec("0.1"), Decimal::ZERO).is_err());
+        let mut kalman = KalmanHedgeRatio::new(dec("0.1"), dec("1")).unwrap();
+        kalman.next((dec("2"), dec("4")));
+        kalman.reset();
+        assert_eq!(kalman.coefficients(), (Decimal::ZERO, Decimal::ZERO));
+    }
+}
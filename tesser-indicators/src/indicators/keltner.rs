@@ -0,0 +1,117 @@
+//! Keltner Channels built on an EMA midline and ATR envelope.
+
+use rust_decimal::Decimal;
+use tesser_core::Candle;
+
+use crate::core::{Indicator, IndicatorError};
+use crate::indicators::{Atr, Ema};
+
+/// Output value of the Keltner Channels indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeltnerChannelsOutput {
+    /// Upper channel (midline + k * ATR).
+    pub upper: Decimal,
+    /// EMA of closes.
+    pub middle: Decimal,
+    /// Lower channel (midline - k * ATR).
+    pub lower: Decimal,
+}
+
+/// Envelope of `atr_multiplier` ATRs around an EMA of closes.
+#[derive(Debug, Clone)]
+pub struct KeltnerChannels {
+    midline: Ema,
+    atr: Atr,
+    atr_multiplier: Decimal,
+}
+
+impl KeltnerChannels {
+    /// Creates new Keltner Channels.
+    pub fn new(
+        ema_period: usize,
+        atr_period: usize,
+        atr_multiplier: Decimal,
+    ) -> Result<Self, IndicatorError> {
+        if atr_multiplier.is_sign_negative() {
+            return Err(IndicatorError::invalid_parameter(
+                "KeltnerChannels",
+                "atr_multiplier",
+                atr_multiplier,
+            ));
+        }
+        Ok(Self {
+            midline: Ema::new(ema_period)?,
+            atr: Atr::new(atr_period)?,
+            atr_multiplier,
+        })
+    }
+}
+
+impl Indicator for KeltnerChannels {
+    type Input = Candle;
+    type Output = KeltnerChannelsOutput;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let middle = self.midline.next(input.close);
+        let atr = self.atr.next(input);
+        let (middle, atr) = (middle?, atr?);
+        let width = atr * self.atr_multiplier;
+        Some(KeltnerChannelsOutput {
+            upper: middle + width,
+            middle,
+            lower: middle - width,
+        })
+    }
+
+    fn reset(&mut self) {
+        self.midline.reset();
+        self.atr.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::indicators::testing::{assert_close, candle_series, candles, dec};
+
+    #[test]
+    fn keltner_wraps_ema_with_atr() {
+        let bars = candles(&[
+            (10.0, 11.0, 9.0, 10.0, 1.0),
+            (10.0, 12.0, 10.0, 12.0, 1.0),
+            (12.0, 13.0, 11.0, 11.0, 1.0),
+        ]);
+        let mut keltner = KeltnerChannels::new(2, 2, dec(2.0)).unwrap();
+        let outputs: Vec<_> = bars.into_iter().map(|bar| keltner.next(bar)).collect();
+        assert!(outputs[0].is_none());
+        // EMA seeds at (10 + 12) / 2 = 11; ATR seeds at (2 + 2) / 2 = 2.
+        assert_eq!(
+            outputs[1],
+            Some(KeltnerChannelsOutput {
+                upper: dec(15.0),
+                middle: dec(11.0),
+                lower: dec(7.0),
+            })
+        );
+        // EMA: 11 + (11 - 11) * 2/3 = 11; ATR: (2 + 2) / 2 = 2.
+        assert_eq!(outputs[2].map(|out| out.middle), Some(dec(11.0)));
+        assert_eq!(outputs[2].map(|out| out.upper), Some(dec(15.0)));
+    }
+
+    proptest! {
+        #[test]
+        fn channel_is_symmetric_around_midline(series in candle_series(60), period in 1usize..10) {
+            let mut keltner = KeltnerChannels::new(period, period, dec(1.5)).unwrap();
+            for candle in series {
+                if let Some(out) = keltner.next(candle) {
+                    prop_assert!(out.lower <= out.middle && out.middle <= out.upper);
+                    let upper_width = out.upper - out.middle;
+                    let lower_width = out.middle - out.lower;
+                    assert_close(upper_width, lower_width, Decimal::new(1, 20));
+                }
+            }
+        }
+    }
+}
@@ -1,20 +1,52 @@
 //! Built-in indicator implementations provided by the crate.
 
+pub mod adx;
 /// Average True Range indicator module.
 pub mod atr;
 pub mod bollinger;
+pub mod correlation;
+pub mod donchian;
 pub mod ema;
+mod extrema;
+pub mod half_life;
+pub mod heikin_ashi;
 /// Ichimoku Cloud indicator module.
 pub mod ichimoku;
+pub mod kalman;
+pub mod keltner;
 /// Moving Average Convergence Divergence module.
 pub mod macd;
+mod moments;
+pub mod obv;
+pub mod ols_beta;
+pub mod range_volatility;
+pub mod rolling_stats;
 pub mod rsi;
 pub mod sma;
+pub mod stochastic;
+#[cfg(test)]
+pub(crate) mod testing;
+pub mod vwap;
+pub mod zscore;
 
+pub use adx::{Adx, AdxOutput};
 pub use atr::Atr;
 pub use bollinger::{BollingerBands, BollingerBandsOutput};
+pub use correlation::Correlation;
+pub use donchian::{DonchianChannels, DonchianChannelsOutput};
 pub use ema::Ema;
+pub use half_life::HalfLife;
+pub use heikin_ashi::HeikinAshi;
 pub use ichimoku::{Ichimoku, IchimokuOutput};
+pub use kalman::{KalmanHedgeOutput, KalmanHedgeRatio};
+pub use keltner::{KeltnerChannels, KeltnerChannelsOutput};
 pub use macd::{Macd, MacdOutput};
+pub use obv::Obv;
+pub use ols_beta::{OlsBeta, OlsBetaOutput};
+pub use range_volatility::{GarmanKlassVolatility, ParkinsonVolatility, YangZhangVolatility};
+pub use rolling_stats::{RollingStats, RollingStatsOutput};
 pub use rsi::Rsi;
 pub use sma::Sma;
+pub use stochastic::{Stochastic, StochasticOutput};
+pub use vwap::{AnchoredVwap, RollingVwap};
+pub use zscore::ZScore;
@@ -0,0 +1,208 @@
+//! Rolling-window moment accumulators shared by the statistical indicators.
+//!
+//! Both accumulators use Welford-style add/remove updates, which stay numerically stable
+//! without rescanning the window.
+
+use std::collections::VecDeque;
+
+use rust_decimal::Decimal;
+
+use crate::core::decimal_from_usize;
+
+/// Mean and second central moment of a single rolling series.
+#[derive(Debug, Clone)]
+pub(crate) struct WindowMoments {
+    period: usize,
+    window: VecDeque<Decimal>,
+    mean: Decimal,
+    m2: Decimal,
+}
+
+impl WindowMoments {
+    pub(crate) fn new(period: usize) -> Self {
+        Self {
+            period,
+            window: VecDeque::with_capacity(period + 1),
+            mean: Decimal::ZERO,
+            m2: Decimal::ZERO,
+        }
+    }
+
+    /// Adds a value, evicting the oldest one once the window is full. Returns whether the
+    /// window now holds `period` values.
+    pub(crate) fn push(&mut self, value: Decimal) -> bool {
+        self.window.push_back(value);
+        let count = decimal_from_usize(self.window.len());
+        let delta = value - self.mean;
+        self.mean += delta / count;
+        self.m2 += delta * (value - self.mean);
+
+        if self.window.len() > self.period {
+            if let Some(oldest) = self.window.pop_front() {
+                let count = decimal_from_usize(self.window.len());
+                let before = self.mean;
+                self.mean -= (oldest - before) / count;
+                self.m2 -= (oldest - self.mean) * (oldest - before);
+            }
+        }
+        self.is_full()
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.window.len() == self.period
+    }
+
+    pub(crate) fn mean(&self) -> Decimal {
+        self.mean
+    }
+
+    /// Population variance of the window, clamped at zero against rounding noise.
+    pub(crate) fn variance(&self) -> Decimal {
+        if self.window.is_empty() {
+            return Decimal::ZERO;
+        }
+        (self.m2 / decimal_from_usize(self.window.len())).max(Decimal::ZERO)
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.window.clear();
+        self.mean = Decimal::ZERO;
+        self.m2 = Decimal::ZERO;
+    }
+}
+
+/// Means, variances and co-moment of a rolling `(x, y)` series.
+#[derive(Debug, Clone)]
+pub(crate) struct PairedMoments {
+    period: usize,
+    window: VecDeque<(Decimal, Decimal)>,
+    mean_x: Decimal,
+    mean_y: Decimal,
+    m2_x: Decimal,
+    m2_y: Decimal,
+    co_moment: Decimal,
+}
+
+impl PairedMoments {
+    pub(crate) fn new(period: usize) -> Self {
+        Self {
+            period,
+            window: VecDeque::with_capacity(period + 1),
+            mean_x: Decimal::ZERO,
+            mean_y: Decimal::ZERO,
+            m2_x: Decimal::ZERO,
+            m2_y: Decimal::ZERO,
+            co_moment: Decimal::ZERO,
+        }
+    }
+
+    /// Adds an observation, evicting the oldest one once the window is full. Returns whether
+    /// the window now holds `period` observations.
+    pub(crate) fn push(&mut self, x: Decimal, y: Decimal) -> bool {
+        self.window.push_back((x, y));
+        let count = decimal_from_usize(self.window.len());
+        let dx = x - self.mean_x;
+        let dy = y - self.mean_y;
+        self.mean_x += dx / count;
+        self.mean_y += dy / count;
+        self.m2_x += dx * (x - self.mean_x);
+        self.m2_y += dy * (y - self.mean_y);
+        self.co_moment += dx * (y - self.mean_y);
+
+        if self.window.len() > self.period {
+            if let Some((old_x, old_y)) = self.window.pop_front() {
+                let count = decimal_from_usize(self.window.len());
+                let (before_x, before_y) = (self.mean_x, self.mean_y);
+                self.mean_x -= (old_x - before_x) / count;
+                self.mean_y -= (old_y - before_y) / count;
+                self.m2_x -= (old_x - self.mean_x) * (old_x - before_x);
+                self.m2_y -= (old_y - self.mean_y) * (old_y - before_y);
+                self.co_moment -= (old_x - self.mean_x) * (old_y - before_y);
+            }
+        }
+        self.is_full()
+    }
+
+    pub(crate) fn is_full(&self) -> bool {
+        self.window.len() == self.period
+    }
+
+    pub(crate) fn mean_x(&self) -> Decimal {
+        self.mean_x
+    }
+
+    pub(crate) fn mean_y(&self) -> Decimal {
+        self.mean_y
+    }
+
+    /// Sum of squared deviations of `x`, clamped at zero against rounding noise.
+    pub(crate) fn m2_x(&self) -> Decimal {
+        self.m2_x.max(Decimal::ZERO)
+    }
+
+    /// Sum of squared deviations of `y`, clamped at zero against rounding noise.
+    pub(crate) fn m2_y(&self) -> Decimal {
+        self.m2_y.max(Decimal::ZERO)
+    }
+
+    /// Sum of cross deviations `(x - mean_x) * (y - mean_y)`.
+    pub(crate) fn co_moment(&self) -> Decimal {
+        self.co_moment
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.window.clear();
+        self.mean_x = Decimal::ZERO;
+        self.mean_y = Decimal::ZERO;
+        self.m2_x = Decimal::ZERO;
+        self.m2_y = Decimal::ZERO;
+        self.co_moment = Decimal::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use super::{PairedMoments, WindowMoments};
+
+    fn assert_close(lhs: Decimal, rhs: Decimal) {
+        assert!((lhs - rhs).abs() <= Decimal::new(1, 12), "{lhs} != {rhs}");
+    }
+
+    #[test]
+    fn rolling_moments_match_direct_computation() {
+        let series = [3, 7, 1, 9, 4, 4, 8, 2, 6, 5].map(Decimal::from);
+        let mut single = WindowMoments::new(4);
+        let mut paired = PairedMoments::new(4);
+        for (idx, value) in series.iter().enumerate() {
+            single.push(*value);
+            let y = *value * Decimal::from(2) + Decimal::from(idx as i64 % 3);
+            paired.push(*value, y);
+        }
+        // Recompute the final window (8, 2, 6, 5) directly.
+        let window = &series[6..];
+        let mean = window.iter().sum::<Decimal>() / Decimal::from(4);
+        let variance = window
+            .iter()
+            .map(|value| (*value - mean) * (*value - mean))
+            .sum::<Decimal>()
+            / Decimal::from(4);
+        assert_close(single.mean(), mean);
+        assert_close(single.variance(), variance);
+        assert_close(paired.mean_x(), mean);
+        assert_close(paired.m2_x(), variance * Decimal::from(4));
+
+        let ys: Vec<Decimal> = (6..10)
+            .map(|idx| series[idx] * Decimal::from(2) + Decimal::from(idx as i64 % 3))
+            .collect();
+        let mean_y = ys.iter().sum::<Decimal>() / Decimal::from(4);
+        let co_moment = window
+            .iter()
+            .zip(&ys)
+            .map(|(x, y)| (*x - mean) * (*y - mean_y))
+            .sum::<Decimal>();
+        assert_close(paired.mean_y(), mean_y);
+        assert_close(paired.co_moment(), co_moment);
+    }
+}
@@ -0,0 +1,79 @@
+//! On-Balance Volume (OBV).
+
+use rust_decimal::Decimal;
+use tesser_core::Candle;
+
+use crate::core::Indicator;
+
+/// Running total that adds a bar's volume when it closes higher and subtracts it when it
+/// closes lower. The first bar only seeds the previous close and reports zero.
+#[derive(Debug, Clone, Default)]
+pub struct Obv {
+    prev_close: Option<Decimal>,
+    total: Decimal,
+}
+
+impl Obv {
+    /// Creates a new OBV starting from zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Indicator for Obv {
+    type Input = Candle;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        if let Some(prev) = self.prev_close {
+            if input.close > prev {
+                self.total += input.volume;
+            } else if input.close < prev {
+                self.total -= input.volume;
+            }
+        }
+        self.prev_close = Some(input.close);
+        Some(self.total)
+    }
+
+    fn reset(&mut self) {
+        self.prev_close = None;
+        self.total = Decimal::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::indicators::testing::{candle_series, candles, dec};
+
+    #[test]
+    fn obv_tracks_close_direction() {
+        let bars = candles(&[
+            (10.0, 10.0, 10.0, 10.0, 5.0),
+            (10.0, 11.0, 10.0, 11.0, 3.0),
+            (11.0, 11.0, 10.0, 10.5, 2.0),
+            (10.5, 10.5, 10.5, 10.5, 9.0),
+        ]);
+        let mut obv = Obv::new();
+        let values: Vec<_> = bars.into_iter().filter_map(|bar| obv.next(bar)).collect();
+        assert_eq!(values, vec![dec(0.0), dec(3.0), dec(1.0), dec(1.0)]);
+    }
+
+    proptest! {
+        #[test]
+        fn obv_is_bounded_by_cumulative_volume(series in candle_series(60)) {
+            let mut obv = Obv::new();
+            let mut volume = Decimal::ZERO;
+            for (idx, candle) in series.iter().enumerate() {
+                let value = obv.next(candle.clone()).unwrap();
+                if idx > 0 {
+                    volume += candle.volume;
+                }
+                prop_assert!(value.abs() <= volume);
+            }
+        }
+    }
+}
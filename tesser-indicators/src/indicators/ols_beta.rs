@@ -0,0 +1,121 @@
+//! Rolling ordinary-least-squares hedge ratio.
+
+use std::marker::PhantomData;
+
+use rust_decimal::Decimal;
+
+use crate::core::{Indicator, IndicatorError, PairInput};
+use crate::indicators::moments::PairedMoments;
+
+/// Output value of the rolling OLS regression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OlsBetaOutput {
+    /// Slope of `y` on `x`, i.e. the hedge ratio.
+    pub beta: Decimal,
+    /// Intercept of the regression.
+    pub alpha: Decimal,
+    /// Residual of the latest observation, `y - (alpha + beta * x)`.
+    pub residual: Decimal,
+}
+
+/// Regresses `y` on `x` over the last `period` observations.
+///
+/// Returns `None` while warming up and whenever `x` is flat over the window.
+#[derive(Debug, Clone)]
+pub struct OlsBeta<P = (Decimal, Decimal)> {
+    moments: PairedMoments,
+    marker: PhantomData<P>,
+}
+
+impl<P> OlsBeta<P>
+where
+    P: PairInput,
+{
+    /// Creates a new rolling regression over `period` observations.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period < 2 {
+            return Err(IndicatorError::invalid_period("OlsBeta", period));
+        }
+        Ok(Self {
+            moments: PairedMoments::new(period),
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<P> Indicator for OlsBeta<P>
+where
+    P: PairInput,
+{
+    type Input = P;
+    type Output = OlsBetaOutput;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let (x, y) = input.pair();
+        if !self.moments.push(x, y) {
+            return None;
+        }
+        let m2_x = self.moments.m2_x();
+        if m2_x.is_zero() {
+            return None;
+        }
+        let beta = self.moments.co_moment() / m2_x;
+        let alpha = self.moments.mean_y() - beta * self.moments.mean_x();
+        Some(OlsBetaOutput {
+            beta,
+            alpha,
+            residual: y - (alpha + beta * x),
+        })
+    }
+
+    fn reset(&mut self) {
+        self.moments.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::OlsBeta;
+    use crate::Indicator;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn assert_close(lhs: Decimal, rhs: Decimal) {
+        let tolerance = dec("0.00000001");
+        assert!((lhs - rhs).abs() <= tolerance, "{lhs} != {rhs}");
+    }
+
+    #[test]
+    fn recovers_linear_relationship() {
+        let mut ols = OlsBeta::new(4).unwrap();
+        let mut output = None;
+        for x in 0..10 {
+            let x = Decimal::from(x);
+            output = ols.next((x, dec("1.5") * x + dec("3")));
+        }
+        let output = output.unwrap();
+        assert_close(output.beta, dec("1.5"));
+        assert_close(output.alpha, dec("3"));
+        assert_close(output.residual, Decimal::ZERO);
+    }
+
+    #[test]
+    fn matches_reference_fit() {
+        let mut ols = OlsBeta::new(5).unwrap();
+        let mut output = None;
+        for (x, y) in [(1, 2), (2, 4), (3, 5), (4, 4), (5, 5)] {
+            output = ols.next((Decimal::from(x), Decimal::from(y)));
+        }
+        // numpy.polyfit([1, 2, 3, 4, 5], [2, 4, 5, 4, 5], 1) == [0.6, 2.2]
+        let output = output.unwrap();
+        assert_close(output.beta, dec("0.6"));
+        assert_close(output.alpha, dec("2.2"));
+        assert_close(output.residual, dec("-0.2"));
+    }
+}
@@ -0,0 +1,290 @@
+//! Range-based volatility estimators: Parkinson, Garman–Klass and Yang–Zhang.
+//!
+//! All estimators report the per-bar standard deviation of log returns over a rolling window;
+//! scale by `sqrt(bars per year)` to annualize. Bars with non-positive prices are skipped.
+
+use rust_decimal::{Decimal, MathematicalOps};
+use tesser_core::Candle;
+
+use crate::core::{decimal_from_usize, Indicator, IndicatorError};
+use crate::indicators::moments::WindowMoments;
+
+fn log_ratio(numerator: Decimal, denominator: Decimal) -> Option<Decimal> {
+    if numerator <= Decimal::ZERO || denominator <= Decimal::ZERO {
+        return None;
+    }
+    (numerator / denominator).checked_ln()
+}
+
+fn ln_two() -> Decimal {
+    Decimal::TWO.ln()
+}
+
+/// Parkinson high/low estimator: `sigma^2 = mean(ln(H/L)^2) / (4 ln 2)`.
+#[derive(Debug, Clone)]
+pub struct ParkinsonVolatility {
+    terms: WindowMoments,
+    scale: Decimal,
+}
+
+impl ParkinsonVolatility {
+    /// Creates a Parkinson estimator over `period` bars.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period(
+                "ParkinsonVolatility",
+                period,
+            ));
+        }
+        Ok(Self {
+            terms: WindowMoments::new(period),
+            scale: Decimal::ONE / (Decimal::from(4) * ln_two()),
+        })
+    }
+}
+
+impl Indicator for ParkinsonVolatility {
+    type Input = Candle;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let range = log_ratio(input.high, input.low)?;
+        if !self.terms.push(range * range) {
+            return None;
+        }
+        (self.terms.mean() * self.scale).max(Decimal::ZERO).sqrt()
+    }
+
+    fn reset(&mut self) {
+        self.terms.reset();
+    }
+}
+
+/// Garman–Klass OHLC estimator:
+/// `sigma^2 = mean(0.5 ln(H/L)^2 - (2 ln 2 - 1) ln(C/O)^2)`.
+#[derive(Debug, Clone)]
+pub struct GarmanKlassVolatility {
+    terms: WindowMoments,
+    body_weight: Decimal,
+}
+
+impl GarmanKlassVolatility {
+    /// Creates a Garman–Klass estimator over `period` bars.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period(
+                "GarmanKlassVolatility",
+                period,
+            ));
+        }
+        Ok(Self {
+            terms: WindowMoments::new(period),
+            body_weight: Decimal::TWO * ln_two() - Decimal::ONE,
+        })
+    }
+}
+
+impl Indicator for GarmanKlassVolatility {
+    type Input = Candle;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let range = log_ratio(input.high, input.low)?;
+        let body = log_ratio(input.close, input.open)?;
+        let term = range * range / Decimal::TWO - self.body_weight * body * body;
+        if !self.terms.push(term) {
+            return None;
+        }
+        self.terms.mean().max(Decimal::ZERO).sqrt()
+    }
+
+    fn reset(&mut self) {
+        self.terms.reset();
+    }
+}
+
+/// Yang–Zhang estimator combining overnight (close-to-open), open-to-close and
+/// Rogers–Satchell variances, robust to both drift and opening gaps.
+///
+/// Needs `period + 1` bars because the first bar only provides the previous close.
+#[derive(Debug, Clone)]
+pub struct YangZhangVolatility {
+    overnight: WindowMoments,
+    open_close: WindowMoments,
+    rogers_satchell: WindowMoments,
+    prev_close: Option<Decimal>,
+    sample_correction: Decimal,
+    k: Decimal,
+}
+
+impl YangZhangVolatility {
+    /// Creates a Yang–Zhang estimator over `period` returns; `period` must be at least 2.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period < 2 {
+            return Err(IndicatorError::invalid_period(
+                "YangZhangVolatility",
+                period,
+            ));
+        }
+        let n = decimal_from_usize(period);
+        let k =
+            Decimal::new(34, 2) / (Decimal::new(134, 2) + (n + Decimal::ONE) / (n - Decimal::ONE));
+        Ok(Self {
+            overnight: WindowMoments::new(period),
+            open_close: WindowMoments::new(period),
+            rogers_satchell: WindowMoments::new(period),
+            prev_close: None,
+            sample_correction: n / (n - Decimal::ONE),
+            k,
+        })
+    }
+}
+
+impl Indicator for YangZhangVolatility {
+    type Input = Candle;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let overnight = self
+            .prev_close
+            .and_then(|prev_close| log_ratio(input.open, prev_close));
+        let up = log_ratio(input.high, input.open);
+        let down = log_ratio(input.low, input.open);
+        let body = log_ratio(input.close, input.open);
+        if input.close > Decimal::ZERO {
+            self.prev_close = Some(input.close);
+        }
+        let (overnight, up, down, body) = (overnight?, up?, down?, body?);
+
+        self.overnight.push(overnight);
+        self.open_close.push(body);
+        let full = self
+            .rogers_satchell
+            .push(up * (up - body) + down * (down - body));
+        if !full {
+            return None;
+        }
+        let overnight_var = self.overnight.variance() * self.sample_correction;
+        let open_close_var = self.open_close.variance() * self.sample_correction;
+        let variance = overnight_var
+            + self.k * open_close_var
+            + (Decimal::ONE - self.k) * self.rogers_satchell.mean();
+        variance.max(Decimal::ZERO).sqrt()
+    }
+
+    fn reset(&mut self) {
+        self.overnight.reset();
+        self.open_close.reset();
+        self.rogers_satchell.reset();
+        self.prev_close = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::indicators::testing::{assert_close, candle_series, candles};
+
+    const TOLERANCE: Decimal = Decimal::from_parts(1, 0, 0, false, 12);
+
+    #[test]
+    fn parkinson_matches_closed_form() {
+        // ln(H/L) = ln 2 on every bar, so sigma^2 = (ln 2)^2 / (4 ln 2) = ln 2 / 4.
+        let bars = candles(&[(15.0, 20.0, 10.0, 15.0, 1.0), (15.0, 30.0, 15.0, 20.0, 1.0)]);
+        let mut parkinson = ParkinsonVolatility::new(2).unwrap();
+        let outputs: Vec<_> = bars.into_iter().map(|bar| parkinson.next(bar)).collect();
+        assert!(outputs[0].is_none());
+        let expected = (ln_two() / Decimal::from(4)).sqrt().unwrap();
+        assert_close(outputs[1].unwrap(), expected, TOLERANCE);
+    }
+
+    #[test]
+    fn garman_klass_matches_closed_form() {
+        // Flat bodies leave only the range term: sigma^2 = (ln 2)^2 / 2.
+        let bars = candles(&[(15.0, 20.0, 10.0, 15.0, 1.0)]);
+        let mut gk = GarmanKlassVolatility::new(1).unwrap();
+        let output = bars.into_iter().filter_map(|bar| gk.next(bar)).last();
+        let expected = (ln_two() * ln_two() / Decimal::TWO).sqrt().unwrap();
+        assert_close(output.unwrap(), expected, TOLERANCE);
+    }
+
+    #[test]
+    fn yang_zhang_is_zero_for_flat_prices() {
+        let bars = candles(&[(10.0, 10.0, 10.0, 10.0, 1.0); 4]);
+        let mut yz = YangZhangVolatility::new(3).unwrap();
+        let outputs: Vec<_> = bars.into_iter().map(|bar| yz.next(bar)).collect();
+        assert!(outputs[..3].iter().all(Option::is_none));
+        assert_eq!(outputs[3], Some(Decimal::ZERO));
+        assert!(YangZhangVolatility::new(1).is_err());
+    }
+
+    proptest! {
+        // Decimal logarithms are slow in debug builds; a few dozen random walks are plenty.
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn estimators_match_window_recomputation(
+            series in candle_series(60),
+            period in 2usize..10,
+        ) {
+            let mut parkinson = ParkinsonVolatility::new(period).unwrap();
+            let mut gk = GarmanKlassVolatility::new(period).unwrap();
+            let mut yz = YangZhangVolatility::new(period).unwrap();
+            let ln = |a: Decimal, b: Decimal| (a / b).ln();
+            let n = decimal_from_usize(period);
+            for (idx, candle) in series.iter().enumerate() {
+                let pk_out = parkinson.next(candle.clone());
+                let gk_out = gk.next(candle.clone());
+                let yz_out = yz.next(candle.clone());
+                if idx + 1 < period {
+                    prop_assert!(pk_out.is_none() && gk_out.is_none() && yz_out.is_none());
+                    continue;
+                }
+                let window = &series[idx + 1 - period..=idx];
+                let pk_var = window.iter().map(|c| ln(c.high, c.low).powi(2)).sum::<Decimal>()
+                    / (n * Decimal::from(4) * ln_two());
+                assert_close(pk_out.unwrap(), pk_var.sqrt().unwrap(), TOLERANCE);
+                let gk_var = window
+                    .iter()
+                    .map(|c| {
+                        ln(c.high, c.low).powi(2) / Decimal::TWO
+                            - (Decimal::TWO * ln_two() - Decimal::ONE) * ln(c.close, c.open).powi(2)
+                    })
+                    .sum::<Decimal>()
+                    / n;
+                assert_close(gk_out.unwrap(), gk_var.max(Decimal::ZERO).sqrt().unwrap(), TOLERANCE);
+
+                if idx < period {
+                    prop_assert!(yz_out.is_none());
+                    continue;
+                }
+                let overnight: Vec<_> = (idx + 1 - period..=idx)
+                    .map(|i| ln(series[i].open, series[i - 1].close))
+                    .collect();
+                let bodies: Vec<_> = window.iter().map(|c| ln(c.close, c.open)).collect();
+                let sample_var = |values: &[Decimal]| {
+                    let mean = values.iter().sum::<Decimal>() / n;
+                    values.iter().map(|v| (*v - mean).powi(2)).sum::<Decimal>() / (n - Decimal::ONE)
+                };
+                let rs = window
+                    .iter()
+                    .map(|c| {
+                        let body = ln(c.close, c.open);
+                        let (up, down) = (ln(c.high, c.open), ln(c.low, c.open));
+                        up * (up - body) + down * (down - body)
+                    })
+                    .sum::<Decimal>()
+                    / n;
+                let k = Decimal::new(34, 2)
+                    / (Decimal::new(134, 2) + (n + Decimal::ONE) / (n - Decimal::ONE));
+                let yz_var = sample_var(&overnight)
+                    + k * sample_var(&bodies)
+                    + (Decimal::ONE - k) * rs;
+                let expected = yz_var.max(Decimal::ZERO).sqrt().unwrap();
+                assert_close(yz_out.unwrap(), expected, TOLERANCE);
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Rolling mean, variance and standard deviation.
+
+use std::marker::PhantomData;
+
+use rust_decimal::{Decimal, MathematicalOps};
+
+use crate::core::{Indicator, IndicatorError, Input};
+use crate::indicators::moments::WindowMoments;
+
+/// Output value of the rolling statistics indicator.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RollingStatsOutput {
+    /// Arithmetic mean of the window.
+    pub mean: Decimal,
+    /// Population variance of the window.
+    pub variance: Decimal,
+    /// Population standard deviation of the window.
+    pub std_dev: Decimal,
+}
+
+/// Tracks the mean and variance of a rolling window with Welford updates.
+#[derive(Debug, Clone)]
+pub struct RollingStats<I = Decimal> {
+    moments: WindowMoments,
+    marker: PhantomData<I>,
+}
+
+impl<I> RollingStats<I>
+where
+    I: Input,
+{
+    /// Creates a new rolling statistics indicator over `period` values.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period("RollingStats", period));
+        }
+        Ok(Self {
+            moments: WindowMoments::new(period),
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<I> Indicator for RollingStats<I>
+where
+    I: Input,
+{
+    type Input = I;
+    type Output = RollingStatsOutput;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        if !self.moments.push(input.value()) {
+            return None;
+        }
+        let variance = self.moments.variance();
+        Some(RollingStatsOutput {
+            mean: self.moments.mean(),
+            variance,
+            std_dev: variance.sqrt().unwrap_or(Decimal::ZERO),
+        })
+    }
+
+    fn reset(&mut self) {
+        self.moments.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::RollingStats;
+    use crate::Indicator;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn assert_close(lhs: Decimal, rhs: Decimal) {
+        let tolerance = dec("0.00000001");
+        assert!((lhs - rhs).abs() <= tolerance, "{lhs} != {rhs}");
+    }
+
+    #[test]
+    fn matches_bollinger_window_statistics() {
+        let mut stats = RollingStats::new(5).unwrap();
+        let mut output = None;
+        for value in ["7", "10", "11", "12", "13", "14"] {
+            output = stats.next(dec(value));
+        }
+        let output = output.unwrap();
+        assert_close(output.mean, dec("12"));
+        assert_close(output.variance, dec("2"));
+        assert_close(output.std_dev, dec("1.41421356"));
+    }
+
+    #[test]
+    fn waits_for_full_window_and_resets() {
+        let mut stats = RollingStats::new(2).unwrap();
+        assert_eq!(stats.next(dec("1")), None);
+        assert!(stats.next(dec("3")).is_some());
+        stats.reset();
+        assert_eq!(stats.next(dec("3")), None);
+    }
+}
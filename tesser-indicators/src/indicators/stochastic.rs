@@ -0,0 +1,136 @@
+//! Stochastic oscillator (%K / %D).
+
+use rust_decimal::Decimal;
+use tesser_core::Candle;
+
+use crate::core::{Indicator, IndicatorError};
+use crate::indicators::extrema::RollingExtrema;
+use crate::indicators::Sma;
+
+/// Output of the stochastic oscillator, both lines on a 0–100 scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StochasticOutput {
+    /// Smoothed %K line.
+    pub k: Decimal,
+    /// Moving average of %K.
+    pub d: Decimal,
+}
+
+/// Locates the close within the recent high/low range.
+///
+/// Raw %K is `100 * (close - lowest low) / (highest high - lowest low)` over `k_period` bars,
+/// smoothed by an SMA of `k_smoothing` bars (use 1 for the "fast" variant). %D is an SMA of the
+/// smoothed %K over `d_period` bars. A flat range reports the midpoint, 50.
+#[derive(Debug, Clone)]
+pub struct Stochastic {
+    range: RollingExtrema,
+    k_smoothing: Sma,
+    d_line: Sma,
+}
+
+impl Stochastic {
+    /// Creates a new stochastic oscillator.
+    pub fn new(
+        k_period: usize,
+        k_smoothing: usize,
+        d_period: usize,
+    ) -> Result<Self, IndicatorError> {
+        if k_period == 0 {
+            return Err(IndicatorError::invalid_period("Stochastic", k_period));
+        }
+        Ok(Self {
+            range: RollingExtrema::new(k_period),
+            k_smoothing: Sma::new(k_smoothing)?,
+            d_line: Sma::new(d_period)?,
+        })
+    }
+}
+
+impl Indicator for Stochastic {
+    type Input = Candle;
+    type Output = StochasticOutput;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        if !self.range.push(input.high, input.low) {
+            return None;
+        }
+        let highest = self.range.highest()?;
+        let lowest = self.range.lowest()?;
+        let span = highest - lowest;
+        let raw = if span.is_zero() {
+            Decimal::from(50)
+        } else {
+            Decimal::ONE_HUNDRED * (input.close - lowest) / span
+        };
+        let k = self.k_smoothing.next(raw)?;
+        let d = self.d_line.next(k)?;
+        Some(StochasticOutput { k, d })
+    }
+
+    fn reset(&mut self) {
+        self.range.reset();
+        self.k_smoothing.reset();
+        self.d_line.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::indicators::testing::{assert_close, candle_series, candles, dec};
+
+    #[test]
+    fn fast_stochastic_matches_reference() {
+        let bars = candles(&[
+            (10.0, 12.0, 8.0, 11.0, 1.0),
+            (11.0, 14.0, 10.0, 13.0, 1.0),
+            (13.0, 13.0, 9.0, 10.0, 1.0),
+            (10.0, 11.0, 7.0, 8.0, 1.0),
+        ]);
+        let mut stoch = Stochastic::new(3, 1, 2).unwrap();
+        let outputs: Vec<_> = bars.into_iter().map(|bar| stoch.next(bar)).collect();
+        assert!(outputs[..3].iter().all(Option::is_none));
+        // Bar 2 sees high 14 / low 8 and closes at 10; bar 3 sees high 14 / low 7 and closes
+        // at 8.
+        let last = outputs[3].unwrap();
+        let prev_k = dec(100.0) * dec(2.0) / dec(6.0);
+        assert_close(last.k, dec(100.0) / dec(7.0), Decimal::new(1, 20));
+        assert_close(last.d, (prev_k + last.k) / dec(2.0), Decimal::new(1, 20));
+    }
+
+    #[test]
+    fn flat_range_reports_midpoint() {
+        let bars = candles(&[(5.0, 5.0, 5.0, 5.0, 1.0), (5.0, 5.0, 5.0, 5.0, 1.0)]);
+        let mut stoch = Stochastic::new(2, 1, 1).unwrap();
+        let output = bars.into_iter().filter_map(|bar| stoch.next(bar)).last();
+        assert_eq!(output.map(|out| out.k), Some(dec(50.0)));
+    }
+
+    proptest! {
+        #[test]
+        fn stochastic_matches_window_scan(series in candle_series(60), k_period in 1usize..10) {
+            let mut stoch = Stochastic::new(k_period, 1, 1).unwrap();
+            for (idx, candle) in series.iter().enumerate() {
+                let output = stoch.next(candle.clone());
+                if idx + 1 < k_period {
+                    prop_assert!(output.is_none());
+                    continue;
+                }
+                let window = &series[idx + 1 - k_period..=idx];
+                let high = window.iter().map(|c| c.high).max().unwrap();
+                let low = window.iter().map(|c| c.low).min().unwrap();
+                let expected = if high == low {
+                    dec(50.0)
+                } else {
+                    Decimal::ONE_HUNDRED * (candle.close - low) / (high - low)
+                };
+                let output = output.unwrap();
+                assert_close(output.k, expected, Decimal::new(1, 20));
+                let slack = Decimal::new(1, 20);
+                prop_assert!(output.k >= -slack && output.k <= Decimal::ONE_HUNDRED + slack);
+            }
+        }
+    }
+}
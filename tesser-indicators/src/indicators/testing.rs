@@ -0,0 +1,86 @@
+//! Candle fixtures and proptest strategies shared by the candle-based indicator tests.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use proptest::prelude::*;
+use rust_decimal::Decimal;
+use tesser_core::{Candle, Interval, Symbol};
+
+pub(crate) fn dec(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value).expect("finite test value")
+}
+
+pub(crate) fn assert_close(lhs: Decimal, rhs: Decimal, tolerance: Decimal) {
+    assert!((lhs - rhs).abs() <= tolerance, "{lhs} != {rhs}");
+}
+
+pub(crate) fn start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+}
+
+pub(crate) fn candle_at(
+    timestamp: DateTime<Utc>,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: f64,
+) -> Candle {
+    Candle {
+        symbol: Symbol::from("BTCUSDT"),
+        interval: Interval::OneMinute,
+        open: dec(open),
+        high: dec(high),
+        low: dec(low),
+        close: dec(close),
+        volume: dec(volume),
+        timestamp,
+    }
+}
+
+/// Builds consecutive one-minute candles from `(open, high, low, close, volume)` rows.
+pub(crate) fn candles(rows: &[(f64, f64, f64, f64, f64)]) -> Vec<Candle> {
+    rows.iter()
+        .enumerate()
+        .map(|(idx, (open, high, low, close, volume))| {
+            let timestamp = start() + Duration::minutes(idx as i64);
+            candle_at(timestamp, *open, *high, *low, *close, *volume)
+        })
+        .collect()
+}
+
+/// Generates a random walk of OHLC-consistent, strictly positive one-minute candles.
+pub(crate) fn candle_series(max_len: usize) -> impl Strategy<Value = Vec<Candle>> {
+    prop::collection::vec(
+        (
+            -50i64..=50,
+            -300i64..=300,
+            0i64..=200,
+            0i64..=200,
+            0i64..=5_000,
+        ),
+        1..max_len,
+    )
+    .prop_map(|steps| {
+        let mut close = Decimal::from(1_000);
+        steps
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (gap, drift, up, down, volume))| {
+                let open = (close + Decimal::new(gap, 1)).max(Decimal::from(100));
+                close = (open + Decimal::new(drift, 1)).max(Decimal::from(100));
+                let high = open.max(close) + Decimal::new(up, 1);
+                let low = (open.min(close) - Decimal::new(down, 1)).max(Decimal::from(50));
+                Candle {
+                    symbol: Symbol::from("BTCUSDT"),
+                    interval: Interval::OneMinute,
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume: Decimal::new(volume, 2),
+                    timestamp: start() + Duration::minutes(idx as i64),
+                }
+            })
+            .collect()
+    })
+}
@@ -0,0 +1,203 @@
+//! Volume-weighted average price, either anchored to fixed sessions or over a rolling window.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use tesser_core::Candle;
+
+use crate::core::{Indicator, IndicatorError};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+fn typical_price(candle: &Candle) -> Decimal {
+    (candle.high + candle.low + candle.close) / Decimal::from(3)
+}
+
+/// VWAP that accumulates from the start of each session and resets at session boundaries.
+///
+/// Sessions are fixed-length buckets aligned to the Unix epoch, so a one-day session resets
+/// at 00:00 UTC. Bars are weighted by their typical price `(high + low + close) / 3`.
+#[derive(Debug, Clone)]
+pub struct AnchoredVwap {
+    session_secs: i64,
+    session: Option<i64>,
+    price_volume: Decimal,
+    volume: Decimal,
+}
+
+impl AnchoredVwap {
+    /// Creates a VWAP that resets every `session`.
+    pub fn new(session: Duration) -> Result<Self, IndicatorError> {
+        let secs = session.as_secs();
+        if secs == 0 || secs > i64::MAX as u64 {
+            return Err(IndicatorError::invalid_period(
+                "AnchoredVwap",
+                secs as usize,
+            ));
+        }
+        Ok(Self {
+            session_secs: secs as i64,
+            session: None,
+            price_volume: Decimal::ZERO,
+            volume: Decimal::ZERO,
+        })
+    }
+
+    /// Creates a VWAP anchored to UTC calendar days.
+    pub fn daily() -> Self {
+        Self::new(Duration::from_secs(SECONDS_PER_DAY)).expect("one day is a valid session")
+    }
+}
+
+impl Indicator for AnchoredVwap {
+    type Input = Candle;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let session = input.timestamp.timestamp().div_euclid(self.session_secs);
+        if self.session != Some(session) {
+            self.session = Some(session);
+            self.price_volume = Decimal::ZERO;
+            self.volume = Decimal::ZERO;
+        }
+        self.price_volume += typical_price(&input) * input.volume;
+        self.volume += input.volume;
+        if self.volume.is_zero() {
+            return None;
+        }
+        Some(self.price_volume / self.volume)
+    }
+
+    fn reset(&mut self) {
+        self.session = None;
+        self.price_volume = Decimal::ZERO;
+        self.volume = Decimal::ZERO;
+    }
+}
+
+/// VWAP over the last `period` bars.
+#[derive(Debug, Clone)]
+pub struct RollingVwap {
+    period: usize,
+    window: VecDeque<(Decimal, Decimal)>,
+    price_volume: Decimal,
+    volume: Decimal,
+}
+
+impl RollingVwap {
+    /// Creates a rolling VWAP over `period` bars.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period == 0 {
+            return Err(IndicatorError::invalid_period("RollingVwap", period));
+        }
+        Ok(Self {
+            period,
+            window: VecDeque::with_capacity(period + 1),
+            price_volume: Decimal::ZERO,
+            volume: Decimal::ZERO,
+        })
+    }
+}
+
+impl Indicator for RollingVwap {
+    type Input = Candle;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let price_volume = typical_price(&input) * input.volume;
+        self.window.push_back((price_volume, input.volume));
+        self.price_volume += price_volume;
+        self.volume += input.volume;
+        if self.window.len() > self.period {
+            if let Some((old_pv, old_volume)) = self.window.pop_front() {
+                self.price_volume -= old_pv;
+                self.volume -= old_volume;
+            }
+        }
+        if self.window.len() < self.period || self.volume.is_zero() {
+            return None;
+        }
+        Some(self.price_volume / self.volume)
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.price_volume = Decimal::ZERO;
+        self.volume = Decimal::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration as ChronoDuration;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::indicators::testing::{assert_close, candle_at, candle_series, dec, start};
+
+    #[test]
+    fn anchored_vwap_resets_at_session_boundary() {
+        let mut vwap = AnchoredVwap::daily();
+        let day_one = start() + ChronoDuration::hours(22);
+        let first = vwap.next(candle_at(day_one, 10.0, 12.0, 9.0, 11.0, 2.0));
+        assert_eq!(first, Some(dec(32.0) / dec(3.0)));
+        // Typical prices 32/3 and 21 weighted 2:1.
+        let second = vwap.next(candle_at(
+            day_one + ChronoDuration::hours(1),
+            11.0,
+            22.0,
+            20.0,
+            21.0,
+            1.0,
+        ));
+        assert_eq!(second, Some((dec(64.0) / dec(3.0) + dec(21.0)) / dec(3.0)));
+
+        let next_day = vwap.next(candle_at(
+            day_one + ChronoDuration::hours(2),
+            30.0,
+            31.0,
+            29.0,
+            30.0,
+            5.0,
+        ));
+        assert_eq!(next_day, Some(dec(30.0)));
+    }
+
+    #[test]
+    fn zero_volume_has_no_vwap() {
+        let mut vwap = RollingVwap::new(1).unwrap();
+        assert!(vwap
+            .next(candle_at(start(), 1.0, 1.0, 1.0, 1.0, 0.0))
+            .is_none());
+        assert!(AnchoredVwap::new(Duration::ZERO).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn rolling_vwap_matches_window_recomputation(
+            series in candle_series(60),
+            period in 1usize..12,
+        ) {
+            let mut vwap = RollingVwap::new(period).unwrap();
+            for (idx, candle) in series.iter().enumerate() {
+                let output = vwap.next(candle.clone());
+                if idx + 1 < period {
+                    prop_assert!(output.is_none());
+                    continue;
+                }
+                let window = &series[idx + 1 - period..=idx];
+                let volume: Decimal = window.iter().map(|c| c.volume).sum();
+                if volume.is_zero() {
+                    prop_assert!(output.is_none());
+                    continue;
+                }
+                let pv: Decimal = window.iter().map(|c| typical_price(c) * c.volume).sum();
+                assert_close(output.unwrap(), pv / volume, Decimal::new(1, 12));
+                let low = window.iter().map(|c| c.low).min().unwrap();
+                let high = window.iter().map(|c| c.high).max().unwrap();
+                prop_assert!(output.unwrap() >= low && output.unwrap() <= high);
+            }
+        }
+    }
+}
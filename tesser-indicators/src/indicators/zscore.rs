@@ -0,0 +1,91 @@
+//! Rolling z-score of the latest value against its window.
+
+use std::marker::PhantomData;
+
+use rust_decimal::{Decimal, MathematicalOps};
+
+use crate::core::{Indicator, IndicatorError, Input};
+use crate::indicators::moments::WindowMoments;
+
+/// Standardizes each value against the mean and population standard deviation of the last
+/// `period` values (including itself).
+///
+/// Returns `None` while warming up and whenever the window has zero variance.
+#[derive(Debug, Clone)]
+pub struct ZScore<I = Decimal> {
+    moments: WindowMoments,
+    marker: PhantomData<I>,
+}
+
+impl<I> ZScore<I>
+where
+    I: Input,
+{
+    /// Creates a new z-score indicator over `period` values.
+    pub fn new(period: usize) -> Result<Self, IndicatorError> {
+        if period < 2 {
+            return Err(IndicatorError::invalid_period("ZScore", period));
+        }
+        Ok(Self {
+            moments: WindowMoments::new(period),
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<I> Indicator for ZScore<I>
+where
+    I: Input,
+{
+    type Input = I;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        let value = input.value();
+        if !self.moments.push(value) {
+            return None;
+        }
+        let std_dev = self.moments.variance().sqrt()?;
+        if std_dev.is_zero() {
+            return None;
+        }
+        Some((value - self.moments.mean()) / std_dev)
+    }
+
+    fn reset(&mut self) {
+        self.moments.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rust_decimal::Decimal;
+
+    use super::ZScore;
+    use crate::Indicator;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn standardizes_latest_value() {
+        let mut z = ZScore::new(4).unwrap();
+        for value in ["100", "2", "4", "4"] {
+            z.next(dec(value));
+        }
+        // Window 2, 4, 4, 6: mean 4, population std sqrt(2).
+        let score = z.next(dec("6")).unwrap();
+        assert!((score - dec("1.41421356")).abs() < dec("0.00000001"));
+    }
+
+    #[test]
+    fn flat_windows_have_no_score() {
+        let mut z = ZScore::new(2).unwrap();
+        z.next(dec("5"));
+        assert_eq!(z.next(dec("5")), None);
+        assert!(ZScore::<Decimal>::new(1).is_err());
+    }
+}
@@ -9,8 +9,12 @@ pub mod combinators;
 pub mod core;
 /// Built-in indicator implementations.
 pub mod indicators;
+/// Indicator graphs declared in configuration and built at runtime.
+pub mod spec;
 
-/// Re-export of the piped indicator combinator for convenience.
-pub use crate::combinators::PipedIndicator;
+/// Re-export of the indicator combinators for convenience.
+pub use crate::combinators::{
+    CrossEvent, Crossover, Diff, Fanout, Lag, Map, PipedIndicator, RateOfChange, Zip,
+};
 /// Re-export of the core traits and error type to make the crate easy to consume.
-pub use crate::core::{Indicator, IndicatorError, Input};
+pub use crate::core::{BoxedIndicator, Indicator, IndicatorError, Input, PairInput};
@@ -0,0 +1,708 @@
+//! Indicator graphs declared in configuration and assembled at runtime.
+//!
+//! Every spec builds into a [`BoxedIndicator`] that consumes candles and emits a single
+//! decimal. Price-series indicators read the candle close unless a nested `source` spec is
+//! given, and multi-output indicators pick one line (`band`, `line`). A strategy can therefore
+//! declare its features in TOML without recompiling:
+//!
+//! ```toml
+//! [[features]]
+//! name = "rsi_14"
+//! kind = "rsi"
+//! period = 14
+//!
+//! [[features]]
+//! name = "rsi_14_z"
+//! kind = "z_score"
+//! period = 50
+//! source = { kind = "rsi", period = 14 }
+//!
+//! [[features]]
+//! name = "bb_upper"
+//! kind = "bollinger"
+//! period = 20
+//! std_multiplier = 2
+//! band = "upper"
+//! ```
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tesser_core::Candle;
+
+use crate::combinators::{Diff, Lag, RateOfChange};
+use crate::core::{BoxedIndicator, Indicator, IndicatorError};
+use crate::indicators::{
+    Adx, AdxOutput, AnchoredVwap, Atr, BollingerBands, DonchianChannels, Ema,
+    GarmanKlassVolatility, HalfLife, KeltnerChannels, Macd, MacdOutput, Obv, ParkinsonVolatility,
+    RollingVwap, Rsi, Sma, Stochastic, StochasticOutput, YangZhangVolatility, ZScore,
+};
+
+/// Candle-to-decimal indicator produced by [`IndicatorSpec::build`].
+pub type CandleFeature = BoxedIndicator<Candle, Decimal>;
+
+type Source = Option<Box<IndicatorSpec>>;
+
+/// Raw candle value used as an indicator input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CandleField {
+    /// Opening price.
+    Open,
+    /// Highest price.
+    High,
+    /// Lowest price.
+    Low,
+    /// Closing price.
+    Close,
+    /// Traded volume.
+    Volume,
+    /// Typical price, `(high + low + close) / 3`.
+    Typical,
+}
+
+impl CandleField {
+    /// Reads the field from a candle.
+    pub fn extract(self, candle: &Candle) -> Decimal {
+        match self {
+            Self::Open => candle.open,
+            Self::High => candle.high,
+            Self::Low => candle.low,
+            Self::Close => candle.close,
+            Self::Volume => candle.volume,
+            Self::Typical => (candle.high + candle.low + candle.close) / Decimal::from(3),
+        }
+    }
+}
+
+impl Indicator for CandleField {
+    type Input = Candle;
+    type Output = Decimal;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        Some(self.extract(&input))
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// Line selected from a channel or band indicator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Band {
+    /// Upper band.
+    Upper,
+    /// Midline.
+    #[default]
+    Middle,
+    /// Lower band.
+    Lower,
+}
+
+impl Band {
+    fn select(self, upper: Decimal, middle: Decimal, lower: Decimal) -> Decimal {
+        match self {
+            Self::Upper => upper,
+            Self::Middle => middle,
+            Self::Lower => lower,
+        }
+    }
+}
+
+/// Line selected from [`Macd`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MacdLine {
+    /// Fast EMA minus slow EMA.
+    #[default]
+    Macd,
+    /// EMA of the MACD line.
+    Signal,
+    /// MACD minus signal.
+    Histogram,
+}
+
+impl MacdLine {
+    fn select(self, output: MacdOutput) -> Decimal {
+        match self {
+            Self::Macd => output.macd,
+            Self::Signal => output.signal,
+            Self::Histogram => output.histogram,
+        }
+    }
+}
+
+/// Line selected from [`Adx`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdxLine {
+    /// Positive directional indicator.
+    PlusDi,
+    /// Negative directional indicator.
+    MinusDi,
+    /// Directional index of the current bar.
+    Dx,
+    /// Smoothed average directional index.
+    #[default]
+    Adx,
+}
+
+impl AdxLine {
+    fn select(self, output: AdxOutput) -> Decimal {
+        match self {
+            Self::PlusDi => output.plus_di,
+            Self::MinusDi => output.minus_di,
+            Self::Dx => output.dx,
+            Self::Adx => output.adx,
+        }
+    }
+}
+
+/// Line selected from [`Stochastic`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StochasticLine {
+    /// Smoothed %K.
+    #[default]
+    K,
+    /// %D signal line.
+    D,
+}
+
+impl StochasticLine {
+    fn select(self, output: StochasticOutput) -> Decimal {
+        match self {
+            Self::K => output.k,
+            Self::D => output.d,
+        }
+    }
+}
+
+fn default_stochastic_smoothing() -> usize {
+    3
+}
+
+/// Declarative description of a single-output indicator over candles.
+///
+/// The `kind` tag selects the indicator; the remaining keys mirror its constructor. Variants
+/// with a `source` accept any nested spec as their input series and default to the close.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum IndicatorSpec {
+    /// A raw candle field.
+    Field {
+        /// Field to read.
+        field: CandleField,
+    },
+    /// [`Sma`] of the source series.
+    Sma {
+        /// Window length.
+        period: usize,
+        /// Input series (defaults to the close).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Source,
+    },
+    /// [`Ema`] of the source series.
+    Ema {
+        /// Smoothing period.
+        period: usize,
+        /// Input series (defaults to the close).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Source,
+    },
+    /// [`Rsi`] of the source series.
+    Rsi {
+        /// Smoothing period.
+        period: usize,
+        /// Input series (defaults to the close).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Source,
+    },
+    /// One band of [`BollingerBands`] over the source series.
+    Bollinger {
+        /// Window length.
+        period: usize,
+        /// Standard deviation multiplier.
+        std_multiplier: Decimal,
+        /// Band to emit.
+        #[serde(default)]
+        band: Band,
+        /// Input series (defaults to the close).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Source,
+    },
+    /// One line of [`Macd`] over the source series.
+    Macd {
+        /// Fast EMA period.
+        fast_period: usize,
+        /// Slow EMA period.
+        slow_period: usize,
+        /// Signal EMA period.
+        signal_period: usize,
+        /// Line to emit.
+        #[serde(default)]
+        line: MacdLine,
+        /// Input series (defaults to the close).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Source,
+    },
+    /// Rolling [`ZScore`] of the source series.
+    ZScore {
+        /// Window length.
+        period: usize,
+        /// Input series (defaults to the close).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Source,
+    },
+    /// Mean-reversion [`HalfLife`] of the source series.
+    HalfLife {
+        /// Window length.
+        period: usize,
+        /// Input series (defaults to the close).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Source,
+    },
+    /// The source series delayed by `period` updates.
+    Lag {
+        /// Number of updates to delay.
+        period: usize,
+        /// Input series (defaults to the close).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Source,
+    },
+    /// Change of the source series over `period` updates.
+    Diff {
+        /// Number of updates to look back.
+        period: usize,
+        /// Input series (defaults to the close).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Source,
+    },
+    /// Percentage change of the source series over `period` updates.
+    Roc {
+        /// Number of updates to look back.
+        period: usize,
+        /// Input series (defaults to the close).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        source: Source,
+    },
+    /// [`Atr`] of the candles.
+    Atr {
+        /// Smoothing period.
+        period: usize,
+    },
+    /// [`Obv`] of the candles.
+    Obv,
+    /// [`RollingVwap`] of the candles.
+    RollingVwap {
+        /// Window length in bars.
+        period: usize,
+    },
+    /// [`AnchoredVwap`] that resets every `session_secs` seconds.
+    AnchoredVwap {
+        /// Session length in seconds (86400 anchors to UTC days).
+        session_secs: u64,
+    },
+    /// One line of [`Adx`].
+    Adx {
+        /// Smoothing period.
+        period: usize,
+        /// Line to emit.
+        #[serde(default)]
+        line: AdxLine,
+    },
+    /// One line of [`Stochastic`].
+    Stochastic {
+        /// High/low lookback.
+        k_period: usize,
+        /// %K smoothing (defaults to 3).
+        #[serde(default = "default_stochastic_smoothing")]
+        k_smoothing: usize,
+        /// %D period (defaults to 3).
+        #[serde(default = "default_stochastic_smoothing")]
+        d_period: usize,
+        /// Line to emit.
+        #[serde(default)]
+        line: StochasticLine,
+    },
+    /// One band of [`DonchianChannels`].
+    Donchian {
+        /// Window length.
+        period: usize,
+        /// Band to emit.
+        #[serde(default)]
+        band: Band,
+    },
+    /// One band of [`KeltnerChannels`].
+    Keltner {
+        /// Midline EMA period.
+        ema_period: usize,
+        /// ATR period.
+        atr_period: usize,
+        /// ATR multiplier.
+        atr_multiplier: Decimal,
+        /// Band to emit.
+        #[serde(default)]
+        band: Band,
+    },
+    /// [`ParkinsonVolatility`] of the candles.
+    Parkinson {
+        /// Window length.
+        period: usize,
+    },
+    /// [`GarmanKlassVolatility`] of the candles.
+    GarmanKlass {
+        /// Window length.
+        period: usize,
+    },
+    /// [`YangZhangVolatility`] of the candles.
+    YangZhang {
+        /// Window length.
+        period: usize,
+    },
+}
+
+impl IndicatorSpec {
+    /// Instantiates the described indicator, validating every parameter.
+    pub fn build(&self) -> Result<CandleFeature, IndicatorError> {
+        let feature = match self {
+            Self::Field { field } => field.boxed(),
+            Self::Sma { period, source } => on_source(source, Sma::new(*period)?)?,
+            Self::Ema { period, source } => on_source(source, Ema::new(*period)?)?,
+            Self::Rsi { period, source } => on_source(source, Rsi::new(*period)?)?,
+            Self::Bollinger {
+                period,
+                std_multiplier,
+                band,
+                source,
+            } => {
+                let band = *band;
+                let bands = BollingerBands::new(*period, *std_multiplier)?
+                    .map(move |out| band.select(out.upper, out.middle, out.lower));
+                on_source(source, bands)?
+            }
+            Self::Macd {
+                fast_period,
+                slow_period,
+                signal_period,
+                line,
+                source,
+            } => {
+                let line = *line;
+                let macd = Macd::new(*fast_period, *slow_period, *signal_period)?
+                    .map(move |out| line.select(out));
+                on_source(source, macd)?
+            }
+            Self::ZScore { period, source } => on_source(source, ZScore::new(*period)?)?,
+            Self::HalfLife { period, source } => on_source(source, HalfLife::new(*period)?)?,
+            Self::Lag { period, source } => on_source(source, Lag::new(*period)?)?,
+            Self::Diff { period, source } => on_source(source, Diff::new(*period)?)?,
+            Self::Roc { period, source } => on_source(source, RateOfChange::new(*period)?)?,
+            Self::Atr { period } => Atr::new(*period)?.boxed(),
+            Self::Obv => Obv::new().boxed(),
+            Self::RollingVwap { period } => RollingVwap::new(*period)?.boxed(),
+            Self::AnchoredVwap { session_secs } => {
+                AnchoredVwap::new(Duration::from_secs(*session_secs))?.boxed()
+            }
+            Self::Adx { period, line } => {
+                let line = *line;
+                Adx::new(*period)?.map(move |out| line.select(out)).boxed()
+            }
+            Self::Stochastic {
+                k_period,
+                k_smoothing,
+                d_period,
+                line,
+            } => {
+                let line = *line;
+                Stochastic::new(*k_period, *k_smoothing, *d_period)?
+                    .map(move |out| line.select(out))
+                    .boxed()
+            }
+            Self::Donchian { period, band } => {
+                let band = *band;
+                DonchianChannels::new(*period)?
+                    .map(move |out| band.select(out.upper, out.middle, out.lower))
+                    .boxed()
+            }
+            Self::Keltner {
+                ema_period,
+                atr_period,
+                atr_multiplier,
+                band,
+            } => {
+                let band = *band;
+                KeltnerChannels::new(*ema_period, *atr_period, *atr_multiplier)?
+                    .map(move |out| band.select(out.upper, out.middle, out.lower))
+                    .boxed()
+            }
+            Self::Parkinson { period } => ParkinsonVolatility::new(*period)?.boxed(),
+            Self::GarmanKlass { period } => GarmanKlassVolatility::new(*period)?.boxed(),
+            Self::YangZhang { period } => YangZhangVolatility::new(*period)?.boxed(),
+        };
+        Ok(feature)
+    }
+}
+
+/// Feeds the source series (the close by default) into a decimal indicator.
+fn on_source<T>(source: &Source, indicator: T) -> Result<CandleFeature, IndicatorError>
+where
+    T: Indicator<Input = Decimal, Output = Decimal> + Send + Sync + 'static,
+{
+    let source = match source {
+        Some(spec) => spec.build()?,
+        None => CandleField::Close.boxed(),
+    };
+    Ok(source.pipe(indicator).boxed())
+}
+
+/// Named indicator declaration, typically one entry of a `[[features]]` array.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct FeatureSpec {
+    /// Unique feature name.
+    pub name: String,
+    /// Indicator producing the feature.
+    #[serde(flatten)]
+    pub indicator: IndicatorSpec,
+}
+
+/// Ordered set of named features updated together from each candle.
+///
+/// As an [`Indicator`] it emits the feature vector in declaration order once every feature
+/// has warmed up.
+pub struct FeatureSet {
+    names: Vec<String>,
+    indicators: Vec<CandleFeature>,
+    latest: Vec<Option<Decimal>>,
+}
+
+impl FeatureSet {
+    /// Builds every declared feature, rejecting blank or duplicate names.
+    pub fn from_specs(specs: &[FeatureSpec]) -> Result<Self, IndicatorError> {
+        let mut seen = HashSet::with_capacity(specs.len());
+        let mut names = Vec::with_capacity(specs.len());
+        let mut indicators = Vec::with_capacity(specs.len());
+        for spec in specs {
+            let name = spec.name.trim();
+            if name.is_empty() {
+                return Err(IndicatorError::InvalidSpec(
+                    "feature names must not be empty".into(),
+                ));
+            }
+            if !seen.insert(name) {
+                return Err(IndicatorError::InvalidSpec(format!(
+                    "duplicate feature name '{name}'"
+                )));
+            }
+            names.push(name.to_string());
+            indicators.push(spec.indicator.build()?);
+        }
+        Ok(Self {
+            latest: vec![None; names.len()],
+            names,
+            indicators,
+        })
+    }
+
+    /// Feature names in declaration order.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Number of features.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` when no features are declared.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Latest value of a feature, if it has warmed up.
+    pub fn value(&self, name: &str) -> Option<Decimal> {
+        let idx = self.names.iter().position(|candidate| candidate == name)?;
+        self.latest[idx]
+    }
+}
+
+impl Indicator for FeatureSet {
+    type Input = Candle;
+    type Output = Vec<Decimal>;
+
+    fn next(&mut self, input: Self::Input) -> Option<Self::Output> {
+        for (indicator, latest) in self.indicators.iter_mut().zip(self.latest.iter_mut()) {
+            *latest = indicator.next(input.clone());
+        }
+        self.latest.iter().copied().collect()
+    }
+
+    fn reset(&mut self) {
+        for indicator in &mut self.indicators {
+            indicator.reset();
+        }
+        self.latest.fill(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::testing::candles;
+
+    #[derive(Deserialize)]
+    struct Config {
+        features: Vec<FeatureSpec>,
+    }
+
+    fn parse(toml_str: &str) -> Vec<FeatureSpec> {
+        toml::from_str::<Config>(toml_str).unwrap().features
+    }
+
+    #[test]
+    fn parses_nested_specs_from_toml() {
+        let specs = parse(
+            r#"
+            [[features]]
+            name = "rsi_z"
+            kind = "z_score"
+            period = 20
+            source = { kind = "rsi", period = 14 }
+
+            [[features]]
+            name = "bb_upper"
+            kind = "bollinger"
+            period = 20
+            std_multiplier = 2.5
+            band = "upper"
+
+            [[features]]
+            name = "stoch"
+            kind = "stochastic"
+            k_period = 14
+            "#,
+        );
+        assert_eq!(
+            specs[0].indicator,
+            IndicatorSpec::ZScore {
+                period: 20,
+                source: Some(Box::new(IndicatorSpec::Rsi {
+                    period: 14,
+                    source: None
+                })),
+            }
+        );
+        assert_eq!(
+            specs[1].indicator,
+            IndicatorSpec::Bollinger {
+                period: 20,
+                std_multiplier: Decimal::new(25, 1),
+                band: Band::Upper,
+                source: None,
+            }
+        );
+        assert_eq!(
+            specs[2].indicator,
+            IndicatorSpec::Stochastic {
+                k_period: 14,
+                k_smoothing: 3,
+                d_period: 3,
+                line: StochasticLine::K,
+            }
+        );
+        let round_trip: IndicatorSpec =
+            toml::from_str(&toml::to_string(&specs[0].indicator).unwrap()).unwrap();
+        assert_eq!(round_trip, specs[0].indicator);
+    }
+
+    #[test]
+    fn built_specs_match_static_indicators() {
+        let specs = parse(
+            r#"
+            [[features]]
+            name = "sma"
+            kind = "sma"
+            period = 3
+
+            [[features]]
+            name = "volume_roc"
+            kind = "roc"
+            period = 1
+            source = { kind = "field", field = "volume" }
+
+            [[features]]
+            name = "adx"
+            kind = "adx"
+            period = 2
+            "#,
+        );
+        let mut features = FeatureSet::from_specs(&specs).unwrap();
+        let mut sma = Sma::<Candle>::new(3).unwrap();
+        let mut adx = Adx::new(2).unwrap();
+        let rows: Vec<_> = (0..40)
+            .map(|step| {
+                let close = 100.0 + f64::from(step % 7) * 1.5 - f64::from(step % 4);
+                let volume = f64::from(1 + step % 5);
+                (close - 0.5, close + 2.0, close - 2.5, close, volume)
+            })
+            .collect();
+        let series = candles(&rows);
+        let mut prev_volume: Option<Decimal> = None;
+        for candle in series {
+            let expected_sma = sma.next(candle.clone());
+            let expected_adx = adx.next(candle.clone()).map(|out| out.adx);
+            let expected_roc = prev_volume
+                .filter(|prev| !prev.is_zero())
+                .map(|prev| Decimal::ONE_HUNDRED * (candle.volume - prev) / prev);
+            prev_volume = Some(candle.volume);
+
+            let vector = features.next(candle);
+            assert_eq!(features.value("sma"), expected_sma);
+            assert_eq!(features.value("adx"), expected_adx);
+            assert_eq!(features.value("volume_roc"), expected_roc);
+            let all_ready = expected_sma.is_some() && expected_adx.is_some();
+            assert_eq!(vector.is_some(), all_ready && expected_roc.is_some());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_feature_sets() {
+        let duplicate = parse(
+            r#"
+            [[features]]
+            name = "fast"
+            kind = "ema"
+            period = 5
+
+            [[features]]
+            name = "fast"
+            kind = "ema"
+            period = 8
+            "#,
+        );
+        assert!(matches!(
+            FeatureSet::from_specs(&duplicate),
+            Err(IndicatorError::InvalidSpec(_))
+        ));
+
+        let zero_period = parse(
+            r#"
+            [[features]]
+            name = "broken"
+            kind = "sma"
+            period = 0
+            "#,
+        );
+        assert_eq!(
+            FeatureSet::from_specs(&zero_period).err(),
+            Some(IndicatorError::invalid_period("SMA", 0))
+        );
+
+        let bars = candles(&[(1.0, 1.0, 1.0, 1.0, 1.0)]);
+        let mut empty = FeatureSet::from_specs(&[]).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.next(bars[0].clone()), Some(Vec::new()));
+    }
+}
@@ -259,6 +259,23 @@ impl Portfolio {
         Ok(())
     }
 
+    /// Overwrite the tracked position for `symbol` with an externally observed one.
+    ///
+    /// `None` or a flat position removes the local entry. Used when reconciliation adopts the
+    /// exchange view; cash is left untouched and must be adjusted through ledger entries.
+    pub fn replace_position(&mut self, symbol: Symbol, position: Option<Position>) {
+        let account = self.account_mut(symbol.exchange);
+        match position.filter(|position| !position.quantity.is_zero()) {
+            Some(position) => {
+                account.positions.insert(symbol, position);
+            }
+            None => {
+                account.positions.remove(&symbol);
+            }
+        }
+        self.update_drawdown_state();
+    }
+
     /// Retrieve a position snapshot for a symbol.
     #[must_use]
     pub fn position(&self, symbol: impl Into<Symbol>) -> Option<&Position> {
@@ -860,6 +877,25 @@ mod tests {
         assert!(portfolio.liquidate_only());
     }
 
+    #[test]
+    fn replace_position_overwrites_and_clears() {
+        let mut portfolio = Portfolio::new(PortfolioConfig::default(), sample_registry());
+        let buy = sample_fill(Side::Buy, Decimal::from(100), Decimal::from(2));
+        apply_with_ledger(&mut portfolio, &buy);
+        let remote = Position {
+            symbol: buy.symbol,
+            side: Some(Side::Sell),
+            quantity: Decimal::from(3),
+            entry_price: Some(Decimal::from(105)),
+            unrealized_pnl: Decimal::ZERO,
+            updated_at: Utc::now(),
+        };
+        portfolio.replace_position(buy.symbol, Some(remote));
+        assert_eq!(portfolio.signed_position_qty(buy.symbol), Decimal::from(-3));
+        portfolio.replace_position(buy.symbol, None);
+        assert!(portfolio.position(buy.symbol).is_none());
+    }
+
     fn apply_with_ledger(portfolio: &mut Portfolio, fill: &Fill) {
         let impact = portfolio.apply_fill_positions(fill).unwrap();
         let registry = sample_registry();
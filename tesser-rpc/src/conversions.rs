@@ -56,6 +56,7 @@ fn interval_to_proto(i: Interval) -> proto::Interval {
         Interval::OneHour => proto::Interval::Interval1h,
         Interval::FourHours => proto::Interval::Interval4h,
         Interval::OneDay => proto::Interval::Interval1d,
+        Interval::Custom { .. } => proto::Interval::Custom,
     }
 }
 
@@ -104,6 +105,7 @@ impl From<Candle> for proto::Candle {
             close: Some(to_decimal_proto(c.close)),
             volume: Some(to_decimal_proto(c.volume)),
             timestamp: Some(to_timestamp_proto(c.timestamp)),
+            interval_spec: c.interval.label(),
         }
     }
 }
@@ -159,6 +159,8 @@ fn build_book() -> OrderBook {
         timestamp: Utc::now(),
         exchange_checksum: None,
         local_checksum: None,
+        sequence: None,
+        prev_sequence: None,
     }
 }
 
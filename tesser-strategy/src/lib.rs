@@ -3,6 +3,7 @@
 extern crate self as tesser_strategy;
 
 use async_trait::async_trait;
+pub use tesser_core::bars::{Bar, BarBuilder, BarSpec};
 pub use tesser_strategy_macros::register_strategy;
 pub use toml::Value;
 
@@ -25,7 +26,8 @@ use tesser_core::{
 };
 use tesser_cortex::{CortexConfig, CortexDevice, CortexEngine, FeatureBuffer};
 use tesser_indicators::{
-    indicators::{Atr, BollingerBands, Ichimoku, IchimokuOutput, Macd, Rsi, Sma},
+    indicators::{Atr, BollingerBands, Ichimoku, IchimokuOutput, Macd, Rsi, Sma, ZScore},
+    spec::{FeatureSet, FeatureSpec},
     Indicator,
 };
 use tesser_markets::MarketRegistry;
@@ -81,6 +83,16 @@ impl StrategyContext {
         }
     }
 
+    /// Context with the same history size, market registry and positions but no market data,
+    /// for replaying buffered candles one at a time.
+    #[must_use]
+    pub fn empty_like(&self) -> Self {
+        let mut ctx = Self::new(self.max_history);
+        ctx.market_registry = self.market_registry.clone();
+        ctx.update_positions(self.positions.clone());
+        ctx
+    }
+
     fn push_with_capacity<T>(buffer: &mut VecDeque<T>, item: T, limit: usize) {
         if buffer.len() >= limit {
             buffer.pop_front();
@@ -231,6 +243,14 @@ pub trait Strategy: Send + Sync + Any {
         vec![self.symbol()]
     }
 
+    /// Number of historical candles per subscribed symbol needed before signals are meaningful.
+    ///
+    /// Live sessions replay this much history through [`Strategy::on_candle`] (discarding any
+    /// signals) before trading starts. Strategies that do not consume candles keep the default.
+    fn warmup_candles(&self) -> usize {
+        0
+    }
+
     /// Called once before the strategy is registered, allowing it to parse parameters.
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()>;
 
@@ -409,28 +429,6 @@ fn collect_symbol_closes(ctx: &StrategyContext, symbol: Symbol, limit: usize) ->
     values
 }
 
-fn z_score(values: &[Decimal]) -> Option<Decimal> {
-    if values.is_empty() {
-        return None;
-    }
-    let len = Decimal::from_usize(values.len())?;
-    let mean = values.iter().copied().sum::<Decimal>() / len;
-    let variance = values
-        .iter()
-        .map(|value| {
-            let diff = *value - mean;
-            diff * diff
-        })
-        .sum::<Decimal>()
-        / len;
-    let std = variance.sqrt()?;
-    if std.is_zero() {
-        return None;
-    }
-    let last = *values.last()?;
-    Some((last - mean) / std)
-}
-
 // -------------------------------------------------------------------------------------------------
 // Baseline Strategies
 // -------------------------------------------------------------------------------------------------
@@ -573,6 +571,10 @@ impl Strategy for SmaCross {
         self.cfg.symbol
     }
 
+    fn warmup_candles(&self) -> usize {
+        self.cfg.min_samples.max(self.cfg.slow_period + 1)
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
         let cfg = SmaCrossConfig::try_from(params)?;
         if cfg.fast_period == 0 || cfg.slow_period == 0 {
@@ -698,6 +700,10 @@ impl Strategy for RsiReversion {
         self.cfg.symbol
     }
 
+    fn warmup_candles(&self) -> usize {
+        self.cfg.lookback.max(self.cfg.period + 1)
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
         let cfg: RsiReversionConfig = params.try_into().map_err(|err: toml::de::Error| {
             StrategyError::InvalidConfig(format!("failed to parse RsiReversion config: {err}"))
@@ -832,6 +838,10 @@ impl Strategy for BollingerBreakout {
         self.cfg.symbol
     }
 
+    fn warmup_candles(&self) -> usize {
+        self.cfg.lookback.max(self.cfg.period)
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
         let cfg: BollingerBreakoutConfig = params.try_into().map_err(|err: toml::de::Error| {
             StrategyError::InvalidConfig(format!("failed to parse BollingerBreakout config: {err}"))
@@ -949,6 +959,10 @@ impl Strategy for MlClassifier {
         self.cfg.symbol
     }
 
+    fn warmup_candles(&self) -> usize {
+        self.cfg.lookback + 1
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
         let cfg: MlClassifierConfig = params.try_into().map_err(|err: toml::de::Error| {
             StrategyError::InvalidConfig(format!("failed to parse MlClassifier config: {err}"))
@@ -1019,6 +1033,8 @@ pub struct LstmCortexConfig {
     pub window: usize,
     pub feature_dim: usize,
     pub device: CortexDevice,
+    /// Indicator features fed to the model; the raw OHLCV bar is used when empty.
+    pub features: Vec<FeatureSpec>,
 }
 
 impl Default for LstmCortexConfig {
@@ -1030,6 +1046,7 @@ impl Default for LstmCortexConfig {
             window: 10,
             feature_dim: 5,
             device: CortexDevice::default(),
+            features: Vec::new(),
         }
     }
 }
@@ -1049,6 +1066,7 @@ pub struct LstmCortex {
     cfg: LstmCortexConfig,
     engine: Option<CortexEngine>,
     features: FeatureBuffer,
+    indicator_features: Option<FeatureSet>,
     signals: Vec<Signal>,
 }
 
@@ -1064,6 +1082,7 @@ impl LstmCortex {
             features: FeatureBuffer::new(cfg.window, cfg.feature_dim),
             cfg,
             engine: None,
+            indicator_features: None,
             signals: Vec::new(),
         }
     }
@@ -1079,13 +1098,32 @@ impl LstmCortex {
         Ok(())
     }
 
+    fn ensure_indicator_features(&mut self) -> StrategyResult<()> {
+        if self.indicator_features.is_none() && !self.cfg.features.is_empty() {
+            let features = FeatureSet::from_specs(&self.cfg.features)
+                .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?;
+            self.indicator_features = Some(features);
+        }
+        Ok(())
+    }
+
     fn push_features(&mut self, features: &[f32]) -> StrategyResult<()> {
         self.features
             .push(features)
             .map_err(|err| StrategyError::Internal(err.to_string()))
     }
 
-    fn extract_features(&self, candle: &Candle) -> Vec<f32> {
+    /// Returns `None` while configured indicator features are still warming up.
+    fn extract_features(&mut self, candle: &Candle) -> Option<Vec<f32>> {
+        if let Some(indicators) = self.indicator_features.as_mut() {
+            let values = indicators.next(candle.clone())?;
+            return Some(
+                values
+                    .iter()
+                    .map(|value| value.to_f32().unwrap_or_default())
+                    .collect(),
+            );
+        }
         let mut feats = vec![0.0; self.cfg.feature_dim];
         if self.cfg.feature_dim >= 5 {
             feats[0] = candle.open.to_f32().unwrap_or_default();
@@ -1094,7 +1132,7 @@ impl LstmCortex {
             feats[3] = candle.close.to_f32().unwrap_or_default();
             feats[4] = candle.volume.to_f32().unwrap_or_default();
         }
-        feats
+        Some(feats)
     }
 }
 
@@ -1108,18 +1146,36 @@ impl Strategy for LstmCortex {
         self.cfg.symbol
     }
 
+    fn warmup_candles(&self) -> usize {
+        self.cfg.window
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
         let cfg: LstmCortexConfig = params.try_into().map_err(|err: toml::de::Error| {
             StrategyError::InvalidConfig(format!("failed to parse LstmCortex config: {err}"))
         })?;
-        if cfg.window == 0 || cfg.feature_dim < 5 {
+        if cfg.window == 0 {
+            return Err(StrategyError::InvalidConfig(
+                "window must be positive".into(),
+            ));
+        }
+        if cfg.features.is_empty() && cfg.feature_dim < 5 {
             return Err(StrategyError::InvalidConfig(
-                "window must be positive and feature_dim >= 5".into(),
+                "feature_dim must be >= 5 when using raw OHLCV features".into(),
             ));
         }
+        if !cfg.features.is_empty() && cfg.feature_dim != cfg.features.len() {
+            return Err(StrategyError::InvalidConfig(format!(
+                "feature_dim ({}) must match the number of declared features ({})",
+                cfg.feature_dim,
+                cfg.features.len()
+            )));
+        }
         self.features = FeatureBuffer::new(cfg.window, cfg.feature_dim);
         self.engine = None;
+        self.indicator_features = None;
         self.cfg = cfg;
+        self.ensure_indicator_features()?;
         self.ensure_engine()
     }
 
@@ -1132,7 +1188,10 @@ impl Strategy for LstmCortex {
             return Ok(());
         }
         self.ensure_engine()?;
-        let features = self.extract_features(candle);
+        self.ensure_indicator_features()?;
+        let Some(features) = self.extract_features(candle) else {
+            return Ok(());
+        };
         self.push_features(&features)?;
         if let Some(engine) = &mut self.engine {
             match engine.predict(&self.features) {
@@ -1272,6 +1331,9 @@ impl Default for PairsTradingConfig {
 pub struct PairsTradingArbitrage {
     cfg: PairsTradingConfig,
     signals: Vec<Signal>,
+    spread_z: ZScore,
+    /// Latest close per leg; a spread is sampled once both legs share a bar timestamp.
+    latest_closes: [Option<(DateTime<Utc>, Decimal)>; 2],
     entry_z_level: Decimal,
     default_exit_strategy: ExitStrategy,
     active_trades: HashMap<Uuid, ManagedPairTrade>,
@@ -1288,9 +1350,13 @@ impl Default for PairsTradingArbitrage {
 impl PairsTradingArbitrage {
     fn from_config(cfg: PairsTradingConfig) -> StrategyResult<Self> {
         Self::validate_symbols(&cfg)?;
+        let spread_z = ZScore::new(cfg.lookback)
+            .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?;
         let mut strategy = Self {
             cfg,
             signals: Vec::new(),
+            spread_z,
+            latest_closes: [None; 2],
             entry_z_level: Decimal::ZERO,
             default_exit_strategy: ExitStrategy::StandardZScore {
                 exit_z: Decimal::new(5, 1),
@@ -1374,20 +1440,27 @@ impl PairsTradingArbitrage {
         Ok(())
     }
 
-    fn spreads(&self, ctx: &StrategyContext) -> Option<Vec<Decimal>> {
-        let closes_a = collect_symbol_closes(ctx, self.cfg.symbols[0], self.cfg.lookback);
-        let closes_b = collect_symbol_closes(ctx, self.cfg.symbols[1], self.cfg.lookback);
-        if closes_a.len() < self.cfg.lookback || closes_b.len() < self.cfg.lookback {
+    /// Record a leg's close and, once both legs closed the same bar, feed the log spread into
+    /// the rolling z-score.
+    fn update_spread(&mut self, candle: &Candle) -> Option<Decimal> {
+        let leg = self
+            .cfg
+            .symbols
+            .iter()
+            .position(|symbol| *symbol == candle.symbol)?;
+        self.latest_closes[leg] = Some((candle.timestamp, candle.close));
+        let [Some((ts_a, close_a)), Some((ts_b, close_b))] = self.latest_closes else {
+            return None;
+        };
+        if ts_a != ts_b {
             return None;
         }
-        let mut spreads = Vec::with_capacity(self.cfg.lookback);
-        for (a, b) in closes_a.iter().zip(closes_b.iter()) {
-            if b.is_zero() {
-                return None;
-            }
-            spreads.push((*a / *b).ln());
+        self.latest_closes = [None; 2];
+        if close_b.is_zero() {
+            return None;
         }
-        Some(spreads)
+        let spread = (close_a / close_b).checked_ln()?;
+        self.spread_z.next(spread)
     }
 
     fn manual_clip(&self, ctx: &StrategyContext) -> Option<Decimal> {
@@ -1545,6 +1618,10 @@ impl Strategy for PairsTradingArbitrage {
         self.cfg.symbols.to_vec()
     }
 
+    fn warmup_candles(&self) -> usize {
+        self.cfg.lookback
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
         let cfg: PairsTradingConfig = params.try_into().map_err(|err: toml::de::Error| {
             StrategyError::InvalidConfig(format!(
@@ -1557,6 +1634,9 @@ impl Strategy for PairsTradingArbitrage {
             ));
         }
         Self::validate_symbols(&cfg)?;
+        self.spread_z = ZScore::new(cfg.lookback)
+            .map_err(|err| StrategyError::InvalidConfig(err.to_string()))?;
+        self.latest_closes = [None; 2];
         self.cfg = cfg;
         self.rebuild_thresholds()?;
         self.active_trades.clear();
@@ -1573,16 +1653,14 @@ impl Strategy for PairsTradingArbitrage {
             return Ok(());
         }
         self.candle_counter = self.candle_counter.saturating_add(1);
-        if let Some(spreads) = self.spreads(ctx) {
-            if let Some(z) = z_score(&spreads) {
-                tracing::info!(target: "strategy", %z, "pairs-trading z-score");
-                let now = candle.timestamp;
-                self.evaluate_trades(ctx, z, now);
-                if self.should_open_short(z) {
-                    self.open_trade(ctx, z, now, PairTradeDirection::ShortFirst);
-                } else if self.should_open_long(z) {
-                    self.open_trade(ctx, z, now, PairTradeDirection::LongFirst);
-                }
+        if let Some(z) = self.update_spread(candle) {
+            tracing::info!(target: "strategy", %z, "pairs-trading z-score");
+            let now = candle.timestamp;
+            self.evaluate_trades(ctx, z, now);
+            if self.should_open_short(z) {
+                self.open_trade(ctx, z, now, PairTradeDirection::ShortFirst);
+            } else if self.should_open_long(z) {
+                self.open_trade(ctx, z, now, PairTradeDirection::LongFirst);
             }
         }
         Ok(())
@@ -2124,6 +2202,10 @@ impl Strategy for VolatilitySkew {
         vec![self.cfg.underlying, self.cfg.vol_symbol]
     }
 
+    fn warmup_candles(&self) -> usize {
+        self.cfg.atr_period + 1
+    }
+
     fn configure(&mut self, params: toml::Value) -> StrategyResult<()> {
         let cfg: VolatilitySkewConfig = params.try_into().map_err(|err: toml::de::Error| {
             StrategyError::InvalidConfig(format!("failed to parse VolatilitySkew config: {err}"))